@@ -0,0 +1,55 @@
+//! Basis points, hundredths of a percent: 10_000 bps are 100%.
+
+use crate::math;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+/// 100%, the most a fee or any other share of an amount can be.
+pub const MAX_BPS: u16 = 10_000;
+
+/// `bps` of `amount`, rounded down. Never `None` for a share, i.e. `bps <= MAX_BPS`.
+pub fn apply(amount: u64, bps: u16) -> Option<u64> {
+    math::mul_div(amount, bps as u64, BPS_DENOMINATOR)
+}
+
+/// `bps` of `amount`, rounded up. Never `None` for a share, i.e. `bps <= MAX_BPS`.
+pub fn apply_ceil(amount: u64, bps: u16) -> Option<u64> {
+    math::mul_div_ceil(amount, bps as u64, BPS_DENOMINATOR)
+}
+
+/// Whether `bps` is a share of something, between 0% and 100%.
+pub fn is_share(bps: u16) -> bool {
+    bps <= MAX_BPS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_shares() {
+        assert_eq!(apply(1_000, 250), Some(25));
+        assert_eq!(apply(1_000, MAX_BPS), Some(1_000));
+        assert_eq!(apply(1_000, 0), Some(0));
+        assert_eq!(apply(u64::MAX, MAX_BPS), Some(u64::MAX));
+    }
+
+    #[test]
+    fn rounds_as_named() {
+        assert_eq!(apply(99, 100), Some(0));
+        assert_eq!(apply_ceil(99, 100), Some(1));
+        assert_eq!(apply_ceil(100, 100), Some(1));
+    }
+
+    #[test]
+    fn multipliers_above_100_percent_can_overflow() {
+        assert_eq!(apply(1_000, 15_000), Some(1_500));
+        assert_eq!(apply(u64::MAX, 20_000), None);
+    }
+
+    #[test]
+    fn shares_end_at_100_percent() {
+        assert!(is_share(0));
+        assert!(is_share(MAX_BPS));
+        assert!(!is_share(MAX_BPS + 1));
+    }
+}
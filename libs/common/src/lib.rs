@@ -0,0 +1,8 @@
+//! Pieces that kept being copied from one example program to the next.
+//!
+//! Everything here is plain Rust without Anchor, so failures are `None`s and `false`s that
+//! each program turns into its own error codes.
+
+pub mod bps;
+pub mod math;
+pub mod time;
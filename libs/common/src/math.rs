@@ -0,0 +1,44 @@
+//! Checked integer math on token amounts.
+
+/// `a * b / c`, rounded down. The product is computed in a `u128`, so only a result that
+/// doesn't fit into a `u64`, or a zero `c`, gives `None`.
+pub fn mul_div(a: u64, b: u64, c: u64) -> Option<u64> {
+    (a as u128 * b as u128)
+        .checked_div(c as u128)
+        .and_then(|result| u64::try_from(result).ok())
+}
+
+/// `a * b / c`, rounded up.
+pub fn mul_div_ceil(a: u64, b: u64, c: u64) -> Option<u64> {
+    if c == 0 {
+        return None;
+    }
+    u64::try_from((a as u128 * b as u128).div_ceil(c as u128)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_as_named() {
+        assert_eq!(mul_div(10, 2, 3), Some(6));
+        assert_eq!(mul_div_ceil(10, 2, 3), Some(7));
+        assert_eq!(mul_div(10, 3, 3), Some(10));
+        assert_eq!(mul_div_ceil(10, 3, 3), Some(10));
+    }
+
+    #[test]
+    fn intermediate_product_does_not_overflow() {
+        assert_eq!(mul_div(u64::MAX, u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(mul_div_ceil(u64::MAX, 3, 4), Some(u64::MAX / 4 * 3 + 3));
+    }
+
+    #[test]
+    fn fails_instead_of_truncating() {
+        assert_eq!(mul_div(u64::MAX, 2, 1), None);
+        assert_eq!(mul_div_ceil(u64::MAX, 2, 1), None);
+        assert_eq!(mul_div(1, 1, 0), None);
+        assert_eq!(mul_div_ceil(1, 1, 0), None);
+    }
+}
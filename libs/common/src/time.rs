@@ -0,0 +1,45 @@
+//! Checks of unix timestamps against the windows programs open and close.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    NotStarted,
+    Active,
+    Ended,
+}
+
+/// Where `now` falls in the window from `start` (inclusive) to `end` (exclusive).
+pub fn phase(now: i64, start: i64, end: i64) -> Phase {
+    if now < start {
+        Phase::NotStarted
+    } else if has_ended(now, end) {
+        Phase::Ended
+    } else {
+        Phase::Active
+    }
+}
+
+/// Whether a window closing at `end` is over. `end` itself already belongs to the next phase.
+pub fn has_ended(now: i64, end: i64) -> bool {
+    now >= end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_is_inclusive_end_is_exclusive() {
+        assert_eq!(phase(99, 100, 200), Phase::NotStarted);
+        assert_eq!(phase(100, 100, 200), Phase::Active);
+        assert_eq!(phase(199, 100, 200), Phase::Active);
+        assert_eq!(phase(200, 100, 200), Phase::Ended);
+        assert!(!has_ended(199, 200));
+        assert!(has_ended(200, 200));
+    }
+
+    #[test]
+    fn empty_window_is_never_active() {
+        assert_eq!(phase(99, 100, 100), Phase::NotStarted);
+        assert_eq!(phase(100, 100, 100), Phase::Ended);
+    }
+}
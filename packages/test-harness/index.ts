@@ -0,0 +1,248 @@
+import { AnchorError, BN, Idl, Program } from '@coral-xyz/anchor';
+import { BankrunProvider } from 'anchor-bankrun';
+import { AccountInfoBytes, Clock, ProgramTestContext, startAnchor } from 'solana-bankrun';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  ACCOUNT_SIZE,
+  Account,
+  AccountLayout,
+  MINT_SIZE,
+  Mint,
+  MintLayout,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  unpackAccount,
+  unpackMint,
+} from '@solana/spl-token';
+import { assert, expect } from 'chai';
+import { readFileSync } from 'fs';
+import { join, resolve } from 'path';
+
+// the root of the workspace, where Anchor.toml and target/ are
+const ROOT = resolve(__dirname, '../..');
+
+async function warp(context: ProgramTestContext, slots: number, seconds: number) {
+  const clock = await context.banksClient.getClock();
+  const slot = clock.slot + BigInt(slots);
+  context.warpToSlot(slot);
+  // the bank would derive the time from the slot, which ignores earlier warps
+  context.setClock(
+    new Clock(slot, clock.epochStartTimestamp, clock.epoch, clock.leaderScheduleEpoch, clock.unixTimestamp + BigInt(seconds))
+  );
+}
+
+// Moves on to the next slot after every transaction, as a validator would. Each slot comes with
+// a new blockhash, so sending the same transaction twice, e.g. a move that failed because it
+// wasn't allowed yet, isn't rejected as already processed the second time.
+class SlotAdvancingProvider extends BankrunProvider {
+  async sendAndConfirm(...args: Parameters<BankrunProvider['sendAndConfirm']>) {
+    try {
+      return await super.sendAndConfirm(...args);
+    } finally {
+      await warp(this.context, 1, 0);
+    }
+  }
+}
+
+/** The program derived address of `seeds`, without its bump. */
+export function findAddress(seeds: (Buffer | Uint8Array)[], programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(seeds, programId)[0];
+}
+
+/**
+ * Expects `promise` to fail with the Anchor error `code`, and with its `number` too if one is
+ * given. Returns the error for further checks, e.g. of `err.program`.
+ */
+export async function expectError(promise: Promise<unknown>, code: string, number?: number): Promise<AnchorError> {
+  try {
+    await promise;
+  } catch (_err) {
+    expect(_err).to.be.instanceOf(AnchorError);
+    const err: AnchorError = _err;
+    expect(err.error.errorCode.code).to.equal(code);
+    if (number !== undefined) {
+      expect(err.error.errorCode.number).to.equal(number);
+    }
+    return err;
+  }
+  assert.fail("should've failed but didn't");
+}
+
+/**
+ * A bank with every program of the workspace deployed, as `anchor build` left them in target/deploy.
+ *
+ * Nothing runs a validator: transactions are processed in-process, accounts can be written
+ * directly and the clock only moves when a test says so. Start a new one per `describe` so tests
+ * don't share state.
+ */
+export class Harness {
+  private constructor(readonly context: ProgramTestContext, readonly provider: BankrunProvider) {}
+
+  static async start(): Promise<Harness> {
+    const context = await startAnchor(ROOT, [], []);
+    return new Harness(context, new SlotAdvancingProvider(context));
+  }
+
+  /** Pays for the transactions of `provider`, with plenty of SOL. */
+  get payer(): Keypair {
+    return this.context.payer;
+  }
+
+  /** A client for a program of the workspace, e.g. `harness.program<Escrow>('escrow')`. */
+  program<T extends Idl>(name: string): Program<T> {
+    const idl = JSON.parse(readFileSync(join(ROOT, 'target', 'idl', `${name}.json`), 'utf8'));
+    return new Program<T>(idl, idl.metadata.address, this.provider);
+  }
+
+  // clock
+
+  /** The unix timestamp programs see in `Clock::get()`. */
+  async now(): Promise<number> {
+    return Number((await this.context.banksClient.getClock()).unixTimestamp);
+  }
+
+  /** Moves the clock `seconds` ahead. */
+  async warp(seconds: number) {
+    await warp(this.context, 1, seconds);
+  }
+
+  /** Moves `slots` slots ahead, leaving the timestamp alone. */
+  async warpSlots(slots: number) {
+    await warp(this.context, slots, 0);
+  }
+
+  // system accounts
+
+  /** Sets the balance of `address`, creating a system account if there's none. */
+  async fund(address: PublicKey, lamports = 10 * LAMPORTS_PER_SOL) {
+    const account = await this.context.banksClient.getAccount(address);
+    this.context.setAccount(address, {
+      ...(account ?? { data: new Uint8Array(), owner: SystemProgram.programId, executable: false }),
+      lamports,
+    });
+  }
+
+  /** A new keypair with `lamports` in its account. */
+  async fundedKeypair(lamports = 10 * LAMPORTS_PER_SOL): Promise<Keypair> {
+    const keypair = Keypair.generate();
+    await this.fund(keypair.publicKey, lamports);
+    return keypair;
+  }
+
+  /** The account at `address`, or `null` if there is none, e.g. because it was closed. */
+  async account(address: PublicKey): Promise<AccountInfoBytes | null> {
+    return this.context.banksClient.getAccount(address);
+  }
+
+  /** The balance of `address`, 0 if the account doesn't exist. */
+  async lamports(address: PublicKey): Promise<number> {
+    return Number((await this.account(address))?.lamports ?? 0);
+  }
+
+  async rentExemption(size: number): Promise<number> {
+    return Number((await this.context.banksClient.getRent()).minimumBalance(BigInt(size)));
+  }
+
+  // tokens
+  //
+  // Fixtures are written straight into the bank instead of going through the token program,
+  // which saves a transaction per mint, account and `mintTo`.
+
+  /** A new mint, without a freeze authority. */
+  async createMint(authority: PublicKey, decimals = 0): Promise<PublicKey> {
+    const mint = Keypair.generate().publicKey;
+    const data = Buffer.alloc(MINT_SIZE);
+    MintLayout.encode(
+      {
+        mintAuthorityOption: 1,
+        mintAuthority: authority,
+        supply: BigInt(0),
+        decimals,
+        isInitialized: true,
+        freezeAuthorityOption: 0,
+        freezeAuthority: PublicKey.default,
+      },
+      data
+    );
+    await this.setTokenProgramAccount(mint, data);
+    return mint;
+  }
+
+  /**
+   * Creates a token account of `owner` for `mint`, holding `amount` fresh tokens. It is the
+   * owner's associated token account, unless another `address` is given.
+   */
+  async createTokenAccount(
+    mint: PublicKey,
+    owner: PublicKey,
+    amount: number | BN = 0,
+    address = getAssociatedTokenAddressSync(mint, owner, true)
+  ): Promise<PublicKey> {
+    const data = Buffer.alloc(ACCOUNT_SIZE);
+    AccountLayout.encode(
+      {
+        mint,
+        owner,
+        amount: BigInt(0),
+        delegateOption: 0,
+        delegate: PublicKey.default,
+        state: 1, // initialized
+        isNativeOption: 0,
+        isNative: BigInt(0),
+        delegatedAmount: BigInt(0),
+        closeAuthorityOption: 0,
+        closeAuthority: PublicKey.default,
+      },
+      data
+    );
+    await this.setTokenProgramAccount(address, data);
+    await this.mintTo(address, amount);
+    return address;
+  }
+
+  /** Adds `amount` fresh tokens to `tokenAccount`, and to the supply of its mint. */
+  async mintTo(tokenAccount: PublicKey, amount: number | BN) {
+    const account = AccountLayout.decode((await this.account(tokenAccount)).data);
+    account.amount += BigInt(amount.toString());
+    const accountData = Buffer.alloc(ACCOUNT_SIZE);
+    AccountLayout.encode(account, accountData);
+    await this.setTokenProgramAccount(tokenAccount, accountData);
+
+    const mint = MintLayout.decode((await this.account(account.mint)).data);
+    mint.supply += BigInt(amount.toString());
+    const mintData = Buffer.alloc(MINT_SIZE);
+    MintLayout.encode(mint, mintData);
+    await this.setTokenProgramAccount(account.mint, mintData);
+  }
+
+  /** A token account, decoded like `getAccount` of @solana/spl-token does. */
+  async tokenAccount(address: PublicKey): Promise<Account> {
+    const account = await this.account(address);
+    return unpackAccount(address, account && { ...account, data: Buffer.from(account.data) });
+  }
+
+  /** The amount held by a token account. */
+  async tokenBalance(tokenAccount: PublicKey): Promise<number> {
+    return Number((await this.tokenAccount(tokenAccount)).amount);
+  }
+
+  /** A mint, decoded like `getMint` of @solana/spl-token does. */
+  async mint(address: PublicKey): Promise<Mint> {
+    const account = await this.account(address);
+    return unpackMint(address, account && { ...account, data: Buffer.from(account.data) });
+  }
+
+  /** The supply of a mint. */
+  async tokenSupply(mint: PublicKey): Promise<number> {
+    return Number((await this.mint(mint)).supply);
+  }
+
+  private async setTokenProgramAccount(address: PublicKey, data: Buffer) {
+    this.context.setAccount(address, {
+      data,
+      owner: TOKEN_PROGRAM_ID,
+      executable: false,
+      lamports: await this.rentExemption(data.length),
+    });
+  }
+}
@@ -0,0 +1,36 @@
+use crate::state::vault::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn initialize_vault(ctx: Context<InitializeVault>, funding: u64) -> Result<()> {
+    ctx.accounts.vault.set_inner(Vault {
+        admin: ctx.accounts.admin.key(),
+        bump: ctx.bumps.vault,
+    });
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        funding,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeVault<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Vault::INIT_SPACE,
+        seeds = [Vault::SEED_PREFIX, admin.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
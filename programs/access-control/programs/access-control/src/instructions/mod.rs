@@ -0,0 +1,11 @@
+pub use initialize_vault::*;
+pub use withdraw_access_control::*;
+pub use withdraw_constraint::*;
+pub use withdraw_has_one::*;
+pub use withdraw_signer_only::*;
+
+pub mod initialize_vault;
+pub mod withdraw_access_control;
+pub mod withdraw_constraint;
+pub mod withdraw_has_one;
+pub mod withdraw_signer_only;
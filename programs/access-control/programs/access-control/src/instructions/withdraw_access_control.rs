@@ -0,0 +1,37 @@
+use crate::errors::AccessControlError;
+use crate::state::vault::*;
+use anchor_lang::prelude::*;
+
+/// The check `#[access_control]` runs before `withdraw_access_control`. Unlike constraints,
+/// it is plain Rust that can be shared between instructions and tested on its own, but it
+/// only guards the instructions that remember to name it.
+pub fn only_admin(ctx: &Context<WithdrawAccessControl>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.admin.key(),
+        ctx.accounts.vault.admin,
+        AccessControlError::Unauthorized
+    );
+    Ok(())
+}
+
+pub fn withdraw_access_control(ctx: Context<WithdrawAccessControl>, amount: u64) -> Result<()> {
+    Vault::withdraw(
+        &ctx.accounts.vault,
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAccessControl<'info> {
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, vault.admin.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    pub admin: Signer<'info>,
+    /// CHECK: only receives lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
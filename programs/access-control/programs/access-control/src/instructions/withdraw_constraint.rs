@@ -0,0 +1,29 @@
+use crate::errors::AccessControlError;
+use crate::state::vault::*;
+use anchor_lang::prelude::*;
+
+// Both halves: `Signer` for the signature, the constraint for whose signature it is. A
+// constraint is any boolean expression over the accounts and instruction arguments, so it
+// also fits checks that `has_one` can't express.
+pub fn withdraw_constraint(ctx: Context<WithdrawConstraint>, amount: u64) -> Result<()> {
+    Vault::withdraw(
+        &ctx.accounts.vault,
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawConstraint<'info> {
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, vault.admin.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(constraint = admin.key() == vault.admin @ AccessControlError::Unauthorized)]
+    pub admin: Signer<'info>,
+    /// CHECK: only receives lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
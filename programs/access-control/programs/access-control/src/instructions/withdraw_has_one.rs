@@ -0,0 +1,29 @@
+use crate::errors::AccessControlError;
+use crate::state::vault::*;
+use anchor_lang::prelude::*;
+
+// BROKEN: `has_one` proves that `admin` is the key stored in `vault.admin`, but that key is
+// public. Without `Signer`, anyone can pass it along without its signature.
+pub fn withdraw_has_one(ctx: Context<WithdrawHasOne>, amount: u64) -> Result<()> {
+    Vault::withdraw(
+        &ctx.accounts.vault,
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawHasOne<'info> {
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, vault.admin.as_ref()],
+        bump = vault.bump,
+        has_one = admin @ AccessControlError::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: deliberately not a `Signer`, see above
+    pub admin: UncheckedAccount<'info>,
+    /// CHECK: only receives lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
@@ -0,0 +1,26 @@
+use crate::state::vault::*;
+use anchor_lang::prelude::*;
+
+// BROKEN: `Signer` proves that whoever is passed as `admin` signed the transaction, but
+// nothing ties that account to `vault.admin`. Any wallet can sign for itself.
+pub fn withdraw_signer_only(ctx: Context<WithdrawSignerOnly>, amount: u64) -> Result<()> {
+    Vault::withdraw(
+        &ctx.accounts.vault,
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSignerOnly<'info> {
+    #[account(
+        mut,
+        seeds = [Vault::SEED_PREFIX, vault.admin.as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+    pub admin: Signer<'info>,
+    /// CHECK: only receives lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
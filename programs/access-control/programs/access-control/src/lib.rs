@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("HoySeoNUxJz8Jc5MAeJLFyHKVM5fhDV2fQfZNTe8qseU");
+
+/// The same guarded action, withdrawing from a vault that only its admin may empty,
+/// implemented four ways. The first two are broken on purpose, see the tests for how.
+#[program]
+pub mod access_control {
+    use super::*;
+
+    pub fn initialize_vault(ctx: Context<InitializeVault>, funding: u64) -> Result<()> {
+        instructions::initialize_vault::initialize_vault(ctx, funding)
+    }
+
+    pub fn withdraw_signer_only(ctx: Context<WithdrawSignerOnly>, amount: u64) -> Result<()> {
+        instructions::withdraw_signer_only::withdraw_signer_only(ctx, amount)
+    }
+
+    pub fn withdraw_has_one(ctx: Context<WithdrawHasOne>, amount: u64) -> Result<()> {
+        instructions::withdraw_has_one::withdraw_has_one(ctx, amount)
+    }
+
+    pub fn withdraw_constraint(ctx: Context<WithdrawConstraint>, amount: u64) -> Result<()> {
+        instructions::withdraw_constraint::withdraw_constraint(ctx, amount)
+    }
+
+    // runs after the accounts are deserialized and before the handler
+    #[access_control(instructions::withdraw_access_control::only_admin(&ctx))]
+    pub fn withdraw_access_control(ctx: Context<WithdrawAccessControl>, amount: u64) -> Result<()> {
+        instructions::withdraw_access_control::withdraw_access_control(ctx, amount)
+    }
+}
@@ -0,0 +1,31 @@
+use crate::errors::AccessControlError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    pub admin: Pubkey,
+    pub bump: u8,
+}
+
+impl Vault {
+    pub const SEED_PREFIX: &'static [u8] = b"vault";
+
+    /// Moves `amount` out of the vault, which has to stay rent exempt. Who may call this is
+    /// up to the instructions.
+    pub fn withdraw<'info>(
+        vault: &Account<'info, Vault>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let reserve = Rent::get()?.minimum_balance(8 + Vault::INIT_SPACE);
+        require_gte!(
+            vault.get_lamports().saturating_sub(reserve),
+            amount,
+            AccessControlError::InsufficientFunds
+        );
+        vault.sub_lamports(amount)?;
+        to.add_lamports(amount)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, Transaction, TransactionInstruction } from '@solana/web3.js';
+import { Harness, expectError } from 'test-harness';
+import { AccessControl } from '../../../target/types/access_control';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('access-control', () => {
+  let harness: Harness;
+  let program: Program<AccessControl>;
+  let admin: Keypair;
+  let vault: PublicKey;
+
+  const attacker = Keypair.generate();
+  const AMOUNT = new BN(LAMPORTS_PER_SOL / 10);
+
+  const withdrawals = {
+    signerOnly: () => program.methods.withdrawSignerOnly(AMOUNT),
+    hasOne: () => program.methods.withdrawHasOne(AMOUNT),
+    constraint: () => program.methods.withdrawConstraint(AMOUNT),
+    accessControl: () => program.methods.withdrawAccessControl(AMOUNT),
+  };
+
+  // Sends a withdrawal to the attacker, with `adminKey` as the admin and its signature only if
+  // `signer` is set. The client would add or ask for signatures following the IDL, so the
+  // instruction is patched to what an attacker would send instead.
+  async function withdrawTo(withdraw: keyof typeof withdrawals, adminKey: PublicKey, signer?: Keypair) {
+    const ix: TransactionInstruction = await withdrawals[withdraw]()
+      .accounts({ vault, admin: adminKey, recipient: attacker.publicKey })
+      .instruction();
+    // the accounts are in the order of the struct: vault, admin, recipient
+    ix.keys[1].isSigner = signer !== undefined;
+    try {
+      await harness.provider.sendAndConfirm(new Transaction().add(ix), signer ? [signer] : []);
+    } catch (err) {
+      throw AnchorError.parse(err.logs) ?? err;
+    }
+  }
+
+  // attack 1: the attacker signs, passing themselves as the admin
+  const withAttackerSignature = (withdraw: keyof typeof withdrawals) =>
+    withdrawTo(withdraw, attacker.publicKey, attacker);
+
+  // attack 2: the attacker passes the real admin, without their signature
+  const withoutAdminSignature = (withdraw: keyof typeof withdrawals) => withdrawTo(withdraw, admin.publicKey);
+
+  async function stolen(attack: Promise<void>) {
+    const before = await harness.lamports(attacker.publicKey);
+    await attack;
+    return (await harness.lamports(attacker.publicKey)) - before;
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<AccessControl>('access_control');
+    admin = harness.payer;
+    [vault] = PublicKey.findProgramAddressSync([Buffer.from('vault'), admin.publicKey.toBuffer()], program.programId);
+
+    await program.methods
+      .initializeVault(new BN(LAMPORTS_PER_SOL))
+      .accounts({ vault, admin: admin.publicKey })
+      .rpc();
+    // the attacker's account has to exist to receive less than its rent exemption
+    await harness.fund(attacker.publicKey, LAMPORTS_PER_SOL);
+  });
+
+  it('a signer check alone lets any signer in', async () => {
+    expect(await stolen(withAttackerSignature('signerOnly'))).to.equal(AMOUNT.toNumber());
+    await expectError(withoutAdminSignature('signerOnly'), 'AccountNotSigner', 3010);
+  });
+
+  it('has_one alone lets the admin in without their signature', async () => {
+    await expectError(withAttackerSignature('hasOne'), 'Unauthorized', 6000);
+    expect(await stolen(withoutAdminSignature('hasOne'))).to.equal(AMOUNT.toNumber());
+  });
+
+  it('a constraint on a signer checks both', async () => {
+    await expectError(withAttackerSignature('constraint'), 'Unauthorized', 6000);
+    await expectError(withoutAdminSignature('constraint'), 'AccountNotSigner', 3010);
+  });
+
+  it('#[access_control] on a signer checks both', async () => {
+    await expectError(withAttackerSignature('accessControl'), 'Unauthorized', 6000);
+    await expectError(withoutAdminSignature('accessControl'), 'AccountNotSigner', 3010);
+  });
+
+  it('the admin can withdraw every way', async () => {
+    for (const withdraw of Object.values(withdrawals)) {
+      await withdraw().accounts({ vault, admin: admin.publicKey, recipient: admin.publicKey }).rpc();
+    }
+  });
+});
@@ -0,0 +1,9 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum AdminConfigError {
+    NotUpgradeAuthority,
+    FeeTooHigh,
+    Paused,
+    ZeroAmount,
+}
@@ -0,0 +1,45 @@
+use crate::errors::AdminConfigError;
+use crate::program::AdminConfig;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+// Whoever initializes the config controls the program, so it can't be first come, first served.
+// The one key that is known in advance is the upgrade authority: it deployed the program, and
+// the upgradeable loader records it in the program's `ProgramData` account.
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    admin: Pubkey,
+    fee_bps: u16,
+    fee_destination: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.set_fee(fee_bps, fee_destination)?;
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [Config::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    // the program account only stores the address of its program data, which must match,
+    // or any program data account with the right authority would do
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AdminConfig>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ AdminConfigError::NotUpgradeAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub use initialize_config::*;
+pub use pay::*;
+pub use set_admin::*;
+pub use update_config::*;
+
+pub mod initialize_config;
+pub mod pay;
+pub mod set_admin;
+pub mod update_config;
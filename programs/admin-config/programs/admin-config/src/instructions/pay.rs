@@ -0,0 +1,48 @@
+use crate::errors::AdminConfigError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+// An instruction for regular users, which is subject to the admin's parameters.
+pub fn pay(ctx: Context<Pay>, amount: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.paused, AdminConfigError::Paused);
+    require_gt!(amount, 0, AdminConfigError::ZeroAmount);
+
+    let fee = config.fee(amount);
+    for (to, lamports) in [
+        (ctx.accounts.fee_destination.to_account_info(), fee),
+        (ctx.accounts.recipient.to_account_info(), amount - fee),
+    ] {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to,
+                },
+            ),
+            lamports,
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Pay<'info> {
+    #[account(
+        has_one = fee_destination,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK: only receives lamports, and is checked against `config.fee_destination`
+    #[account(mut)]
+    pub fee_destination: UncheckedAccount<'info>,
+    /// CHECK: only receives lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,21 @@
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+// Both admins sign, so the program can't be handed to a key nobody controls.
+pub fn set_admin(ctx: Context<SetAdmin>) -> Result<()> {
+    ctx.accounts.config.admin = ctx.accounts.new_admin.key();
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAdmin<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    pub new_admin: Signer<'info>,
+}
@@ -0,0 +1,26 @@
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    fee_bps: u16,
+    fee_destination: Pubkey,
+    paused: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.set_fee(fee_bps, fee_destination)?;
+    config.paused = paused;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
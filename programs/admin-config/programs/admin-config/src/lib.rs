@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("C9Mx887vJ833nucg4AqM2YdfdiHHtfYjRExxPA2WSvVs");
+
+#[program]
+pub mod admin_config {
+    use super::*;
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        admin: Pubkey,
+        fee_bps: u16,
+        fee_destination: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx, admin, fee_bps, fee_destination)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        fee_bps: u16,
+        fee_destination: Pubkey,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::update_config::update_config(ctx, fee_bps, fee_destination, paused)
+    }
+
+    pub fn set_admin(ctx: Context<SetAdmin>) -> Result<()> {
+        instructions::set_admin::set_admin(ctx)
+    }
+
+    pub fn pay(ctx: Context<Pay>, amount: u64) -> Result<()> {
+        instructions::pay::pay(ctx, amount)
+    }
+}
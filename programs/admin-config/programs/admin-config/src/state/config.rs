@@ -0,0 +1,33 @@
+use crate::errors::AdminConfigError;
+use anchor_lang::prelude::*;
+use common::bps;
+
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// The program's global parameters. There is only one, at a PDA without variable seeds,
+/// so every instruction can find it and nobody can make up a second one.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub fee_destination: Pubkey,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+impl Config {
+    pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    pub fn set_fee(&mut self, fee_bps: u16, fee_destination: Pubkey) -> Result<()> {
+        require_gte!(MAX_FEE_BPS, fee_bps, AdminConfigError::FeeTooHigh);
+        self.fee_bps = fee_bps;
+        self.fee_destination = fee_destination;
+        Ok(())
+    }
+
+    pub fn fee(&self, amount: u64) -> u64 {
+        // `set_fee` keeps the fee below 100%, so it is always smaller than `amount`
+        bps::apply(amount, self.fee_bps).unwrap()
+    }
+}
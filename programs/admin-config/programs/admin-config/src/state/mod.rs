@@ -0,0 +1,3 @@
+pub use config::*;
+
+pub mod config;
@@ -0,0 +1,112 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { expectError } from 'test-harness';
+import { AdminConfig } from '../../../target/types/admin_config';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+// Runs against the local validator, not the bankrun harness: `anchor test` deploys the program
+// with the upgradeable loader, so it has the program data account and upgrade authority that
+// `initialize` checks. The harness loads programs without either.
+describe('admin-config', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdminConfig as Program<AdminConfig>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+  // `anchor test` deploys with the provider wallet, which makes it the upgrade authority
+  const upgradeAuthority = (programProvider.wallet as anchor.Wallet).payer;
+
+  const admin = Keypair.generate();
+  const newAdmin = Keypair.generate();
+  const feeDestination = Keypair.generate();
+  const recipient = Keypair.generate();
+
+  const [config] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from('config')], program.programId);
+  const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+  );
+
+  async function initializeConfig(authority: Keypair, feeBps: number) {
+    await program.methods
+      .initializeConfig(admin.publicKey, feeBps, feeDestination.publicKey)
+      .accounts({ config, program: program.programId, programData, authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+  }
+
+  async function updateConfig(signer: Keypair, feeBps: number, paused: boolean) {
+    await program.methods
+      .updateConfig(feeBps, feeDestination.publicKey, paused)
+      .accounts({ config, admin: signer.publicKey })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function pay(amount: number) {
+    await program.methods
+      .pay(new anchor.BN(amount))
+      .accounts({
+        config,
+        feeDestination: feeDestination.publicKey,
+        recipient: recipient.publicKey,
+        payer: upgradeAuthority.publicKey,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    await connection.confirmTransaction(await connection.requestAirdrop(admin.publicKey, LAMPORTS_PER_SOL));
+  });
+
+  it('only the upgrade authority initializes the config!', async () => {
+    await expectError(initializeConfig(admin, 100), 'NotUpgradeAuthority', 6000);
+    await expectError(initializeConfig(upgradeAuthority, 1_001), 'FeeTooHigh', 6001);
+    await initializeConfig(upgradeAuthority, 100);
+
+    // it picked an admin, who is now in charge instead
+    const configState = await program.account.config.fetch(config);
+    expect(configState.admin).to.deep.equal(admin.publicKey);
+    expect(configState.feeBps).to.equal(100);
+  });
+
+  it('payments follow the config', async () => {
+    await pay(LAMPORTS_PER_SOL);
+    expect(await connection.getBalance(feeDestination.publicKey)).to.equal(LAMPORTS_PER_SOL / 100);
+    expect(await connection.getBalance(recipient.publicKey)).to.equal((LAMPORTS_PER_SOL * 99) / 100);
+
+    // the upgrade authority has no say over the config anymore
+    try {
+      await updateConfig(upgradeAuthority, 0, true);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      expect((_err as AnchorError).error.errorCode.code).to.equal('ConstraintHasOne');
+    }
+
+    await updateConfig(admin, 0, true);
+    await expectError(pay(LAMPORTS_PER_SOL), 'Paused', 6002);
+
+    await updateConfig(admin, 500, false);
+    await pay(LAMPORTS_PER_SOL);
+    expect(await connection.getBalance(feeDestination.publicKey)).to.equal((LAMPORTS_PER_SOL * 6) / 100);
+  });
+
+  it('hand over to a new admin', async () => {
+    await program.methods
+      .setAdmin()
+      .accounts({ config, admin: admin.publicKey, newAdmin: newAdmin.publicKey })
+      .signers([admin, newAdmin])
+      .rpc();
+
+    await updateConfig(newAdmin, 0, false);
+    expect((await program.account.config.fetch(config)).feeBps).to.equal(0);
+  });
+});
@@ -0,0 +1,12 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum AmmError {
+    InvalidFee,
+    IdenticalMints,
+    ZeroAmount,
+    EmptyPool,
+    SlippageExceeded,
+    MathOverflow,
+    InsufficientInitialLiquidity,
+}
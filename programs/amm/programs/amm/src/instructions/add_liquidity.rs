@@ -0,0 +1,119 @@
+use crate::errors::AmmError;
+use crate::math;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+pub fn add_liquidity(
+    ctx: Context<AddLiquidity>,
+    max_amount_a: u64,
+    max_amount_b: u64,
+    min_liquidity: u64,
+) -> Result<()> {
+    let reserve_a = ctx.accounts.pool.reserve_a;
+    let reserve_b = ctx.accounts.pool.reserve_b;
+    let lp_supply = ctx.accounts.lp_mint.supply;
+
+    let (amount_a, amount_b) =
+        math::deposit_amounts(max_amount_a, max_amount_b, reserve_a, reserve_b)?;
+    require!(amount_a > 0 && amount_b > 0, AmmError::ZeroAmount);
+
+    let liquidity =
+        math::liquidity_for_deposit(amount_a, amount_b, reserve_a, reserve_b, lp_supply)?;
+    require_gt!(liquidity, 0, AmmError::ZeroAmount);
+    require_gte!(liquidity, min_liquidity, AmmError::SlippageExceeded);
+
+    for (from, to, amount) in [
+        (
+            &ctx.accounts.user_token_account_a,
+            &ctx.accounts.vault_a,
+            amount_a,
+        ),
+        (
+            &ctx.accounts.user_token_account_b,
+            &ctx.accounts.vault_b,
+            amount_b,
+        ),
+    ] {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.reserve_a = reserve_a
+        .checked_add(amount_a)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.reserve_b = reserve_b
+        .checked_add(amount_b)
+        .ok_or(AmmError::MathOverflow)?;
+
+    let mut mints = vec![(&ctx.accounts.user_lp_token_account, liquidity)];
+    if lp_supply == 0 {
+        mints.push((&ctx.accounts.locked_lp, math::MINIMUM_LIQUIDITY));
+    }
+    for (to, amount) in mints {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&ctx.accounts.pool.signer_seeds()],
+            ),
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        mut,
+        has_one = vault_a,
+        has_one = vault_b,
+        has_one = lp_mint,
+        has_one = locked_lp
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut)]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub locked_lp: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = pool.mint_a,
+        token::authority = user
+    )]
+    pub user_token_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = pool.mint_b,
+        token::authority = user
+    )]
+    pub user_token_account_b: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = user
+    )]
+    pub user_lp_token_account: Box<Account<'info, TokenAccount>>,
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,80 @@
+use crate::errors::AmmError;
+use crate::math::BPS_DENOMINATOR;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn initialize_pair(ctx: Context<InitializePair>, fee_bps: u16) -> Result<()> {
+    require!((fee_bps as u64) < BPS_DENOMINATOR, AmmError::InvalidFee);
+
+    ctx.accounts.pool.set_inner(Pool {
+        mint_a: ctx.accounts.mint_a.key(),
+        mint_b: ctx.accounts.mint_b.key(),
+        vault_a: ctx.accounts.vault_a.key(),
+        vault_b: ctx.accounts.vault_b.key(),
+        lp_mint: ctx.accounts.lp_mint.key(),
+        locked_lp: ctx.accounts.locked_lp.key(),
+        fee_bps,
+        reserve_a: 0,
+        reserve_b: 0,
+        bump: ctx.bumps.pool,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePair<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [Pool::SEED_PREFIX, mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    pub mint_a: Box<Account<'info, Mint>>,
+    #[account(constraint = mint_a.key() != mint_b.key() @ AmmError::IdenticalMints)]
+    pub mint_b: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint_a,
+        token::authority = pool,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref(), mint_a.key().as_ref()],
+        bump
+    )]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint_b,
+        token::authority = pool,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref(), mint_b.key().as_ref()],
+        bump
+    )]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+    // only the pool can mint or (through the owners) burn LP tokens
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = Pool::LP_DECIMALS,
+        mint::authority = pool,
+        seeds = [Pool::LP_MINT_SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    // owned by the pool, which never transfers out of it, so its LP tokens are locked for good
+    #[account(
+        init,
+        payer = payer,
+        token::mint = lp_mint,
+        token::authority = pool,
+        seeds = [Pool::LOCKED_LP_SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub locked_lp: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
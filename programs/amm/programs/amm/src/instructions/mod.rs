@@ -0,0 +1,9 @@
+pub use add_liquidity::*;
+pub use initialize_pair::*;
+pub use remove_liquidity::*;
+pub use swap::*;
+
+pub mod add_liquidity;
+pub mod initialize_pair;
+pub mod remove_liquidity;
+pub mod swap;
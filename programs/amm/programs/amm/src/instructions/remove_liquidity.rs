@@ -0,0 +1,102 @@
+use crate::errors::AmmError;
+use crate::math;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+pub fn remove_liquidity(
+    ctx: Context<RemoveLiquidity>,
+    liquidity: u64,
+    min_amount_a: u64,
+    min_amount_b: u64,
+) -> Result<()> {
+    require_gt!(liquidity, 0, AmmError::ZeroAmount);
+
+    let pool = &mut ctx.accounts.pool;
+    let lp_supply = ctx.accounts.lp_mint.supply;
+    let amount_a = math::withdrawal_amount(liquidity, pool.reserve_a, lp_supply)?;
+    let amount_b = math::withdrawal_amount(liquidity, pool.reserve_b, lp_supply)?;
+    require_gte!(amount_a, min_amount_a, AmmError::SlippageExceeded);
+    require_gte!(amount_b, min_amount_b, AmmError::SlippageExceeded);
+    pool.reserve_a = pool
+        .reserve_a
+        .checked_sub(amount_a)
+        .ok_or(AmmError::MathOverflow)?;
+    pool.reserve_b = pool
+        .reserve_b
+        .checked_sub(amount_b)
+        .ok_or(AmmError::MathOverflow)?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.user_lp_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        liquidity,
+    )?;
+
+    let signer_seeds = ctx.accounts.pool.signer_seeds();
+    for (from, to, amount) in [
+        (
+            &ctx.accounts.vault_a,
+            &ctx.accounts.user_token_account_a,
+            amount_a,
+        ),
+        (
+            &ctx.accounts.vault_b,
+            &ctx.accounts.user_token_account_b,
+            amount_b,
+        ),
+    ] {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: from.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveLiquidity<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b, has_one = lp_mint)]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut)]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub lp_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = pool.mint_a,
+        token::authority = user
+    )]
+    pub user_token_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = pool.mint_b,
+        token::authority = user
+    )]
+    pub user_token_account_b: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = user
+    )]
+    pub user_lp_token_account: Box<Account<'info, TokenAccount>>,
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
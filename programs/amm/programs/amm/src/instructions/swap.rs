@@ -0,0 +1,99 @@
+use crate::errors::AmmError;
+use crate::math;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64, a_to_b: bool) -> Result<()> {
+    require_gt!(amount_in, 0, AmmError::ZeroAmount);
+
+    let accounts = &ctx.accounts;
+    let pool = &accounts.pool;
+    let (reserve_in, reserve_out, vault_in, vault_out, user_in, user_out) = if a_to_b {
+        (
+            pool.reserve_a,
+            pool.reserve_b,
+            &accounts.vault_a,
+            &accounts.vault_b,
+            &accounts.user_token_account_a,
+            &accounts.user_token_account_b,
+        )
+    } else {
+        (
+            pool.reserve_b,
+            pool.reserve_a,
+            &accounts.vault_b,
+            &accounts.vault_a,
+            &accounts.user_token_account_b,
+            &accounts.user_token_account_a,
+        )
+    };
+    require!(reserve_in > 0 && reserve_out > 0, AmmError::EmptyPool);
+
+    let amount_out = math::swap_output(amount_in, reserve_in, reserve_out, pool.fee_bps)?;
+    require_gt!(amount_out, 0, AmmError::ZeroAmount);
+    require_gte!(amount_out, min_amount_out, AmmError::SlippageExceeded);
+
+    token::transfer(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: user_in.to_account_info(),
+                to: vault_in.to_account_info(),
+                authority: accounts.user.to_account_info(),
+            },
+        ),
+        amount_in,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: vault_out.to_account_info(),
+                to: user_out.to_account_info(),
+                authority: accounts.pool.to_account_info(),
+            },
+            &[&accounts.pool.signer_seeds()],
+        ),
+        amount_out,
+    )?;
+
+    // the whole input stays in the pool, fee included
+    let reserve_in = reserve_in
+        .checked_add(amount_in)
+        .ok_or(AmmError::MathOverflow)?;
+    // less than `reserve_out`, see `swap_output`
+    let reserve_out = reserve_out - amount_out;
+    let pool = &mut ctx.accounts.pool;
+    if a_to_b {
+        (pool.reserve_a, pool.reserve_b) = (reserve_in, reserve_out);
+    } else {
+        (pool.reserve_b, pool.reserve_a) = (reserve_in, reserve_out);
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, has_one = vault_a, has_one = vault_b)]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut)]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = pool.mint_a,
+        token::authority = user
+    )]
+    pub user_token_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = pool.mint_b,
+        token::authority = user
+    )]
+    pub user_token_account_b: Box<Account<'info, TokenAccount>>,
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
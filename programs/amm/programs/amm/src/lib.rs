@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod math;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("6cJgqc8syhYZLugbbWPP6wsvVA8vEpknH4HrhVtzxz6u");
+
+#[program]
+pub mod amm {
+    use super::*;
+
+    pub fn initialize_pair(ctx: Context<InitializePair>, fee_bps: u16) -> Result<()> {
+        instructions::initialize_pair::initialize_pair(ctx, fee_bps)
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        max_amount_a: u64,
+        max_amount_b: u64,
+        min_liquidity: u64,
+    ) -> Result<()> {
+        instructions::add_liquidity::add_liquidity(ctx, max_amount_a, max_amount_b, min_liquidity)
+    }
+
+    pub fn remove_liquidity(
+        ctx: Context<RemoveLiquidity>,
+        liquidity: u64,
+        min_amount_a: u64,
+        min_amount_b: u64,
+    ) -> Result<()> {
+        instructions::remove_liquidity::remove_liquidity(ctx, liquidity, min_amount_a, min_amount_b)
+    }
+
+    pub fn swap(
+        ctx: Context<Swap>,
+        amount_in: u64,
+        min_amount_out: u64,
+        a_to_b: bool,
+    ) -> Result<()> {
+        instructions::swap::swap(ctx, amount_in, min_amount_out, a_to_b)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::errors::AmmError;
+use anchor_lang::prelude::*;
+
+pub use common::bps::BPS_DENOMINATOR;
+
+/// LP tokens the first deposit locks for good. Without them, the first depositor could mint a
+/// single LP token, make it worth a lot and take the rounding loss of everyone depositing after.
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;
+
+/// Amounts a deposit actually uses so that it matches the pool's current ratio,
+/// never exceeding either of the two maximums.
+pub fn deposit_amounts(
+    max_amount_a: u64,
+    max_amount_b: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+) -> Result<(u64, u64)> {
+    // the first deposit sets the price
+    if reserve_a == 0 || reserve_b == 0 {
+        return Ok((max_amount_a, max_amount_b));
+    }
+
+    let optimal_b = mul_div(max_amount_a, reserve_b, reserve_a)?;
+    if optimal_b <= max_amount_b {
+        return Ok((max_amount_a, optimal_b));
+    }
+
+    let optimal_a = mul_div(max_amount_b, reserve_a, reserve_b)?;
+    Ok((optimal_a, max_amount_b))
+}
+
+/// LP tokens minted to the depositor for a deposit of `amount_a` and `amount_b` at the pool's
+/// ratio. The first deposit gets `MINIMUM_LIQUIDITY` less than it mints, they're locked.
+pub fn liquidity_for_deposit(
+    amount_a: u64,
+    amount_b: u64,
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+) -> Result<u64> {
+    if lp_supply == 0 {
+        // the geometric mean makes the initial share independent of the price
+        let product = amount_a as u128 * amount_b as u128;
+        let liquidity = u64::try_from(integer_sqrt(product)).map_err(|_| AmmError::MathOverflow)?;
+        return liquidity
+            .checked_sub(MINIMUM_LIQUIDITY)
+            .filter(|&liquidity| liquidity > 0)
+            .ok_or_else(|| AmmError::InsufficientInitialLiquidity.into());
+    }
+
+    Ok(std::cmp::min(
+        mul_div(amount_a, lp_supply, reserve_a)?,
+        mul_div(amount_b, lp_supply, reserve_b)?,
+    ))
+}
+
+/// Tokens of one side of the pool paid out for burning `liquidity` LP tokens.
+pub fn withdrawal_amount(liquidity: u64, reserve: u64, lp_supply: u64) -> Result<u64> {
+    mul_div(liquidity, reserve, lp_supply)
+}
+
+/// Output of a swap on the x * y = k curve. The fee is taken from the input
+/// and stays in the pool, which slowly grows k for the liquidity providers.
+pub fn swap_output(amount_in: u64, reserve_in: u64, reserve_out: u64, fee_bps: u16) -> Result<u64> {
+    // rounding the fee up leaves the pool with the remainder
+    let fee = common::bps::apply_ceil(amount_in, fee_bps).ok_or(AmmError::MathOverflow)?;
+    let amount_in_after_fee = amount_in - fee;
+
+    let reserve_in_after = reserve_in
+        .checked_add(amount_in_after_fee)
+        .ok_or(AmmError::MathOverflow)?;
+    mul_div(amount_in_after_fee, reserve_out, reserve_in_after)
+}
+
+fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+    require_neq!(c, 0, AmmError::EmptyPool);
+    common::math::mul_div(a, b, c).ok_or_else(|| error!(AmmError::MathOverflow))
+}
+
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    // Newton's method, converges from above
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub lp_mint: Pubkey,
+    // holds the LP tokens locked by the first deposit
+    pub locked_lp: Pubkey,
+    // fee charged on every swap, in basis points of the input amount
+    pub fee_bps: u16,
+    // the tokens owned by the liquidity providers. The vaults may hold more, anyone can send
+    // tokens to them, but those don't count: otherwise a donation would change the price of
+    // LP tokens without minting any
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const SEED_PREFIX: &'static [u8] = b"pool";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+    pub const LP_MINT_SEED_PREFIX: &'static [u8] = b"lp_mint";
+    pub const LOCKED_LP_SEED_PREFIX: &'static [u8] = b"locked_lp";
+    pub const LP_DECIMALS: u8 = 6;
+
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            Self::SEED_PREFIX,
+            self.mint_a.as_ref(),
+            self.mint_b.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
@@ -0,0 +1,294 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { createTransferInstruction } from '@solana/spl-token';
+import { Harness, findAddress } from 'test-harness';
+import { Amm } from '../../../target/types/amm';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('amm', () => {
+  let harness: Harness;
+  let program: Program<Amm>;
+  let payer: Keypair;
+  let user: Keypair;
+
+  let mintA: anchor.web3.PublicKey;
+  let mintB: anchor.web3.PublicKey;
+  let pool: anchor.web3.PublicKey;
+  let vaultA: anchor.web3.PublicKey;
+  let vaultB: anchor.web3.PublicKey;
+  let lpMint: anchor.web3.PublicKey;
+  let lockedLp: anchor.web3.PublicKey;
+  let userTokenAccountA: anchor.web3.PublicKey;
+  let userTokenAccountB: anchor.web3.PublicKey;
+  let userLpTokenAccount: anchor.web3.PublicKey;
+
+  async function swap(amountIn: number, minAmountOut: number, aToB: boolean) {
+    await program.methods
+      .swap(new BN(amountIn), new BN(minAmountOut), aToB)
+      .accounts({
+        pool,
+        vaultA,
+        vaultB,
+        userTokenAccountA,
+        userTokenAccountB,
+        user: user.publicKey,
+      })
+      .rpc();
+  }
+
+  const liquidityAccounts = () => ({
+    pool,
+    vaultA,
+    vaultB,
+    lpMint,
+    lockedLp,
+    userTokenAccountA,
+    userTokenAccountB,
+    userLpTokenAccount,
+    user: user.publicKey,
+  });
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Amm>('amm');
+    payer = harness.payer;
+    user = harness.payer;
+
+    mintA = await harness.createMint(payer.publicKey);
+    mintB = await harness.createMint(payer.publicKey);
+    pool = findAddress([Buffer.from('pool'), mintA.toBuffer(), mintB.toBuffer()], program.programId);
+    vaultA = findAddress([Buffer.from('vault'), pool.toBuffer(), mintA.toBuffer()], program.programId);
+    vaultB = findAddress([Buffer.from('vault'), pool.toBuffer(), mintB.toBuffer()], program.programId);
+    lpMint = findAddress([Buffer.from('lp_mint'), pool.toBuffer()], program.programId);
+    lockedLp = findAddress([Buffer.from('locked_lp'), pool.toBuffer()], program.programId);
+
+    userTokenAccountA = await harness.createTokenAccount(mintA, user.publicKey, 100_000);
+    userTokenAccountB = await harness.createTokenAccount(mintB, user.publicKey, 100_000);
+  });
+
+  it('initialize pair!', async () => {
+    await program.methods
+      .initializePair(30)
+      .accounts({
+        pool,
+        mintA,
+        mintB,
+        vaultA,
+        vaultB,
+        lpMint,
+        lockedLp,
+        payer: payer.publicKey,
+      })
+      .rpc();
+
+    const poolState = await program.account.pool.fetch(pool);
+    expect(poolState.mintA).to.eql(mintA);
+    expect(poolState.mintB).to.eql(mintB);
+    expect(poolState.lpMint).to.eql(lpMint);
+    expect(poolState.feeBps).to.equal(30);
+
+    // the pool PDA is the only one allowed to mint LP tokens
+    const lpMintState = await harness.mint(lpMint);
+    expect(lpMintState.mintAuthority).to.eql(pool);
+    expect(lpMintState.supply).to.equal(BigInt(0));
+
+    userLpTokenAccount = await harness.createTokenAccount(lpMint, user.publicKey);
+  });
+
+  it('add initial liquidity', async () => {
+    // sqrt(500 * 2_000) = 1_000, all of which would be locked
+    try {
+      await program.methods
+        .addLiquidity(new BN(500), new BN(2_000), new BN(0))
+        .accounts(liquidityAccounts())
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InsufficientInitialLiquidity');
+      expect(err.error.errorCode.number).to.equal(6006);
+    }
+
+    await program.methods
+      .addLiquidity(new BN(1_000), new BN(4_000), new BN(1_000))
+      .accounts(liquidityAccounts())
+      .rpc();
+
+    expect(await harness.tokenBalance(vaultA)).to.equal(1_000);
+    expect(await harness.tokenBalance(vaultB)).to.equal(4_000);
+    // sqrt(1_000 * 4_000), minus the 1_000 locked for good
+    expect(await harness.tokenBalance(userLpTokenAccount)).to.equal(1_000);
+    expect(await harness.tokenBalance(lockedLp)).to.equal(1_000);
+    const poolState = await program.account.pool.fetch(pool);
+    expect(poolState.reserveA.toNumber()).to.equal(1_000);
+    expect(poolState.reserveB.toNumber()).to.equal(4_000);
+  });
+
+  it('add liquidity at the pool ratio', async () => {
+    // only 500 of B match 125 of A at a 1:4 price
+    await program.methods
+      .addLiquidity(new BN(125), new BN(10_000), new BN(0))
+      .accounts(liquidityAccounts())
+      .rpc();
+
+    expect(await harness.tokenBalance(vaultA)).to.equal(1_125);
+    expect(await harness.tokenBalance(vaultB)).to.equal(4_500);
+    expect(await harness.tokenBalance(userLpTokenAccount)).to.equal(1_250);
+  });
+
+  it('swap!', async () => {
+    const reserveA = await harness.tokenBalance(vaultA);
+    const reserveB = await harness.tokenBalance(vaultB);
+
+    await swap(100, 1, true);
+
+    // 100 in, minus the 0.3% fee rounded down
+    const amountInAfterFee = Math.floor((100 * 9_970) / 10_000);
+    const expectedOut = Math.floor((amountInAfterFee * reserveB) / (reserveA + amountInAfterFee));
+    expect(await harness.tokenBalance(vaultA)).to.equal(reserveA + 100);
+    expect(await harness.tokenBalance(vaultB)).to.equal(reserveB - expectedOut);
+
+    // k never decreases
+    expect((reserveA + 100) * (reserveB - expectedOut)).to.be.at.least(reserveA * reserveB);
+  });
+
+  it('swap respects slippage', async () => {
+    try {
+      await swap(100, 1_000, false);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('SlippageExceeded');
+      expect(err.error.errorCode.number).to.equal(6004);
+      expect(err.program.equals(program.programId)).is.true;
+    }
+  });
+
+  it('remove liquidity', async () => {
+    const reserveA = await harness.tokenBalance(vaultA);
+    const reserveB = await harness.tokenBalance(vaultB);
+    const userA = await harness.tokenBalance(userTokenAccountA);
+    const userB = await harness.tokenBalance(userTokenAccountB);
+    const lpSupply = await harness.tokenSupply(lpMint);
+
+    await program.methods
+      .removeLiquidity(new BN(lpSupply / 2), new BN(1), new BN(1))
+      .accounts(liquidityAccounts())
+      .rpc();
+
+    const amountA = Math.floor((lpSupply / 2) * reserveA / lpSupply);
+    const amountB = Math.floor((lpSupply / 2) * reserveB / lpSupply);
+    expect(await harness.tokenBalance(userTokenAccountA)).to.equal(userA + amountA);
+    expect(await harness.tokenBalance(userTokenAccountB)).to.equal(userB + amountB);
+    expect(await harness.tokenSupply(lpMint)).to.equal(lpSupply / 2);
+
+    try {
+      await program.methods
+        .removeLiquidity(new BN(10), new BN(1_000_000), new BN(0))
+        .accounts(liquidityAccounts())
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('SlippageExceeded');
+    }
+  });
+
+  it('rejects a fee of 100%', async () => {
+    const otherMint = await harness.createMint(payer.publicKey);
+    const otherPool = findAddress([Buffer.from('pool'), mintA.toBuffer(), otherMint.toBuffer()], program.programId);
+
+    try {
+      await program.methods
+        .initializePair(10_000)
+        .accounts({
+          pool: otherPool,
+          mintA,
+          mintB: otherMint,
+          vaultA: findAddress([Buffer.from('vault'), otherPool.toBuffer(), mintA.toBuffer()], program.programId),
+          vaultB: findAddress([Buffer.from('vault'), otherPool.toBuffer(), otherMint.toBuffer()], program.programId),
+          lpMint: findAddress([Buffer.from('lp_mint'), otherPool.toBuffer()], program.programId),
+          lockedLp: findAddress([Buffer.from('locked_lp'), otherPool.toBuffer()], program.programId),
+          payer: payer.publicKey,
+        })
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InvalidFee');
+      expect(err.error.errorCode.number).to.equal(6000);
+    }
+  });
+
+  it('donations to the vaults do not change the price of LP tokens', async () => {
+    const attacker = user;
+    const victim = await harness.fundedKeypair();
+    const mintC = await harness.createMint(payer.publicKey);
+    const mintD = await harness.createMint(payer.publicKey);
+    const otherPool = findAddress([Buffer.from('pool'), mintC.toBuffer(), mintD.toBuffer()], program.programId);
+    const accounts = {
+      pool: otherPool,
+      vaultA: findAddress([Buffer.from('vault'), otherPool.toBuffer(), mintC.toBuffer()], program.programId),
+      vaultB: findAddress([Buffer.from('vault'), otherPool.toBuffer(), mintD.toBuffer()], program.programId),
+      lpMint: findAddress([Buffer.from('lp_mint'), otherPool.toBuffer()], program.programId),
+      lockedLp: findAddress([Buffer.from('locked_lp'), otherPool.toBuffer()], program.programId),
+    };
+    await program.methods
+      .initializePair(30)
+      .accounts({ ...accounts, mintA: mintC, mintB: mintD, payer: payer.publicKey })
+      .rpc();
+
+    const tokenAccounts = async (owner: Keypair, amount: number) => ({
+      userTokenAccountA: await harness.createTokenAccount(mintC, owner.publicKey, amount),
+      userTokenAccountB: await harness.createTokenAccount(mintD, owner.publicKey, amount),
+      userLpTokenAccount: await harness.createTokenAccount(accounts.lpMint, owner.publicKey),
+      user: owner.publicKey,
+    });
+    const attackerAccounts = await tokenAccounts(attacker, 1_000_000);
+    const victimAccounts = await tokenAccounts(victim, 2_000);
+
+    // the attacker ends up with a single LP token...
+    await program.methods
+      .addLiquidity(new BN(1_001), new BN(1_001), new BN(1))
+      .accounts({ ...accounts, ...attackerAccounts })
+      .rpc();
+    expect(await harness.tokenBalance(attackerAccounts.userLpTokenAccount)).to.equal(1);
+
+    // ... and sends tokens straight to the vaults, hoping to make it worth a fortune
+    await harness.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createTransferInstruction(attackerAccounts.userTokenAccountA, accounts.vaultA, attacker.publicKey, 100_000),
+        createTransferInstruction(attackerAccounts.userTokenAccountB, accounts.vaultB, attacker.publicKey, 100_000)
+      )
+    );
+    const poolState = await program.account.pool.fetch(otherPool);
+    expect(poolState.reserveA.toNumber()).to.equal(1_001);
+    expect(poolState.reserveB.toNumber()).to.equal(1_001);
+
+    // the donation doesn't count, so the victim's deposit is priced as before it
+    await program.methods
+      .addLiquidity(new BN(2_000), new BN(2_000), new BN(2_000))
+      .accounts({ ...accounts, ...victimAccounts })
+      .signers([victim])
+      .rpc();
+    expect(await harness.tokenBalance(victimAccounts.userLpTokenAccount)).to.equal(2_000);
+
+    await program.methods
+      .removeLiquidity(new BN(2_000), new BN(2_000), new BN(2_000))
+      .accounts({ ...accounts, ...victimAccounts })
+      .signers([victim])
+      .rpc();
+    expect(await harness.tokenBalance(victimAccounts.userTokenAccountA)).to.equal(2_000);
+    expect(await harness.tokenBalance(victimAccounts.userTokenAccountB)).to.equal(2_000);
+  });
+});
@@ -0,0 +1,13 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum AuctionError {
+    ZeroAmount,
+    EndInThePast,
+    InvalidExtensionWindow,
+    AuctionEnded,
+    AuctionStillRunning,
+    BidTooLow,
+    MissingPreviousBidder,
+    InvalidWinner,
+}
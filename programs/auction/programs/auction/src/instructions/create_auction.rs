@@ -0,0 +1,80 @@
+use crate::errors::AuctionError;
+use crate::state::auction::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn create_auction(
+    ctx: Context<CreateAuction>,
+    id: u64,
+    item_amount: u64,
+    min_bid: u64,
+    end_ts: i64,
+    extension_window: i64,
+) -> Result<()> {
+    require_gt!(item_amount, 0, AuctionError::ZeroAmount);
+    require_gt!(
+        end_ts,
+        Clock::get()?.unix_timestamp,
+        AuctionError::EndInThePast
+    );
+    require_gte!(extension_window, 0, AuctionError::InvalidExtensionWindow);
+
+    ctx.accounts.auction.set_inner(Auction {
+        seller: ctx.accounts.seller.key(),
+        id,
+        item_mint: ctx.accounts.item_mint.key(),
+        item_amount,
+        min_bid,
+        highest_bid: 0,
+        highest_bidder: None,
+        end_ts,
+        extension_window,
+        bump: ctx.bumps.auction,
+    });
+
+    // an NFT is just an item with an amount of 1
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.seller_token_account.to_account_info(),
+                to: ctx.accounts.item_vault.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        item_amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateAuction<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [Auction::SEED_PREFIX, seller.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        init,
+        payer = seller,
+        token::mint = item_mint,
+        token::authority = auction,
+        seeds = [Auction::VAULT_SEED_PREFIX, auction.key().as_ref()],
+        bump
+    )]
+    pub item_vault: Account<'info, TokenAccount>,
+    pub item_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = item_mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
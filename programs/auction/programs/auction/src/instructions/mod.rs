@@ -0,0 +1,7 @@
+pub use create_auction::*;
+pub use place_bid::*;
+pub use settle::*;
+
+pub mod create_auction;
+pub mod place_bid;
+pub mod settle;
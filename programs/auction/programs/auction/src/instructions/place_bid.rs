@@ -0,0 +1,55 @@
+use crate::errors::AuctionError;
+use crate::state::auction::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let previous = auction.bid(
+        ctx.accounts.bidder.key(),
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // bids are held in the auction account itself
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bidder.to_account_info(),
+                to: auction.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // The auction is owned by this program, so it can move lamports out directly
+    // instead of going through the system program.
+    if let Some((_, previous_bid)) = previous {
+        let previous_bidder = ctx
+            .accounts
+            .previous_bidder
+            .as_ref()
+            .ok_or(AuctionError::MissingPreviousBidder)?;
+        auction.sub_lamports(previous_bid)?;
+        previous_bidder.add_lamports(previous_bid)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub auction: Account<'info, Auction>,
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    /// CHECK: only receives the refund and must be the current highest bidder.
+    /// Left out for the first bid.
+    #[account(
+        mut,
+        constraint = auction.highest_bidder == Some(previous_bidder.key()) @ AuctionError::MissingPreviousBidder
+    )]
+    pub previous_bidder: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
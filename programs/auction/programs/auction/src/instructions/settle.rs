@@ -0,0 +1,79 @@
+use crate::errors::AuctionError;
+use crate::state::auction::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+pub fn settle(ctx: Context<Settle>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+    require!(
+        auction.has_ended(Clock::get()?.unix_timestamp),
+        AuctionError::AuctionStillRunning
+    );
+
+    let id_bytes = auction.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Auction::SEED_PREFIX,
+        auction.seller.as_ref(),
+        id_bytes.as_ref(),
+        &[auction.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.item_vault.to_account_info(),
+                to: ctx.accounts.winner_token_account.to_account_info(),
+                authority: auction.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        auction.item_amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.item_vault.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: auction.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+// permissionless, anyone can settle an auction once it is over
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    // closing the auction hands the seller its rent together with the winning bid
+    #[account(mut, has_one = seller, has_one = item_mint, close = seller)]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        seeds = [Auction::VAULT_SEED_PREFIX, auction.key().as_ref()],
+        bump
+    )]
+    pub item_vault: Account<'info, TokenAccount>,
+    pub item_mint: Account<'info, Mint>,
+    /// CHECK: the highest bidder, or the seller if nobody bid
+    #[account(
+        constraint = winner.key() == auction.highest_bidder.unwrap_or(auction.seller) @ AuctionError::InvalidWinner
+    )]
+    pub winner: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = item_mint,
+        associated_token::authority = winner
+    )]
+    pub winner_token_account: Account<'info, TokenAccount>,
+    /// CHECK: checked against `auction.seller`
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("3YVTGxfL75YVtu4iHSRkxaKV2pn3rEVatPPXZiiswXyv");
+
+#[program]
+pub mod auction {
+    use super::*;
+
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        id: u64,
+        item_amount: u64,
+        min_bid: u64,
+        end_ts: i64,
+        extension_window: i64,
+    ) -> Result<()> {
+        instructions::create_auction::create_auction(
+            ctx,
+            id,
+            item_amount,
+            min_bid,
+            end_ts,
+            extension_window,
+        )
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        instructions::place_bid::place_bid(ctx, amount)
+    }
+
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        instructions::settle::settle(ctx)
+    }
+}
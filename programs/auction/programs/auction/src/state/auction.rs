@@ -0,0 +1,49 @@
+use crate::errors::AuctionError;
+use anchor_lang::prelude::*;
+use common::time;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Auction {
+    pub seller: Pubkey,
+    pub id: u64,
+    pub item_mint: Pubkey,
+    pub item_amount: u64,
+    // in lamports
+    pub min_bid: u64,
+    pub highest_bid: u64,
+    pub highest_bidder: Option<Pubkey>,
+    pub end_ts: i64,
+    // a bid this close to the end pushes the end back, so there is always
+    // time left to answer it
+    pub extension_window: i64,
+    pub bump: u8,
+}
+
+impl Auction {
+    pub const SEED_PREFIX: &'static [u8] = b"auction";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    pub fn has_ended(&self, now: i64) -> bool {
+        time::has_ended(now, self.end_ts)
+    }
+
+    /// Records a new highest bid and returns the previous one that has to be refunded.
+    pub fn bid(&mut self, bidder: Pubkey, amount: u64, now: i64) -> Result<Option<(Pubkey, u64)>> {
+        require!(!self.has_ended(now), AuctionError::AuctionEnded);
+        require_gte!(amount, self.min_bid, AuctionError::BidTooLow);
+        require_gt!(amount, self.highest_bid, AuctionError::BidTooLow);
+
+        let previous = self
+            .highest_bidder
+            .replace(bidder)
+            .map(|previous_bidder| (previous_bidder, self.highest_bid));
+        self.highest_bid = amount;
+
+        if self.end_ts - now < self.extension_window {
+            self.end_ts = now + self.extension_window;
+        }
+
+        Ok(previous)
+    }
+}
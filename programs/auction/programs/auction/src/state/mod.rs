@@ -0,0 +1,3 @@
+pub use auction::*;
+
+pub mod auction;
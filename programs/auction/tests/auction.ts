@@ -0,0 +1,201 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { Harness, findAddress } from 'test-harness';
+import { Auction } from '../../../target/types/auction';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('auction', () => {
+  let harness: Harness;
+  let program: Program<Auction>;
+  let payer: anchor.web3.Keypair;
+  let seller: anchor.web3.Keypair;
+  let nextId = 0;
+
+  let itemMint: anchor.web3.PublicKey;
+  let sellerTokenAccount: anchor.web3.PublicKey;
+
+  async function newBidder() {
+    return harness.fundedKeypair(10 * LAMPORTS_PER_SOL);
+  }
+
+  async function createAuction(minBid: number, secondsToEnd: number, extensionWindow: number) {
+    const id = new BN(nextId++);
+    const auction = findAddress(
+      [Buffer.from('auction'), seller.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const itemVault = findAddress([Buffer.from('vault'), auction.toBuffer()], program.programId);
+
+    await program.methods
+      .createAuction(id, new BN(1), new BN(minBid), new BN((await harness.now()) + secondsToEnd), new BN(extensionWindow))
+      .accounts({
+        auction,
+        itemVault,
+        itemMint,
+        sellerTokenAccount,
+        seller: seller.publicKey,
+      })
+      .rpc();
+
+    return { auction, itemVault };
+  }
+
+  async function placeBid(
+    auction: anchor.web3.PublicKey,
+    bidder: anchor.web3.Keypair,
+    amount: number,
+    previousBidder: anchor.web3.PublicKey | null
+  ) {
+    await program.methods
+      .placeBid(new BN(amount))
+      .accounts({
+        auction,
+        bidder: bidder.publicKey,
+        previousBidder,
+      })
+      .signers([bidder])
+      .rpc();
+  }
+
+  async function settle(auction: anchor.web3.PublicKey, itemVault: anchor.web3.PublicKey, winner: anchor.web3.PublicKey) {
+    await program.methods
+      .settle()
+      .accounts({
+        auction,
+        itemVault,
+        itemMint,
+        winner,
+        winnerTokenAccount: getAssociatedTokenAddressSync(itemMint, winner),
+        seller: seller.publicKey,
+        payer: payer.publicKey,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Auction>('auction');
+    // the provider wallet pays for and signs everything the seller does
+    payer = harness.payer;
+    seller = harness.payer;
+
+    // a 0-decimals mint with a supply of one per auction, i.e. NFT-like items
+    itemMint = await harness.createMint(payer.publicKey);
+    sellerTokenAccount = await harness.createTokenAccount(itemMint, seller.publicKey, 10);
+  });
+
+  it('outbid, refund and settle!', async () => {
+    const { auction, itemVault } = await createAuction(LAMPORTS_PER_SOL, 4, 0);
+    const alice = await newBidder();
+    const bob = await newBidder();
+    expect(await harness.tokenBalance(itemVault)).to.equal(1);
+
+    await placeBid(auction, alice, LAMPORTS_PER_SOL, null);
+    const aliceBalance = await harness.lamports(alice.publicKey);
+
+    await placeBid(auction, bob, 2 * LAMPORTS_PER_SOL, alice.publicKey);
+
+    // alice got her bid back the moment she was outbid
+    expect(await harness.lamports(alice.publicKey)).to.equal(aliceBalance + LAMPORTS_PER_SOL);
+    const auctionState = await program.account.auction.fetch(auction);
+    expect(auctionState.highestBidder).to.eql(bob.publicKey);
+    expect(auctionState.highestBid.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+
+    try {
+      await settle(auction, itemVault, bob.publicKey);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('AuctionStillRunning');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+
+    await harness.warp(5);
+
+    try {
+      await settle(auction, itemVault, alice.publicKey);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InvalidWinner');
+    }
+
+    const sellerBalance = await harness.lamports(seller.publicKey);
+    await settle(auction, itemVault, bob.publicKey);
+
+    const bobTokenAccount = getAssociatedTokenAddressSync(itemMint, bob.publicKey);
+    expect(await harness.tokenBalance(bobTokenAccount)).to.equal(1);
+    expect(await harness.lamports(seller.publicKey)).to.be.greaterThan(sellerBalance + 2 * LAMPORTS_PER_SOL - 3_000_000);
+    expect(await harness.account(auction)).to.be.null;
+    expect(await harness.account(itemVault)).to.be.null;
+  });
+
+  it('bids must beat the highest bid', async () => {
+    const { auction } = await createAuction(LAMPORTS_PER_SOL, 60, 0);
+    const alice = await newBidder();
+    const bob = await newBidder();
+
+    try {
+      await placeBid(auction, alice, LAMPORTS_PER_SOL / 2, null);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('BidTooLow');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+
+    await placeBid(auction, alice, LAMPORTS_PER_SOL, null);
+
+    try {
+      await placeBid(auction, bob, LAMPORTS_PER_SOL, alice.publicKey);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('BidTooLow');
+    }
+
+    // the refund has to go to the actual previous bidder
+    try {
+      await placeBid(auction, bob, 2 * LAMPORTS_PER_SOL, bob.publicKey);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('MissingPreviousBidder');
+    }
+  });
+
+  it('late bids extend the auction', async () => {
+    const { auction } = await createAuction(1, 3, 30);
+    const alice = await newBidder();
+    const endBefore = (await program.account.auction.fetch(auction)).endTs.toNumber();
+
+    await placeBid(auction, alice, 1, null);
+
+    const endAfter = (await program.account.auction.fetch(auction)).endTs.toNumber();
+    expect(endAfter).to.be.greaterThan(endBefore);
+    expect(endAfter).to.equal((await harness.now()) + 30);
+  });
+
+  it('no bids: the seller gets the item back', async () => {
+    const { auction, itemVault } = await createAuction(1, 2, 0);
+    const balance = await harness.tokenBalance(sellerTokenAccount);
+
+    await harness.warp(3);
+    await settle(auction, itemVault, seller.publicKey);
+
+    // the seller's token account is its associated one, where the item goes back to
+    expect(await harness.tokenBalance(sellerTokenAccount)).to.equal(balance + 1);
+  });
+});
@@ -0,0 +1,76 @@
+use crate::errors::BondingCurveError;
+use crate::state::bonding_curve::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+pub fn buy(ctx: Context<Buy>, amount: u64, max_cost: u64) -> Result<()> {
+    require_gt!(amount, 0, BondingCurveError::ZeroAmount);
+    let mint = &ctx.accounts.mint;
+    let cost = ctx
+        .accounts
+        .bonding_curve
+        .curve
+        .buy_cost(mint.supply, amount, mint.decimals)?;
+    // the price moves with every trade, a buy landing after others may cost more than quoted
+    require_gte!(max_cost, cost, BondingCurveError::SlippageExceeded);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer_quote_account.to_account_info(),
+                to: ctx.accounts.reserve.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        cost,
+    )?;
+
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BondingCurve::SEED_PREFIX,
+        bonding_curve.creator.as_ref(),
+        &bonding_curve.id.to_le_bytes(),
+        &[bonding_curve.bump],
+    ]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+                authority: bonding_curve.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Buy<'info> {
+    #[account(has_one = mint)]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [BondingCurve::RESERVE_SEED_PREFIX, bonding_curve.key().as_ref()],
+        bump = bonding_curve.reserve_bump
+    )]
+    pub reserve: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = bonding_curve.quote_mint,
+        token::authority = buyer
+    )]
+    pub buyer_quote_account: Account<'info, TokenAccount>,
+    pub buyer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,59 @@
+use crate::errors::BondingCurveError;
+use crate::state::bonding_curve::*;
+use crate::state::curve::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn create_curve(ctx: Context<CreateCurve>, id: u64, _decimals: u8, curve: Curve) -> Result<()> {
+    curve.validate()?;
+
+    ctx.accounts.bonding_curve.set_inner(BondingCurve {
+        creator: ctx.accounts.creator.key(),
+        id,
+        mint: ctx.accounts.mint.key(),
+        quote_mint: ctx.accounts.quote_mint.key(),
+        curve,
+        bump: ctx.bumps.bonding_curve,
+        mint_bump: ctx.bumps.mint,
+        reserve_bump: ctx.bumps.reserve,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64, decimals: u8)]
+pub struct CreateCurve<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [BondingCurve::SEED_PREFIX, creator.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    // only the curve can mint, so the supply always matches the reserve
+    #[account(
+        init,
+        payer = creator,
+        seeds = [BondingCurve::MINT_SEED_PREFIX, bonding_curve.key().as_ref()],
+        bump,
+        mint::decimals = decimals,
+        mint::authority = bonding_curve,
+        constraint = decimals <= BondingCurve::MAX_DECIMALS @ BondingCurveError::InvalidDecimals
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = creator,
+        seeds = [BondingCurve::RESERVE_SEED_PREFIX, bonding_curve.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = bonding_curve
+    )]
+    pub reserve: Account<'info, TokenAccount>,
+    pub quote_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,7 @@
+pub use buy::*;
+pub use create_curve::*;
+pub use sell::*;
+
+pub mod buy;
+pub mod create_curve;
+pub mod sell;
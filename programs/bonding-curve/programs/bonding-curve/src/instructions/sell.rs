@@ -0,0 +1,75 @@
+use crate::errors::BondingCurveError;
+use crate::state::bonding_curve::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+pub fn sell(ctx: Context<Sell>, amount: u64, min_proceeds: u64) -> Result<()> {
+    require_gt!(amount, 0, BondingCurveError::ZeroAmount);
+    let mint = &ctx.accounts.mint;
+    let proceeds =
+        ctx.accounts
+            .bonding_curve
+            .curve
+            .sell_proceeds(mint.supply, amount, mint.decimals)?;
+    require_gte!(proceeds, min_proceeds, BondingCurveError::SlippageExceeded);
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.seller_token_account.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let bonding_curve = &ctx.accounts.bonding_curve;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        BondingCurve::SEED_PREFIX,
+        bonding_curve.creator.as_ref(),
+        &bonding_curve.id.to_le_bytes(),
+        &[bonding_curve.bump],
+    ]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve.to_account_info(),
+                to: ctx.accounts.seller_quote_account.to_account_info(),
+                authority: bonding_curve.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        proceeds,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Sell<'info> {
+    #[account(has_one = mint)]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [BondingCurve::RESERVE_SEED_PREFIX, bonding_curve.key().as_ref()],
+        bump = bonding_curve.reserve_bump
+    )]
+    pub reserve: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = bonding_curve.quote_mint,
+        token::authority = seller
+    )]
+    pub seller_quote_account: Account<'info, TokenAccount>,
+    pub seller: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+use state::curve::Curve;
+
+pub mod errors;
+pub mod instructions;
+pub mod math;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("4Q6TSxBkM6RZxnBtwqkqEYFrW1Gh81a4fn3ZTx7L183Y");
+
+#[program]
+pub mod bonding_curve {
+    use super::*;
+
+    pub fn create_curve(
+        ctx: Context<CreateCurve>,
+        id: u64,
+        decimals: u8,
+        curve: Curve,
+    ) -> Result<()> {
+        instructions::create_curve::create_curve(ctx, id, decimals, curve)
+    }
+
+    pub fn buy(ctx: Context<Buy>, amount: u64, max_cost: u64) -> Result<()> {
+        instructions::buy::buy(ctx, amount, max_cost)
+    }
+
+    pub fn sell(ctx: Context<Sell>, amount: u64, min_proceeds: u64) -> Result<()> {
+        instructions::sell::sell(ctx, amount, min_proceeds)
+    }
+}
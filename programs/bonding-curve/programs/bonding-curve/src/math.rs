@@ -0,0 +1,128 @@
+//! Fixed-point arithmetic with 9 decimals on top of `u128`.
+//!
+//! Floats aren't forbidden on-chain, but they are emulated in software, costly, and their
+//! rounding is hard to reason about. With integers, every rounding step is explicit, so
+//! the program can always round in its own favour.
+
+use crate::errors::BondingCurveError;
+use anchor_lang::prelude::*;
+
+/// 1.0. Nine decimals leave enough headroom that the product of two
+/// values up to ~1.8e10 (1.8e19 raw) still fits into a `u128`.
+pub const ONE: u128 = 1_000_000_000;
+/// ln(2), rounded to nine decimals.
+pub const LN_2: u128 = 693_147_181;
+
+pub fn mul(a: u128, b: u128) -> Result<u128> {
+    Ok(a.checked_mul(b).ok_or(BondingCurveError::MathOverflow)? / ONE)
+}
+
+pub fn div(a: u128, b: u128) -> Result<u128> {
+    Ok(a.checked_mul(ONE).ok_or(BondingCurveError::MathOverflow)? / b)
+}
+
+/// e^x, for x >= 0.
+///
+/// With x = n * ln(2) + r and 0 <= r < ln(2), e^x = 2^n * e^r. The power of two
+/// is a shift, and the Taylor series of e^r converges quickly for such a small r.
+pub fn exp(x: u128) -> Result<u128> {
+    let n = x / LN_2;
+    let r = x % LN_2;
+
+    let mut term = ONE;
+    let mut sum = ONE;
+    // r^k / k! < 1e-9 from k = 12 onwards
+    for k in 1..=12 {
+        term = term * r / (k * ONE);
+        sum += term;
+    }
+
+    require_gt!(
+        sum.leading_zeros() as u128,
+        n,
+        BondingCurveError::MathOverflow
+    );
+    Ok(sum << n)
+}
+
+/// Turns fixed-point quote atoms into whole ones, rounding up.
+pub fn to_atoms_up(value: u128) -> Result<u64> {
+    u64::try_from(value.div_ceil(ONE)).map_err(|_| error!(BondingCurveError::MathOverflow))
+}
+
+/// Turns fixed-point quote atoms into whole ones, rounding down.
+pub fn to_atoms_down(value: u128) -> Result<u64> {
+    u64::try_from(value / ONE).map_err(|_| error!(BondingCurveError::MathOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_f64(value: u128) -> f64 {
+        value as f64 / ONE as f64
+    }
+
+    fn from_f64(value: f64) -> u128 {
+        (value * ONE as f64) as u128
+    }
+
+    #[test]
+    fn mul_and_div_round_down() {
+        assert_eq!(mul(3 * ONE / 2, 3 * ONE / 2).unwrap(), 9 * ONE / 4);
+        assert_eq!(mul(1, 1).unwrap(), 0);
+        assert_eq!(div(ONE, 3 * ONE).unwrap(), 333_333_333);
+        assert!(mul(u128::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn exp_of_zero_is_one() {
+        assert_eq!(exp(0).unwrap(), ONE);
+    }
+
+    #[test]
+    fn exp_matches_reference() {
+        for x in [
+            0.000_001,
+            0.1,
+            0.5,
+            std::f64::consts::LN_2,
+            1.0,
+            2.5,
+            10.0,
+            20.0,
+            40.0,
+        ] {
+            let expected = f64::exp(x);
+            let actual = to_f64(exp(from_f64(x)).unwrap());
+            let error = (actual - expected).abs() / expected;
+            assert!(error < 1e-7, "exp({x}) = {actual}, expected {expected}");
+        }
+    }
+
+    #[test]
+    fn exp_is_monotonic() {
+        let mut previous = exp(0).unwrap();
+        // across several multiples of ln(2), where the range reduction switches over
+        for x in (0..3 * LN_2 + 1_000).step_by(997) {
+            let current = exp(x).unwrap();
+            assert!(current >= previous, "exp({x}) < exp({})", x - 997);
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn exp_overflows_with_an_error() {
+        // a u128 with 9 decimals ends just short of e^68
+        assert!(exp(67 * ONE).is_ok());
+        assert!(exp(68 * ONE).is_err());
+    }
+
+    #[test]
+    fn conversions_round_as_named() {
+        assert_eq!(to_atoms_up(ONE + 1).unwrap(), 2);
+        assert_eq!(to_atoms_down(2 * ONE - 1).unwrap(), 1);
+        assert_eq!(to_atoms_up(ONE).unwrap(), 1);
+        assert_eq!(to_atoms_down(ONE).unwrap(), 1);
+    }
+}
@@ -0,0 +1,25 @@
+use super::curve::*;
+use anchor_lang::prelude::*;
+
+// Sells a token this program mints on demand. Every buy mints along the curve and pays
+// into the reserve, every sell burns and is paid out of it; there is no other supply.
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    pub creator: Pubkey,
+    pub id: u64,
+    pub mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub curve: Curve,
+    pub bump: u8,
+    pub mint_bump: u8,
+    pub reserve_bump: u8,
+}
+
+impl BondingCurve {
+    pub const SEED_PREFIX: &'static [u8] = b"bonding_curve";
+    pub const MINT_SEED_PREFIX: &'static [u8] = b"mint";
+    pub const RESERVE_SEED_PREFIX: &'static [u8] = b"reserve";
+    // keeps `to_fixed` exact, the fixed-point numbers have 9 decimals
+    pub const MAX_DECIMALS: u8 = 9;
+}
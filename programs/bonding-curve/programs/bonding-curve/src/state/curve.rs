@@ -0,0 +1,228 @@
+use crate::errors::BondingCurveError;
+use crate::math::{self, ONE};
+use anchor_lang::prelude::*;
+
+/// The price of one whole token as a function of the supply `x` in whole tokens,
+/// with all parameters in quote atoms as fixed-point numbers (see `math::ONE`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq)]
+pub enum Curve {
+    /// price(x) = base_price + slope * x
+    Linear { base_price: u128, slope: u128 },
+    /// price(x) = base_price * e^(growth * x)
+    Exponential { base_price: u128, growth: u128 },
+}
+
+impl Curve {
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            Curve::Linear { base_price, slope } => base_price > 0 || slope > 0,
+            Curve::Exponential { base_price, growth } => base_price > 0 && growth > 0,
+        };
+        require!(valid, BondingCurveError::InvalidCurve);
+        Ok(())
+    }
+
+    /// The area under the curve from a supply of 0 to `x`: what buying all of it would cost.
+    ///
+    /// Any buy or sell costs the difference of two values of this function. As long as it only
+    /// depends on `x`, the order of the trades doesn't matter and the reserve always holds at
+    /// least `integral(supply)`, enough to buy back the whole supply.
+    pub fn integral(&self, x: u128) -> Result<u128> {
+        match *self {
+            Curve::Linear { base_price, slope } => {
+                // base_price * x + slope * x^2 / 2
+                let x_squared = math::mul(x, x)?;
+                math::mul(base_price, x)?
+                    .checked_add(math::mul(slope, x_squared)? / 2)
+                    .ok_or_else(|| error!(BondingCurveError::MathOverflow))
+            }
+            Curve::Exponential { base_price, growth } => {
+                // base_price / growth * (e^(growth * x) - 1)
+                let exp = math::exp(math::mul(growth, x)?)?;
+                math::div(math::mul(base_price, exp - ONE)?, growth)
+            }
+        }
+    }
+
+    /// Quote atoms the buyer pays to raise the supply from `supply` by `amount`, rounded up.
+    pub fn buy_cost(&self, supply: u64, amount: u64, decimals: u8) -> Result<u64> {
+        let new_supply = supply
+            .checked_add(amount)
+            .ok_or(BondingCurveError::MathOverflow)?;
+        let cost = self
+            .integral(to_fixed(new_supply, decimals))?
+            .checked_sub(self.integral(to_fixed(supply, decimals))?)
+            .ok_or(BondingCurveError::MathOverflow)?;
+        math::to_atoms_up(cost)
+    }
+
+    /// Quote atoms the seller receives for lowering the supply from `supply` by `amount`, rounded down.
+    pub fn sell_proceeds(&self, supply: u64, amount: u64, decimals: u8) -> Result<u64> {
+        let new_supply = supply
+            .checked_sub(amount)
+            .ok_or(BondingCurveError::MathOverflow)?;
+        let proceeds = self
+            .integral(to_fixed(supply, decimals))?
+            .checked_sub(self.integral(to_fixed(new_supply, decimals))?)
+            .ok_or(BondingCurveError::MathOverflow)?;
+        math::to_atoms_down(proceeds)
+    }
+}
+
+/// Token atoms to whole tokens.
+fn to_fixed(atoms: u64, decimals: u8) -> u128 {
+    atoms as u128 * ONE / 10u128.pow(decimals as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECIMALS: u8 = 6;
+    const TOKEN: u64 = 1_000_000;
+
+    // The same curves in floating point, straight from the formulas.
+    fn reference_cost(curve: &Curve, from: f64, to: f64) -> f64 {
+        let integral = |x: f64| match *curve {
+            Curve::Linear { base_price, slope } => {
+                let (base_price, slope) = (base_price as f64 / 1e9, slope as f64 / 1e9);
+                base_price * x + slope * x * x / 2.0
+            }
+            Curve::Exponential { base_price, growth } => {
+                let (base_price, growth) = (base_price as f64 / 1e9, growth as f64 / 1e9);
+                base_price / growth * ((growth * x).exp() - 1.0)
+            }
+        };
+        integral(to) - integral(from)
+    }
+
+    fn linear() -> Curve {
+        // starts at 1000 quote atoms per token, +10 per token sold
+        Curve::Linear {
+            base_price: 1_000 * ONE,
+            slope: 10 * ONE,
+        }
+    }
+
+    fn exponential() -> Curve {
+        // starts at 1000 quote atoms per token, +1% per token sold (roughly)
+        Curve::Exponential {
+            base_price: 1_000 * ONE,
+            growth: ONE / 100,
+        }
+    }
+
+    #[test]
+    fn rejects_flat_curves() {
+        assert!(Curve::Linear {
+            base_price: 0,
+            slope: 0
+        }
+        .validate()
+        .is_err());
+        assert!(Curve::Linear {
+            base_price: 0,
+            slope: 1
+        }
+        .validate()
+        .is_ok());
+        assert!(Curve::Exponential {
+            base_price: ONE,
+            growth: 0
+        }
+        .validate()
+        .is_err());
+        assert!(Curve::Exponential {
+            base_price: 0,
+            growth: ONE
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn linear_first_token() {
+        // 1000 + 10 * 1 / 2
+        assert_eq!(linear().buy_cost(0, TOKEN, DECIMALS).unwrap(), 1_005);
+        // from 100 to 101 tokens: 1000 + 10 * 100.5
+        assert_eq!(
+            linear().buy_cost(100 * TOKEN, TOKEN, DECIMALS).unwrap(),
+            2_005
+        );
+    }
+
+    #[test]
+    fn matches_reference() {
+        for curve in [linear(), exponential()] {
+            for (supply, amount) in [
+                (0, 1),
+                (0, TOKEN),
+                (0, 1_000 * TOKEN),
+                (123 * TOKEN + 456, 7 * TOKEN + 89),
+                (1_000 * TOKEN, 1),
+                (1_000 * TOKEN, 500 * TOKEN),
+            ] {
+                let from = supply as f64 / TOKEN as f64;
+                let to = (supply + amount) as f64 / TOKEN as f64;
+                let expected = reference_cost(&curve, from, to);
+
+                let cost = curve.buy_cost(supply, amount, DECIMALS).unwrap() as f64;
+                let proceeds = curve
+                    .sell_proceeds(supply + amount, amount, DECIMALS)
+                    .unwrap() as f64;
+                // rounding to whole atoms is worth at most one atom, the fixed-point
+                // arithmetic adds a relative error far below that
+                let tolerance = 1.0 + expected * 1e-7;
+                assert!(
+                    (cost - expected).abs() <= tolerance,
+                    "{curve:?}: buying {amount} at {supply} costs {cost}, expected {expected}"
+                );
+                assert!(
+                    (proceeds - expected).abs() <= tolerance,
+                    "{curve:?}: selling {amount} at {} pays {proceeds}, expected {expected}",
+                    supply + amount
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_never_profit() {
+        for curve in [linear(), exponential()] {
+            for supply in [0, 1, TOKEN - 1, 42 * TOKEN + 1] {
+                for amount in [1, 3, TOKEN / 3, 10 * TOKEN + 7] {
+                    let cost = curve.buy_cost(supply, amount, DECIMALS).unwrap();
+                    let proceeds = curve
+                        .sell_proceeds(supply + amount, amount, DECIMALS)
+                        .unwrap();
+                    assert!(proceeds <= cost, "{curve:?}: {supply} + {amount}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reserve_covers_selling_everything() {
+        // buy in many small steps, where per-trade rounding would add up if it went the wrong way
+        for curve in [linear(), exponential()] {
+            let mut supply = 0;
+            let mut reserve = 0;
+            for step in 1..=200 {
+                let amount = step * 12_345;
+                reserve += curve.buy_cost(supply, amount, DECIMALS).unwrap();
+                supply += amount;
+                if step % 3 == 0 {
+                    let amount = amount / 2;
+                    reserve -= curve.sell_proceeds(supply, amount, DECIMALS).unwrap();
+                    supply -= amount;
+                }
+            }
+            assert!(curve.sell_proceeds(supply, supply, DECIMALS).unwrap() <= reserve);
+        }
+    }
+
+    #[test]
+    fn cannot_sell_more_than_the_supply() {
+        assert!(linear().sell_proceeds(TOKEN, TOKEN + 1, DECIMALS).is_err());
+    }
+}
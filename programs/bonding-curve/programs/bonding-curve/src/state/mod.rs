@@ -0,0 +1,5 @@
+pub use bonding_curve::*;
+pub use curve::*;
+
+pub mod bonding_curve;
+pub mod curve;
@@ -0,0 +1,127 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Harness, expectError } from 'test-harness';
+import { BondingCurve } from '../../../target/types/bonding_curve';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+// the curve's parameters are fixed-point numbers with 9 decimals
+const ONE = new BN(1_000_000_000);
+const TOKEN = new BN(1_000_000);
+
+describe('bonding-curve', () => {
+  let harness: Harness;
+  let program: Program<BondingCurve>;
+  let creator: anchor.web3.PublicKey;
+
+  let quoteMint: anchor.web3.PublicKey;
+  let quoteAccount: anchor.web3.PublicKey;
+
+  function curveAccounts(id: number) {
+    const [bondingCurve] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('bonding_curve'), creator.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const [mint] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('mint'), bondingCurve.toBuffer()],
+      program.programId
+    );
+    const [reserve] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('reserve'), bondingCurve.toBuffer()],
+      program.programId
+    );
+    return { bondingCurve, mint, reserve };
+  }
+
+  function trade(id: number, tokenAccount: anchor.web3.PublicKey) {
+    const { bondingCurve, mint, reserve } = curveAccounts(id);
+    return {
+      buy: (amount: anchor.BN, maxCost: number) =>
+        program.methods
+          .buy(amount, new BN(maxCost))
+          .accounts({
+            bondingCurve,
+            mint,
+            reserve,
+            buyerTokenAccount: tokenAccount,
+            buyerQuoteAccount: quoteAccount,
+            buyer: creator,
+          })
+          .rpc(),
+      sell: (amount: anchor.BN, minProceeds: number) =>
+        program.methods
+          .sell(amount, new BN(minProceeds))
+          .accounts({
+            bondingCurve,
+            mint,
+            reserve,
+            sellerTokenAccount: tokenAccount,
+            sellerQuoteAccount: quoteAccount,
+            seller: creator,
+          })
+          .rpc(),
+    };
+  }
+
+  async function createCurve(id: number, curve: any) {
+    await program.methods
+      .createCurve(new BN(id), 6, curve)
+      .accounts({ ...curveAccounts(id), quoteMint, creator })
+      .rpc();
+    return harness.createTokenAccount(curveAccounts(id).mint, creator);
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<BondingCurve>('bonding_curve');
+    creator = harness.payer.publicKey;
+
+    quoteMint = await harness.createMint(creator);
+    quoteAccount = await harness.createTokenAccount(quoteMint, creator, 1_000_000_000);
+  });
+
+  it('buy and sell along a linear curve!', async () => {
+    await expectError(createCurve(0, { linear: { basePrice: new BN(0), slope: new BN(0) } }), 'InvalidCurve', 6000);
+    // starts at 1000 quote atoms per token, +10 per token sold
+    const tokenAccount = await createCurve(0, { linear: { basePrice: ONE.muln(1_000), slope: ONE.muln(10) } });
+    const { buy, sell } = trade(0, tokenAccount);
+    const { reserve, mint } = curveAccounts(0);
+
+    // the first token costs 1000 + 10 / 2
+    await expectError(buy(TOKEN, 1_004), 'SlippageExceeded', 6003);
+    await buy(TOKEN, 1_005);
+    expect(await harness.tokenBalance(tokenAccount)).to.equal(TOKEN.toNumber());
+    expect(await harness.tokenBalance(reserve)).to.equal(1_005);
+
+    // the hundredth token costs 1000 + 10 * 99.5
+    await buy(TOKEN.muln(98), 1_000_000);
+    await buy(TOKEN, 1_995);
+
+    // selling walks back down the same curve
+    await expectError(sell(TOKEN, 1_996), 'SlippageExceeded', 6003);
+    await sell(TOKEN, 1_995);
+    await sell(TOKEN.muln(99), 0);
+    expect(await harness.tokenSupply(mint)).to.equal(0);
+    expect(await harness.tokenBalance(reserve)).to.equal(0);
+  });
+
+  it('rounding always favours the reserve', async () => {
+    const tokenAccount = await createCurve(1, { exponential: { basePrice: ONE.muln(1_000), growth: ONE.divn(100) } });
+    const { buy, sell } = trade(1, tokenAccount);
+    const { reserve } = curveAccounts(1);
+
+    // many tiny trades, each of which is worth a fraction of a quote atom
+    for (let i = 0; i < 5; i++) {
+      await buy(new BN(1), 1);
+    }
+    // each buy was rounded up to a full atom
+    expect(await harness.tokenBalance(reserve)).to.equal(5);
+    // and selling all of it back is rounded down
+    await sell(new BN(5), 0);
+    expect(await harness.tokenBalance(reserve)).to.be.greaterThan(0);
+  });
+});
@@ -0,0 +1,14 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum BountiesError {
+    TitleTooLong,
+    ZeroReward,
+    DeadlineInThePast,
+    InvalidReviewPeriod,
+    SubmissionsClosed,
+    InvalidState,
+    WrongHunter,
+    ReviewPeriodNotOver,
+    DeadlineNotReached,
+}
@@ -0,0 +1,29 @@
+use crate::errors::BountiesError;
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+
+// Pays the hunter and closes the bounty, its rent going back to the poster.
+pub fn accept_submission(ctx: Context<AcceptSubmission>) -> Result<()> {
+    let hunter = ctx.accounts.bounty.accept()?;
+    require_keys_eq!(
+        hunter,
+        ctx.accounts.hunter.key(),
+        BountiesError::WrongHunter
+    );
+
+    let reward = ctx.accounts.bounty.reward;
+    ctx.accounts.bounty.sub_lamports(reward)?;
+    ctx.accounts.hunter.add_lamports(reward)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptSubmission<'info> {
+    #[account(mut, close = poster, has_one = poster)]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: only receives the reward, and is checked against the submission
+    #[account(mut)]
+    pub hunter: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub poster: Signer<'info>,
+}
@@ -0,0 +1,15 @@
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+
+// Nobody delivered in time, the reward and the rent both go back to the poster.
+pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+    ctx.accounts.bounty.cancel(Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct CancelBounty<'info> {
+    #[account(mut, close = poster, has_one = poster)]
+    pub bounty: Account<'info, Bounty>,
+    #[account(mut)]
+    pub poster: Signer<'info>,
+}
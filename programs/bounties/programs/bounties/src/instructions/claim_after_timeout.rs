@@ -0,0 +1,32 @@
+use crate::errors::BountiesError;
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+
+// A poster who neither accepts nor disputes in time is taken to accept.
+pub fn claim_after_timeout(ctx: Context<ClaimAfterTimeout>) -> Result<()> {
+    let hunter = ctx
+        .accounts
+        .bounty
+        .claim_after_timeout(Clock::get()?.unix_timestamp)?;
+    require_keys_eq!(
+        hunter,
+        ctx.accounts.hunter.key(),
+        BountiesError::WrongHunter
+    );
+
+    let reward = ctx.accounts.bounty.reward;
+    ctx.accounts.bounty.sub_lamports(reward)?;
+    ctx.accounts.hunter.add_lamports(reward)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimAfterTimeout<'info> {
+    #[account(mut, close = poster, has_one = poster)]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: only receives the rent of the closed bounty, and is checked against `bounty.poster`
+    #[account(mut)]
+    pub poster: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub hunter: Signer<'info>,
+}
@@ -0,0 +1,14 @@
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+
+// The poster isn't satisfied, and hands the decision to the arbiter.
+pub fn dispute_submission(ctx: Context<DisputeSubmission>) -> Result<()> {
+    ctx.accounts.bounty.dispute()
+}
+
+#[derive(Accounts)]
+pub struct DisputeSubmission<'info> {
+    #[account(mut, has_one = poster)]
+    pub bounty: Account<'info, Bounty>,
+    pub poster: Signer<'info>,
+}
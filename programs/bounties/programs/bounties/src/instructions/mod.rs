@@ -0,0 +1,15 @@
+pub use accept_submission::*;
+pub use cancel_bounty::*;
+pub use claim_after_timeout::*;
+pub use dispute_submission::*;
+pub use post_bounty::*;
+pub use resolve_dispute::*;
+pub use submit_work::*;
+
+pub mod accept_submission;
+pub mod cancel_bounty;
+pub mod claim_after_timeout;
+pub mod dispute_submission;
+pub mod post_bounty;
+pub mod resolve_dispute;
+pub mod submit_work;
@@ -0,0 +1,62 @@
+use crate::errors::BountiesError;
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn post_bounty(
+    ctx: Context<PostBounty>,
+    id: u64,
+    title: String,
+    reward: u64,
+    deadline_ts: i64,
+    review_period: i64,
+    arbiter: Pubkey,
+) -> Result<()> {
+    require_gte!(MAX_TITLE_LEN, title.len(), BountiesError::TitleTooLong);
+    require_gt!(reward, 0, BountiesError::ZeroReward);
+    require_gt!(
+        deadline_ts,
+        Clock::get()?.unix_timestamp,
+        BountiesError::DeadlineInThePast
+    );
+    require_gt!(review_period, 0, BountiesError::InvalidReviewPeriod);
+
+    ctx.accounts.bounty.set_inner(Bounty {
+        poster: ctx.accounts.poster.key(),
+        id,
+        title,
+        reward,
+        deadline_ts,
+        review_period,
+        arbiter,
+        state: BountyState::Open,
+        bump: ctx.bumps.bounty,
+    });
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.poster.to_account_info(),
+                to: ctx.accounts.bounty.to_account_info(),
+            },
+        ),
+        reward,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct PostBounty<'info> {
+    #[account(
+        init,
+        payer = poster,
+        space = 8 + Bounty::INIT_SPACE,
+        seeds = [Bounty::SEED_PREFIX, poster.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(mut)]
+    pub poster: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
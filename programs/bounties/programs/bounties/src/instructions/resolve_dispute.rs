@@ -0,0 +1,34 @@
+use crate::errors::BountiesError;
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, pay_hunter: bool) -> Result<()> {
+    let Some(hunter) = ctx.accounts.bounty.resolve(pay_hunter)? else {
+        return Ok(());
+    };
+    require_keys_eq!(
+        hunter,
+        ctx.accounts.hunter.key(),
+        BountiesError::WrongHunter
+    );
+
+    let reward = ctx.accounts.bounty.reward;
+    ctx.accounts.bounty.sub_lamports(reward)?;
+    ctx.accounts.hunter.add_lamports(reward)?;
+    ctx.accounts
+        .bounty
+        .close(ctx.accounts.poster.to_account_info())
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut, has_one = arbiter, has_one = poster)]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: only receives the reward, and is checked against the submission
+    #[account(mut)]
+    pub hunter: UncheckedAccount<'info>,
+    /// CHECK: only receives the rent of the closed bounty, and is checked against `bounty.poster`
+    #[account(mut)]
+    pub poster: UncheckedAccount<'info>,
+    pub arbiter: Signer<'info>,
+}
@@ -0,0 +1,17 @@
+use crate::state::bounty::*;
+use anchor_lang::prelude::*;
+
+pub fn submit_work(ctx: Context<SubmitWork>, deliverable: [u8; 32]) -> Result<()> {
+    ctx.accounts.bounty.submit(
+        ctx.accounts.hunter.key(),
+        deliverable,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+#[derive(Accounts)]
+pub struct SubmitWork<'info> {
+    #[account(mut)]
+    pub bounty: Account<'info, Bounty>,
+    pub hunter: Signer<'info>,
+}
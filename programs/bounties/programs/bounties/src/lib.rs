@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("4FK8vjEQZBaoFJwW9reL8GoJ2HoTe4iyXtEGcZbjAUAt");
+
+#[program]
+pub mod bounties {
+    use super::*;
+
+    pub fn post_bounty(
+        ctx: Context<PostBounty>,
+        id: u64,
+        title: String,
+        reward: u64,
+        deadline_ts: i64,
+        review_period: i64,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        instructions::post_bounty::post_bounty(
+            ctx,
+            id,
+            title,
+            reward,
+            deadline_ts,
+            review_period,
+            arbiter,
+        )
+    }
+
+    pub fn submit_work(ctx: Context<SubmitWork>, deliverable: [u8; 32]) -> Result<()> {
+        instructions::submit_work::submit_work(ctx, deliverable)
+    }
+
+    pub fn accept_submission(ctx: Context<AcceptSubmission>) -> Result<()> {
+        instructions::accept_submission::accept_submission(ctx)
+    }
+
+    pub fn dispute_submission(ctx: Context<DisputeSubmission>) -> Result<()> {
+        instructions::dispute_submission::dispute_submission(ctx)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, pay_hunter: bool) -> Result<()> {
+        instructions::resolve_dispute::resolve_dispute(ctx, pay_hunter)
+    }
+
+    pub fn claim_after_timeout(ctx: Context<ClaimAfterTimeout>) -> Result<()> {
+        instructions::claim_after_timeout::claim_after_timeout(ctx)
+    }
+
+    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        instructions::cancel_bounty::cancel_bounty(ctx)
+    }
+}
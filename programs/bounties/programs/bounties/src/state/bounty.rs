@@ -0,0 +1,124 @@
+use crate::errors::BountiesError;
+use anchor_lang::prelude::*;
+
+pub const MAX_TITLE_LEN: usize = 64;
+
+/// A reward, escrowed in the bounty account on top of its rent, for whoever delivers.
+///
+/// Every party has a way out if another one goes silent:
+/// - a poster who never reviews a submission loses the reward after `review_period`,
+/// - a bounty nobody delivers on can be cancelled once the deadline passed,
+/// - a disputed submission is settled by the arbiter, picked when posting.
+#[account]
+#[derive(InitSpace)]
+pub struct Bounty {
+    pub poster: Pubkey,
+    pub id: u64,
+    #[max_len(MAX_TITLE_LEN)]
+    pub title: String,
+    // in lamports
+    pub reward: u64,
+    // submissions are accepted until then
+    pub deadline_ts: i64,
+    // seconds the poster has to accept or dispute a submission
+    pub review_period: i64,
+    pub arbiter: Pubkey,
+    pub state: BountyState,
+    pub bump: u8,
+}
+
+// Paid and cancelled bounties are closed, so they need no state of their own.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum BountyState {
+    Open,
+    // `deliverable` is a hash of the work, which itself is handed over off-chain
+    Submitted {
+        hunter: Pubkey,
+        deliverable: [u8; 32],
+        submitted_ts: i64,
+    },
+    Disputed {
+        hunter: Pubkey,
+        deliverable: [u8; 32],
+    },
+}
+
+impl Bounty {
+    pub const SEED_PREFIX: &'static [u8] = b"bounty";
+
+    pub fn submit(&mut self, hunter: Pubkey, deliverable: [u8; 32], now: i64) -> Result<()> {
+        // one submission at a time, the next hunter waits for it to be disputed and rejected
+        require!(self.state == BountyState::Open, BountiesError::InvalidState);
+        require_gt!(self.deadline_ts, now, BountiesError::SubmissionsClosed);
+        self.state = BountyState::Submitted {
+            hunter,
+            deliverable,
+            submitted_ts: now,
+        };
+        Ok(())
+    }
+
+    /// Returns the hunter to pay.
+    pub fn accept(&self) -> Result<Pubkey> {
+        match self.state {
+            BountyState::Submitted { hunter, .. } => Ok(hunter),
+            _ => err!(BountiesError::InvalidState),
+        }
+    }
+
+    pub fn dispute(&mut self) -> Result<()> {
+        match self.state {
+            BountyState::Submitted {
+                hunter,
+                deliverable,
+                ..
+            } => {
+                self.state = BountyState::Disputed {
+                    hunter,
+                    deliverable,
+                };
+                Ok(())
+            }
+            _ => err!(BountiesError::InvalidState),
+        }
+    }
+
+    /// Returns the hunter to pay, if the arbiter sided with them. Otherwise,
+    /// the bounty opens up again for other hunters.
+    pub fn resolve(&mut self, pay_hunter: bool) -> Result<Option<Pubkey>> {
+        match self.state {
+            BountyState::Disputed { hunter, .. } if pay_hunter => Ok(Some(hunter)),
+            BountyState::Disputed { .. } => {
+                self.state = BountyState::Open;
+                Ok(None)
+            }
+            _ => err!(BountiesError::InvalidState),
+        }
+    }
+
+    /// Returns the hunter to pay, whose submission went unanswered for too long.
+    pub fn claim_after_timeout(&self, now: i64) -> Result<Pubkey> {
+        match self.state {
+            BountyState::Submitted {
+                hunter,
+                submitted_ts,
+                ..
+            } => {
+                require_gte!(
+                    now,
+                    submitted_ts + self.review_period,
+                    BountiesError::ReviewPeriodNotOver
+                );
+                Ok(hunter)
+            }
+            _ => err!(BountiesError::InvalidState),
+        }
+    }
+
+    pub fn cancel(&self, now: i64) -> Result<()> {
+        require!(self.state == BountyState::Open, BountiesError::InvalidState);
+        // hunters may be working on it, so it stays up until the deadline
+        require_gte!(now, self.deadline_ts, BountiesError::DeadlineNotReached);
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub use bounty::*;
+
+pub mod bounty;
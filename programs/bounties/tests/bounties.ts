@@ -0,0 +1,149 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { createHash } from 'crypto';
+import { Harness, expectError } from 'test-harness';
+import { Bounties } from '../../../target/types/bounties';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('bounties', () => {
+  let harness: Harness;
+  let program: Program<Bounties>;
+
+  const poster = Keypair.generate();
+  const hunter = Keypair.generate();
+  const otherHunter = Keypair.generate();
+  const arbiter = Keypair.generate();
+  const reward = LAMPORTS_PER_SOL / 2;
+
+  const deliverable = (work: string) => Array.from(createHash('sha256').update(work).digest());
+
+  function bountyAddress(id: number) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('bounty'), poster.publicKey.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+  }
+
+  async function postBounty(id: number, deadline: number, reviewPeriod: number) {
+    await program.methods
+      .postBounty(new BN(id), `bounty ${id}`, new BN(reward), new BN(deadline), new BN(reviewPeriod), arbiter.publicKey)
+      .accounts({ bounty: bountyAddress(id), poster: poster.publicKey })
+      .signers([poster])
+      .rpc();
+  }
+
+  async function submitWork(id: number, signer: Keypair, work: string) {
+    await program.methods
+      .submitWork(deliverable(work))
+      .accounts({ bounty: bountyAddress(id), hunter: signer.publicKey })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function disputeSubmission(id: number) {
+    await program.methods
+      .disputeSubmission()
+      .accounts({ bounty: bountyAddress(id), poster: poster.publicKey })
+      .signers([poster])
+      .rpc();
+  }
+
+  async function resolveDispute(id: number, payHunter: boolean, paid: Keypair) {
+    await program.methods
+      .resolveDispute(payHunter)
+      .accounts({ bounty: bountyAddress(id), hunter: paid.publicKey, poster: poster.publicKey, arbiter: arbiter.publicKey })
+      .signers([arbiter])
+      .rpc();
+  }
+
+  async function claimAfterTimeout(id: number) {
+    await program.methods
+      .claimAfterTimeout()
+      .accounts({ bounty: bountyAddress(id), poster: poster.publicKey, hunter: hunter.publicKey })
+      .signers([hunter])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Bounties>('bounties');
+    await harness.fund(poster.publicKey, 10 * LAMPORTS_PER_SOL);
+  });
+
+  it('post, deliver, get paid!', async () => {
+    await expectError(postBounty(0, (await harness.now()) - 1, 60), 'DeadlineInThePast', 6002);
+    await postBounty(0, (await harness.now()) + 60, 60);
+    const bounty = bountyAddress(0);
+    const rent = await harness.rentExemption((await harness.account(bounty)).data.length);
+    expect(await harness.lamports(bounty)).to.equal(rent + reward);
+
+    await submitWork(0, hunter, 'the work');
+    await expectError(submitWork(0, otherHunter, 'other work'), 'InvalidState', 6005);
+    const bountyState = await program.account.bounty.fetch(bounty);
+    expect(bountyState.state.submitted.deliverable).to.deep.equal(deliverable('the work'));
+
+    // the reward only goes to whoever submitted
+    const accept = (paid: Keypair) =>
+      program.methods
+        .acceptSubmission()
+        .accounts({ bounty, hunter: paid.publicKey, poster: poster.publicKey })
+        .signers([poster])
+        .rpc();
+    await expectError(accept(otherHunter), 'WrongHunter', 6006);
+    await accept(hunter);
+    expect(await harness.lamports(hunter.publicKey)).to.equal(reward);
+    expect(await harness.account(bounty)).to.be.null;
+  });
+
+  it('the arbiter settles disputes', async () => {
+    await postBounty(1, (await harness.now()) + 60, 60);
+    await expectError(disputeSubmission(1), 'InvalidState', 6005);
+
+    // the arbiter rejects the first submission, which opens the bounty up again
+    await submitWork(1, otherHunter, 'sloppy work');
+    await disputeSubmission(1);
+    await resolveDispute(1, false, otherHunter);
+    expect((await program.account.bounty.fetch(bountyAddress(1))).state).to.deep.equal({ open: {} });
+    expect(await harness.lamports(otherHunter.publicKey)).to.equal(0);
+
+    // ... and sides with the second one
+    await submitWork(1, hunter, 'good work');
+    await disputeSubmission(1);
+    await resolveDispute(1, true, hunter);
+    expect(await harness.lamports(hunter.publicKey)).to.equal(2 * reward);
+    expect(await harness.account(bountyAddress(1))).to.be.null;
+  });
+
+  it('timeouts when someone goes silent', async () => {
+    // the poster never reviews
+    await postBounty(2, (await harness.now()) + 60, 2);
+    await submitWork(2, hunter, 'ignored work');
+    await expectError(claimAfterTimeout(2), 'ReviewPeriodNotOver', 6007);
+    await harness.warp(3);
+    await claimAfterTimeout(2);
+    expect(await harness.lamports(hunter.publicKey)).to.equal(3 * reward);
+
+    // nobody delivers
+    await postBounty(3, (await harness.now()) + 2, 60);
+    const cancel = () =>
+      program.methods
+        .cancelBounty()
+        .accounts({ bounty: bountyAddress(3), poster: poster.publicKey })
+        .signers([poster])
+        .rpc();
+    await expectError(cancel(), 'DeadlineNotReached', 6008);
+    await harness.warp(3);
+    await expectError(submitWork(3, hunter, 'too late'), 'SubmissionsClosed', 6004);
+    const posterBefore = await harness.lamports(poster.publicKey);
+    const bountyLamports = await harness.lamports(bountyAddress(3));
+    await cancel();
+    expect(await harness.lamports(poster.publicKey)).to.equal(posterBefore + bountyLamports);
+  });
+});
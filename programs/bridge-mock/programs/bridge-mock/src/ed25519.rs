@@ -0,0 +1,69 @@
+//! Reading signatures checked by the ed25519 program.
+//!
+//! Verifying a signature inside a program would blow through the compute budget. Instead,
+//! the transaction includes instructions for the native ed25519 program, which fail the whole
+//! transaction if any signature is invalid. A program then only has to look at those
+//! instructions, through the instructions sysvar, to learn which keys signed which message.
+
+use crate::errors::BridgeError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+const PUBKEY_LEN: usize = 32;
+const OFFSETS_START: usize = 2;
+const OFFSETS_LEN: usize = 14;
+
+/// The keys that signed `message` in ed25519 instructions placed before the current one.
+pub fn signers_of(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Vec<Pubkey>> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let mut signers = vec![];
+    for index in 0..current {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if instruction.program_id == ed25519_program::ID {
+            signers.extend(parse(&instruction.data, message)?);
+        }
+    }
+    Ok(signers)
+}
+
+// The data starts with the number of signatures and a padding byte, followed by one set of
+// offsets per signature, each pointing at a signature, a public key and a message.
+fn parse(data: &[u8], message: &[u8]) -> Result<Vec<Pubkey>> {
+    let count = *data
+        .first()
+        .ok_or(BridgeError::InvalidSignatureInstruction)? as usize;
+    let mut signers = vec![];
+    for i in 0..count {
+        let start = OFFSETS_START + i * OFFSETS_LEN;
+        let offsets = data
+            .get(start..start + OFFSETS_LEN)
+            .ok_or(BridgeError::InvalidSignatureInstruction)?;
+        let read = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+        let (pubkey_offset, pubkey_ix) = (read(4) as usize, read(6));
+        let (message_offset, message_len, message_ix) =
+            (read(8) as usize, read(10) as usize, read(12));
+
+        // the offsets may point into other instructions, whose data we didn't check, so only
+        // signatures with everything inside this one instruction (`u16::MAX`) are trusted
+        require!(
+            read(2) == u16::MAX && pubkey_ix == u16::MAX && message_ix == u16::MAX,
+            BridgeError::InvalidSignatureInstruction
+        );
+
+        let pubkey = data
+            .get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+            .ok_or(BridgeError::InvalidSignatureInstruction)?;
+        let signed = data
+            .get(message_offset..message_offset + message_len)
+            .ok_or(BridgeError::InvalidSignatureInstruction)?;
+        if signed == message {
+            signers.push(
+                Pubkey::try_from(pubkey).map_err(|_| BridgeError::InvalidSignatureInstruction)?,
+            );
+        }
+    }
+    Ok(signers)
+}
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// What the guardians watch for, and sign once it is final.
+#[event]
+pub struct Locked {
+    pub bridge: Pubkey,
+    pub sequence: u64,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,75 @@
+use crate::errors::BridgeError;
+use crate::state::bridge::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+// Burning first means the wrapped supply never exceeds what is locked, not even in between.
+pub fn burn_and_unlock(ctx: Context<BurnAndUnlock>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, BridgeError::ZeroAmount);
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.wrapped_mint.to_account_info(),
+                from: ctx.accounts.owner_wrapped_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let bridge = &ctx.accounts.bridge;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Bridge::SEED_PREFIX,
+        bridge.native_mint.as_ref(),
+        &[bridge.bump],
+    ]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner_native_token_account.to_account_info(),
+                authority: ctx.accounts.bridge.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct BurnAndUnlock<'info> {
+    #[account(
+        seeds = [Bridge::SEED_PREFIX, bridge.native_mint.as_ref()],
+        bump = bridge.bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+    #[account(
+        mut,
+        seeds = [Bridge::VAULT_SEED_PREFIX, bridge.key().as_ref()],
+        bump = bridge.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [Bridge::WRAPPED_MINT_SEED_PREFIX, bridge.key().as_ref()],
+        bump = bridge.wrapped_mint_bump
+    )]
+    pub wrapped_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = wrapped_mint,
+        token::authority = owner
+    )]
+    pub owner_wrapped_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = bridge.native_mint,
+        token::authority = owner
+    )]
+    pub owner_native_token_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,49 @@
+use crate::state::bridge::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn initialize(ctx: Context<Initialize>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    bridge.native_mint = ctx.accounts.native_mint.key();
+    bridge.sequence = 0;
+    bridge.bump = ctx.bumps.bridge;
+    bridge.vault_bump = ctx.bumps.vault;
+    bridge.wrapped_mint_bump = ctx.bumps.wrapped_mint;
+    bridge.set_guardians(guardians, threshold)
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Bridge::INIT_SPACE,
+        seeds = [Bridge::SEED_PREFIX, native_mint.key().as_ref()],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+    pub native_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = native_mint,
+        token::authority = bridge,
+        seeds = [Bridge::VAULT_SEED_PREFIX, bridge.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    // only the bridge can mint, and only with the guardians' signatures
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = native_mint.decimals,
+        mint::authority = bridge,
+        seeds = [Bridge::WRAPPED_MINT_SEED_PREFIX, bridge.key().as_ref()],
+        bump
+    )]
+    pub wrapped_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
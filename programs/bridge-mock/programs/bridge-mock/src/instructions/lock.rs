@@ -0,0 +1,56 @@
+use crate::errors::BridgeError;
+use crate::events::Locked;
+use crate::state::bridge::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn lock(ctx: Context<Lock>, amount: u64, recipient: Pubkey) -> Result<()> {
+    require_gt!(amount, 0, BridgeError::ZeroAmount);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let bridge = &mut ctx.accounts.bridge;
+    emit!(Locked {
+        bridge: bridge.key(),
+        sequence: bridge.sequence,
+        sender: ctx.accounts.sender.key(),
+        recipient,
+        amount,
+    });
+    bridge.sequence += 1;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Lock<'info> {
+    #[account(
+        mut,
+        seeds = [Bridge::SEED_PREFIX, bridge.native_mint.as_ref()],
+        bump = bridge.bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+    #[account(
+        mut,
+        seeds = [Bridge::VAULT_SEED_PREFIX, bridge.key().as_ref()],
+        bump = bridge.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = bridge.native_mint,
+        token::authority = sender
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    pub sender: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,104 @@
+use crate::ed25519;
+use crate::errors::BridgeError;
+use crate::state::bridge::*;
+use crate::state::claim::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+// Anyone may relay an attestation, the guardians' signatures are what counts.
+pub fn mint_wrapped(
+    ctx: Context<MintWrapped>,
+    sequence: u64,
+    recipient: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    require_gt!(amount, 0, BridgeError::ZeroAmount);
+
+    let bridge = &ctx.accounts.bridge;
+    let message = Attestation {
+        bridge: bridge.key(),
+        sequence,
+        recipient,
+        amount,
+    }
+    .try_to_vec()?;
+    let signers = ed25519::signers_of(&ctx.accounts.instructions_sysvar, &message)?;
+    bridge.check_quorum(&signers)?;
+
+    // even a quorum of compromised guardians can't mint more than was locked
+    let supply = ctx.accounts.wrapped_mint.supply;
+    require_gte!(
+        ctx.accounts.vault.amount,
+        supply
+            .checked_add(amount)
+            .ok_or(BridgeError::SupplyExceedsLocked)?,
+        BridgeError::SupplyExceedsLocked
+    );
+
+    ctx.accounts.claim.set_inner(Claim {
+        sequence,
+        recipient,
+        amount,
+    });
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Bridge::SEED_PREFIX,
+        bridge.native_mint.as_ref(),
+        &[bridge.bump],
+    ]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.wrapped_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.bridge.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(sequence: u64, recipient: Pubkey)]
+pub struct MintWrapped<'info> {
+    #[account(
+        seeds = [Bridge::SEED_PREFIX, bridge.native_mint.as_ref()],
+        bump = bridge.bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+    #[account(
+        seeds = [Bridge::VAULT_SEED_PREFIX, bridge.key().as_ref()],
+        bump = bridge.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [Bridge::WRAPPED_MINT_SEED_PREFIX, bridge.key().as_ref()],
+        bump = bridge.wrapped_mint_bump
+    )]
+    pub wrapped_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Claim::INIT_SPACE,
+        seeds = [Claim::SEED_PREFIX, bridge.key().as_ref(), sequence.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, Claim>,
+    #[account(
+        mut,
+        token::mint = wrapped_mint,
+        token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: the address is checked, it holds the instructions of the current transaction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
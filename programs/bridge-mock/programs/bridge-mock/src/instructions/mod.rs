@@ -0,0 +1,9 @@
+pub use burn_and_unlock::*;
+pub use initialize::*;
+pub use lock::*;
+pub use mint_wrapped::*;
+
+pub mod burn_and_unlock;
+pub mod initialize;
+pub mod lock;
+pub mod mint_wrapped;
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod ed25519;
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("7qKteUHikZizFsJyhng78MS9tmZfbjGkdqKobSBmXxDC");
+
+/// A bridge between two chains, both of which are this one.
+///
+/// Tokens of `native_mint` are locked in a vault, and a set of guardians attests to it by
+/// signing. With enough of their signatures, a wrapped token is minted "on the other side".
+/// Burning the wrapped token unlocks the original. In a real bridge the guardians would also
+/// attest to the burn on the other chain, here burn and unlock can simply happen together.
+#[program]
+pub mod bridge_mock {
+    use super::*;
+
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::initialize::initialize(ctx, guardians, threshold)
+    }
+
+    pub fn lock(ctx: Context<Lock>, amount: u64, recipient: Pubkey) -> Result<()> {
+        instructions::lock::lock(ctx, amount, recipient)
+    }
+
+    pub fn mint_wrapped(
+        ctx: Context<MintWrapped>,
+        sequence: u64,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_wrapped::mint_wrapped(ctx, sequence, recipient, amount)
+    }
+
+    pub fn burn_and_unlock(ctx: Context<BurnAndUnlock>, amount: u64) -> Result<()> {
+        instructions::burn_and_unlock::burn_and_unlock(ctx, amount)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::errors::BridgeError;
+use anchor_lang::prelude::*;
+
+pub const MAX_GUARDIANS: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Bridge {
+    pub native_mint: Pubkey,
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+    // how many of the guardians have to sign for tokens to be minted
+    pub threshold: u8,
+    // numbers every lock, so each one can be minted exactly once
+    pub sequence: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+    pub wrapped_mint_bump: u8,
+}
+
+/// What the guardians sign: "`amount` was locked, for `recipient`, as transfer `sequence`".
+/// Naming the bridge keeps signatures for one bridge from being replayed on another.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct Attestation {
+    pub bridge: Pubkey,
+    pub sequence: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl Bridge {
+    pub const SEED_PREFIX: &'static [u8] = b"bridge";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+    pub const WRAPPED_MINT_SEED_PREFIX: &'static [u8] = b"wrapped_mint";
+
+    pub fn set_guardians(&mut self, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(
+            !guardians.is_empty() && guardians.len() <= MAX_GUARDIANS,
+            BridgeError::InvalidGuardianSet
+        );
+        require!(
+            guardians
+                .iter()
+                .enumerate()
+                .all(|(i, guardian)| !guardians[..i].contains(guardian)),
+            BridgeError::InvalidGuardianSet
+        );
+        // more than half, or a minority of guardians could mint on their own
+        require!(
+            threshold as usize > guardians.len() / 2 && threshold as usize <= guardians.len(),
+            BridgeError::InvalidThreshold
+        );
+        self.guardians = guardians;
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    /// Counts the distinct guardians among `signers`, anybody else's signature doesn't count.
+    pub fn check_quorum(&self, signers: &[Pubkey]) -> Result<()> {
+        let approvals = self
+            .guardians
+            .iter()
+            .filter(|guardian| signers.contains(guardian))
+            .count();
+        require_gte!(
+            approvals,
+            self.threshold as usize,
+            BridgeError::NotEnoughSignatures
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Marks transfer `sequence` as minted. It is created with `init`, which fails if it exists,
+/// so the same attestation can't be used twice.
+#[account]
+#[derive(InitSpace)]
+pub struct Claim {
+    pub sequence: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+impl Claim {
+    pub const SEED_PREFIX: &'static [u8] = b"claim";
+}
@@ -0,0 +1,5 @@
+pub use bridge::*;
+pub use claim::*;
+
+pub mod bridge;
+pub mod claim;
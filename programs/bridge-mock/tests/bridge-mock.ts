@@ -0,0 +1,146 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Ed25519Program, Keypair, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js';
+import { Harness, expectError } from 'test-harness';
+import { BridgeMock } from '../../../target/types/bridge_mock';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('bridge-mock', () => {
+  let harness: Harness;
+  let program: Program<BridgeMock>;
+  let payer: Keypair;
+
+  const guardians = [...Array(5)].map(() => Keypair.generate());
+  const outsider = Keypair.generate();
+  const user = Keypair.generate();
+  let nativeMint: PublicKey;
+  let bridge: PublicKey;
+  let vault: PublicKey;
+  let wrappedMint: PublicKey;
+  let userNative: PublicKey;
+  let userWrapped: PublicKey;
+
+  // the borsh encoding of `Attestation`
+  function attestation(sequence: number, recipient: PublicKey, amount: number) {
+    return Buffer.concat([
+      bridge.toBuffer(),
+      new BN(sequence).toArrayLike(Buffer, 'le', 8),
+      recipient.toBuffer(),
+      new BN(amount).toArrayLike(Buffer, 'le', 8),
+    ]);
+  }
+
+  async function lock(amount: number) {
+    await program.methods
+      .lock(new BN(amount), user.publicKey)
+      .accounts({ bridge, vault, senderTokenAccount: userNative, sender: user.publicKey })
+      .signers([user])
+      .rpc();
+  }
+
+  // relays an attestation, with one ed25519 instruction per signer in front of it
+  async function mintWrapped(sequence: number, amount: number, signers: Keypair[], message?: Buffer) {
+    const [claim] = PublicKey.findProgramAddressSync(
+      [Buffer.from('claim'), bridge.toBuffer(), new BN(sequence).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const signed = message ?? attestation(sequence, user.publicKey, amount);
+    await program.methods
+      .mintWrapped(new BN(sequence), user.publicKey, new BN(amount))
+      .accounts({
+        bridge,
+        vault,
+        wrappedMint,
+        claim,
+        recipientTokenAccount: userWrapped,
+        payer: payer.publicKey,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions(
+        signers.map((signer) =>
+          Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message: signed })
+        )
+      )
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<BridgeMock>('bridge_mock');
+    payer = harness.payer;
+
+    nativeMint = await harness.createMint(payer.publicKey, 6);
+    [bridge] = PublicKey.findProgramAddressSync([Buffer.from('bridge'), nativeMint.toBuffer()], program.programId);
+    [vault] = PublicKey.findProgramAddressSync([Buffer.from('vault'), bridge.toBuffer()], program.programId);
+    [wrappedMint] = PublicKey.findProgramAddressSync([Buffer.from('wrapped_mint'), bridge.toBuffer()], program.programId);
+
+    const initialize = (threshold: number) =>
+      program.methods
+        .initialize(
+          guardians.map((g) => g.publicKey),
+          threshold
+        )
+        .accounts({ bridge, nativeMint, vault, wrappedMint, payer: payer.publicKey })
+        .rpc();
+    // 2 of 5 would let a minority mint
+    await expectError(initialize(2), 'InvalidThreshold', 6001);
+    await initialize(3);
+
+    userNative = await harness.createTokenAccount(nativeMint, user.publicKey, 1_000);
+    userWrapped = await harness.createTokenAccount(wrappedMint, user.publicKey);
+  });
+
+  it('lock, and mint with a quorum of guardians!', async () => {
+    await lock(600);
+    expect(await harness.tokenBalance(vault)).to.equal(600);
+
+    await expectError(mintWrapped(0, 600, guardians.slice(0, 2)), 'NotEnoughSignatures', 6004);
+    // outsiders don't count, and neither does the same guardian twice
+    await expectError(mintWrapped(0, 600, [guardians[0], guardians[1], outsider]), 'NotEnoughSignatures', 6004);
+    await expectError(mintWrapped(0, 600, [guardians[0], guardians[1], guardians[1]]), 'NotEnoughSignatures', 6004);
+    // signatures over a different amount don't count either
+    await expectError(
+      mintWrapped(0, 600, guardians.slice(0, 3), attestation(0, user.publicKey, 6_000)),
+      'NotEnoughSignatures',
+      6004
+    );
+
+    await mintWrapped(0, 600, guardians.slice(1, 4));
+    expect(await harness.tokenBalance(userWrapped)).to.equal(600);
+
+    // each transfer is minted once
+    try {
+      await mintWrapped(0, 600, guardians.slice(1, 4));
+      chai.assert(false, "should've failed but didn't ");
+    } catch (err) {
+      // the system program refuses to create the claim again, and says so in its logs
+      expect([String(err), ...(err.logs ?? [])].join('\n')).to.contain('already in use');
+    }
+  });
+
+  it('wrapped supply never exceeds what is locked', async () => {
+    // even with every guardian signing
+    await expectError(mintWrapped(1, 1, guardians), 'SupplyExceedsLocked', 6005);
+
+    await program.methods
+      .burnAndUnlock(new BN(250))
+      .accounts({
+        bridge,
+        vault,
+        wrappedMint,
+        ownerWrappedTokenAccount: userWrapped,
+        ownerNativeTokenAccount: userNative,
+        owner: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    expect(await harness.tokenBalance(userNative)).to.equal(650);
+    expect(await harness.tokenBalance(vault)).to.equal(350);
+    expect(await harness.tokenSupply(wrappedMint)).to.equal(350);
+  });
+});
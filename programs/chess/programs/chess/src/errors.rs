@@ -0,0 +1,19 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum ChessError {
+    InvalidTimeControl,
+    CannotPlayYourself,
+    GameAlreadyStarted,
+    GameNotActive,
+    NotAPlayer,
+    NotPlayersTurn,
+    SquareOutOfBounds,
+    NotYourPiece,
+    IllegalMove,
+    LeavesKingInCheck,
+    InvalidPromotion,
+    OutOfTime,
+    NotOutOfTime,
+    NoDrawToClaim,
+}
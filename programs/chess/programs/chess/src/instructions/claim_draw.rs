@@ -0,0 +1,15 @@
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn claim_draw(ctx: Context<ClaimDraw>) -> Result<()> {
+    let mut game = ctx.accounts.game.load_mut()?;
+    game.player_index(ctx.accounts.player.key())?;
+    game.claim_draw()
+}
+
+#[derive(Accounts)]
+pub struct ClaimDraw<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+}
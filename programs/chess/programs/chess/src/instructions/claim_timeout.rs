@@ -0,0 +1,16 @@
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+// Permissionless: a flag falls no matter who points it out.
+pub fn claim_timeout(ctx: Context<ClaimTimeout>) -> Result<()> {
+    ctx.accounts
+        .game
+        .load_mut()?
+        .claim_timeout(Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct ClaimTimeout<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+}
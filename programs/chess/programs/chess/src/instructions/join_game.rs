@@ -0,0 +1,21 @@
+use crate::errors::ChessError;
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+// Starts the clocks, so white can't run down black's time before black has even shown up.
+pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
+    let mut game = ctx.accounts.game.load_mut()?;
+    require_keys_eq!(
+        game.players[1],
+        ctx.accounts.black.key(),
+        ChessError::NotAPlayer
+    );
+    game.start(Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct JoinGame<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    pub black: Signer<'info>,
+}
@@ -0,0 +1,26 @@
+use crate::errors::ChessError;
+use crate::state::game::*;
+use crate::state::position::Promotion;
+use anchor_lang::prelude::*;
+
+pub fn make_move(
+    ctx: Context<MakeMove>,
+    from: u8,
+    to: u8,
+    promotion: Option<Promotion>,
+) -> Result<()> {
+    let mut game = ctx.accounts.game.load_mut()?;
+    require_keys_eq!(
+        game.player_to_move(),
+        ctx.accounts.player.key(),
+        ChessError::NotPlayersTurn
+    );
+    game.play(from, to, promotion, Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct MakeMove<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+}
@@ -0,0 +1,13 @@
+pub use claim_draw::*;
+pub use claim_timeout::*;
+pub use join_game::*;
+pub use make_move::*;
+pub use resign::*;
+pub use setup_game::*;
+
+pub mod claim_draw;
+pub mod claim_timeout;
+pub mod join_game;
+pub mod make_move;
+pub mod resign;
+pub mod setup_game;
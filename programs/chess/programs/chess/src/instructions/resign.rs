@@ -0,0 +1,16 @@
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn resign(ctx: Context<Resign>) -> Result<()> {
+    ctx.accounts
+        .game
+        .load_mut()?
+        .resign(ctx.accounts.player.key())
+}
+
+#[derive(Accounts)]
+pub struct Resign<'info> {
+    #[account(mut)]
+    pub game: AccountLoader<'info, Game>,
+    pub player: Signer<'info>,
+}
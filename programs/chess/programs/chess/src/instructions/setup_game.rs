@@ -0,0 +1,23 @@
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn setup_game(
+    ctx: Context<SetupGame>,
+    black: Pubkey,
+    time_control: i64,
+    increment: i64,
+) -> Result<()> {
+    ctx.accounts
+        .game
+        .load_init()?
+        .setup([ctx.accounts.white.key(), black], time_control, increment)
+}
+
+#[derive(Accounts)]
+pub struct SetupGame<'info> {
+    #[account(init, payer = white, space = 8 + std::mem::size_of::<Game>())]
+    pub game: AccountLoader<'info, Game>,
+    #[account(mut)]
+    pub white: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
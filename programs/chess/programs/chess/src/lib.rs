@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+use state::position::Promotion;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("BiuUKdkRX1kJhaDgEqbVJoQVEG6UVmNdXcnuqym15r1G");
+
+#[program]
+pub mod chess {
+    use super::*;
+
+    pub fn setup_game(
+        ctx: Context<SetupGame>,
+        black: Pubkey,
+        time_control: i64,
+        increment: i64,
+    ) -> Result<()> {
+        instructions::setup_game::setup_game(ctx, black, time_control, increment)
+    }
+
+    pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
+        instructions::join_game::join_game(ctx)
+    }
+
+    pub fn make_move(
+        ctx: Context<MakeMove>,
+        from: u8,
+        to: u8,
+        promotion: Option<Promotion>,
+    ) -> Result<()> {
+        instructions::make_move::make_move(ctx, from, to, promotion)
+    }
+
+    pub fn claim_draw(ctx: Context<ClaimDraw>) -> Result<()> {
+        instructions::claim_draw::claim_draw(ctx)
+    }
+
+    pub fn claim_timeout(ctx: Context<ClaimTimeout>) -> Result<()> {
+        instructions::claim_timeout::claim_timeout(ctx)
+    }
+
+    pub fn resign(ctx: Context<Resign>) -> Result<()> {
+        instructions::resign::resign(ctx)
+    }
+}
@@ -0,0 +1,314 @@
+use super::position::*;
+use crate::errors::ChessError;
+use anchor_lang::prelude::*;
+
+// Positions since the last capture or pawn move. There are never more than 151 of them:
+// the game ends by the 75-move rule after 150 moves without one.
+pub const MAX_HISTORY: usize = 151;
+
+pub const WAITING: u8 = 0;
+pub const ACTIVE: u8 = 1;
+pub const WHITE_WON: u8 = 2;
+pub const BLACK_WON: u8 = 3;
+pub const DRAW: u8 = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EndReason {
+    Checkmate,
+    Stalemate,
+    FiftyMoveRule,
+    ThreefoldRepetition,
+    SeventyFiveMoveRule,
+    Timeout,
+    Resignation,
+}
+
+/// At over a kilobyte, most of it the position history, the game is kept as a zero
+/// copy account: every move only touches the board and a few counters, so there's no
+/// point in deserializing and serializing the whole history each time.
+///
+/// Zero copy types must not contain padding, which is why the fields are ordered from
+/// the largest alignment to the smallest and the end is padded to a multiple of 8 by hand.
+#[account(zero_copy)]
+pub struct Game {
+    // white, black
+    pub players: [Pubkey; 2],
+    // in seconds, per player
+    pub time_remaining: [i64; 2],
+    pub last_move_ts: i64,
+    // added to a player's clock after each of their moves
+    pub increment: i64,
+    pub history: [u64; MAX_HISTORY],
+    // moves since the last capture or pawn move, for the 50- and 75-move rules
+    pub halfmove_clock: u16,
+    pub fullmove_number: u16,
+    pub history_len: u16,
+    pub position: Position,
+    pub state: u8,
+    // an `EndReason` + 1, 0 while the game is still running
+    pub end_reason: u8,
+    pub _padding: [u8; 5],
+}
+
+impl Game {
+    pub fn setup(&mut self, players: [Pubkey; 2], time_control: i64, increment: i64) -> Result<()> {
+        require_gt!(time_control, 0, ChessError::InvalidTimeControl);
+        require_gte!(increment, 0, ChessError::InvalidTimeControl);
+        require_keys_neq!(players[0], players[1], ChessError::CannotPlayYourself);
+
+        self.players = players;
+        self.time_remaining = [time_control; 2];
+        self.increment = increment;
+        self.position = Position::initial();
+        self.fullmove_number = 1;
+        self.history[0] = self.position.hash();
+        self.history_len = 1;
+        self.state = WAITING;
+        Ok(())
+    }
+
+    pub fn start(&mut self, now: i64) -> Result<()> {
+        require_eq!(self.state, WAITING, ChessError::GameAlreadyStarted);
+        self.state = ACTIVE;
+        // white's clock starts running
+        self.last_move_ts = now;
+        Ok(())
+    }
+
+    pub fn player_to_move(&self) -> Pubkey {
+        self.players[self.position.side_to_move as usize]
+    }
+
+    pub fn player_index(&self, player: Pubkey) -> Result<usize> {
+        self.players
+            .iter()
+            .position(|p| *p == player)
+            .ok_or_else(|| error!(ChessError::NotAPlayer))
+    }
+
+    pub fn play(&mut self, from: u8, to: u8, promotion: Option<Promotion>, now: i64) -> Result<()> {
+        require_eq!(self.state, ACTIVE, ChessError::GameNotActive);
+
+        // a player whose time has run out can't move anymore, the opponent claims the win instead
+        let side = self.position.side_to_move as usize;
+        let elapsed = now - self.last_move_ts;
+        require_gte!(self.time_remaining[side], elapsed, ChessError::OutOfTime);
+
+        let irreversible = self.position.make_move(from, to, promotion)?;
+        self.time_remaining[side] += self.increment - elapsed;
+        self.last_move_ts = now;
+        if side == BLACK as usize {
+            self.fullmove_number += 1;
+        }
+
+        // no earlier position can come up again after a capture or a pawn move
+        if irreversible {
+            self.halfmove_clock = 0;
+            self.history_len = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+        self.history[self.history_len as usize] = self.position.hash();
+        self.history_len += 1;
+
+        if !self.position.has_legal_move() {
+            if self.position.in_check() {
+                self.finish(Some(side), EndReason::Checkmate);
+            } else {
+                self.finish(None, EndReason::Stalemate);
+            }
+        } else if self.halfmove_clock >= 150 {
+            // unlike the 50-move rule, this one applies without either player claiming it
+            self.finish(None, EndReason::SeventyFiveMoveRule);
+        }
+        Ok(())
+    }
+
+    /// Draws that the rules allow but don't enforce: either player may claim them.
+    pub fn claim_draw(&mut self) -> Result<()> {
+        require_eq!(self.state, ACTIVE, ChessError::GameNotActive);
+
+        if self.halfmove_clock >= 100 {
+            self.finish(None, EndReason::FiftyMoveRule);
+            return Ok(());
+        }
+        let history = &self.history[..self.history_len as usize];
+        let current = history[history.len() - 1];
+        if history.iter().filter(|&&hash| hash == current).count() >= 3 {
+            self.finish(None, EndReason::ThreefoldRepetition);
+            return Ok(());
+        }
+        err!(ChessError::NoDrawToClaim)
+    }
+
+    pub fn claim_timeout(&mut self, now: i64) -> Result<()> {
+        require_eq!(self.state, ACTIVE, ChessError::GameNotActive);
+        let side = self.position.side_to_move as usize;
+        require_gt!(
+            now - self.last_move_ts,
+            self.time_remaining[side],
+            ChessError::NotOutOfTime
+        );
+        self.finish(Some(side ^ 1), EndReason::Timeout);
+        Ok(())
+    }
+
+    pub fn resign(&mut self, player: Pubkey) -> Result<()> {
+        require_eq!(self.state, ACTIVE, ChessError::GameNotActive);
+        let loser = self.player_index(player)?;
+        self.finish(Some(loser ^ 1), EndReason::Resignation);
+        Ok(())
+    }
+
+    fn finish(&mut self, winner: Option<usize>, reason: EndReason) {
+        self.state = match winner {
+            Some(0) => WHITE_WON,
+            Some(_) => BLACK_WON,
+            None => DRAW,
+        };
+        self.end_reason = reason as u8 + 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    const WHITE_PLAYER: Pubkey = Pubkey::new_from_array([1; 32]);
+    const BLACK_PLAYER: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    // "e4" -> 28
+    fn square(name: &str) -> u8 {
+        let [file, rank] = name.as_bytes() else {
+            panic!("not a square: {name}")
+        };
+        (rank - b'1') * 8 + (file - b'a')
+    }
+
+    // a running game, white to move, with only the given pieces on the board
+    fn game(pieces: &[(&str, u8, u8)]) -> Game {
+        let mut game = Game::zeroed();
+        game.setup([WHITE_PLAYER, BLACK_PLAYER], 600, 0).unwrap();
+        game.position.squares = [EMPTY; 64];
+        game.position.castling = 0;
+        for &(name, kind, color) in pieces {
+            game.position.squares[square(name) as usize] = piece(kind, color);
+        }
+        game.history[0] = game.position.hash();
+        game.start(0).unwrap();
+        game
+    }
+
+    fn play(game: &mut Game, from: &str, to: &str) -> Result<()> {
+        game.play(square(from), square(to), None, 0)
+    }
+
+    fn end_reason(game: &Game) -> Option<EndReason> {
+        [
+            EndReason::Checkmate,
+            EndReason::Stalemate,
+            EndReason::FiftyMoveRule,
+            EndReason::ThreefoldRepetition,
+            EndReason::SeventyFiveMoveRule,
+            EndReason::Timeout,
+            EndReason::Resignation,
+        ]
+        .into_iter()
+        .find(|reason| *reason as u8 + 1 == game.end_reason)
+    }
+
+    #[test]
+    fn checkmate_is_a_win() {
+        let mut game = game(&[
+            ("f6", KING, WHITE),
+            ("g5", QUEEN, WHITE),
+            ("h8", KING, BLACK),
+        ]);
+        play(&mut game, "g5", "g7").unwrap();
+        assert_eq!(game.state, WHITE_WON);
+        assert!(end_reason(&game) == Some(EndReason::Checkmate));
+        assert_eq!(
+            play(&mut game, "h8", "g8").unwrap_err(),
+            ChessError::GameNotActive.into()
+        );
+    }
+
+    #[test]
+    fn stalemate_is_a_draw() {
+        // the same king and queen, but the queen takes the king's last squares without giving check
+        let mut game = game(&[
+            ("f7", KING, WHITE),
+            ("g5", QUEEN, WHITE),
+            ("h8", KING, BLACK),
+        ]);
+        play(&mut game, "g5", "g6").unwrap();
+        assert_eq!(game.state, DRAW);
+        assert!(end_reason(&game) == Some(EndReason::Stalemate));
+    }
+
+    #[test]
+    fn threefold_repetition_has_to_be_claimed() {
+        let mut game = game(&[
+            ("e1", KING, WHITE),
+            ("a2", PAWN, WHITE),
+            ("e8", KING, BLACK),
+        ]);
+        for _ in 0..2 {
+            assert_eq!(
+                game.claim_draw().unwrap_err(),
+                ChessError::NoDrawToClaim.into()
+            );
+            play(&mut game, "e1", "e2").unwrap();
+            play(&mut game, "e8", "e7").unwrap();
+            play(&mut game, "e2", "e1").unwrap();
+            play(&mut game, "e7", "e8").unwrap();
+        }
+        // the starting position, for the third time
+        assert_eq!(game.state, ACTIVE);
+        game.claim_draw().unwrap();
+        assert_eq!(game.state, DRAW);
+        assert!(end_reason(&game) == Some(EndReason::ThreefoldRepetition));
+    }
+
+    #[test]
+    fn pawn_moves_reset_the_repetition_history() {
+        let mut game = game(&[
+            ("e1", KING, WHITE),
+            ("a2", PAWN, WHITE),
+            ("e8", KING, BLACK),
+        ]);
+        play(&mut game, "e1", "e2").unwrap();
+        play(&mut game, "e8", "e7").unwrap();
+        play(&mut game, "a2", "a3").unwrap();
+        assert_eq!(game.history_len, 1);
+        assert_eq!(game.halfmove_clock, 0);
+    }
+
+    #[test]
+    fn fifty_moves_can_be_claimed_seventy_five_end_the_game() {
+        let mut game = game(&[
+            ("e1", KING, WHITE),
+            ("a2", PAWN, WHITE),
+            ("e8", KING, BLACK),
+        ]);
+        game.halfmove_clock = 98;
+        play(&mut game, "e1", "e2").unwrap();
+        assert_eq!(
+            game.claim_draw().unwrap_err(),
+            ChessError::NoDrawToClaim.into()
+        );
+        play(&mut game, "e8", "e7").unwrap();
+        assert_eq!(game.halfmove_clock, 100);
+
+        let mut claimed = game;
+        claimed.claim_draw().unwrap();
+        assert!(end_reason(&claimed) == Some(EndReason::FiftyMoveRule));
+
+        // the history would overflow past 150 moves, so the game ends on its own
+        game.halfmove_clock = 149;
+        play(&mut game, "e2", "e1").unwrap();
+        assert_eq!(game.state, DRAW);
+        assert!(end_reason(&game) == Some(EndReason::SeventyFiveMoveRule));
+    }
+}
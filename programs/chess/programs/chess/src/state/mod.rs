@@ -0,0 +1,5 @@
+pub use game::*;
+pub use position::*;
+
+pub mod game;
+pub mod position;
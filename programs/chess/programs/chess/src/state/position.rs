@@ -0,0 +1,664 @@
+use crate::errors::ChessError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+// A piece is its kind, plus `BLACK_PIECE` if it's black.
+pub const EMPTY: u8 = 0;
+pub const PAWN: u8 = 1;
+pub const KNIGHT: u8 = 2;
+pub const BISHOP: u8 = 3;
+pub const ROOK: u8 = 4;
+pub const QUEEN: u8 = 5;
+pub const KING: u8 = 6;
+pub const BLACK_PIECE: u8 = 8;
+
+pub const WHITE: u8 = 0;
+pub const BLACK: u8 = 1;
+
+pub const WHITE_KINGSIDE: u8 = 1;
+pub const WHITE_QUEENSIDE: u8 = 2;
+pub const BLACK_KINGSIDE: u8 = 4;
+pub const BLACK_QUEENSIDE: u8 = 8;
+
+pub const NO_SQUARE: u8 = 64;
+
+const KNIGHT_OFFSETS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Promotion {
+    Knight,
+    Bishop,
+    Rook,
+    Queen,
+}
+
+impl Promotion {
+    fn kind(self) -> u8 {
+        match self {
+            Promotion::Knight => KNIGHT,
+            Promotion::Bishop => BISHOP,
+            Promotion::Rook => ROOK,
+            Promotion::Queen => QUEEN,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MoveKind {
+    Normal,
+    DoublePush,
+    EnPassant,
+    Castle { rook_from: u8, rook_to: u8 },
+}
+
+pub fn piece(kind: u8, color: u8) -> u8 {
+    kind | (color * BLACK_PIECE)
+}
+
+fn kind_of(piece: u8) -> u8 {
+    piece & !BLACK_PIECE
+}
+
+fn color_of(piece: u8) -> u8 {
+    piece / BLACK_PIECE
+}
+
+fn file(square: u8) -> i8 {
+    (square % 8) as i8
+}
+
+fn rank(square: u8) -> i8 {
+    (square / 8) as i8
+}
+
+fn offset(square: u8, df: i8, dr: i8) -> Option<u8> {
+    let (file, rank) = (file(square) + df, rank(square) + dr);
+    ((0..8).contains(&file) && (0..8).contains(&rank)).then(|| (rank * 8 + file) as u8)
+}
+
+// the castling rights lost when a piece moves from or to `square`
+fn castling_rights_at(square: u8) -> u8 {
+    match square {
+        0 => WHITE_QUEENSIDE,
+        4 => WHITE_KINGSIDE | WHITE_QUEENSIDE,
+        7 => WHITE_KINGSIDE,
+        56 => BLACK_QUEENSIDE,
+        60 => BLACK_KINGSIDE | BLACK_QUEENSIDE,
+        63 => BLACK_KINGSIDE,
+        _ => 0,
+    }
+}
+
+/// Everything the rules need to know about a position.
+/// Squares are numbered from a1 = 0, b1 = 1, ... to h8 = 63.
+#[zero_copy]
+#[derive(PartialEq, Eq)]
+pub struct Position {
+    pub squares: [u8; 64],
+    pub side_to_move: u8,
+    pub castling: u8,
+    // the square a pawn skipped with its double step, if it can be captured there
+    pub en_passant: u8,
+}
+
+impl Position {
+    pub fn initial() -> Self {
+        let mut squares = [EMPTY; 64];
+        let back_rank = [ROOK, KNIGHT, BISHOP, QUEEN, KING, BISHOP, KNIGHT, ROOK];
+        for (file, kind) in back_rank.into_iter().enumerate() {
+            squares[file] = piece(kind, WHITE);
+            squares[8 + file] = piece(PAWN, WHITE);
+            squares[48 + file] = piece(PAWN, BLACK);
+            squares[56 + file] = piece(kind, BLACK);
+        }
+        Self {
+            squares,
+            side_to_move: WHITE,
+            castling: WHITE_KINGSIDE | WHITE_QUEENSIDE | BLACK_KINGSIDE | BLACK_QUEENSIDE,
+            en_passant: NO_SQUARE,
+        }
+    }
+
+    /// Plays a move, returning whether it was a capture or a pawn move,
+    /// after which none of the earlier positions can ever come up again.
+    pub fn make_move(&mut self, from: u8, to: u8, promotion: Option<Promotion>) -> Result<bool> {
+        let (next, irreversible) = self.successor(from, to, promotion)?;
+        *self = next;
+        Ok(irreversible)
+    }
+
+    pub fn in_check(&self) -> bool {
+        let side = self.side_to_move;
+        self.is_attacked(self.king_square(side), side ^ 1)
+    }
+
+    /// Whether the side to move has any legal move at all; if not, it's checkmate or stalemate.
+    pub fn has_legal_move(&self) -> bool {
+        // Trying every square as a target is wasteful, but simple, and the first legal move ends the search.
+        // Without a king, the game would have ended already, so this only runs on complete positions.
+        for from in 0..64 {
+            let piece = self.squares[from as usize];
+            if piece == EMPTY || color_of(piece) != self.side_to_move {
+                continue;
+            }
+            for to in 0..64 {
+                // a pawn reaching the last rank has to promote, to what doesn't matter here
+                let promotion = self.must_promote(from, to).then_some(Promotion::Queen);
+                if self.successor(from, to, promotion).is_ok() {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Identifies the position for the repetition rules: the same pieces on the same squares,
+    /// the same side to move, and the same castling and en passant possibilities.
+    pub fn hash(&self) -> u64 {
+        let hash = hashv(&[
+            &self.squares,
+            &[self.side_to_move, self.castling, self.en_passant],
+        ]);
+        u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap())
+    }
+
+    // Returns a plain `ChessError` rather than an anchor `Error`, which `has_legal_move`
+    // would otherwise allocate on the heap, that is never freed, for every move it rejects.
+    fn successor(
+        &self,
+        from: u8,
+        to: u8,
+        promotion: Option<Promotion>,
+    ) -> std::result::Result<(Position, bool), ChessError> {
+        if from >= 64 || to >= 64 {
+            return Err(ChessError::SquareOutOfBounds);
+        }
+        let piece = self.squares[from as usize];
+        if piece == EMPTY || color_of(piece) != self.side_to_move {
+            return Err(ChessError::NotYourPiece);
+        }
+        let kind = self.pseudo_legal(from, to).ok_or(ChessError::IllegalMove)?;
+        if self.must_promote(from, to) != promotion.is_some() {
+            return Err(ChessError::InvalidPromotion);
+        }
+
+        let them = self.side_to_move ^ 1;
+        if let MoveKind::Castle { .. } = kind {
+            // the king may neither castle out of check, nor through an attacked square
+            let passed = (from + to) / 2;
+            if self.is_attacked(from, them) || self.is_attacked(passed, them) {
+                return Err(ChessError::IllegalMove);
+            }
+        }
+
+        let mut next = *self;
+        let irreversible = next.apply(from, to, kind, promotion);
+        if next.is_attacked(next.king_square(self.side_to_move), them) {
+            return Err(ChessError::LeavesKingInCheck);
+        }
+        Ok((next, irreversible))
+    }
+
+    fn must_promote(&self, from: u8, to: u8) -> bool {
+        let last_rank = if self.side_to_move == WHITE { 7 } else { 0 };
+        kind_of(self.squares[from as usize]) == PAWN && rank(to) == last_rank
+    }
+
+    // Checks how the piece on `from` moves, ignoring whether its own king ends up in check.
+    fn pseudo_legal(&self, from: u8, to: u8) -> Option<MoveKind> {
+        let side = self.side_to_move;
+        let target = self.squares[to as usize];
+        if from == to || (target != EMPTY && color_of(target) == side) {
+            return None;
+        }
+        let (df, dr) = (file(to) - file(from), rank(to) - rank(from));
+
+        match kind_of(self.squares[from as usize]) {
+            PAWN => {
+                let (forward, start_rank) = if side == WHITE { (1, 1) } else { (-1, 6) };
+                let passed = offset(from, 0, forward)?;
+                if df == 0 && dr == forward && target == EMPTY {
+                    Some(MoveKind::Normal)
+                } else if df == 0
+                    && dr == 2 * forward
+                    && rank(from) == start_rank
+                    && target == EMPTY
+                    && self.squares[passed as usize] == EMPTY
+                {
+                    Some(MoveKind::DoublePush)
+                } else if df.abs() == 1 && dr == forward && target != EMPTY {
+                    Some(MoveKind::Normal)
+                } else if df.abs() == 1 && dr == forward && to == self.en_passant {
+                    Some(MoveKind::EnPassant)
+                } else {
+                    None
+                }
+            }
+            KNIGHT => KNIGHT_OFFSETS
+                .contains(&(df, dr))
+                .then_some(MoveKind::Normal),
+            BISHOP => {
+                (df.abs() == dr.abs() && self.path_is_clear(from, to)).then_some(MoveKind::Normal)
+            }
+            ROOK => {
+                ((df == 0 || dr == 0) && self.path_is_clear(from, to)).then_some(MoveKind::Normal)
+            }
+            QUEEN => ((df == 0 || dr == 0 || df.abs() == dr.abs()) && self.path_is_clear(from, to))
+                .then_some(MoveKind::Normal),
+            KING => {
+                if df.abs() <= 1 && dr.abs() <= 1 {
+                    return Some(MoveKind::Normal);
+                }
+                let (home, kingside, queenside) = if side == WHITE {
+                    (4, WHITE_KINGSIDE, WHITE_QUEENSIDE)
+                } else {
+                    (60, BLACK_KINGSIDE, BLACK_QUEENSIDE)
+                };
+                let (right, rook_from, rook_to) = match (from == home, dr, df) {
+                    (true, 0, 2) => (kingside, home + 3, home + 1),
+                    (true, 0, -2) => (queenside, home - 4, home - 1),
+                    _ => return None,
+                };
+                // the rights are lost as soon as the king or the rook moves or the rook is captured,
+                // so the rook is still on its square; only the squares in between must be empty
+                (self.castling & right != 0 && self.path_is_clear(from, rook_from))
+                    .then_some(MoveKind::Castle { rook_from, rook_to })
+            }
+            _ => None,
+        }
+    }
+
+    // whether all squares strictly between `from` and `to`, on a line or a diagonal, are empty
+    fn path_is_clear(&self, from: u8, to: u8) -> bool {
+        let (df, dr) = (file(to) - file(from), rank(to) - rank(from));
+        let (step_f, step_r) = (df.signum(), dr.signum());
+        let mut square = from;
+        loop {
+            square = match offset(square, step_f, step_r) {
+                Some(square) => square,
+                None => return false,
+            };
+            if square == to {
+                return true;
+            }
+            if self.squares[square as usize] != EMPTY {
+                return false;
+            }
+        }
+    }
+
+    fn apply(&mut self, from: u8, to: u8, kind: MoveKind, promotion: Option<Promotion>) -> bool {
+        let side = self.side_to_move;
+        let moving = self.squares[from as usize];
+        let irreversible = kind_of(moving) == PAWN || self.squares[to as usize] != EMPTY;
+
+        self.squares[to as usize] = match promotion {
+            Some(promotion) => piece(promotion.kind(), side),
+            None => moving,
+        };
+        self.squares[from as usize] = EMPTY;
+        match kind {
+            MoveKind::EnPassant => {
+                // the captured pawn is next to the moving one, not on the target square
+                self.squares[(rank(from) * 8 + file(to)) as usize] = EMPTY;
+            }
+            MoveKind::Castle { rook_from, rook_to } => {
+                self.squares[rook_to as usize] = self.squares[rook_from as usize];
+                self.squares[rook_from as usize] = EMPTY;
+            }
+            MoveKind::Normal | MoveKind::DoublePush => {}
+        }
+
+        // Only remembered if an enemy pawn could actually capture, otherwise two positions
+        // that only differ in an unusable en passant square wouldn't count as a repetition.
+        self.en_passant = NO_SQUARE;
+        if kind == MoveKind::DoublePush {
+            let enemy_pawn = piece(PAWN, side ^ 1);
+            let capturable = [-1, 1]
+                .into_iter()
+                .filter_map(|df| offset(to, df, 0))
+                .any(|square| self.squares[square as usize] == enemy_pawn);
+            if capturable {
+                self.en_passant = (from + to) / 2;
+            }
+        }
+
+        self.castling &= !(castling_rights_at(from) | castling_rights_at(to));
+        self.side_to_move ^= 1;
+        irreversible
+    }
+
+    fn king_square(&self, color: u8) -> u8 {
+        let king = piece(KING, color);
+        self.squares.iter().position(|&p| p == king).unwrap() as u8
+    }
+
+    fn is_attacked(&self, square: u8, by: u8) -> bool {
+        let is = |df: i8, dr: i8, kinds: &[u8]| {
+            offset(square, df, dr).is_some_and(|s| {
+                let p = self.squares[s as usize];
+                p != EMPTY && color_of(p) == by && kinds.contains(&kind_of(p))
+            })
+        };
+
+        // pawns attack diagonally forward, so they sit diagonally behind the attacked square
+        let backward = if by == WHITE { -1 } else { 1 };
+        if is(-1, backward, &[PAWN]) || is(1, backward, &[PAWN]) {
+            return true;
+        }
+        if KNIGHT_OFFSETS.iter().any(|&(df, dr)| is(df, dr, &[KNIGHT])) {
+            return true;
+        }
+        let mut king_offsets = ROOK_DIRECTIONS.iter().chain(BISHOP_DIRECTIONS.iter());
+        if king_offsets.any(|&(df, dr)| is(df, dr, &[KING])) {
+            return true;
+        }
+
+        // sliding pieces: the first piece in each direction
+        for (directions, kinds) in [
+            (ROOK_DIRECTIONS, [ROOK, QUEEN]),
+            (BISHOP_DIRECTIONS, [BISHOP, QUEEN]),
+        ] {
+            for (df, dr) in directions {
+                let mut current = square;
+                while let Some(next) = offset(current, df, dr) {
+                    let p = self.squares[next as usize];
+                    if p != EMPTY {
+                        if color_of(p) == by && kinds.contains(&kind_of(p)) {
+                            return true;
+                        }
+                        break;
+                    }
+                    current = next;
+                }
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "e4" -> 28
+    fn square(name: &str) -> u8 {
+        let [file, rank] = name.as_bytes() else {
+            panic!("not a square: {name}")
+        };
+        (rank - b'1') * 8 + (file - b'a')
+    }
+
+    fn position(side_to_move: u8, castling: u8, pieces: &[(&str, u8, u8)]) -> Position {
+        let mut squares = [EMPTY; 64];
+        for &(name, kind, color) in pieces {
+            squares[square(name) as usize] = piece(kind, color);
+        }
+        Position {
+            squares,
+            side_to_move,
+            castling,
+            en_passant: NO_SQUARE,
+        }
+    }
+
+    fn play(
+        position: &Position,
+        from: &str,
+        to: &str,
+    ) -> std::result::Result<Position, ChessError> {
+        let (next, _) = position.successor(square(from), square(to), None)?;
+        Ok(next)
+    }
+
+    fn castling_position(attacker: Option<&'static str>) -> Position {
+        let mut pieces = vec![
+            ("e1", KING, WHITE),
+            ("a1", ROOK, WHITE),
+            ("h1", ROOK, WHITE),
+            ("d8", KING, BLACK),
+        ];
+        pieces.extend(attacker.map(|square| (square, ROOK, BLACK)));
+        position(WHITE, WHITE_KINGSIDE | WHITE_QUEENSIDE, &pieces)
+    }
+
+    #[test]
+    fn castling_moves_king_and_rook() {
+        let next = play(&castling_position(None), "e1", "g1").unwrap();
+        assert_eq!(next.squares[square("g1") as usize], piece(KING, WHITE));
+        assert_eq!(next.squares[square("f1") as usize], piece(ROOK, WHITE));
+        assert_eq!(next.squares[square("h1") as usize], EMPTY);
+        assert_eq!(next.castling, 0);
+
+        let next = play(&castling_position(None), "e1", "c1").unwrap();
+        assert_eq!(next.squares[square("c1") as usize], piece(KING, WHITE));
+        assert_eq!(next.squares[square("d1") as usize], piece(ROOK, WHITE));
+    }
+
+    #[test]
+    fn castling_out_of_or_through_check_is_illegal() {
+        // out of check
+        let position = castling_position(Some("e8"));
+        assert!(matches!(
+            play(&position, "e1", "g1"),
+            Err(ChessError::IllegalMove)
+        ));
+        // through f1, on the way to g1
+        let position = castling_position(Some("f8"));
+        assert!(matches!(
+            play(&position, "e1", "g1"),
+            Err(ChessError::IllegalMove)
+        ));
+        // into check is caught like any other move that leaves the king attacked
+        let position = castling_position(Some("g8"));
+        assert!(matches!(
+            play(&position, "e1", "g1"),
+            Err(ChessError::LeavesKingInCheck)
+        ));
+    }
+
+    #[test]
+    fn only_the_king_has_to_pass_safely() {
+        // the rook passes b1 when castling queenside, the king doesn't
+        let position = castling_position(Some("b8"));
+        assert!(play(&position, "e1", "c1").is_ok());
+    }
+
+    #[test]
+    fn castling_rights_are_lost_for_good() {
+        // a rook that moves away and back has still lost its side's right
+        let mut position = castling_position(None);
+        position
+            .make_move(square("h1"), square("h2"), None)
+            .unwrap();
+        position
+            .make_move(square("d8"), square("c8"), None)
+            .unwrap();
+        position
+            .make_move(square("h2"), square("h1"), None)
+            .unwrap();
+        position
+            .make_move(square("c8"), square("d8"), None)
+            .unwrap();
+        assert_eq!(position.castling, WHITE_QUEENSIDE);
+        assert!(matches!(
+            play(&position, "e1", "g1"),
+            Err(ChessError::IllegalMove)
+        ));
+        assert!(play(&position, "e1", "c1").is_ok());
+
+        // so does a rook that gets captured
+        let mut position = castling_position(None);
+        position.side_to_move = BLACK;
+        position.squares[square("h8") as usize] = piece(ROOK, BLACK);
+        position
+            .make_move(square("h8"), square("h1"), None)
+            .unwrap();
+        assert_eq!(position.castling, WHITE_QUEENSIDE);
+    }
+
+    // black's pawn on c7 double-steps next to white's on b5
+    fn en_passant_position(pinning_rook: bool) -> Position {
+        let mut pieces = vec![
+            ("e1", KING, WHITE),
+            ("b5", PAWN, WHITE),
+            ("e8", KING, BLACK),
+            ("c7", PAWN, BLACK),
+        ];
+        if pinning_rook {
+            pieces[0] = ("a5", KING, WHITE);
+            pieces.push(("h5", ROOK, BLACK));
+        }
+        let mut position = position(BLACK, 0, &pieces);
+        position
+            .make_move(square("c7"), square("c5"), None)
+            .unwrap();
+        position
+    }
+
+    #[test]
+    fn en_passant_captures_the_pawn_beside() {
+        let position = en_passant_position(false);
+        assert_eq!(position.en_passant, square("c6"));
+
+        let next = play(&position, "b5", "c6").unwrap();
+        assert_eq!(next.squares[square("c6") as usize], piece(PAWN, WHITE));
+        assert_eq!(next.squares[square("c5") as usize], EMPTY);
+        assert_eq!(next.squares[square("b5") as usize], EMPTY);
+    }
+
+    #[test]
+    fn en_passant_must_not_expose_own_king() {
+        // both pawns leave the fifth rank at once, which opens it between the rook and the king
+        let position = en_passant_position(true);
+        assert!(matches!(
+            play(&position, "b5", "c6"),
+            Err(ChessError::LeavesKingInCheck)
+        ));
+        assert!(play(&position, "b5", "b6").is_ok());
+    }
+
+    #[test]
+    fn en_passant_is_only_possible_right_away() {
+        let mut position = en_passant_position(false);
+        position
+            .make_move(square("e1"), square("e2"), None)
+            .unwrap();
+        position
+            .make_move(square("e8"), square("e7"), None)
+            .unwrap();
+        assert_eq!(position.en_passant, NO_SQUARE);
+        assert!(matches!(
+            play(&position, "b5", "c6"),
+            Err(ChessError::IllegalMove)
+        ));
+    }
+
+    #[test]
+    fn en_passant_square_is_only_set_when_usable() {
+        // no white pawn next to c5, so the position doesn't remember c6
+        let mut position = position(
+            BLACK,
+            0,
+            &[
+                ("e1", KING, WHITE),
+                ("e8", KING, BLACK),
+                ("c7", PAWN, BLACK),
+            ],
+        );
+        position
+            .make_move(square("c7"), square("c5"), None)
+            .unwrap();
+        assert_eq!(position.en_passant, NO_SQUARE);
+    }
+
+    #[test]
+    fn pawns_promote_on_the_last_rank_only() {
+        let position = position(
+            WHITE,
+            0,
+            &[
+                ("a1", KING, WHITE),
+                ("e7", PAWN, WHITE),
+                ("d6", PAWN, WHITE),
+                ("h8", KING, BLACK),
+            ],
+        );
+        let (e7, e8, d6, d7) = (square("e7"), square("e8"), square("d6"), square("d7"));
+
+        assert!(matches!(
+            position.successor(e7, e8, None),
+            Err(ChessError::InvalidPromotion)
+        ));
+        assert!(matches!(
+            position.successor(d6, d7, Some(Promotion::Queen)),
+            Err(ChessError::InvalidPromotion)
+        ));
+        let (next, irreversible) = position.successor(e7, e8, Some(Promotion::Knight)).unwrap();
+        assert_eq!(next.squares[e8 as usize], piece(KNIGHT, WHITE));
+        assert!(irreversible);
+    }
+
+    #[test]
+    fn checkmate_and_stalemate_both_leave_no_legal_move() {
+        // the queen on g7 is protected by the king, black can't take it
+        let checkmate = position(
+            BLACK,
+            0,
+            &[
+                ("f6", KING, WHITE),
+                ("g7", QUEEN, WHITE),
+                ("h8", KING, BLACK),
+            ],
+        );
+        assert!(checkmate.in_check());
+        assert!(!checkmate.has_legal_move());
+
+        // every square around the king is covered, but the king itself isn't attacked
+        let stalemate = position(
+            BLACK,
+            0,
+            &[
+                ("f7", KING, WHITE),
+                ("g6", QUEEN, WHITE),
+                ("h8", KING, BLACK),
+            ],
+        );
+        assert!(!stalemate.in_check());
+        assert!(!stalemate.has_legal_move());
+
+        // a single pawn move is enough to not be stalemated
+        let mut with_pawn = stalemate;
+        with_pawn.squares[square("a7") as usize] = piece(PAWN, BLACK);
+        assert!(with_pawn.has_legal_move());
+    }
+
+    #[test]
+    fn a_pinned_piece_cannot_move_off_the_pin() {
+        let position = position(
+            WHITE,
+            0,
+            &[
+                ("e1", KING, WHITE),
+                ("e2", KNIGHT, WHITE),
+                ("e8", ROOK, BLACK),
+                ("a8", KING, BLACK),
+            ],
+        );
+        assert!(matches!(
+            play(&position, "e2", "c3"),
+            Err(ChessError::LeavesKingInCheck)
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { Harness, expectError } from 'test-harness';
+import { Chess } from '../../../target/types/chess';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+// game states
+const ACTIVE = 1;
+const WHITE_WON = 2;
+const BLACK_WON = 3;
+const DRAW = 4;
+
+// pieces
+const KING = 6;
+const QUEEN = 5;
+const ROOK = 4;
+const PAWN = 1;
+const BLACK_PIECE = 8;
+
+// a1 = 0, b1 = 1, ..., h8 = 63
+function square(name: string) {
+  return name.charCodeAt(0) - 'a'.charCodeAt(0) + (Number(name[1]) - 1) * 8;
+}
+
+describe('chess', () => {
+  let harness: Harness;
+  let program: Program<Chess>;
+  // the provider wallet plays white
+  let white: anchor.web3.PublicKey;
+  let black: Keypair;
+
+  async function newGame(timeControl = 600, increment = 0) {
+    const gameKeypair = Keypair.generate();
+    await program.methods
+      .setupGame(black.publicKey, new BN(timeControl), new BN(increment))
+      .accounts({ game: gameKeypair.publicKey, white })
+      .signers([gameKeypair])
+      .rpc();
+    await program.methods
+      .joinGame()
+      .accounts({ game: gameKeypair.publicKey, black: black.publicKey })
+      .signers([black])
+      .rpc();
+    return gameKeypair.publicKey;
+  }
+
+  // plays moves in long algebraic notation, alternating between white and black
+  async function play(game: anchor.web3.PublicKey, moves: string[]) {
+    for (const move of moves) {
+      const { position } = await program.account.game.fetch(game);
+      await playAs(position.sideToMove === 0 ? undefined : black, game, move);
+    }
+  }
+
+  async function playAs(
+    player: Keypair | undefined,
+    game: anchor.web3.PublicKey,
+    move: string,
+    promotion: { queen: Record<string, never> } | null = null
+  ) {
+    const builder = program.methods
+      .makeMove(square(move.slice(0, 2)), square(move.slice(2, 4)), promotion)
+      .accounts({ game, player: player ? player.publicKey : white });
+    await (player ? builder.signers([player]) : builder).rpc();
+  }
+
+  async function pieceAt(game: anchor.web3.PublicKey, name: string) {
+    return (await program.account.game.fetch(game)).position.squares[square(name)];
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Chess>('chess');
+    white = harness.payer.publicKey;
+    black = await harness.fundedKeypair();
+  });
+
+  it("fool's mate!", async () => {
+    const game = await newGame();
+
+    await expectError(playAs(black, game, 'e7e5'), 'NotPlayersTurn', 6005);
+    await expectError(playAs(undefined, game, 'e7e5'), 'NotYourPiece', 6007);
+    await expectError(playAs(undefined, game, 'g1g4'), 'IllegalMove', 6008);
+
+    await play(game, ['f2f3', 'e7e5', 'g2g4', 'd8h4']);
+
+    const gameState = await program.account.game.fetch(game);
+    expect(gameState.state).to.equal(BLACK_WON);
+    // Checkmate
+    expect(gameState.endReason).to.equal(1);
+    await expectError(playAs(undefined, game, 'a2a3'), 'GameNotActive', 6003);
+  });
+
+  it('castling and pins', async () => {
+    const game = await newGame();
+    await play(game, ['e2e4', 'e7e5', 'g1f3', 'd7d6', 'f1b5']);
+
+    // the bishop gives check, black has to deal with it
+    await expectError(playAs(black, game, 'a7a6'), 'LeavesKingInCheck', 6009);
+    await play(game, ['c7c6', 'e1g1']);
+
+    expect(await pieceAt(game, 'g1')).to.equal(KING);
+    expect(await pieceAt(game, 'f1')).to.equal(ROOK);
+    expect(await pieceAt(game, 'h1')).to.equal(0);
+  });
+
+  it('en passant', async () => {
+    const game = await newGame();
+    await play(game, ['e2e4', 'a7a6', 'e4e5', 'd7d5', 'e5d6']);
+
+    expect(await pieceAt(game, 'd6')).to.equal(PAWN);
+    // the captured pawn wasn't on the target square
+    expect(await pieceAt(game, 'd5')).to.equal(0);
+  });
+
+  it('promotion', async () => {
+    const game = await newGame();
+    await play(game, ['e2e4', 'd7d5', 'e4d5', 'c7c6', 'd5c6', 'g8f6', 'c6b7', 'b8d7']);
+
+    await expectError(playAs(undefined, game, 'b7a8'), 'InvalidPromotion', 6010);
+    await expectError(playAs(undefined, game, 'a2a3', { queen: {} }), 'InvalidPromotion', 6010);
+    await playAs(undefined, game, 'b7a8', { queen: {} });
+    expect(await pieceAt(game, 'a8')).to.equal(QUEEN);
+    expect(await pieceAt(game, 'b8')).to.equal(0);
+    expect(await pieceAt(game, 'e8')).to.equal(KING + BLACK_PIECE);
+  });
+
+  it('threefold repetition can be claimed', async () => {
+    const game = await newGame();
+    const claimDraw = () =>
+      program.methods.claimDraw().accounts({ game, player: black.publicKey }).signers([black]).rpc();
+
+    // the starting position comes up for the second time
+    await play(game, ['g1f3', 'g8f6', 'f3g1', 'f6g8']);
+    await expectError(claimDraw(), 'NoDrawToClaim', 6013);
+
+    // ... and for the third time
+    await play(game, ['g1f3', 'g8f6', 'f3g1', 'f6g8']);
+    await claimDraw();
+
+    const gameState = await program.account.game.fetch(game);
+    expect(gameState.state).to.equal(DRAW);
+    // ThreefoldRepetition
+    expect(gameState.endReason).to.equal(4);
+  });
+
+  it('a player runs out of time', async () => {
+    const game = await newGame(2);
+    const claimTimeout = () => program.methods.claimTimeout().accounts({ game }).rpc();
+
+    await expectError(claimTimeout(), 'NotOutOfTime', 6012);
+    await harness.warp(4);
+    await expectError(playAs(undefined, game, 'e2e4'), 'OutOfTime', 6011);
+
+    await claimTimeout();
+    const gameState = await program.account.game.fetch(game);
+    expect(gameState.state).to.equal(BLACK_WON);
+    // Timeout
+    expect(gameState.endReason).to.equal(6);
+  });
+
+  it('resign', async () => {
+    const game = await newGame();
+    expect((await program.account.game.fetch(game)).state).to.equal(ACTIVE);
+
+    await program.methods.resign().accounts({ game, player: black.publicKey }).signers([black]).rpc();
+    expect((await program.account.game.fetch(game)).state).to.equal(WHITE_WON);
+  });
+});
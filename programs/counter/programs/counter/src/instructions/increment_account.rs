@@ -0,0 +1,15 @@
+use crate::state::counter::*;
+use anchor_lang::prelude::*;
+
+// The default choice. `Account` checks the owner and the discriminator, deserializes
+// the whole account before the handler runs and serializes it back afterwards.
+pub fn increment_account(ctx: Context<IncrementAccount>) -> Result<()> {
+    ctx.accounts.counter.increment()
+}
+
+#[derive(Accounts)]
+pub struct IncrementAccount<'info> {
+    #[account(mut, has_one = authority)]
+    pub counter: Account<'info, Counter>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,15 @@
+use crate::state::counter_interface::*;
+use anchor_lang::prelude::*;
+
+// `InterfaceAccount` behaves like `Account`, except that the owner may be any of
+// `CounterInterface::owners()`. Useful when several programs share an account layout.
+pub fn increment_interface(ctx: Context<IncrementInterface>) -> Result<()> {
+    ctx.accounts.counter.increment()
+}
+
+#[derive(Accounts)]
+pub struct IncrementInterface<'info> {
+    #[account(mut, has_one = authority)]
+    pub counter: InterfaceAccount<'info, CounterInterface>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,52 @@
+use crate::errors::CounterError;
+use crate::state::counter::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+// Every check `Account` would have done is written out by hand here. Leaving out any one of
+// them is a vulnerability: without the owner check anyone could pass in an account of their
+// own program with the same layout, without the discriminator check any other account type
+// of this program whose bytes happen to line up.
+pub fn increment_unchecked(ctx: Context<IncrementUnchecked>) -> Result<()> {
+    let counter = &ctx.accounts.counter;
+    require_keys_eq!(
+        *counter.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+
+    let mut data = counter.try_borrow_mut_data()?;
+    require_gte!(
+        data.len(),
+        8 + Counter::INIT_SPACE,
+        ErrorCode::AccountDidNotDeserialize
+    );
+    require!(
+        data[..8] == Counter::DISCRIMINATOR,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let authority = Pubkey::try_from(&data[Counter::AUTHORITY_OFFSET..Counter::COUNT_OFFSET])
+        .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+    require_keys_eq!(
+        authority,
+        ctx.accounts.authority.key(),
+        CounterError::NotAuthority
+    );
+
+    // only the 8 bytes that change are read and written
+    let count_bytes = &mut data[Counter::COUNT_OFFSET..Counter::COUNT_OFFSET + 8];
+    let count = u64::from_le_bytes(count_bytes.try_into().unwrap())
+        .checked_add(1)
+        .ok_or(CounterError::Overflow)?;
+    count_bytes.copy_from_slice(&count.to_le_bytes());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct IncrementUnchecked<'info> {
+    /// CHECK: owner, discriminator and authority are checked in the handler
+    #[account(mut)]
+    pub counter: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
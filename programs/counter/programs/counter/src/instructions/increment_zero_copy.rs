@@ -0,0 +1,15 @@
+use crate::state::zero_copy_counter::*;
+use anchor_lang::prelude::*;
+
+// `AccountLoader` checks the owner and the discriminator just like `Account`, but
+// `load_mut` reinterprets the bytes instead of copying them, and there's nothing to write back.
+pub fn increment_zero_copy(ctx: Context<IncrementZeroCopy>) -> Result<()> {
+    ctx.accounts.counter.load_mut()?.increment()
+}
+
+#[derive(Accounts)]
+pub struct IncrementZeroCopy<'info> {
+    #[account(mut, has_one = authority)]
+    pub counter: AccountLoader<'info, ZeroCopyCounter>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,20 @@
+use crate::state::counter::*;
+use anchor_lang::prelude::*;
+
+pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    ctx.accounts.counter.set_inner(Counter {
+        authority: ctx.accounts.authority.key(),
+        count: 0,
+    });
+    Ok(())
+}
+
+// used by `increment_account`, `increment_unchecked` and `increment_interface` alike
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = authority, space = 8 + Counter::INIT_SPACE)]
+    pub counter: Account<'info, Counter>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,18 @@
+use crate::state::zero_copy_counter::*;
+use anchor_lang::prelude::*;
+
+pub fn initialize_zero_copy(ctx: Context<InitializeZeroCopy>) -> Result<()> {
+    let mut counter = ctx.accounts.counter.load_init()?;
+    counter.authority = ctx.accounts.authority.key();
+    counter.count = 0;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeZeroCopy<'info> {
+    #[account(init, payer = authority, space = 8 + ZeroCopyCounter::INIT_SPACE)]
+    pub counter: AccountLoader<'info, ZeroCopyCounter>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
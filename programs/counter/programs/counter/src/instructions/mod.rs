@@ -0,0 +1,13 @@
+pub use increment_account::*;
+pub use increment_interface::*;
+pub use increment_unchecked::*;
+pub use increment_zero_copy::*;
+pub use initialize::*;
+pub use initialize_zero_copy::*;
+
+pub mod increment_account;
+pub mod increment_interface;
+pub mod increment_unchecked;
+pub mod increment_zero_copy;
+pub mod initialize;
+pub mod initialize_zero_copy;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("JTbNrKPY3FEt3vvSwUBEmiC7et7VBQij1WAFpDYU1Y2");
+
+/// The same increment, implemented four times with different account types,
+/// so that their safety checks and compute costs can be compared side by side.
+#[program]
+pub mod counter {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        instructions::initialize::initialize(ctx)
+    }
+
+    pub fn initialize_zero_copy(ctx: Context<InitializeZeroCopy>) -> Result<()> {
+        instructions::initialize_zero_copy::initialize_zero_copy(ctx)
+    }
+
+    pub fn increment_account(ctx: Context<IncrementAccount>) -> Result<()> {
+        instructions::increment_account::increment_account(ctx)
+    }
+
+    pub fn increment_zero_copy(ctx: Context<IncrementZeroCopy>) -> Result<()> {
+        instructions::increment_zero_copy::increment_zero_copy(ctx)
+    }
+
+    pub fn increment_unchecked(ctx: Context<IncrementUnchecked>) -> Result<()> {
+        instructions::increment_unchecked::increment_unchecked(ctx)
+    }
+
+    pub fn increment_interface(ctx: Context<IncrementInterface>) -> Result<()> {
+        instructions::increment_interface::increment_interface(ctx)
+    }
+}
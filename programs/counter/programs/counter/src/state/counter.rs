@@ -0,0 +1,21 @@
+use crate::errors::CounterError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Counter {
+    pub authority: Pubkey,
+    pub count: u64,
+}
+
+impl Counter {
+    // Offsets into the raw account data, for `increment_unchecked`.
+    // Borsh lays the fields out in declaration order, right after the discriminator.
+    pub const AUTHORITY_OFFSET: usize = 8;
+    pub const COUNT_OFFSET: usize = Self::AUTHORITY_OFFSET + 32;
+
+    pub fn increment(&mut self) -> Result<()> {
+        self.count = self.count.checked_add(1).ok_or(CounterError::Overflow)?;
+        Ok(())
+    }
+}
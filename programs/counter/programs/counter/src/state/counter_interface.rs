@@ -0,0 +1,47 @@
+use super::counter::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Owners;
+use std::ops::{Deref, DerefMut};
+
+// A `Counter` as seen through `InterfaceAccount`. Instead of a single owner, the type
+// lists every program it accepts accounts from; `anchor_spl::token_interface` uses the
+// same mechanism to take accounts of both the Token and the Token-2022 program.
+// Any program listed here must use the same layout, discriminator included.
+#[derive(Clone)]
+pub struct CounterInterface(Counter);
+
+impl Owners for CounterInterface {
+    fn owners() -> &'static [Pubkey] {
+        std::slice::from_ref(&crate::ID)
+    }
+}
+
+impl AccountDeserialize for CounterInterface {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        Counter::try_deserialize_unchecked(buf).map(Self)
+    }
+
+    fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+        Counter::try_deserialize(buf).map(Self)
+    }
+}
+
+impl AccountSerialize for CounterInterface {
+    fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.try_serialize(writer)
+    }
+}
+
+impl Deref for CounterInterface {
+    type Target = Counter;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CounterInterface {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
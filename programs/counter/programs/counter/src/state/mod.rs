@@ -0,0 +1,7 @@
+pub use counter::*;
+pub use counter_interface::*;
+pub use zero_copy_counter::*;
+
+pub mod counter;
+pub mod counter_interface;
+pub mod zero_copy_counter;
@@ -0,0 +1,18 @@
+use crate::errors::CounterError;
+use anchor_lang::prelude::*;
+
+// Never deserialized: `AccountLoader` casts the account data in place.
+// Tiny for a counter, but the same approach works for accounts far larger than the stack.
+#[account(zero_copy)]
+#[derive(InitSpace)]
+pub struct ZeroCopyCounter {
+    pub authority: Pubkey,
+    pub count: u64,
+}
+
+impl ZeroCopyCounter {
+    pub fn increment(&mut self) -> Result<()> {
+        self.count = self.count.checked_add(1).ok_or(CounterError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { expectError } from 'test-harness';
+import { Counter } from '../../../target/types/counter';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+// Runs against the local validator, not the bankrun harness: the compute units come from the
+// transaction's metadata, fetched with `getTransaction`, which only an RPC node keeps.
+describe('counter', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.Counter as Program<Counter>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+  const authority = programProvider.wallet.publicKey;
+
+  const counter = Keypair.generate();
+  const zeroCopyCounter = Keypair.generate();
+
+  async function computeUnits(signature: string) {
+    await connection.confirmTransaction(signature, 'confirmed');
+    const tx = await connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+    return tx.meta.computeUnitsConsumed;
+  }
+
+  before(async () => {
+    await program.methods.initialize().accounts({ counter: counter.publicKey, authority }).signers([counter]).rpc();
+    await program.methods
+      .initializeZeroCopy()
+      .accounts({ counter: zeroCopyCounter.publicKey, authority })
+      .signers([zeroCopyCounter])
+      .rpc();
+  });
+
+  it('all four variants increment the same way', async () => {
+    const accounts = { counter: counter.publicKey, authority };
+    const costs = {
+      Account: await computeUnits(await program.methods.incrementAccount().accounts(accounts).rpc()),
+      AccountLoader: await computeUnits(
+        await program.methods.incrementZeroCopy().accounts({ counter: zeroCopyCounter.publicKey, authority }).rpc()
+      ),
+      UncheckedAccount: await computeUnits(await program.methods.incrementUnchecked().accounts(accounts).rpc()),
+      InterfaceAccount: await computeUnits(await program.methods.incrementInterface().accounts(accounts).rpc()),
+    };
+    console.table(costs);
+
+    // three of the variants share the same account
+    expect((await program.account.counter.fetch(counter.publicKey)).count.toNumber()).to.equal(3);
+    expect((await program.account.zeroCopyCounter.fetch(zeroCopyCounter.publicKey)).count.toNumber()).to.equal(1);
+
+    // skipping the deserialization of the whole account pays off even for a tiny one
+    expect(costs.UncheckedAccount).to.be.lessThan(costs.Account);
+  });
+
+  it('every variant rejects a foreign authority', async () => {
+    const mallory = Keypair.generate();
+    const accounts = { counter: counter.publicKey, authority: mallory.publicKey };
+
+    await expectError(program.methods.incrementAccount().accounts(accounts).signers([mallory]).rpc(), 'ConstraintHasOne');
+    await expectError(
+      program.methods
+        .incrementZeroCopy()
+        .accounts({ counter: zeroCopyCounter.publicKey, authority: mallory.publicKey })
+        .signers([mallory])
+        .rpc(),
+      'ConstraintHasOne'
+    );
+    await expectError(program.methods.incrementUnchecked().accounts(accounts).signers([mallory]).rpc(), 'NotAuthority');
+    await expectError(
+      program.methods.incrementInterface().accounts(accounts).signers([mallory]).rpc(),
+      'ConstraintHasOne'
+    );
+  });
+
+  it('the manual checks catch what Account would have caught', async () => {
+    // an account of another program, here the system program
+    await expectError(
+      program.methods.incrementUnchecked().accounts({ counter: authority, authority }).rpc(),
+      'AccountOwnedByWrongProgram'
+    );
+    // an account of this program, but of the wrong type
+    await expectError(
+      program.methods.incrementUnchecked().accounts({ counter: zeroCopyCounter.publicKey, authority }).rpc(),
+      'AccountDiscriminatorMismatch'
+    );
+    await expectError(
+      program.methods.incrementAccount().accounts({ counter: zeroCopyCounter.publicKey, authority }).rpc(),
+      'AccountDiscriminatorMismatch'
+    );
+    await expectError(
+      program.methods.incrementInterface().accounts({ counter: authority, authority }).rpc(),
+      'AccountOwnedByWrongProgram'
+    );
+  });
+});
@@ -0,0 +1,11 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum CrowdfundError {
+    ZeroAmount,
+    DeadlineInThePast,
+    CampaignEnded,
+    CampaignStillRunning,
+    GoalNotReached,
+    GoalReached,
+}
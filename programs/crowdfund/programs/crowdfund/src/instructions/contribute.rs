@@ -0,0 +1,58 @@
+use crate::errors::CrowdfundError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, CrowdfundError::ZeroAmount);
+
+    let campaign = &mut ctx.accounts.campaign;
+    require!(
+        campaign.is_running(Clock::get()?.unix_timestamp),
+        CrowdfundError::CampaignEnded
+    );
+
+    let contribution = &mut ctx.accounts.contribution;
+    // first contribution of this contributor to this campaign
+    if contribution.contributor == Pubkey::default() {
+        contribution.campaign = campaign.key();
+        contribution.contributor = ctx.accounts.contributor.key();
+        contribution.bump = ctx.bumps.contribution;
+    }
+    contribution.amount += amount;
+    campaign.total_raised += amount;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.contributor.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Contribute<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+    #[account(
+        mut,
+        seeds = [Campaign::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + Contribution::INIT_SPACE,
+        seeds = [Contribution::SEED_PREFIX, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,63 @@
+use crate::errors::CrowdfundError;
+use crate::state::campaign::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn create_campaign(
+    ctx: Context<CreateCampaign>,
+    id: u64,
+    goal: u64,
+    deadline: i64,
+) -> Result<()> {
+    require_gt!(goal, 0, CrowdfundError::ZeroAmount);
+    require_gt!(
+        deadline,
+        Clock::get()?.unix_timestamp,
+        CrowdfundError::DeadlineInThePast
+    );
+
+    ctx.accounts.campaign.set_inner(Campaign {
+        creator: ctx.accounts.creator.key(),
+        id,
+        goal,
+        deadline,
+        total_raised: 0,
+        bump: ctx.bumps.campaign,
+        vault_bump: ctx.bumps.vault,
+    });
+
+    // The vault is a plain system account. Funding it with the rent-exempt minimum
+    // up front means refunds can never leave it with a balance the runtime rejects.
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        Rent::get()?.minimum_balance(0),
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateCampaign<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Campaign::INIT_SPACE,
+        seeds = [Campaign::SEED_PREFIX, creator.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+    #[account(
+        mut,
+        seeds = [Campaign::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub use contribute::*;
+pub use create_campaign::*;
+pub use refund::*;
+pub use withdraw::*;
+
+pub mod contribute;
+pub mod create_campaign;
+pub mod refund;
+pub mod withdraw;
@@ -0,0 +1,56 @@
+use crate::errors::CrowdfundError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn refund(ctx: Context<Refund>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require!(
+        !campaign.is_running(Clock::get()?.unix_timestamp),
+        CrowdfundError::CampaignStillRunning
+    );
+    require!(!campaign.goal_reached(), CrowdfundError::GoalReached);
+
+    let campaign_key = campaign.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Campaign::VAULT_SEED_PREFIX,
+        campaign_key.as_ref(),
+        &[campaign.vault_bump],
+    ]];
+
+    // the contribution record is closed afterwards, so this can only happen once
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.contributor.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.contribution.amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    pub campaign: Account<'info, Campaign>,
+    #[account(
+        mut,
+        seeds = [Campaign::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = contributor,
+        has_one = campaign,
+        has_one = contributor,
+        seeds = [Contribution::SEED_PREFIX, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
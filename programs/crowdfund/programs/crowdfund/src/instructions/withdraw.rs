@@ -0,0 +1,49 @@
+use crate::errors::CrowdfundError;
+use crate::state::campaign::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require!(
+        !campaign.is_running(Clock::get()?.unix_timestamp),
+        CrowdfundError::CampaignStillRunning
+    );
+    require!(campaign.goal_reached(), CrowdfundError::GoalNotReached);
+
+    let campaign_key = campaign.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Campaign::VAULT_SEED_PREFIX,
+        campaign_key.as_ref(),
+        &[campaign.vault_bump],
+    ]];
+
+    // everything, including the rent-exempt minimum the creator put in
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.creator.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.vault.lamports(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    // closing the campaign also makes a second withdrawal impossible
+    #[account(mut, has_one = creator, close = creator)]
+    pub campaign: Account<'info, Campaign>,
+    #[account(
+        mut,
+        seeds = [Campaign::VAULT_SEED_PREFIX, campaign.key().as_ref()],
+        bump = campaign.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("3PQrBg4CVTW2HeSEhT6ztYUTsJTafDSvRG7ssdoiPvJx");
+
+#[program]
+pub mod crowdfund {
+    use super::*;
+
+    pub fn create_campaign(
+        ctx: Context<CreateCampaign>,
+        id: u64,
+        goal: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::create_campaign::create_campaign(ctx, id, goal, deadline)
+    }
+
+    pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+        instructions::contribute::contribute(ctx, amount)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        instructions::withdraw::withdraw(ctx)
+    }
+
+    pub fn refund(ctx: Context<Refund>) -> Result<()> {
+        instructions::refund::refund(ctx)
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Campaign {
+    pub creator: Pubkey,
+    pub id: u64,
+    // in lamports
+    pub goal: u64,
+    pub deadline: i64,
+    pub total_raised: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Campaign {
+    pub const SEED_PREFIX: &'static [u8] = b"campaign";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    pub fn is_running(&self, now: i64) -> bool {
+        now < self.deadline
+    }
+
+    pub fn goal_reached(&self) -> bool {
+        self.total_raised >= self.goal
+    }
+}
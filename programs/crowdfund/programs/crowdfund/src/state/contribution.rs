@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Contribution {
+    pub campaign: Pubkey,
+    pub contributor: Pubkey,
+    // in lamports, summed over all contributions of this contributor
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Contribution {
+    pub const SEED_PREFIX: &'static [u8] = b"contribution";
+}
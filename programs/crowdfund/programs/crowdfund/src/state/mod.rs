@@ -0,0 +1,5 @@
+pub use campaign::*;
+pub use contribution::*;
+
+pub mod campaign;
+pub mod contribution;
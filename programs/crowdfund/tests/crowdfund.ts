@@ -0,0 +1,183 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Crowdfund } from '../../../target/types/crowdfund';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('crowdfund', () => {
+  let harness: Harness;
+  let program: Program<Crowdfund>;
+  // the provider wallet creates the campaigns
+  let creator: anchor.web3.Keypair;
+  let nextId = 0;
+
+  async function newContributor() {
+    return harness.fundedKeypair(10 * LAMPORTS_PER_SOL);
+  }
+
+  async function createCampaign(goal: number, secondsToDeadline: number) {
+    const id = new BN(nextId++);
+    const campaign = findAddress(
+      [Buffer.from('campaign'), creator.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const vault = findAddress([Buffer.from('vault'), campaign.toBuffer()], program.programId);
+
+    await program.methods
+      .createCampaign(id, new BN(goal), new BN((await harness.now()) + secondsToDeadline))
+      .accounts({
+        campaign,
+        vault,
+        creator: creator.publicKey,
+      })
+      .rpc();
+
+    return { campaign, vault };
+  }
+
+  async function contribute(
+    campaign: anchor.web3.PublicKey,
+    vault: anchor.web3.PublicKey,
+    contributor: anchor.web3.Keypair,
+    amount: number
+  ) {
+    await program.methods
+      .contribute(new BN(amount))
+      .accounts({
+        campaign,
+        vault,
+        contribution: findAddress(
+          [Buffer.from('contribution'), campaign.toBuffer(), contributor.publicKey.toBuffer()],
+          program.programId
+        ),
+        contributor: contributor.publicKey,
+      })
+      .signers([contributor])
+      .rpc();
+  }
+
+  async function refund(campaign: anchor.web3.PublicKey, vault: anchor.web3.PublicKey, contributor: anchor.web3.Keypair) {
+    await program.methods
+      .refund()
+      .accounts({
+        campaign,
+        vault,
+        contribution: findAddress(
+          [Buffer.from('contribution'), campaign.toBuffer(), contributor.publicKey.toBuffer()],
+          program.programId
+        ),
+        contributor: contributor.publicKey,
+      })
+      .signers([contributor])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Crowdfund>('crowdfund');
+    creator = harness.payer;
+  });
+
+  it('missed goal: contributors get refunded!', async () => {
+    const { campaign, vault } = await createCampaign(5 * LAMPORTS_PER_SOL, 3);
+    const alice = await newContributor();
+    const bob = await newContributor();
+
+    await contribute(campaign, vault, alice, LAMPORTS_PER_SOL);
+    await contribute(campaign, vault, alice, LAMPORTS_PER_SOL);
+    await contribute(campaign, vault, bob, LAMPORTS_PER_SOL);
+
+    const campaignState = await program.account.campaign.fetch(campaign);
+    expect(campaignState.totalRaised.toNumber()).to.equal(3 * LAMPORTS_PER_SOL);
+    const aliceContribution = await program.account.contribution.fetch(
+      findAddress([Buffer.from('contribution'), campaign.toBuffer(), alice.publicKey.toBuffer()], program.programId)
+    );
+    expect(aliceContribution.amount.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+
+    // refunds only open once the campaign is over
+    try {
+      await refund(campaign, vault, alice);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('CampaignStillRunning');
+      expect(err.error.errorCode.number).to.equal(6003);
+    }
+
+    await harness.warp(4);
+
+    try {
+      await program.methods.withdraw().accounts({ campaign, vault, creator: creator.publicKey }).rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('GoalNotReached');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+
+    const aliceBalance = await harness.lamports(alice.publicKey);
+    await refund(campaign, vault, alice);
+    await refund(campaign, vault, bob);
+    // the refund plus the rent of the closed contribution record
+    expect(await harness.lamports(alice.publicKey)).to.be.greaterThan(aliceBalance + 2 * LAMPORTS_PER_SOL);
+
+    // a second refund fails because the contribution record is gone
+    await expect(refund(campaign, vault, alice)).to.be.rejected;
+
+    // only the rent-exempt minimum is left in the vault
+    expect(await harness.lamports(vault)).to.equal(await harness.rentExemption(0));
+  });
+
+  it('reached goal: creator withdraws!', async () => {
+    const { campaign, vault } = await createCampaign(2 * LAMPORTS_PER_SOL, 2);
+    const alice = await newContributor();
+
+    await contribute(campaign, vault, alice, 3 * LAMPORTS_PER_SOL);
+    await harness.warp(3);
+
+    try {
+      await contribute(campaign, vault, alice, LAMPORTS_PER_SOL);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('CampaignEnded');
+      expect(err.error.errorCode.number).to.equal(6002);
+    }
+
+    try {
+      await refund(campaign, vault, alice);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('GoalReached');
+    }
+
+    const creatorBalance = await harness.lamports(creator.publicKey);
+    await program.methods.withdraw().accounts({ campaign, vault, creator: creator.publicKey }).rpc();
+    expect(await harness.lamports(creator.publicKey)).to.be.greaterThan(creatorBalance + 3 * LAMPORTS_PER_SOL - 10_000);
+    expect(await harness.lamports(vault)).to.equal(0);
+    expect(await harness.account(campaign)).to.be.null;
+  });
+
+  it('deadline must be in the future', async () => {
+    try {
+      await createCampaign(LAMPORTS_PER_SOL, -10);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('DeadlineInThePast');
+      expect(err.error.errorCode.number).to.equal(6001);
+    }
+  });
+});
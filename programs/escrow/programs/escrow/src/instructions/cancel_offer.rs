@@ -0,0 +1,65 @@
+use crate::state::offer::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    let maker = offer.maker;
+    let id_bytes = offer.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Offer::SEED_PREFIX,
+        maker.as_ref(),
+        id_bytes.as_ref(),
+        &[offer.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.maker_token_account_a.to_account_info(),
+                authority: ctx.accounts.offer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.vault.amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.maker.to_account_info(),
+            authority: ctx.accounts.offer.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker,
+        seeds = [Offer::SEED_PREFIX, maker.key().as_ref(), offer.id.to_le_bytes().as_ref()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [Offer::VAULT_SEED_PREFIX, offer.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = offer.token_mint_a,
+        token::authority = maker
+    )]
+    pub maker_token_account_a: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,69 @@
+use crate::errors::EscrowError;
+use crate::state::offer::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn make_offer(
+    ctx: Context<MakeOffer>,
+    id: u64,
+    token_a_offered_amount: u64,
+    token_b_wanted_amount: u64,
+) -> Result<()> {
+    require_gt!(token_a_offered_amount, 0, EscrowError::ZeroAmount);
+    require_gt!(token_b_wanted_amount, 0, EscrowError::ZeroAmount);
+
+    ctx.accounts.offer.set_inner(Offer {
+        id,
+        maker: ctx.accounts.maker.key(),
+        token_mint_a: ctx.accounts.token_mint_a.key(),
+        token_mint_b: ctx.accounts.token_mint_b.key(),
+        token_b_wanted_amount,
+        bump: ctx.bumps.offer,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.maker_token_account_a.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.maker.to_account_info(),
+            },
+        ),
+        token_a_offered_amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct MakeOffer<'info> {
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + Offer::INIT_SPACE,
+        seeds = [Offer::SEED_PREFIX, maker.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        init,
+        payer = maker,
+        token::mint = token_mint_a,
+        token::authority = offer,
+        seeds = [Offer::VAULT_SEED_PREFIX, offer.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub token_mint_a: Account<'info, Mint>,
+    pub token_mint_b: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = token_mint_a,
+        token::authority = maker
+    )]
+    pub maker_token_account_a: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
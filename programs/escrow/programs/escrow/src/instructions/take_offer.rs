@@ -0,0 +1,103 @@
+use crate::state::offer::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+pub fn take_offer(ctx: Context<TakeOffer>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+
+    // the taker pays first, so the swap either happens in full or not at all
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.taker_token_account_b.to_account_info(),
+                to: ctx.accounts.maker_token_account_b.to_account_info(),
+                authority: ctx.accounts.taker.to_account_info(),
+            },
+        ),
+        offer.token_b_wanted_amount,
+    )?;
+
+    let maker = offer.maker;
+    let id_bytes = offer.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Offer::SEED_PREFIX,
+        maker.as_ref(),
+        id_bytes.as_ref(),
+        &[offer.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.taker_token_account_a.to_account_info(),
+                authority: ctx.accounts.offer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.vault.amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.maker.to_account_info(),
+            authority: ctx.accounts.offer.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct TakeOffer<'info> {
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker,
+        has_one = token_mint_a,
+        has_one = token_mint_b,
+        seeds = [Offer::SEED_PREFIX, maker.key().as_ref(), offer.id.to_le_bytes().as_ref()],
+        bump = offer.bump
+    )]
+    pub offer: Account<'info, Offer>,
+    #[account(
+        mut,
+        seeds = [Offer::VAULT_SEED_PREFIX, offer.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub token_mint_a: Box<Account<'info, Mint>>,
+    pub token_mint_b: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = taker
+    )]
+    pub taker_token_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = token_mint_b,
+        token::authority = taker
+    )]
+    pub taker_token_account_b: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = maker
+    )]
+    pub maker_token_account_b: Box<Account<'info, TokenAccount>>,
+    /// CHECK: only receives the rent of the closed offer and vault, and is checked against `offer.maker`
+    #[account(mut)]
+    pub maker: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub taker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,3 @@
+pub use offer::*;
+
+pub mod offer;
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    pub id: u64,
+    pub maker: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub token_b_wanted_amount: u64,
+    pub bump: u8,
+}
+
+impl Offer {
+    pub const SEED_PREFIX: &'static [u8] = b"offer";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+}
@@ -0,0 +1,169 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { Harness } from 'test-harness';
+import { Escrow } from '../../../target/types/escrow';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('escrow', () => {
+  let harness: Harness;
+  let program: Program<Escrow>;
+  let maker: anchor.web3.Keypair;
+  const taker = anchor.web3.Keypair.generate();
+
+  let tokenMintA: anchor.web3.PublicKey;
+  let tokenMintB: anchor.web3.PublicKey;
+  let makerTokenAccountA: anchor.web3.PublicKey;
+  let takerTokenAccountB: anchor.web3.PublicKey;
+
+  function offerAddresses(id: anchor.BN) {
+    const [offer] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('offer'), maker.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), offer.toBuffer()],
+      program.programId
+    );
+    return { offer, vault };
+  }
+
+  async function makeOffer(id: anchor.BN, offeredAmount: number, wantedAmount: number) {
+    const { offer, vault } = offerAddresses(id);
+    await program.methods
+      .makeOffer(id, new BN(offeredAmount), new BN(wantedAmount))
+      .accounts({
+        offer,
+        vault,
+        tokenMintA,
+        tokenMintB,
+        makerTokenAccountA,
+        maker: maker.publicKey,
+      })
+      .rpc();
+    return { offer, vault };
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Escrow>('escrow');
+    // the maker pays for the transactions, so it signs all of them
+    maker = harness.payer;
+
+    await harness.fund(taker.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    tokenMintA = await harness.createMint(maker.publicKey);
+    tokenMintB = await harness.createMint(maker.publicKey);
+    makerTokenAccountA = await harness.createTokenAccount(tokenMintA, maker.publicKey, 1_000);
+    takerTokenAccountB = await harness.createTokenAccount(tokenMintB, taker.publicKey, 1_000);
+  });
+
+  it('make offer!', async () => {
+    const id = new BN(1);
+    const { offer, vault } = await makeOffer(id, 100, 50);
+
+    const offerState = await program.account.offer.fetch(offer);
+    expect(offerState.id.toNumber()).to.equal(1);
+    expect(offerState.maker).to.eql(maker.publicKey);
+    expect(offerState.tokenMintA).to.eql(tokenMintA);
+    expect(offerState.tokenMintB).to.eql(tokenMintB);
+    expect(offerState.tokenBWantedAmount.toNumber()).to.equal(50);
+
+    expect(await harness.tokenBalance(vault)).to.equal(100);
+    expect(await harness.tokenBalance(makerTokenAccountA)).to.equal(900);
+  });
+
+  it('take offer!', async () => {
+    const id = new BN(1);
+    const { offer, vault } = offerAddresses(id);
+    const takerTokenAccountA = getAssociatedTokenAddressSync(tokenMintA, taker.publicKey);
+    const makerTokenAccountB = getAssociatedTokenAddressSync(tokenMintB, maker.publicKey);
+
+    await program.methods
+      .takeOffer()
+      .accounts({
+        offer,
+        vault,
+        tokenMintA,
+        tokenMintB,
+        takerTokenAccountA,
+        takerTokenAccountB,
+        makerTokenAccountB,
+        maker: maker.publicKey,
+        taker: taker.publicKey,
+      })
+      .signers([taker])
+      .rpc();
+
+    expect(await harness.tokenBalance(takerTokenAccountA)).to.equal(100);
+    expect(await harness.tokenBalance(takerTokenAccountB)).to.equal(950);
+    expect(await harness.tokenBalance(makerTokenAccountB)).to.equal(50);
+
+    // both the offer and its vault are closed and their rent went back to the maker
+    expect(await harness.account(offer)).to.be.null;
+    expect(await harness.account(vault)).to.be.null;
+  });
+
+  it('cancel offer!', async () => {
+    const id = new BN(2);
+    const { offer, vault } = await makeOffer(id, 200, 10);
+    expect(await harness.tokenBalance(makerTokenAccountA)).to.equal(700);
+
+    await program.methods
+      .cancelOffer()
+      .accounts({
+        offer,
+        vault,
+        makerTokenAccountA,
+        maker: maker.publicKey,
+      })
+      .rpc();
+
+    expect(await harness.tokenBalance(makerTokenAccountA)).to.equal(900);
+    expect(await harness.account(offer)).to.be.null;
+    expect(await harness.account(vault)).to.be.null;
+  });
+
+  it('only the maker can cancel an offer', async () => {
+    const id = new BN(3);
+    const { offer, vault } = await makeOffer(id, 10, 10);
+    const takerTokenAccountA = getAssociatedTokenAddressSync(tokenMintA, taker.publicKey);
+
+    try {
+      await program.methods
+        .cancelOffer()
+        .accounts({
+          offer,
+          vault,
+          makerTokenAccountA: takerTokenAccountA,
+          maker: taker.publicKey,
+        })
+        .signers([taker])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintSeeds');
+      expect(err.program.equals(program.programId)).is.true;
+    }
+
+    expect(await harness.tokenBalance(vault)).to.equal(10);
+  });
+
+  it('rejects empty offers', async () => {
+    try {
+      await makeOffer(new BN(4), 0, 10);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ZeroAmount');
+      expect(err.error.errorCode.number).to.equal(6000);
+    }
+  });
+});
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use flash_loan::cpi::accounts::Borrow;
+use flash_loan::program::FlashLoan;
+use flash_loan::state::Pool;
+
+/// Always fails: the flash loan program refuses to be called through CPI.
+/// Kept as an example of what the instructions sysvar check in `borrow` prevents.
+pub fn borrow_via_cpi(ctx: Context<BorrowViaCpi>, amount: u64) -> Result<()> {
+    flash_loan::cpi::borrow(
+        CpiContext::new(
+            ctx.accounts.flash_loan_program.to_account_info(),
+            Borrow {
+                pool: ctx.accounts.pool.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                borrower_token_account: ctx.accounts.borrower_token_account.to_account_info(),
+                borrower: ctx.accounts.borrower.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct BorrowViaCpi<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    /// CHECK: checked by the flash loan program
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+    pub borrower: Signer<'info>,
+    /// CHECK: the instructions sysvar, checked by the flash loan program
+    pub instructions: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub flash_loan_program: Program<'info, FlashLoan>,
+}
@@ -0,0 +1,5 @@
+pub use borrow_via_cpi::*;
+pub use use_loan::*;
+
+pub mod borrow_via_cpi;
+pub mod use_loan;
@@ -0,0 +1,23 @@
+use crate::errors::FlashBorrowerError;
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+pub fn use_loan(ctx: Context<UseLoan>, amount: u64) -> Result<()> {
+    // By the time this runs, the borrowed tokens have arrived. This is where an
+    // arbitrage or a liquidation would go, as long as it leaves enough behind
+    // for the `repay` that follows.
+    require_gte!(
+        ctx.accounts.token_account.amount,
+        amount,
+        FlashBorrowerError::LoanNotReceived
+    );
+    msg!("holding {} borrowed tokens", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UseLoan<'info> {
+    #[account(token::authority = owner)]
+    pub token_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("F5ZHdWmGNbLmSvxEtaE32GwnW7zEwg4bx28XtYuqSSB8");
+
+/// A companion to the flash loan program. Its instructions are meant to be
+/// sandwiched between a `borrow` and a `repay` in the same transaction.
+#[program]
+pub mod flash_borrower {
+    use super::*;
+
+    pub fn use_loan(ctx: Context<UseLoan>, amount: u64) -> Result<()> {
+        instructions::use_loan::use_loan(ctx, amount)
+    }
+
+    pub fn borrow_via_cpi(ctx: Context<BorrowViaCpi>, amount: u64) -> Result<()> {
+        instructions::borrow_via_cpi::borrow_via_cpi(ctx, amount)
+    }
+}
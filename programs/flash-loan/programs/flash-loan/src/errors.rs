@@ -0,0 +1,13 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum FlashLoanError {
+    InvalidFee,
+    ZeroAmount,
+    CpiNotAllowed,
+    LoanAlreadyActive,
+    MissingRepay,
+    NoActiveLoan,
+    LoanActive,
+    MathOverflow,
+}
@@ -0,0 +1,86 @@
+use crate::errors::FlashLoanError;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    get_instruction_relative, load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, FlashLoanError::ZeroAmount);
+    let pool = &mut ctx.accounts.pool;
+    require_eq!(pool.active_loan, 0, FlashLoanError::LoanAlreadyActive);
+
+    let instructions = ctx.accounts.instructions.to_account_info();
+
+    // The instructions sysvar only knows about top-level instructions. Relative
+    // index 0 is the one that is currently executing, so if it belongs to another
+    // program, we were called through CPI. Refusing that keeps the reasoning
+    // simple: a borrow and its repay are both top-level instructions of this program.
+    require_keys_eq!(
+        get_instruction_relative(0, &instructions)?.program_id,
+        crate::ID,
+        FlashLoanError::CpiNotAllowed
+    );
+
+    // Look for a repay of this pool anywhere after the current instruction.
+    // We don't have to check that it succeeds, if it fails, the whole
+    // transaction fails and the loan never happened.
+    let current_index = load_current_index_checked(&instructions)? as usize;
+    let mut index = current_index + 1;
+    let mut repay_found = false;
+    while let Ok(instruction) = load_instruction_at_checked(index, &instructions) {
+        if instruction.program_id == crate::ID
+            && instruction.data.get(..8) == Some(&crate::instruction::Repay::DISCRIMINATOR)
+            && instruction.accounts.first().map(|meta| meta.pubkey) == Some(pool.key())
+        {
+            repay_found = true;
+            break;
+        }
+        index += 1;
+    }
+    require!(repay_found, FlashLoanError::MissingRepay);
+
+    pool.active_loan = amount
+        .checked_add(pool.fee(amount)?)
+        .ok_or(FlashLoanError::MathOverflow)?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[Pool::SEED_PREFIX, pool.mint.as_ref(), &[pool.bump]]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.borrower_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.mint,
+        token::authority = borrower
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+    pub borrower: Signer<'info>,
+    /// CHECK: the instructions sysvar, its address is checked
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
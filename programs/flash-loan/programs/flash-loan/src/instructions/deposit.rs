@@ -0,0 +1,41 @@
+use crate::errors::FlashLoanError;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, FlashLoanError::ZeroAmount);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+// Anyone can add liquidity, but only the authority can take it out again.
+// Keeping track of individual depositors is out of scope for this example.
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.mint,
+        token::authority = depositor
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    pub depositor: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
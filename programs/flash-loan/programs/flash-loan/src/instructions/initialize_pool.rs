@@ -0,0 +1,44 @@
+use crate::errors::FlashLoanError;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn initialize_pool(ctx: Context<InitializePool>, fee_bps: u16) -> Result<()> {
+    require_gte!(Pool::MAX_FEE_BPS, fee_bps, FlashLoanError::InvalidFee);
+
+    ctx.accounts.pool.set_inner(Pool {
+        authority: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        fee_bps,
+        active_loan: 0,
+        bump: ctx.bumps.pool,
+        vault_bump: ctx.bumps.vault,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [Pool::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = pool
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,11 @@
+pub use borrow::*;
+pub use deposit::*;
+pub use initialize_pool::*;
+pub use repay::*;
+pub use withdraw::*;
+
+pub mod borrow;
+pub mod deposit;
+pub mod initialize_pool;
+pub mod repay;
+pub mod withdraw;
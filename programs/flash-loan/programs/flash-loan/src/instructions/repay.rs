@@ -0,0 +1,45 @@
+use crate::errors::FlashLoanError;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn repay(ctx: Context<Repay>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let amount = pool.active_loan;
+    require_gt!(amount, 0, FlashLoanError::NoActiveLoan);
+    pool.active_loan = 0;
+
+    // the loan plus the fee
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.borrower_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.borrower.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    // must stay the first account, `borrow` looks for it at that position
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.mint,
+        token::authority = borrower
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+    pub borrower: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,46 @@
+use crate::errors::FlashLoanError;
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, FlashLoanError::ZeroAmount);
+    let pool = &ctx.accounts.pool;
+    // otherwise the vault could be drained in between a borrow and its repay
+    require_eq!(pool.active_loan, 0, FlashLoanError::LoanActive);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[Pool::SEED_PREFIX, pool.mint.as_ref(), &[pool.bump]]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(has_one = authority)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.mint,
+        token::authority = authority
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
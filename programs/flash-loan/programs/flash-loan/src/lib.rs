@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("E3aBJh4vNwXjVT9BsuAA45K4kGbVc9FdsUDFoAaNvhM5");
+
+#[program]
+pub mod flash_loan {
+    use super::*;
+
+    pub fn initialize_pool(ctx: Context<InitializePool>, fee_bps: u16) -> Result<()> {
+        instructions::initialize_pool::initialize_pool(ctx, fee_bps)
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        instructions::deposit::deposit(ctx, amount)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        instructions::withdraw::withdraw(ctx, amount)
+    }
+
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        instructions::borrow::borrow(ctx, amount)
+    }
+
+    pub fn repay(ctx: Context<Repay>) -> Result<()> {
+        instructions::repay::repay(ctx)
+    }
+}
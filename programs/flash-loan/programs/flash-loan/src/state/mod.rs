@@ -0,0 +1,3 @@
+pub use pool::*;
+
+pub mod pool;
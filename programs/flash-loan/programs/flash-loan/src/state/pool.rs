@@ -0,0 +1,26 @@
+use crate::errors::FlashLoanError;
+use anchor_lang::prelude::*;
+use common::bps;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub fee_bps: u16,
+    // what has to be paid back by the end of the current transaction, 0 if nothing was borrowed
+    pub active_loan: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Pool {
+    pub const SEED_PREFIX: &'static [u8] = b"pool";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+    pub const MAX_FEE_BPS: u16 = bps::MAX_BPS;
+
+    /// Rounds up, so even the smallest loans pay something.
+    pub fn fee(&self, amount: u64) -> Result<u64> {
+        Ok(bps::apply_ceil(amount, self.fee_bps).ok_or(FlashLoanError::MathOverflow)?)
+    }
+}
@@ -0,0 +1,181 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { createTransferInstruction } from '@solana/spl-token';
+import { Harness } from 'test-harness';
+import { FlashLoan } from '../../../target/types/flash_loan';
+import { FlashBorrower } from '../../../target/types/flash_borrower';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { SYSVAR_INSTRUCTIONS_PUBKEY } = anchor.web3;
+
+describe('flash-loan', () => {
+  let harness: Harness;
+  let program: Program<FlashLoan>;
+  let borrowerProgram: Program<FlashBorrower>;
+  let payer: anchor.web3.Keypair;
+  let borrower: anchor.web3.Keypair;
+
+  let mint: anchor.web3.PublicKey;
+  let pool: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let borrowerTokenAccount: anchor.web3.PublicKey;
+
+  function borrowIx(amount: number) {
+    return program.methods
+      .borrow(new BN(amount))
+      .accounts({
+        pool,
+        vault,
+        borrowerTokenAccount,
+        borrower: borrower.publicKey,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .instruction();
+  }
+
+  function useLoanIx(amount: number) {
+    return borrowerProgram.methods
+      .useLoan(new BN(amount))
+      .accounts({ tokenAccount: borrowerTokenAccount, owner: borrower.publicKey })
+      .instruction();
+  }
+
+  function repayIx() {
+    return program.methods
+      .repay()
+      .accounts({ pool, vault, borrowerTokenAccount, borrower: borrower.publicKey })
+      .instruction();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<FlashLoan>('flash_loan');
+    borrowerProgram = harness.program<FlashBorrower>('flash_borrower');
+    payer = harness.payer;
+    borrower = harness.payer;
+
+    mint = await harness.createMint(payer.publicKey);
+    [pool] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from('pool'), mint.toBuffer()], program.programId);
+    [vault] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from('vault'), pool.toBuffer()], program.programId);
+    // enough to pay the fees, not to pay back the loans
+    borrowerTokenAccount = await harness.createTokenAccount(mint, borrower.publicKey, 100);
+
+    // 0.5%
+    await program.methods
+      .initializePool(50)
+      .accounts({ pool, vault, mint, authority: payer.publicKey })
+      .rpc();
+
+    const depositorTokenAccount = await harness.createTokenAccount(
+      mint,
+      payer.publicKey,
+      10_000,
+      anchor.web3.Keypair.generate().publicKey
+    );
+    await program.methods
+      .deposit(new BN(10_000))
+      .accounts({ pool, vault, depositorTokenAccount, depositor: payer.publicKey })
+      .rpc();
+  });
+
+  it('borrow and repay in one transaction!', async () => {
+    await harness.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(await borrowIx(1_000), await useLoanIx(1_000), await repayIx())
+    );
+
+    expect(await harness.tokenBalance(vault)).to.equal(10_005);
+    expect(await harness.tokenBalance(borrowerTokenAccount)).to.equal(95);
+    expect((await program.account.pool.fetch(pool)).activeLoan.toNumber()).to.equal(0);
+  });
+
+  it('fees round up', async () => {
+    await harness.provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(await borrowIx(1), await repayIx())
+    );
+    expect(await harness.tokenBalance(vault)).to.equal(10_006);
+  });
+
+  it('borrow without repay', async () => {
+    try {
+      await program.methods
+        .borrow(new BN(1_000))
+        .accounts({
+          pool,
+          vault,
+          borrowerTokenAccount,
+          borrower: borrower.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .postInstructions([await useLoanIx(1_000)])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('MissingRepay');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+  });
+
+  it('repay without borrow', async () => {
+    try {
+      await program.methods
+        .repay()
+        .accounts({ pool, vault, borrowerTokenAccount, borrower: borrower.publicKey })
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NoActiveLoan');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+  });
+
+  it('cannot borrow through cpi', async () => {
+    try {
+      await borrowerProgram.methods
+        .borrowViaCpi(new BN(1_000))
+        .accounts({
+          pool,
+          vault,
+          borrowerTokenAccount,
+          borrower: borrower.publicKey,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          flashLoanProgram: program.programId,
+        })
+        .postInstructions([await repayIx()])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('CpiNotAllowed');
+      expect(err.program.equals(program.programId)).is.true;
+    }
+  });
+
+  it('cannot repay less than borrowed', async () => {
+    // the borrower only has 94 tokens of their own, and moves the loan away
+    const stash = await harness.createTokenAccount(mint, borrower.publicKey, 0, anchor.web3.Keypair.generate().publicKey);
+    try {
+      await harness.provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          await borrowIx(1_000),
+          createTransferInstruction(borrowerTokenAccount, stash, borrower.publicKey, 1_000),
+          await repayIx()
+        )
+      );
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      // the token program rejects the repay, which rolls back the whole transaction
+      expect(_err).to.not.be.instanceOf(chai.AssertionError);
+    }
+    expect(await harness.tokenBalance(vault)).to.equal(10_006);
+    expect(await harness.tokenBalance(stash)).to.equal(0);
+  });
+});
@@ -0,0 +1,14 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum GrantsError {
+    NoMilestones,
+    TooManyMilestones,
+    ZeroAmount,
+    InvalidMilestone,
+    InvalidTransition,
+    GrantNotActive,
+    GrantStillActive,
+    DisputeOpen,
+    MathOverflow,
+}
@@ -0,0 +1,52 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn approve_milestone(ctx: Context<ApproveMilestone>, index: u8) -> Result<()> {
+    let amount = ctx
+        .accounts
+        .grant
+        .approve(index, Clock::get()?.unix_timestamp)?;
+
+    let grant = &ctx.accounts.grant;
+    let id_bytes = grant.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Grant::SEED_PREFIX,
+        grant.dao.as_ref(),
+        id_bytes.as_ref(),
+        &[grant.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.grant.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ApproveMilestone<'info> {
+    #[account(mut, has_one = approver)]
+    pub grant: Account<'info, Grant>,
+    #[account(
+        mut,
+        seeds = [Grant::VAULT_SEED_PREFIX, grant.key().as_ref()],
+        bump = grant.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = grant.mint,
+        token::authority = grant.recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub approver: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
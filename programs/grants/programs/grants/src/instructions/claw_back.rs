@@ -0,0 +1,51 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+// Tranches that were already released stay with the recipient, everything else
+// goes back to the treasury. Open disputes have to be resolved first.
+pub fn claw_back(ctx: Context<ClawBack>) -> Result<()> {
+    let returned = ctx.accounts.grant.claw_back()?;
+
+    let grant = &ctx.accounts.grant;
+    let id_bytes = grant.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Grant::SEED_PREFIX,
+        grant.dao.as_ref(),
+        id_bytes.as_ref(),
+        &[grant.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.grant.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        returned,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ClawBack<'info> {
+    #[account(mut, has_one = dao)]
+    pub grant: Account<'info, Grant>,
+    #[account(
+        mut,
+        seeds = [Grant::VAULT_SEED_PREFIX, grant.key().as_ref()],
+        bump = grant.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = grant.mint,
+        token::authority = dao
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    pub dao: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,47 @@
+use crate::errors::GrantsError;
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+// Once a grant is completed or clawed back its vault is empty,
+// so both accounts can go and their rent returns to the DAO.
+pub fn close_grant(ctx: Context<CloseGrant>) -> Result<()> {
+    let grant = &ctx.accounts.grant;
+    require!(
+        grant.status != GrantStatus::Active,
+        GrantsError::GrantStillActive
+    );
+
+    let id_bytes = grant.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Grant::SEED_PREFIX,
+        grant.dao.as_ref(),
+        id_bytes.as_ref(),
+        &[grant.bump],
+    ]];
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.dao.to_account_info(),
+            authority: ctx.accounts.grant.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct CloseGrant<'info> {
+    #[account(mut, close = dao, has_one = dao)]
+    pub grant: Account<'info, Grant>,
+    #[account(
+        mut,
+        seeds = [Grant::VAULT_SEED_PREFIX, grant.key().as_ref()],
+        bump = grant.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub dao: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,78 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+// The whole grant is escrowed up front, so the recipient can rely on every
+// approved tranche actually being paid.
+pub fn create_grant(
+    ctx: Context<CreateGrant>,
+    id: u64,
+    recipient: Pubkey,
+    approver: Pubkey,
+    amounts: Vec<u64>,
+) -> Result<()> {
+    let total = Grant::total(&amounts)?;
+
+    ctx.accounts.grant.set_inner(Grant {
+        dao: ctx.accounts.dao.key(),
+        id,
+        recipient,
+        approver,
+        mint: ctx.accounts.mint.key(),
+        milestones: amounts
+            .into_iter()
+            .map(|amount| Milestone {
+                amount,
+                status: MilestoneStatus::Pending,
+            })
+            .collect(),
+        status: GrantStatus::Active,
+        bump: ctx.bumps.grant,
+        vault_bump: ctx.bumps.vault,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.dao.to_account_info(),
+            },
+        ),
+        total,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateGrant<'info> {
+    #[account(
+        init,
+        payer = dao,
+        space = 8 + Grant::INIT_SPACE,
+        seeds = [Grant::SEED_PREFIX, dao.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub grant: Account<'info, Grant>,
+    #[account(
+        init,
+        payer = dao,
+        token::mint = mint,
+        token::authority = grant,
+        seeds = [Grant::VAULT_SEED_PREFIX, grant.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = dao
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub dao: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
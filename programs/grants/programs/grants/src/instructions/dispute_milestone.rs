@@ -0,0 +1,16 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+
+// Escalates a rejection to the DAO, which then has the final word in `resolve_dispute`.
+pub fn dispute_milestone(ctx: Context<DisputeMilestone>, index: u8) -> Result<()> {
+    ctx.accounts
+        .grant
+        .dispute(index, Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct DisputeMilestone<'info> {
+    #[account(mut, has_one = recipient)]
+    pub grant: Account<'info, Grant>,
+    pub recipient: Signer<'info>,
+}
@@ -0,0 +1,17 @@
+pub use approve_milestone::*;
+pub use claw_back::*;
+pub use close_grant::*;
+pub use create_grant::*;
+pub use dispute_milestone::*;
+pub use reject_milestone::*;
+pub use resolve_dispute::*;
+pub use submit_milestone::*;
+
+pub mod approve_milestone;
+pub mod claw_back;
+pub mod close_grant;
+pub mod create_grant;
+pub mod dispute_milestone;
+pub mod reject_milestone;
+pub mod resolve_dispute;
+pub mod submit_milestone;
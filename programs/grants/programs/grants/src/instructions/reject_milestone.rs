@@ -0,0 +1,15 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+
+pub fn reject_milestone(ctx: Context<RejectMilestone>, index: u8) -> Result<()> {
+    ctx.accounts
+        .grant
+        .reject(index, Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct RejectMilestone<'info> {
+    #[account(mut, has_one = approver)]
+    pub grant: Account<'info, Grant>,
+    pub approver: Signer<'info>,
+}
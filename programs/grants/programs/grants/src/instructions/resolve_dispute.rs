@@ -0,0 +1,65 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+// The DAO either sides with the recipient and releases the tranche,
+// or with the approver and takes the tranche back into the treasury.
+pub fn resolve_dispute(ctx: Context<ResolveDispute>, index: u8, release: bool) -> Result<()> {
+    let amount = ctx
+        .accounts
+        .grant
+        .resolve(index, release, Clock::get()?.unix_timestamp)?;
+
+    let grant = &ctx.accounts.grant;
+    let id_bytes = grant.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Grant::SEED_PREFIX,
+        grant.dao.as_ref(),
+        id_bytes.as_ref(),
+        &[grant.bump],
+    ]];
+
+    let to = if release {
+        ctx.accounts.recipient_token_account.to_account_info()
+    } else {
+        ctx.accounts.treasury.to_account_info()
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to,
+                authority: ctx.accounts.grant.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut, has_one = dao)]
+    pub grant: Account<'info, Grant>,
+    #[account(
+        mut,
+        seeds = [Grant::VAULT_SEED_PREFIX, grant.key().as_ref()],
+        bump = grant.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = grant.mint,
+        token::authority = grant.recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = grant.mint,
+        token::authority = dao
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+    pub dao: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,19 @@
+use crate::state::grant::*;
+use anchor_lang::prelude::*;
+
+pub fn submit_milestone(
+    ctx: Context<SubmitMilestone>,
+    index: u8,
+    evidence: [u8; 32],
+) -> Result<()> {
+    ctx.accounts
+        .grant
+        .submit(index, evidence, Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    #[account(mut, has_one = recipient)]
+    pub grant: Account<'info, Grant>,
+    pub recipient: Signer<'info>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("2uPFAzZrUZJ5mtAh57DazAyWo39TcoidsLahUcstvK1h");
+
+#[program]
+pub mod grants {
+    use super::*;
+
+    pub fn create_grant(
+        ctx: Context<CreateGrant>,
+        id: u64,
+        recipient: Pubkey,
+        approver: Pubkey,
+        amounts: Vec<u64>,
+    ) -> Result<()> {
+        instructions::create_grant::create_grant(ctx, id, recipient, approver, amounts)
+    }
+
+    pub fn submit_milestone(
+        ctx: Context<SubmitMilestone>,
+        index: u8,
+        evidence: [u8; 32],
+    ) -> Result<()> {
+        instructions::submit_milestone::submit_milestone(ctx, index, evidence)
+    }
+
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>, index: u8) -> Result<()> {
+        instructions::approve_milestone::approve_milestone(ctx, index)
+    }
+
+    pub fn reject_milestone(ctx: Context<RejectMilestone>, index: u8) -> Result<()> {
+        instructions::reject_milestone::reject_milestone(ctx, index)
+    }
+
+    pub fn dispute_milestone(ctx: Context<DisputeMilestone>, index: u8) -> Result<()> {
+        instructions::dispute_milestone::dispute_milestone(ctx, index)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, index: u8, release: bool) -> Result<()> {
+        instructions::resolve_dispute::resolve_dispute(ctx, index, release)
+    }
+
+    pub fn claw_back(ctx: Context<ClawBack>) -> Result<()> {
+        instructions::claw_back::claw_back(ctx)
+    }
+
+    pub fn close_grant(ctx: Context<CloseGrant>) -> Result<()> {
+        instructions::close_grant::close_grant(ctx)
+    }
+}
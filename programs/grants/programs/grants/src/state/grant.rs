@@ -0,0 +1,203 @@
+use crate::errors::GrantsError;
+use anchor_lang::prelude::*;
+
+pub const MAX_MILESTONES: usize = 10;
+
+/// Funds escrowed by a DAO treasury for a recipient, paid out one tranche per milestone.
+///
+/// Three parties take part, each with their own instructions:
+/// - the recipient submits milestones and may dispute a rejection,
+/// - the approver approves milestones, which releases their tranche, or rejects them,
+/// - the DAO settles disputes and may claw back whatever hasn't been released.
+#[account]
+#[derive(InitSpace)]
+pub struct Grant {
+    // the treasury's authority, a wallet or a governance program's PDA
+    pub dao: Pubkey,
+    pub id: u64,
+    pub recipient: Pubkey,
+    pub approver: Pubkey,
+    pub mint: Pubkey,
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    pub status: GrantStatus,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Milestone {
+    pub amount: u64,
+    pub status: MilestoneStatus,
+}
+
+// Pending -> Submitted -> Released
+//               ^  |
+//               |  v
+//             Rejected -> Disputed -> Released or Cancelled
+//
+// Any milestone not final yet becomes Cancelled when the grant is clawed back.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum MilestoneStatus {
+    Pending,
+    // `evidence` is a hash of the deliverable, which itself lives off-chain
+    Submitted {
+        evidence: [u8; 32],
+        submitted_ts: i64,
+    },
+    Rejected {
+        rejected_ts: i64,
+    },
+    Disputed {
+        disputed_ts: i64,
+    },
+    Released {
+        released_ts: i64,
+    },
+    Cancelled,
+}
+
+impl MilestoneStatus {
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            MilestoneStatus::Released { .. } | MilestoneStatus::Cancelled
+        )
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum GrantStatus {
+    Active,
+    // every milestone was either released or cancelled in a dispute
+    Completed,
+    ClawedBack { returned: u64 },
+}
+
+impl Grant {
+    pub const SEED_PREFIX: &'static [u8] = b"grant";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    /// Checks the tranches of a new grant and returns their total.
+    pub fn total(amounts: &[u64]) -> Result<u64> {
+        require!(!amounts.is_empty(), GrantsError::NoMilestones);
+        require_gte!(
+            MAX_MILESTONES,
+            amounts.len(),
+            GrantsError::TooManyMilestones
+        );
+        require!(amounts.iter().all(|&a| a > 0), GrantsError::ZeroAmount);
+        amounts
+            .iter()
+            .try_fold(0u64, |total, &amount| total.checked_add(amount))
+            .ok_or_else(|| error!(GrantsError::MathOverflow))
+    }
+
+    pub fn submit(&mut self, index: u8, evidence: [u8; 32], now: i64) -> Result<()> {
+        let milestone = self.milestone_mut(index)?;
+        // a rejected milestone can be reworked and submitted again
+        require!(
+            matches!(
+                milestone.status,
+                MilestoneStatus::Pending | MilestoneStatus::Rejected { .. }
+            ),
+            GrantsError::InvalidTransition
+        );
+        milestone.status = MilestoneStatus::Submitted {
+            evidence,
+            submitted_ts: now,
+        };
+        Ok(())
+    }
+
+    /// Returns the tranche to pay out to the recipient.
+    pub fn approve(&mut self, index: u8, now: i64) -> Result<u64> {
+        let milestone = self.milestone_mut(index)?;
+        require!(
+            matches!(milestone.status, MilestoneStatus::Submitted { .. }),
+            GrantsError::InvalidTransition
+        );
+        milestone.status = MilestoneStatus::Released { released_ts: now };
+        let amount = milestone.amount;
+        self.complete_if_settled();
+        Ok(amount)
+    }
+
+    pub fn reject(&mut self, index: u8, now: i64) -> Result<()> {
+        let milestone = self.milestone_mut(index)?;
+        require!(
+            matches!(milestone.status, MilestoneStatus::Submitted { .. }),
+            GrantsError::InvalidTransition
+        );
+        milestone.status = MilestoneStatus::Rejected { rejected_ts: now };
+        Ok(())
+    }
+
+    pub fn dispute(&mut self, index: u8, now: i64) -> Result<()> {
+        let milestone = self.milestone_mut(index)?;
+        require!(
+            matches!(milestone.status, MilestoneStatus::Rejected { .. }),
+            GrantsError::InvalidTransition
+        );
+        milestone.status = MilestoneStatus::Disputed { disputed_ts: now };
+        Ok(())
+    }
+
+    /// Returns the tranche, which goes to the recipient if `release` is set
+    /// and back to the treasury otherwise.
+    pub fn resolve(&mut self, index: u8, release: bool, now: i64) -> Result<u64> {
+        let milestone = self.milestone_mut(index)?;
+        require!(
+            matches!(milestone.status, MilestoneStatus::Disputed { .. }),
+            GrantsError::InvalidTransition
+        );
+        milestone.status = if release {
+            MilestoneStatus::Released { released_ts: now }
+        } else {
+            MilestoneStatus::Cancelled
+        };
+        let amount = milestone.amount;
+        self.complete_if_settled();
+        Ok(amount)
+    }
+
+    /// Cancels every milestone that isn't final and returns what they were worth.
+    pub fn claw_back(&mut self) -> Result<u64> {
+        require!(
+            self.status == GrantStatus::Active,
+            GrantsError::GrantNotActive
+        );
+        // the recipient's claim has to be settled first, a clawback must not sidestep it
+        require!(
+            !self
+                .milestones
+                .iter()
+                .any(|m| matches!(m.status, MilestoneStatus::Disputed { .. })),
+            GrantsError::DisputeOpen
+        );
+
+        let mut returned = 0;
+        for milestone in self.milestones.iter_mut().filter(|m| !m.status.is_final()) {
+            milestone.status = MilestoneStatus::Cancelled;
+            returned += milestone.amount;
+        }
+        self.status = GrantStatus::ClawedBack { returned };
+        Ok(returned)
+    }
+
+    fn milestone_mut(&mut self, index: u8) -> Result<&mut Milestone> {
+        require!(
+            self.status == GrantStatus::Active,
+            GrantsError::GrantNotActive
+        );
+        self.milestones
+            .get_mut(index as usize)
+            .ok_or_else(|| error!(GrantsError::InvalidMilestone))
+    }
+
+    fn complete_if_settled(&mut self) {
+        if self.milestones.iter().all(|m| m.status.is_final()) {
+            self.status = GrantStatus::Completed;
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub use grant::*;
+
+pub mod grant;
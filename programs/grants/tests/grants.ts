@@ -0,0 +1,199 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Harness, expectError } from 'test-harness';
+import { Grants } from '../../../target/types/grants';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('grants', () => {
+  let harness: Harness;
+  let program: Program<Grants>;
+
+  const dao = anchor.web3.Keypair.generate();
+  const recipient = anchor.web3.Keypair.generate();
+  const approver = anchor.web3.Keypair.generate();
+  let mint: anchor.web3.PublicKey;
+  let treasury: anchor.web3.PublicKey;
+  let recipientTokenAccount: anchor.web3.PublicKey;
+
+  const evidence = (text: string) => Array.from(Buffer.from(anchor.utils.sha256.hash(text), 'hex'));
+
+  function grantAccounts(id: number) {
+    const [grant] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('grant'), dao.publicKey.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), grant.toBuffer()],
+      program.programId
+    );
+    return { grant, vault };
+  }
+
+  async function createGrant(id: number, amounts: number[]) {
+    await program.methods
+      .createGrant(
+        new BN(id),
+        recipient.publicKey,
+        approver.publicKey,
+        amounts.map((amount) => new BN(amount))
+      )
+      .accounts({ ...grantAccounts(id), mint, treasury, dao: dao.publicKey })
+      .signers([dao])
+      .rpc();
+  }
+
+  async function submit(id: number, index: number) {
+    await program.methods
+      .submitMilestone(index, evidence(`milestone ${index}`))
+      .accounts({ grant: grantAccounts(id).grant, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+  }
+
+  async function approve(id: number, index: number) {
+    await program.methods
+      .approveMilestone(index)
+      .accounts({ ...grantAccounts(id), recipientTokenAccount, approver: approver.publicKey })
+      .signers([approver])
+      .rpc();
+  }
+
+  async function reject(id: number, index: number) {
+    await program.methods
+      .rejectMilestone(index)
+      .accounts({ grant: grantAccounts(id).grant, approver: approver.publicKey })
+      .signers([approver])
+      .rpc();
+  }
+
+  async function dispute(id: number, index: number) {
+    await program.methods
+      .disputeMilestone(index)
+      .accounts({ grant: grantAccounts(id).grant, recipient: recipient.publicKey })
+      .signers([recipient])
+      .rpc();
+  }
+
+  async function resolve(id: number, index: number, release: boolean) {
+    await program.methods
+      .resolveDispute(index, release)
+      .accounts({ ...grantAccounts(id), recipientTokenAccount, treasury, dao: dao.publicKey })
+      .signers([dao])
+      .rpc();
+  }
+
+  async function clawBack(id: number) {
+    await program.methods
+      .clawBack()
+      .accounts({ ...grantAccounts(id), treasury, dao: dao.publicKey })
+      .signers([dao])
+      .rpc();
+  }
+
+  async function closeGrant(id: number) {
+    await program.methods
+      .closeGrant()
+      .accounts({ ...grantAccounts(id), dao: dao.publicKey })
+      .signers([dao])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Grants>('grants');
+    for (const keypair of [dao, recipient, approver]) {
+      await harness.fund(keypair.publicKey);
+    }
+    mint = await harness.createMint(harness.payer.publicKey);
+    treasury = await harness.createTokenAccount(mint, dao.publicKey, 10_000);
+    recipientTokenAccount = await harness.createTokenAccount(mint, recipient.publicKey);
+  });
+
+  it('release every tranche, one milestone at a time!', async () => {
+    await expectError(createGrant(0, []), 'NoMilestones', 6000);
+    await expectError(createGrant(0, new Array(11).fill(1)), 'TooManyMilestones', 6001);
+    await expectError(createGrant(0, [100, 0]), 'ZeroAmount', 6002);
+
+    const { grant, vault } = grantAccounts(0);
+    await createGrant(0, [100, 200, 300]);
+    expect(await harness.tokenBalance(vault)).to.equal(600);
+    expect(await harness.tokenBalance(treasury)).to.equal(9_400);
+
+    // nothing to approve before the recipient submits
+    await expectError(approve(0, 0), 'InvalidTransition', 6004);
+    await expectError(submit(0, 3), 'InvalidMilestone', 6003);
+
+    await submit(0, 0);
+    let grantState = await program.account.grant.fetch(grant);
+    expect(grantState.milestones[0].status.submitted.evidence).to.deep.equal(evidence('milestone 0'));
+    await approve(0, 0);
+    expect(await harness.tokenBalance(recipientTokenAccount)).to.equal(100);
+
+    // a rejected milestone can be reworked and submitted again
+    await submit(0, 1);
+    await reject(0, 1);
+    await expectError(approve(0, 1), 'InvalidTransition', 6004);
+    await submit(0, 1);
+    await approve(0, 1);
+
+    await expectError(closeGrant(0), 'GrantStillActive', 6006);
+    await submit(0, 2);
+    await approve(0, 2);
+    expect(await harness.tokenBalance(recipientTokenAccount)).to.equal(600);
+    grantState = await program.account.grant.fetch(grant);
+    expect(grantState.status).to.deep.equal({ completed: {} });
+    await expectError(submit(0, 0), 'GrantNotActive', 6005);
+
+    await closeGrant(0);
+    expect(await harness.account(grant)).to.be.null;
+    expect(await harness.account(vault)).to.be.null;
+  });
+
+  it('disputes go to the dao, which can claw back the rest', async () => {
+    const { grant, vault } = grantAccounts(1);
+    await createGrant(1, [100, 200, 300, 400]);
+    expect(await harness.tokenBalance(treasury)).to.equal(8_400);
+
+    // the dao sides with the recipient
+    await submit(1, 0);
+    await reject(1, 0);
+    await dispute(1, 0);
+    await resolve(1, 0, true);
+    expect(await harness.tokenBalance(recipientTokenAccount)).to.equal(700);
+
+    // ... and then with the approver, the tranche returns to the treasury
+    await submit(1, 1);
+    await reject(1, 1);
+    await dispute(1, 1);
+    await resolve(1, 1, false);
+    expect(await harness.tokenBalance(treasury)).to.equal(8_600);
+
+    // no clawback while a dispute is open
+    await submit(1, 2);
+    await reject(1, 2);
+    await dispute(1, 2);
+    await expectError(clawBack(1), 'DisputeOpen', 6007);
+    await resolve(1, 2, false);
+
+    await clawBack(1);
+    expect(await harness.tokenBalance(treasury)).to.equal(9_300);
+    expect(await harness.tokenBalance(vault)).to.equal(0);
+    const grantState = await program.account.grant.fetch(grant);
+    expect(grantState.status.clawedBack.returned.toNumber()).to.equal(400);
+    expect(grantState.milestones.map((m) => Object.keys(m.status)[0])).to.deep.equal([
+      'released',
+      'cancelled',
+      'cancelled',
+      'cancelled',
+    ]);
+    await expectError(submit(1, 3), 'GrantNotActive', 6005);
+
+    await closeGrant(1);
+    expect(await harness.account(grant)).to.be.null;
+  });
+});
@@ -0,0 +1,32 @@
+use crate::errors::InboxError;
+use crate::state::inbox::*;
+use crate::state::message::*;
+use anchor_lang::prelude::*;
+
+// A legit message: the deposit goes back to the sender, the message stays.
+pub fn accept_message(ctx: Context<AcceptMessage>) -> Result<()> {
+    let message = &mut ctx.accounts.message;
+    require!(
+        message.status == MessageStatus::Pending,
+        InboxError::NotPending
+    );
+    message.status = MessageStatus::Accepted;
+
+    // the message is owned by this program, so it can hand out lamports without a CPI
+    let deposit = std::mem::take(&mut message.deposit);
+    message.sub_lamports(deposit)?;
+    ctx.accounts.sender.add_lamports(deposit)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptMessage<'info> {
+    #[account(has_one = owner)]
+    pub inbox: Account<'info, Inbox>,
+    #[account(mut, has_one = inbox, has_one = sender)]
+    pub message: Account<'info, Message>,
+    /// CHECK: only receives the deposit, and is checked against `message.sender`
+    #[account(mut)]
+    pub sender: UncheckedAccount<'info>,
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,29 @@
+use crate::errors::InboxError;
+use crate::state::inbox::*;
+use crate::state::message::*;
+use anchor_lang::prelude::*;
+
+// An owner who never decides can't hold the deposit hostage: after the expiry, anyone
+// may close the message, and the deposit and rent both go back to the sender.
+pub fn close_expired(ctx: Context<CloseExpired>) -> Result<()> {
+    let message = &ctx.accounts.message;
+    require!(
+        message.status == MessageStatus::Pending,
+        InboxError::NotPending
+    );
+    require!(
+        message.is_expired(ctx.accounts.inbox.expiry, Clock::get()?.unix_timestamp),
+        InboxError::NotExpired
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseExpired<'info> {
+    pub inbox: Account<'info, Inbox>,
+    #[account(mut, close = sender, has_one = inbox, has_one = sender)]
+    pub message: Account<'info, Message>,
+    /// CHECK: only receives the lamports of the closed message, and is checked against `message.sender`
+    #[account(mut)]
+    pub sender: UncheckedAccount<'info>,
+}
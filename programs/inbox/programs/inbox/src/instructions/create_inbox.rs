@@ -0,0 +1,31 @@
+use crate::errors::InboxError;
+use crate::state::inbox::*;
+use anchor_lang::prelude::*;
+
+pub fn create_inbox(ctx: Context<CreateInbox>, deposit: u64, expiry: i64) -> Result<()> {
+    require_gt!(expiry, 0, InboxError::InvalidExpiry);
+
+    ctx.accounts.inbox.set_inner(Inbox {
+        owner: ctx.accounts.owner.key(),
+        deposit,
+        expiry,
+        message_count: 0,
+        bump: ctx.bumps.inbox,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateInbox<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Inbox::INIT_SPACE,
+        seeds = [Inbox::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub inbox: Account<'info, Inbox>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,25 @@
+use crate::errors::InboxError;
+use crate::state::inbox::*;
+use crate::state::message::*;
+use anchor_lang::prelude::*;
+
+// The owner clears out an accepted message, whose rent the sender paid and gets back.
+pub fn delete_message(ctx: Context<DeleteMessage>) -> Result<()> {
+    require!(
+        ctx.accounts.message.status == MessageStatus::Accepted,
+        InboxError::NotAccepted
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeleteMessage<'info> {
+    #[account(has_one = owner)]
+    pub inbox: Account<'info, Inbox>,
+    #[account(mut, close = sender, has_one = inbox, has_one = sender)]
+    pub message: Account<'info, Message>,
+    /// CHECK: only receives the rent of the closed message, and is checked against `message.sender`
+    #[account(mut)]
+    pub sender: UncheckedAccount<'info>,
+    pub owner: Signer<'info>,
+}
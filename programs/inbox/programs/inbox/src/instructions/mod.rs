@@ -0,0 +1,13 @@
+pub use accept_message::*;
+pub use close_expired::*;
+pub use create_inbox::*;
+pub use delete_message::*;
+pub use report_spam::*;
+pub use send_message::*;
+
+pub mod accept_message;
+pub mod close_expired;
+pub mod create_inbox;
+pub mod delete_message;
+pub mod report_spam;
+pub mod send_message;
@@ -0,0 +1,32 @@
+use crate::errors::InboxError;
+use crate::state::inbox::*;
+use crate::state::message::*;
+use anchor_lang::prelude::*;
+
+// Spam: the owner keeps the deposit and the message is deleted. The rent still goes
+// back to the sender, the deposit alone is the price of spamming.
+pub fn report_spam(ctx: Context<ReportSpam>) -> Result<()> {
+    let message = &ctx.accounts.message;
+    require!(
+        message.status == MessageStatus::Pending,
+        InboxError::NotPending
+    );
+
+    let deposit = message.deposit;
+    ctx.accounts.message.sub_lamports(deposit)?;
+    ctx.accounts.owner.add_lamports(deposit)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportSpam<'info> {
+    #[account(has_one = owner)]
+    pub inbox: Account<'info, Inbox>,
+    #[account(mut, close = sender, has_one = inbox, has_one = sender)]
+    pub message: Account<'info, Message>,
+    /// CHECK: only receives the rent of the closed message, and is checked against `message.sender`
+    #[account(mut)]
+    pub sender: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
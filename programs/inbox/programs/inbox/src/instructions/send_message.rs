@@ -0,0 +1,60 @@
+use crate::state::inbox::*;
+use crate::state::message::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn send_message(ctx: Context<SendMessage>, content: String) -> Result<()> {
+    Message::validate_content(&content)?;
+
+    let inbox = &mut ctx.accounts.inbox;
+    ctx.accounts.message.set_inner(Message {
+        inbox: inbox.key(),
+        sender: ctx.accounts.sender.key(),
+        index: inbox.message_count,
+        content,
+        // fixed at sending, so a later change of the inbox's deposit can't affect this message
+        deposit: inbox.deposit,
+        sent_ts: Clock::get()?.unix_timestamp,
+        status: MessageStatus::Pending,
+        bump: ctx.bumps.message,
+    });
+    inbox.message_count += 1;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender.to_account_info(),
+                to: ctx.accounts.message.to_account_info(),
+            },
+        ),
+        ctx.accounts.inbox.deposit,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(content: String)]
+pub struct SendMessage<'info> {
+    #[account(
+        mut,
+        seeds = [Inbox::SEED_PREFIX, inbox.owner.as_ref()],
+        bump = inbox.bump
+    )]
+    pub inbox: Account<'info, Inbox>,
+    // sized to fit `content` exactly, the sender pays for what they write
+    #[account(
+        init,
+        payer = sender,
+        space = Message::space(&content),
+        seeds = [
+            Message::SEED_PREFIX,
+            inbox.key().as_ref(),
+            inbox.message_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub message: Account<'info, Message>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
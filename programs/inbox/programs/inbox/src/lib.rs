@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("9d8RDFP5mh3w6aokGXKVBwfCTuFvRwevyjAdSxmRJPF6");
+
+#[program]
+pub mod inbox {
+    use super::*;
+
+    pub fn create_inbox(ctx: Context<CreateInbox>, deposit: u64, expiry: i64) -> Result<()> {
+        instructions::create_inbox::create_inbox(ctx, deposit, expiry)
+    }
+
+    pub fn send_message(ctx: Context<SendMessage>, content: String) -> Result<()> {
+        instructions::send_message::send_message(ctx, content)
+    }
+
+    pub fn accept_message(ctx: Context<AcceptMessage>) -> Result<()> {
+        instructions::accept_message::accept_message(ctx)
+    }
+
+    pub fn report_spam(ctx: Context<ReportSpam>) -> Result<()> {
+        instructions::report_spam::report_spam(ctx)
+    }
+
+    pub fn delete_message(ctx: Context<DeleteMessage>) -> Result<()> {
+        instructions::delete_message::delete_message(ctx)
+    }
+
+    pub fn close_expired(ctx: Context<CloseExpired>) -> Result<()> {
+        instructions::close_expired::close_expired(ctx)
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// A user's inbox. Writing to it costs a deposit, which the owner keeps if the
+/// message turns out to be spam: cheap for real correspondents, costly for spammers.
+#[account]
+#[derive(InitSpace)]
+pub struct Inbox {
+    pub owner: Pubkey,
+    // in lamports, on top of the message's rent
+    pub deposit: u64,
+    // seconds the owner has to decide on a message, after which the sender may take it back
+    pub expiry: i64,
+    // index of the next message, which is part of its address so clients can page through them
+    pub message_count: u64,
+    pub bump: u8,
+}
+
+impl Inbox {
+    pub const SEED_PREFIX: &'static [u8] = b"inbox";
+}
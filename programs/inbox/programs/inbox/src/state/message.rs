@@ -0,0 +1,45 @@
+use crate::errors::InboxError;
+use anchor_lang::prelude::*;
+
+pub const MAX_CONTENT_LEN: usize = 280;
+
+// One account per message. It holds the sender's deposit on top of its rent, until the
+// owner either returns it or claims it, so neither side can walk away with the other's lamports.
+#[account]
+#[derive(InitSpace)]
+pub struct Message {
+    pub inbox: Pubkey,
+    pub sender: Pubkey,
+    pub index: u64,
+    #[max_len(MAX_CONTENT_LEN)]
+    pub content: String,
+    pub deposit: u64,
+    pub sent_ts: i64,
+    pub status: MessageStatus,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum MessageStatus {
+    Pending,
+    Accepted,
+}
+
+impl Message {
+    pub const SEED_PREFIX: &'static [u8] = b"message";
+
+    // `INIT_SPACE` reserves `MAX_CONTENT_LEN` bytes for the content, swap those for the real length
+    pub fn space(content: &str) -> usize {
+        8 + Message::INIT_SPACE - MAX_CONTENT_LEN + content.len()
+    }
+
+    pub fn validate_content(content: &str) -> Result<()> {
+        require!(!content.is_empty(), InboxError::EmptyContent);
+        require_gte!(MAX_CONTENT_LEN, content.len(), InboxError::ContentTooLong);
+        Ok(())
+    }
+
+    pub fn is_expired(&self, expiry: i64, now: i64) -> bool {
+        now >= self.sent_ts + expiry
+    }
+}
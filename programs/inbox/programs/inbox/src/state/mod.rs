@@ -0,0 +1,5 @@
+pub use inbox::*;
+pub use message::*;
+
+pub mod inbox;
+pub mod message;
@@ -0,0 +1,136 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { Harness, expectError } from 'test-harness';
+import { Inbox } from '../../../target/types/inbox';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('inbox', () => {
+  let harness: Harness;
+  let program: Program<Inbox>;
+
+  const owner = Keypair.generate();
+  const friend = Keypair.generate();
+  const spammer = Keypair.generate();
+  let inbox: anchor.web3.PublicKey;
+  const deposit = LAMPORTS_PER_SOL / 100;
+  const expiry = 3;
+
+  function messageAddress(index: number) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('message'), inbox.toBuffer(), new BN(index).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+  }
+
+  async function sendMessage(sender: Keypair, content: string) {
+    const { messageCount } = await program.account.inbox.fetch(inbox);
+    const message = messageAddress(messageCount.toNumber());
+    await program.methods
+      .sendMessage(content)
+      .accounts({ inbox, message, sender: sender.publicKey })
+      .signers([sender])
+      .rpc();
+    return message;
+  }
+
+  // what a client shows: one page of messages, newest first, skipping deleted ones
+  async function fetchPage(page: number, pageSize: number) {
+    const { messageCount } = await program.account.inbox.fetch(inbox);
+    const last = messageCount.toNumber() - 1 - page * pageSize;
+    const indexes = [...Array(pageSize).keys()].map((i) => last - i).filter((i) => i >= 0);
+    const messages = await program.account.message.fetchMultiple(indexes.map(messageAddress));
+    return messages.filter((m) => m !== null).map((m) => m.content);
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Inbox>('inbox');
+    [inbox] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('inbox'), owner.publicKey.toBuffer()],
+      program.programId
+    );
+
+    for (const keypair of [owner, friend, spammer]) {
+      await harness.fund(keypair.publicKey, LAMPORTS_PER_SOL);
+    }
+    await program.methods
+      .createInbox(new BN(deposit), new BN(expiry))
+      .accounts({ inbox, owner: owner.publicKey })
+      .signers([owner])
+      .rpc();
+  });
+
+  it('legit messages get their deposit back!', async () => {
+    await expectError(sendMessage(friend, ''), 'EmptyContent', 6001);
+
+    const friendBefore = await harness.lamports(friend.publicKey);
+    const message = await sendMessage(friend, 'lunch tomorrow?');
+    const rent = await harness.rentExemption((await harness.account(message)).data.length);
+    expect(await harness.lamports(message)).to.equal(rent + deposit);
+
+    await program.methods
+      .acceptMessage()
+      .accounts({ inbox, message, sender: friend.publicKey, owner: owner.publicKey })
+      .signers([owner])
+      .rpc();
+    expect(await harness.lamports(message)).to.equal(rent);
+    // all the friend paid is the rent of the message, the provider wallet pays the fees
+    expect(await harness.lamports(friend.publicKey)).to.equal(friendBefore - rent);
+
+    const messageState = await program.account.message.fetch(message);
+    expect(messageState.status).to.deep.equal({ accepted: {} });
+    expect(messageState.deposit.toNumber()).to.equal(0);
+  });
+
+  it('spam costs the deposit', async () => {
+    const message = await sendMessage(spammer, 'you won a prize!!!');
+    const ownerBefore = await harness.lamports(owner.publicKey);
+    await program.methods
+      .reportSpam()
+      .accounts({ inbox, message, sender: spammer.publicKey, owner: owner.publicKey })
+      .signers([owner])
+      .rpc();
+    expect(await harness.account(message)).to.be.null;
+    expect(await harness.lamports(owner.publicKey)).to.equal(ownerBefore + deposit);
+  });
+
+  it('expired messages go back to the sender', async () => {
+    const message = await sendMessage(friend, 'are you there?');
+    const closeExpired = () =>
+      program.methods.closeExpired().accounts({ inbox, message, sender: friend.publicKey }).rpc();
+    await expectError(closeExpired(), 'NotExpired', 6005);
+
+    await harness.warp(expiry + 1);
+    const friendBefore = await harness.lamports(friend.publicKey);
+    const messageLamports = await harness.lamports(message);
+    // anyone can clean up
+    await closeExpired();
+    expect(await harness.account(message)).to.be.null;
+    expect(await harness.lamports(friend.publicKey)).to.equal(friendBefore + messageLamports);
+  });
+
+  it('page through the messages', async () => {
+    for (let i = 0; i < 4; i++) {
+      await sendMessage(friend, `note ${i}`);
+    }
+    // indexes 1 and 2 were closed above, and are skipped
+    expect(await fetchPage(0, 3)).to.deep.equal(['note 3', 'note 2', 'note 1']);
+    expect(await fetchPage(1, 3)).to.deep.equal(['note 0']);
+    expect(await fetchPage(2, 3)).to.deep.equal(['lunch tomorrow?']);
+
+    const message = messageAddress(0);
+    await program.methods
+      .deleteMessage()
+      .accounts({ inbox, message, sender: friend.publicKey, owner: owner.publicKey })
+      .signers([owner])
+      .rpc();
+    expect(await fetchPage(2, 3)).to.deep.equal([]);
+  });
+});
@@ -0,0 +1,15 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum LendingError {
+    InvalidConfig,
+    InvalidPrice,
+    ZeroAmount,
+    InsufficientShares,
+    InsufficientLiquidity,
+    InsufficientCollateral,
+    Unhealthy,
+    NotLiquidatable,
+    NothingToRepay,
+    MathOverflow,
+}
@@ -0,0 +1,75 @@
+use crate::errors::LendingError;
+use crate::state::market::*;
+use crate::state::mock_oracle::*;
+use crate::state::obligation::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, LendingError::ZeroAmount);
+    let market = &mut ctx.accounts.market;
+    let obligation = &mut ctx.accounts.obligation;
+    market.accrue(Clock::get()?.unix_timestamp)?;
+
+    require_gte!(
+        ctx.accounts.loan_vault.amount,
+        amount,
+        LendingError::InsufficientLiquidity
+    );
+    obligation.add_debt(amount, market.borrow_index)?;
+    market.total_borrows = market
+        .total_borrows
+        .checked_add(amount)
+        .ok_or(LendingError::MathOverflow)?;
+    require!(
+        market.is_healthy(obligation, &ctx.accounts.oracle)?,
+        LendingError::Unhealthy
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Market::SEED_PREFIX,
+        market.collateral_mint.as_ref(),
+        market.loan_mint.as_ref(),
+        &[market.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.loan_vault.to_account_info(),
+                to: ctx.accounts.owner_loan_account.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(mut, has_one = oracle)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        seeds = [Obligation::SEED_PREFIX, market.key().as_ref(), owner.key().as_ref()],
+        bump = obligation.bump
+    )]
+    pub obligation: Account<'info, Obligation>,
+    pub oracle: Account<'info, MockOracle>,
+    #[account(
+        mut,
+        seeds = [Market::LOAN_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.loan_vault_bump
+    )]
+    pub loan_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.loan_mint,
+        token::authority = owner
+    )]
+    pub owner_loan_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,19 @@
+use crate::state::mock_oracle::*;
+use anchor_lang::prelude::*;
+
+pub fn create_oracle(ctx: Context<CreateOracle>, price: u64) -> Result<()> {
+    ctx.accounts.oracle.set_inner(MockOracle {
+        authority: ctx.accounts.authority.key(),
+        price,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateOracle<'info> {
+    #[account(init, payer = authority, space = 8 + MockOracle::INIT_SPACE)]
+    pub oracle: Account<'info, MockOracle>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
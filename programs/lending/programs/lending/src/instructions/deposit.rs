@@ -0,0 +1,69 @@
+use crate::errors::LendingError;
+use crate::state::lender_position::*;
+use crate::state::market::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, LendingError::ZeroAmount);
+    let market = &mut ctx.accounts.market;
+    market.accrue(Clock::get()?.unix_timestamp)?;
+
+    let shares = market.shares_for_deposit(amount)?;
+    market.total_deposits = market
+        .total_deposits
+        .checked_add(amount)
+        .ok_or(LendingError::MathOverflow)?;
+    market.total_shares = market
+        .total_shares
+        .checked_add(shares)
+        .ok_or(LendingError::MathOverflow)?;
+
+    let position = &mut ctx.accounts.position;
+    position.market = market.key();
+    position.owner = ctx.accounts.owner.key();
+    position.shares += shares;
+    position.bump = ctx.bumps.position;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_loan_account.to_account_info(),
+                to: ctx.accounts.loan_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + LenderPosition::INIT_SPACE,
+        seeds = [LenderPosition::SEED_PREFIX, market.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, LenderPosition>,
+    #[account(
+        mut,
+        seeds = [Market::LOAN_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.loan_vault_bump
+    )]
+    pub loan_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.loan_mint,
+        token::authority = owner
+    )]
+    pub owner_loan_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
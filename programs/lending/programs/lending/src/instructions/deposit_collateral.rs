@@ -0,0 +1,59 @@
+use crate::errors::LendingError;
+use crate::state::market::*;
+use crate::state::obligation::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn deposit_collateral(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, LendingError::ZeroAmount);
+    let market = &mut ctx.accounts.market;
+    market.accrue(Clock::get()?.unix_timestamp)?;
+
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.market = market.key();
+    obligation.owner = ctx.accounts.owner.key();
+    obligation.collateral += amount;
+    obligation.bump = ctx.bumps.obligation;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_collateral_account.to_account_info(),
+                to: ctx.accounts.collateral_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct DepositCollateral<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Obligation::INIT_SPACE,
+        seeds = [Obligation::SEED_PREFIX, market.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub obligation: Account<'info, Obligation>,
+    #[account(
+        mut,
+        seeds = [Market::COLLATERAL_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.collateral_vault_bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = owner
+    )]
+    pub owner_collateral_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
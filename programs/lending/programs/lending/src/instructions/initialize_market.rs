@@ -0,0 +1,63 @@
+use crate::state::market::*;
+use crate::state::mock_oracle::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn initialize_market(ctx: Context<InitializeMarket>, config: MarketConfig) -> Result<()> {
+    Market::validate(&config)?;
+
+    ctx.accounts.market.set_inner(Market {
+        authority: ctx.accounts.authority.key(),
+        collateral_mint: ctx.accounts.collateral_mint.key(),
+        loan_mint: ctx.accounts.loan_mint.key(),
+        oracle: ctx.accounts.oracle.key(),
+        config,
+        total_deposits: 0,
+        total_shares: 0,
+        total_borrows: 0,
+        borrow_index: INDEX_ONE,
+        last_update_ts: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.market,
+        collateral_vault_bump: ctx.bumps.collateral_vault,
+        loan_vault_bump: ctx.bumps.loan_vault,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Market::INIT_SPACE,
+        seeds = [Market::SEED_PREFIX, collateral_mint.key().as_ref(), loan_mint.key().as_ref()],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Market::COLLATERAL_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = market
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [Market::LOAN_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump,
+        token::mint = loan_mint,
+        token::authority = market
+    )]
+    pub loan_vault: Account<'info, TokenAccount>,
+    pub collateral_mint: Account<'info, Mint>,
+    pub loan_mint: Account<'info, Mint>,
+    // prices the collateral in loan tokens
+    pub oracle: Account<'info, MockOracle>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
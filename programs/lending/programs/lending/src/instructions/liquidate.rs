@@ -0,0 +1,90 @@
+use crate::errors::LendingError;
+use crate::state::market::*;
+use crate::state::mock_oracle::*;
+use crate::state::obligation::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn liquidate(ctx: Context<Liquidate>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, LendingError::ZeroAmount);
+    let market = &mut ctx.accounts.market;
+    let obligation = &mut ctx.accounts.obligation;
+    let oracle = &ctx.accounts.oracle;
+    market.accrue(Clock::get()?.unix_timestamp)?;
+
+    require!(
+        market.is_liquidatable(obligation, oracle)?,
+        LendingError::NotLiquidatable
+    );
+    let (repay, seized) = market.liquidation_amounts(obligation, oracle, amount)?;
+    let repaid = obligation.repay(repay, market.borrow_index)?;
+    obligation.collateral -= seized;
+    market.total_borrows = market.total_borrows.saturating_sub(repaid);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.liquidator_loan_account.to_account_info(),
+                to: ctx.accounts.loan_vault.to_account_info(),
+                authority: ctx.accounts.liquidator.to_account_info(),
+            },
+        ),
+        repaid,
+    )?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Market::SEED_PREFIX,
+        market.collateral_mint.as_ref(),
+        market.loan_mint.as_ref(),
+        &[market.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.liquidator_collateral_account.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        seized,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Liquidate<'info> {
+    #[account(mut, has_one = oracle)]
+    pub market: Account<'info, Market>,
+    #[account(mut, has_one = market)]
+    pub obligation: Account<'info, Obligation>,
+    pub oracle: Account<'info, MockOracle>,
+    #[account(
+        mut,
+        seeds = [Market::COLLATERAL_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.collateral_vault_bump
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [Market::LOAN_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.loan_vault_bump
+    )]
+    pub loan_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.loan_mint,
+        token::authority = liquidator
+    )]
+    pub liquidator_loan_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = liquidator
+    )]
+    pub liquidator_collateral_account: Account<'info, TokenAccount>,
+    pub liquidator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
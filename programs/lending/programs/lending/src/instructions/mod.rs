@@ -0,0 +1,21 @@
+pub use borrow::*;
+pub use create_oracle::*;
+pub use deposit::*;
+pub use deposit_collateral::*;
+pub use initialize_market::*;
+pub use liquidate::*;
+pub use repay::*;
+pub use set_price::*;
+pub use withdraw::*;
+pub use withdraw_collateral::*;
+
+pub mod borrow;
+pub mod create_oracle;
+pub mod deposit;
+pub mod deposit_collateral;
+pub mod initialize_market;
+pub mod liquidate;
+pub mod repay;
+pub mod set_price;
+pub mod withdraw;
+pub mod withdraw_collateral;
@@ -0,0 +1,52 @@
+use crate::errors::LendingError;
+use crate::state::market::*;
+use crate::state::obligation::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, LendingError::ZeroAmount);
+    let market = &mut ctx.accounts.market;
+    market.accrue(Clock::get()?.unix_timestamp)?;
+
+    // anything above the debt is simply not taken
+    let repaid = ctx.accounts.obligation.repay(amount, market.borrow_index)?;
+    require_gt!(repaid, 0, LendingError::NothingToRepay);
+    // rounding can leave individual debts a tiny bit above their share of the total
+    market.total_borrows = market.total_borrows.saturating_sub(repaid);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer_loan_account.to_account_info(),
+                to: ctx.accounts.loan_vault.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        repaid,
+    )
+}
+
+// anyone can pay off someone else's debt
+#[derive(Accounts)]
+pub struct Repay<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(mut, has_one = market)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(
+        mut,
+        seeds = [Market::LOAN_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.loan_vault_bump
+    )]
+    pub loan_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.loan_mint,
+        token::authority = payer
+    )]
+    pub payer_loan_account: Account<'info, TokenAccount>,
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
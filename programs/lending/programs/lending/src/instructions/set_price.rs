@@ -0,0 +1,14 @@
+use crate::state::mock_oracle::*;
+use anchor_lang::prelude::*;
+
+pub fn set_price(ctx: Context<SetPrice>, price: u64) -> Result<()> {
+    ctx.accounts.oracle.price = price;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    #[account(mut, has_one = authority)]
+    pub oracle: Account<'info, MockOracle>,
+    pub authority: Signer<'info>,
+}
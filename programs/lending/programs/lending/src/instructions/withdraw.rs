@@ -0,0 +1,76 @@
+use crate::errors::LendingError;
+use crate::state::lender_position::*;
+use crate::state::market::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+    require_gt!(shares, 0, LendingError::ZeroAmount);
+    let market = &mut ctx.accounts.market;
+    let position = &mut ctx.accounts.position;
+    require_gte!(position.shares, shares, LendingError::InsufficientShares);
+    market.accrue(Clock::get()?.unix_timestamp)?;
+
+    let amount = market.amount_for_shares(shares)?;
+    // whatever is lent out can't be withdrawn until it is repaid
+    require_gte!(
+        ctx.accounts.loan_vault.amount,
+        amount,
+        LendingError::InsufficientLiquidity
+    );
+    market.total_deposits = market
+        .total_deposits
+        .checked_sub(amount)
+        .ok_or(LendingError::MathOverflow)?;
+    market.total_shares = market
+        .total_shares
+        .checked_sub(shares)
+        .ok_or(LendingError::MathOverflow)?;
+    position.shares -= shares;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Market::SEED_PREFIX,
+        market.collateral_mint.as_ref(),
+        market.loan_mint.as_ref(),
+        &[market.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.loan_vault.to_account_info(),
+                to: ctx.accounts.owner_loan_account.to_account_info(),
+                authority: market.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(
+        mut,
+        seeds = [LenderPosition::SEED_PREFIX, market.key().as_ref(), owner.key().as_ref()],
+        bump = position.bump
+    )]
+    pub position: Account<'info, LenderPosition>,
+    #[account(
+        mut,
+        seeds = [Market::LOAN_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.loan_vault_bump
+    )]
+    pub loan_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.loan_mint,
+        token::authority = owner
+    )]
+    pub owner_loan_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+use state::market::MarketConfig;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("5rSwXhqYz3sBqywVQLbzMpZmPZU5WdcTeMgB59wLtnSm");
+
+#[program]
+pub mod lending {
+    use super::*;
+
+    pub fn create_oracle(ctx: Context<CreateOracle>, price: u64) -> Result<()> {
+        instructions::create_oracle::create_oracle(ctx, price)
+    }
+
+    pub fn set_price(ctx: Context<SetPrice>, price: u64) -> Result<()> {
+        instructions::set_price::set_price(ctx, price)
+    }
+
+    pub fn initialize_market(ctx: Context<InitializeMarket>, config: MarketConfig) -> Result<()> {
+        instructions::initialize_market::initialize_market(ctx, config)
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        instructions::deposit::deposit(ctx, amount)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+        instructions::withdraw::withdraw(ctx, shares)
+    }
+
+    pub fn deposit_collateral(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+        instructions::deposit_collateral::deposit_collateral(ctx, amount)
+    }
+
+    pub fn withdraw_collateral(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
+        instructions::withdraw_collateral::withdraw_collateral(ctx, amount)
+    }
+
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        instructions::borrow::borrow(ctx, amount)
+    }
+
+    pub fn repay(ctx: Context<Repay>, amount: u64) -> Result<()> {
+        instructions::repay::repay(ctx, amount)
+    }
+
+    pub fn liquidate(ctx: Context<Liquidate>, amount: u64) -> Result<()> {
+        instructions::liquidate::liquidate(ctx, amount)
+    }
+}
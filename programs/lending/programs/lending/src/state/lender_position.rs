@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LenderPosition {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    // a claim on `Market::total_deposits`, which grows as interest accrues
+    pub shares: u64,
+    pub bump: u8,
+}
+
+impl LenderPosition {
+    pub const SEED_PREFIX: &'static [u8] = b"lender";
+}
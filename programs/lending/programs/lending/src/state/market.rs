@@ -0,0 +1,166 @@
+use super::mock_oracle::MockOracle;
+use super::obligation::Obligation;
+use crate::errors::LendingError;
+use anchor_lang::prelude::*;
+use common::{bps, math};
+
+// the basis point denominator, widened for the u128 math below
+const BPS: u128 = bps::BPS_DENOMINATOR as u128;
+pub const INDEX_ONE: u128 = 1_000_000_000_000;
+pub const SECONDS_PER_YEAR: u128 = 365 * 24 * 60 * 60;
+// at most half of an underwater debt can be liquidated at once
+pub const CLOSE_FACTOR_BPS: u16 = 5_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
+pub struct MarketConfig {
+    // how much can be borrowed against the value of the collateral
+    pub max_ltv_bps: u16,
+    // above this loan-to-value ratio, an obligation can be liquidated
+    pub liquidation_threshold_bps: u16,
+    // the discount on the collateral liquidators receive
+    pub liquidation_bonus_bps: u16,
+    // the yearly borrow rate at 0% utilization, growing by `slope_bps` up to 100%
+    pub base_rate_bps: u16,
+    pub slope_bps: u16,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    pub authority: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub loan_mint: Pubkey,
+    pub oracle: Pubkey,
+    pub config: MarketConfig,
+    // loan tokens owed to lenders, including accrued interest
+    pub total_deposits: u64,
+    pub total_shares: u64,
+    // loan tokens owed by borrowers, including accrued interest
+    pub total_borrows: u64,
+    pub borrow_index: u128,
+    pub last_update_ts: i64,
+    pub bump: u8,
+    pub collateral_vault_bump: u8,
+    pub loan_vault_bump: u8,
+}
+
+pub(crate) fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| error!(LendingError::MathOverflow))
+}
+
+impl Market {
+    pub const SEED_PREFIX: &'static [u8] = b"market";
+    pub const COLLATERAL_VAULT_SEED_PREFIX: &'static [u8] = b"collateral_vault";
+    pub const LOAN_VAULT_SEED_PREFIX: &'static [u8] = b"loan_vault";
+
+    pub fn validate(config: &MarketConfig) -> Result<()> {
+        require!(
+            config.max_ltv_bps < config.liquidation_threshold_bps
+                && config.liquidation_threshold_bps < bps::MAX_BPS,
+            LendingError::InvalidConfig
+        );
+        // liquidating must not take more collateral than there is
+        require!(
+            config.liquidation_threshold_bps as u128 * (BPS + config.liquidation_bonus_bps as u128)
+                <= BPS * BPS,
+            LendingError::InvalidConfig
+        );
+        Ok(())
+    }
+
+    pub fn utilization_bps(&self) -> u128 {
+        if self.total_deposits == 0 {
+            return 0;
+        }
+        self.total_borrows as u128 * BPS / self.total_deposits as u128
+    }
+
+    /// Yearly rate, rising linearly with utilization so that scarce liquidity gets expensive.
+    pub fn borrow_rate_bps(&self) -> u128 {
+        self.config.base_rate_bps as u128
+            + self.config.slope_bps as u128 * self.utilization_bps() / BPS
+    }
+
+    /// Charges interest for the time since the last interaction. Interest is
+    /// linear in between, and compounds every time anyone touches the market.
+    pub fn accrue(&mut self, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u128;
+        if elapsed == 0 {
+            return Ok(());
+        }
+
+        let growth = self.borrow_rate_bps() * elapsed * INDEX_ONE / (BPS * SECONDS_PER_YEAR);
+        let interest = to_u64(self.total_borrows as u128 * growth / INDEX_ONE)?;
+        let index_growth = self
+            .borrow_index
+            .checked_mul(growth)
+            .ok_or(LendingError::MathOverflow)?
+            / INDEX_ONE;
+        self.borrow_index = self
+            .borrow_index
+            .checked_add(index_growth)
+            .ok_or(LendingError::MathOverflow)?;
+        // everything borrowers pay ends up with the lenders
+        self.total_borrows = self
+            .total_borrows
+            .checked_add(interest)
+            .ok_or(LendingError::MathOverflow)?;
+        self.total_deposits = self
+            .total_deposits
+            .checked_add(interest)
+            .ok_or(LendingError::MathOverflow)?;
+        self.last_update_ts = now;
+        Ok(())
+    }
+
+    pub fn shares_for_deposit(&self, amount: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(amount);
+        }
+        math::mul_div(amount, self.total_shares, self.total_deposits)
+            .ok_or_else(|| error!(LendingError::MathOverflow))
+    }
+
+    pub fn amount_for_shares(&self, shares: u64) -> Result<u64> {
+        math::mul_div(shares, self.total_deposits, self.total_shares)
+            .ok_or_else(|| error!(LendingError::MathOverflow))
+    }
+
+    /// The collateral's worth in loan tokens.
+    pub fn collateral_value(collateral: u64, oracle: &MockOracle) -> u128 {
+        collateral as u128 * oracle.price as u128 / MockOracle::PRICE_SCALE
+    }
+
+    /// Whether the debt stays within the maximum loan-to-value ratio.
+    pub fn is_healthy(&self, obligation: &Obligation, oracle: &MockOracle) -> Result<bool> {
+        Ok(obligation.debt(self.borrow_index)? as u128 * BPS
+            <= Self::collateral_value(obligation.collateral, oracle)
+                * self.config.max_ltv_bps as u128)
+    }
+
+    pub fn is_liquidatable(&self, obligation: &Obligation, oracle: &MockOracle) -> Result<bool> {
+        Ok(obligation.debt(self.borrow_index)? as u128 * BPS
+            > Self::collateral_value(obligation.collateral, oracle)
+                * self.config.liquidation_threshold_bps as u128)
+    }
+
+    /// Caps a liquidator's repayment at the close factor and returns it
+    /// together with the collateral they receive for it.
+    pub fn liquidation_amounts(
+        &self,
+        obligation: &Obligation,
+        oracle: &MockOracle,
+        amount: u64,
+    ) -> Result<(u64, u64)> {
+        require_gt!(oracle.price, 0, LendingError::InvalidPrice);
+        let max_repay = bps::apply(obligation.debt(self.borrow_index)?, CLOSE_FACTOR_BPS)
+            .ok_or(LendingError::MathOverflow)?;
+        let repay = amount.min(max_repay);
+
+        let seized = repay as u128
+            * (BPS + self.config.liquidation_bonus_bps as u128)
+            * MockOracle::PRICE_SCALE
+            / (BPS * oracle.price as u128);
+        Ok((repay, to_u64(seized)?.min(obligation.collateral)))
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Stands in for a real price feed like Pyth or Switchboard, whose accounts
+/// would also have to be checked for staleness and confidence before use.
+#[account]
+#[derive(InitSpace)]
+pub struct MockOracle {
+    pub authority: Pubkey,
+    // loan token atoms per collateral token atom, scaled by `PRICE_SCALE`
+    pub price: u64,
+}
+
+impl MockOracle {
+    pub const PRICE_SCALE: u128 = 1_000_000;
+}
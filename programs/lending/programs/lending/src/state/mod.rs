@@ -0,0 +1,9 @@
+pub use lender_position::*;
+pub use market::*;
+pub use mock_oracle::*;
+pub use obligation::*;
+
+pub mod lender_position;
+pub mod market;
+pub mod mock_oracle;
+pub mod obligation;
@@ -0,0 +1,50 @@
+use super::market::*;
+use crate::errors::LendingError;
+use anchor_lang::prelude::*;
+
+/// A borrower's collateral and debt in a market.
+#[account]
+#[derive(InitSpace)]
+pub struct Obligation {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub collateral: u64,
+    // the debt divided by the market's borrow index at the time of borrowing,
+    // so that multiplying it by the current index gives the debt including interest
+    pub borrowed_scaled: u128,
+    pub bump: u8,
+}
+
+impl Obligation {
+    pub const SEED_PREFIX: &'static [u8] = b"obligation";
+
+    /// Rounds up, borrowers never owe less than they took.
+    pub fn debt(&self, borrow_index: u128) -> Result<u64> {
+        let debt = self
+            .borrowed_scaled
+            .checked_mul(borrow_index)
+            .ok_or(LendingError::MathOverflow)?;
+        to_u64(debt.div_ceil(INDEX_ONE))
+    }
+
+    pub fn add_debt(&mut self, amount: u64, borrow_index: u128) -> Result<()> {
+        self.borrowed_scaled = self
+            .borrowed_scaled
+            .checked_add((amount as u128 * INDEX_ONE).div_ceil(borrow_index))
+            .ok_or(LendingError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Pays off up to `amount` and returns how much was actually repaid.
+    pub fn repay(&mut self, amount: u64, borrow_index: u128) -> Result<u64> {
+        let debt = self.debt(borrow_index)?;
+        if amount >= debt {
+            self.borrowed_scaled = 0;
+            Ok(debt)
+        } else {
+            // less than the debt, so less than `borrowed_scaled` too
+            self.borrowed_scaled -= amount as u128 * INDEX_ONE / borrow_index;
+            Ok(amount)
+        }
+    }
+}
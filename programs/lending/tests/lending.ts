@@ -0,0 +1,280 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Harness, expectError, findAddress } from 'test-harness';
+import { Lending } from '../../../target/types/lending';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+// large amounts, so that interest becomes visible within seconds
+const UNIT = new BN(1_000_000_000_000);
+const INDEX_ONE = new BN(1_000_000_000_000);
+
+describe('lending', () => {
+  let harness: Harness;
+  let program: Program<Lending>;
+  let payer: anchor.web3.Keypair;
+
+  const oracle = anchor.web3.Keypair.generate();
+  let collateralMint: anchor.web3.PublicKey;
+  let loanMint: anchor.web3.PublicKey;
+  let market: anchor.web3.PublicKey;
+  let collateralVault: anchor.web3.PublicKey;
+  let loanVault: anchor.web3.PublicKey;
+
+  type User = {
+    keypair: anchor.web3.Keypair;
+    collateral: anchor.web3.PublicKey;
+    loan: anchor.web3.PublicKey;
+  };
+  let lender: User;
+  let borrower: User;
+  let liquidator: User;
+
+  async function balance(tokenAccount: anchor.web3.PublicKey) {
+    return new BN(await harness.tokenBalance(tokenAccount));
+  }
+
+  async function newUser(collateral: anchor.BN, loan: anchor.BN): Promise<User> {
+    const keypair = await harness.fundedKeypair();
+    return {
+      keypair,
+      collateral: await harness.createTokenAccount(collateralMint, keypair.publicKey, collateral),
+      loan: await harness.createTokenAccount(loanMint, keypair.publicKey, loan),
+    };
+  }
+
+  function obligation(user: User) {
+    return findAddress(
+      [Buffer.from('obligation'), market.toBuffer(), user.keypair.publicKey.toBuffer()],
+      program.programId
+    );
+  }
+
+  function lenderPosition(user: User) {
+    return findAddress(
+      [Buffer.from('lender'), market.toBuffer(), user.keypair.publicKey.toBuffer()],
+      program.programId
+    );
+  }
+
+  async function borrow(user: User, amount: anchor.BN) {
+    await program.methods
+      .borrow(amount)
+      .accounts({
+        market,
+        obligation: obligation(user),
+        oracle: oracle.publicKey,
+        loanVault,
+        ownerLoanAccount: user.loan,
+        owner: user.keypair.publicKey,
+      })
+      .signers([user.keypair])
+      .rpc();
+  }
+
+  async function liquidate(amount: anchor.BN) {
+    await program.methods
+      .liquidate(amount)
+      .accounts({
+        market,
+        obligation: obligation(borrower),
+        oracle: oracle.publicKey,
+        collateralVault,
+        loanVault,
+        liquidatorLoanAccount: liquidator.loan,
+        liquidatorCollateralAccount: liquidator.collateral,
+        liquidator: liquidator.keypair.publicKey,
+      })
+      .signers([liquidator.keypair])
+      .rpc();
+  }
+
+  async function withdraw(shares: anchor.BN) {
+    await program.methods
+      .withdraw(shares)
+      .accounts({
+        market,
+        position: lenderPosition(lender),
+        loanVault,
+        ownerLoanAccount: lender.loan,
+        owner: lender.keypair.publicKey,
+      })
+      .signers([lender.keypair])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Lending>('lending');
+    payer = harness.payer;
+
+    collateralMint = await harness.createMint(payer.publicKey, 9);
+    loanMint = await harness.createMint(payer.publicKey, 9);
+    market = findAddress([Buffer.from('market'), collateralMint.toBuffer(), loanMint.toBuffer()], program.programId);
+    collateralVault = findAddress([Buffer.from('collateral_vault'), market.toBuffer()], program.programId);
+    loanVault = findAddress([Buffer.from('loan_vault'), market.toBuffer()], program.programId);
+
+    lender = await newUser(new BN(0), UNIT.muln(2));
+    borrower = await newUser(UNIT, UNIT);
+    liquidator = await newUser(new BN(0), UNIT);
+  });
+
+  it('initialize market!', async () => {
+    // one collateral token is worth two loan tokens
+    await program.methods
+      .createOracle(new BN(2_000_000))
+      .accounts({ oracle: oracle.publicKey, authority: payer.publicKey })
+      .signers([oracle])
+      .rpc();
+
+    await expectError(
+      program.methods
+        .initializeMarket({
+          maxLtvBps: 8_000,
+          liquidationThresholdBps: 5_000,
+          liquidationBonusBps: 500,
+          baseRateBps: 200,
+          slopeBps: 60_000,
+        })
+        .accounts({
+          market,
+          collateralVault,
+          loanVault,
+          collateralMint,
+          loanMint,
+          oracle: oracle.publicKey,
+          authority: payer.publicKey,
+        })
+        .rpc(),
+      'InvalidConfig',
+      6000
+    );
+
+    // a very steep rate curve, so that interest shows up within seconds
+    await program.methods
+      .initializeMarket({
+        maxLtvBps: 5_000,
+        liquidationThresholdBps: 8_000,
+        liquidationBonusBps: 500,
+        baseRateBps: 200,
+        slopeBps: 60_000,
+      })
+      .accounts({
+        market,
+        collateralVault,
+        loanVault,
+        collateralMint,
+        loanMint,
+        oracle: oracle.publicKey,
+        authority: payer.publicKey,
+      })
+      .rpc();
+  });
+
+  it('lend and borrow!', async () => {
+    await program.methods
+      .deposit(UNIT.muln(2))
+      .accounts({
+        market,
+        position: lenderPosition(lender),
+        loanVault,
+        ownerLoanAccount: lender.loan,
+        owner: lender.keypair.publicKey,
+      })
+      .signers([lender.keypair])
+      .rpc();
+
+    await program.methods
+      .depositCollateral(UNIT)
+      .accounts({
+        market,
+        obligation: obligation(borrower),
+        collateralVault,
+        ownerCollateralAccount: borrower.collateral,
+        owner: borrower.keypair.publicKey,
+      })
+      .signers([borrower.keypair])
+      .rpc();
+
+    // the collateral is worth 2 units, half of that can be borrowed
+    await expectError(borrow(borrower, UNIT.addn(1)), 'Unhealthy', 6006);
+    await borrow(borrower, UNIT.muln(9).divn(10));
+    expect((await balance(borrower.loan)).eq(UNIT.muln(19).divn(10))).is.true;
+
+    await expectError(
+      program.methods
+        .withdrawCollateral(UNIT.divn(2))
+        .accounts({
+          market,
+          obligation: obligation(borrower),
+          oracle: oracle.publicKey,
+          collateralVault,
+          ownerCollateralAccount: borrower.collateral,
+          owner: borrower.keypair.publicKey,
+        })
+        .signers([borrower.keypair])
+        .rpc(),
+      'Unhealthy'
+    );
+  });
+
+  it('interest accrues with every interaction', async () => {
+    const before = await program.account.market.fetch(market);
+    await harness.warp(3);
+
+    await program.methods
+      .repay(new BN(1))
+      .accounts({
+        market,
+        obligation: obligation(borrower),
+        loanVault,
+        payerLoanAccount: borrower.loan,
+        payer: borrower.keypair.publicKey,
+      })
+      .signers([borrower.keypair])
+      .rpc();
+
+    const after = await program.account.market.fetch(market);
+    expect(after.borrowIndex.gt(INDEX_ONE)).is.true;
+    // borrowers pay exactly what lenders earn
+    const interest = after.totalBorrows.sub(before.totalBorrows).addn(1);
+    expect(interest.gtn(0)).is.true;
+    expect(after.totalDeposits.sub(before.totalDeposits).eq(interest)).is.true;
+  });
+
+  it('only underwater obligations can be liquidated', async () => {
+    await expectError(liquidate(UNIT), 'NotLiquidatable', 6007);
+
+    // the collateral halves in value, the debt is now at ~90% of it
+    await program.methods
+      .setPrice(new BN(1_000_000))
+      .accounts({ oracle: oracle.publicKey, authority: payer.publicKey })
+      .rpc();
+
+    const debtBefore = await program.account.obligation.fetch(obligation(borrower));
+    await liquidate(UNIT);
+
+    // capped at half of the debt, paid for with collateral at a 5% discount
+    const repaid = UNIT.sub(await balance(liquidator.loan));
+    const seized = await balance(liquidator.collateral);
+    expect(repaid.gt(UNIT.muln(45).divn(100))).is.true;
+    expect(repaid.lt(UNIT.muln(46).divn(100))).is.true;
+    expect(seized.eq(repaid.muln(105).divn(100))).is.true;
+
+    const obligationAfter = await program.account.obligation.fetch(obligation(borrower));
+    expect(obligationAfter.collateral.eq(debtBefore.collateral.sub(seized))).is.true;
+  });
+
+  it('lenders withdraw with interest, up to the available liquidity', async () => {
+    const position = await program.account.lenderPosition.fetch(lenderPosition(lender));
+    await expectError(withdraw(position.shares), 'InsufficientLiquidity', 6004);
+
+    await withdraw(position.shares.divn(2));
+    // half of the shares are worth more than half of the original deposit
+    expect((await balance(lender.loan)).gt(UNIT)).is.true;
+  });
+});
@@ -0,0 +1,53 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn create_pool(
+    ctx: Context<CreatePool>,
+    min_duration: i64,
+    max_duration: i64,
+    max_multiplier_bps: u16,
+    penalty_bps: u16,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.set_inner(Pool {
+        authority: ctx.accounts.authority.key(),
+        mint: ctx.accounts.mint.key(),
+        min_duration,
+        max_duration,
+        max_multiplier_bps,
+        penalty_bps,
+        total_weight: 0,
+        penalty_per_weight: 0,
+        undistributed: 0,
+        bump: ctx.bumps.pool,
+        vault_bump: ctx.bumps.vault,
+    });
+    pool.validate()
+}
+
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [Pool::SEED_PREFIX, authority.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        init,
+        payer = authority,
+        token::mint = mint,
+        token::authority = pool,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,76 @@
+use crate::errors::LockupError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn lock(ctx: Context<Lock>, id: u64, amount: u64, duration: i64) -> Result<()> {
+    require_gt!(amount, 0, LockupError::ZeroAmount);
+
+    let pool = &mut ctx.accounts.pool;
+    let weight = pool.weight(amount, duration)?;
+    // the lock only shares in penalties paid from now on, hence the snapshot before `add_weight`,
+    // which may hand it what was forfeited while the pool was empty
+    let penalty_per_weight_paid = pool.penalty_per_weight;
+    pool.add_weight(weight)?;
+
+    let start_ts = Clock::get()?.unix_timestamp;
+    ctx.accounts.lock_account.set_inner(LockAccount {
+        pool: pool.key(),
+        owner: ctx.accounts.owner.key(),
+        id,
+        amount,
+        weight,
+        start_ts,
+        unlock_ts: start_ts + duration,
+        penalty_per_weight_paid,
+        bump: ctx.bumps.lock_account,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct Lock<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + LockAccount::INIT_SPACE,
+        seeds = [
+            LockAccount::SEED_PREFIX,
+            pool.key().as_ref(),
+            owner.key().as_ref(),
+            id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub lock_account: Account<'info, LockAccount>,
+    #[account(
+        mut,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
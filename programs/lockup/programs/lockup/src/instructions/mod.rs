@@ -0,0 +1,7 @@
+pub use create_pool::*;
+pub use lock::*;
+pub use withdraw::*;
+
+pub mod create_pool;
+pub mod lock;
+pub mod withdraw;
@@ -0,0 +1,59 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+// Withdrawing before `unlock_ts` is allowed, at the cost of the penalty. It is shared
+// among the remaining lockers, so the leaver's own weight is removed first.
+pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let lock_account = &ctx.accounts.lock_account;
+
+    let earned = lock_account.penalties_earned(pool)?;
+    let penalty = lock_account.penalty(pool, Clock::get()?.unix_timestamp);
+    pool.total_weight -= lock_account.weight;
+    pool.distribute(penalty)?;
+
+    // cannot underflow, the penalty is at most the locked amount
+    let payout = lock_account.amount - penalty + earned;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[&pool.signer_seeds()],
+        ),
+        payout,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        close = owner,
+        has_one = pool,
+        has_one = owner,
+    )]
+    pub lock_account: Account<'info, LockAccount>,
+    #[account(
+        mut,
+        seeds = [Pool::VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump = pool.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
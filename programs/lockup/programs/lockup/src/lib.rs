@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("BvS9DQC2WnBddJ98L3iXsmU9ivbF6hdPopW8UTVWWjQa");
+
+#[program]
+pub mod lockup {
+    use super::*;
+
+    pub fn create_pool(
+        ctx: Context<CreatePool>,
+        min_duration: i64,
+        max_duration: i64,
+        max_multiplier_bps: u16,
+        penalty_bps: u16,
+    ) -> Result<()> {
+        instructions::create_pool::create_pool(
+            ctx,
+            min_duration,
+            max_duration,
+            max_multiplier_bps,
+            penalty_bps,
+        )
+    }
+
+    pub fn lock(ctx: Context<Lock>, id: u64, amount: u64, duration: i64) -> Result<()> {
+        instructions::lock::lock(ctx, id, amount, duration)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        instructions::withdraw::withdraw(ctx)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::errors::LockupError;
+use crate::state::pool::Pool;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct LockAccount {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub id: u64,
+    pub amount: u64,
+    pub weight: u128,
+    pub start_ts: i64,
+    pub unlock_ts: i64,
+    // the pool's `penalty_per_weight` when the lock was created, only later penalties are ours
+    pub penalty_per_weight_paid: u128,
+    pub bump: u8,
+}
+
+impl LockAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"lock";
+
+    /// This lock's share of the penalties paid by others since it was created.
+    pub fn penalties_earned(&self, pool: &Pool) -> Result<u64> {
+        let earned = (pool.penalty_per_weight - self.penalty_per_weight_paid)
+            .checked_mul(self.weight)
+            .ok_or(LockupError::MathOverflow)?
+            / Pool::PRECISION;
+        u64::try_from(earned).map_err(|_| error!(LockupError::MathOverflow))
+    }
+
+    /// What leaving at `now` costs: the full penalty right after locking, nothing once unlocked.
+    pub fn penalty(&self, pool: &Pool, now: i64) -> u64 {
+        let remaining = self.unlock_ts.saturating_sub(now).max(0) as u128;
+        let duration = (self.unlock_ts - self.start_ts) as u128;
+        // cannot overflow, `remaining <= duration` keeps every intermediate result below 2^128
+        (self.amount as u128 * remaining / duration * pool.penalty_bps as u128 / Pool::BPS) as u64
+    }
+}
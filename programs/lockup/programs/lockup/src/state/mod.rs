@@ -0,0 +1,5 @@
+pub use lock_account::*;
+pub use pool::*;
+
+pub mod lock_account;
+pub mod pool;
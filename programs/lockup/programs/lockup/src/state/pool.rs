@@ -0,0 +1,95 @@
+use crate::errors::LockupError;
+use anchor_lang::prelude::*;
+use common::bps;
+
+/// Locked tokens earn weight, the longer the lock the more weight per token. Whoever
+/// leaves early forfeits part of their tokens, which are shared among everybody still
+/// locked, in proportion to their weight.
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub min_duration: i64,
+    pub max_duration: i64,
+    // the multiplier of a lock for `max_duration`, one of 0 seconds would get 1x
+    pub max_multiplier_bps: u16,
+    // the share of a lock forfeited when leaving right away, it shrinks linearly to zero at unlock
+    pub penalty_bps: u16,
+    pub total_weight: u128,
+    // penalties a single unit of weight has received, scaled by `PRECISION`
+    pub penalty_per_weight: u128,
+    // penalties paid while nobody else was locked, they go to the next locker
+    pub undistributed: u64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Pool {
+    pub const SEED_PREFIX: &'static [u8] = b"pool";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+    pub const PRECISION: u128 = 1_000_000_000_000;
+    pub const BPS: u128 = bps::BPS_DENOMINATOR as u128;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            0 < self.min_duration && self.min_duration <= self.max_duration,
+            LockupError::InvalidDuration
+        );
+        require_gte!(
+            self.max_multiplier_bps as u128,
+            Self::BPS,
+            LockupError::InvalidMultiplier
+        );
+        require!(bps::is_share(self.penalty_bps), LockupError::InvalidPenalty);
+        Ok(())
+    }
+
+    /// The weight of `amount` tokens locked for `duration` seconds.
+    pub fn weight(&self, amount: u64, duration: i64) -> Result<u128> {
+        require!(
+            (self.min_duration..=self.max_duration).contains(&duration),
+            LockupError::InvalidDuration
+        );
+        // 1x plus the bonus, pro rata of `max_duration`
+        let bonus_bps = (self.max_multiplier_bps as u128 - Self::BPS) * duration as u128
+            / self.max_duration as u128;
+        Ok(amount as u128 * (Self::BPS + bonus_bps))
+    }
+
+    pub fn add_weight(&mut self, weight: u128) -> Result<()> {
+        self.total_weight = self
+            .total_weight
+            .checked_add(weight)
+            .ok_or(LockupError::MathOverflow)?;
+        // whatever was forfeited while the pool was empty, goes to whoever is here now
+        let undistributed = std::mem::take(&mut self.undistributed);
+        self.distribute(undistributed)
+    }
+
+    /// Shares `penalty` among the remaining weight. Whatever rounds down stays in the vault.
+    pub fn distribute(&mut self, penalty: u64) -> Result<()> {
+        if self.total_weight == 0 {
+            self.undistributed += penalty;
+            return Ok(());
+        }
+        let per_weight = (penalty as u128)
+            .checked_mul(Self::PRECISION)
+            .ok_or(LockupError::MathOverflow)?
+            / self.total_weight;
+        self.penalty_per_weight = self
+            .penalty_per_weight
+            .checked_add(per_weight)
+            .ok_or(LockupError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            Self::SEED_PREFIX,
+            self.authority.as_ref(),
+            self.mint.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
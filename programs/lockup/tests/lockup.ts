@@ -0,0 +1,129 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { Harness, expectError } from 'test-harness';
+import { Lockup } from '../../../target/types/lockup';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('lockup', () => {
+  let harness: Harness;
+  let program: Program<Lockup>;
+  let authority: Keypair;
+
+  const [alice, bob, carol] = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const tokenAccounts = new Map<Keypair, anchor.web3.PublicKey>();
+  let mint: anchor.web3.PublicKey;
+  let pool: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+
+  // locks between 2 and 8 seconds, 3x for the longest, half the tokens forfeited when leaving right away
+  const [minDuration, maxDuration, maxMultiplierBps, penaltyBps] = [2, 8, 30_000, 5_000];
+
+  function lockAddress(owner: Keypair, id: number) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('lock'), pool.toBuffer(), owner.publicKey.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+  }
+
+  async function balance(owner: Keypair) {
+    return harness.tokenBalance(tokenAccounts.get(owner));
+  }
+
+  async function lock(owner: Keypair, id: number, amount: number, duration: number) {
+    await program.methods
+      .lock(new BN(id), new BN(amount), new BN(duration))
+      .accounts({
+        pool,
+        lockAccount: lockAddress(owner, id),
+        vault,
+        ownerTokenAccount: tokenAccounts.get(owner),
+        owner: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+  }
+
+  // returns how many tokens came back
+  async function withdraw(owner: Keypair, id: number) {
+    const before = await balance(owner);
+    await program.methods
+      .withdraw()
+      .accounts({
+        pool,
+        lockAccount: lockAddress(owner, id),
+        vault,
+        ownerTokenAccount: tokenAccounts.get(owner),
+        owner: owner.publicKey,
+      })
+      .signers([owner])
+      .rpc();
+    return (await balance(owner)) - before;
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Lockup>('lockup');
+    authority = harness.payer;
+
+    mint = await harness.createMint(authority.publicKey);
+    for (const owner of [alice, bob, carol]) {
+      await harness.fund(owner.publicKey);
+      tokenAccounts.set(owner, await harness.createTokenAccount(mint, owner.publicKey, 10_000));
+    }
+    [pool] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('pool'), authority.publicKey.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+    [vault] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from('vault'), pool.toBuffer()], program.programId);
+
+    await program.methods
+      .createPool(new BN(minDuration), new BN(maxDuration), maxMultiplierBps, penaltyBps)
+      .accounts({ pool, vault, mint, authority: authority.publicKey })
+      .rpc();
+  });
+
+  it('early exits pay the ones who stay, by weight!', async () => {
+    await expectError(lock(alice, 0, 1_000, maxDuration + 1), 'InvalidDuration', 6000);
+    await expectError(lock(alice, 0, 0, maxDuration), 'ZeroAmount', 6003);
+
+    // 3x and 2x the weight of a plain token
+    await lock(alice, 0, 1_000, 8);
+    await lock(bob, 0, 1_000, 4);
+    const poolState = await program.account.pool.fetch(pool);
+    expect(poolState.totalWeight.toString()).to.equal(String(1_000 * 30_000 + 1_000 * 20_000));
+
+    // leaving a second into an 8 second lock forfeits 7/16 of it
+    await lock(carol, 0, 1_000, 8);
+    await harness.warp(1);
+    const penalty = 1_000 - (await withdraw(carol, 0));
+    expect(penalty).to.equal(437);
+
+    // once unlocked, the rest leave for free and split the penalty 3:2
+    await harness.warp(8);
+    const aliceShare = (await withdraw(alice, 0)) - 1_000;
+    const bobShare = (await withdraw(bob, 0)) - 1_000;
+    expect(aliceShare).to.be.within(Math.floor((penalty * 3) / 5) - 1, Math.floor((penalty * 3) / 5));
+    expect(bobShare).to.be.within(Math.floor((penalty * 2) / 5) - 1, Math.floor((penalty * 2) / 5));
+
+    // only rounding dust is left behind
+    expect(await harness.tokenBalance(vault)).to.equal(penalty - aliceShare - bobShare);
+    expect(penalty - aliceShare - bobShare).to.be.at.most(2);
+  });
+
+  it('with nobody left to share it, a penalty goes to the next locker', async () => {
+    await lock(carol, 1, 1_000, 8);
+    const penalty = 1_000 - (await withdraw(carol, 1));
+    expect((await program.account.pool.fetch(pool)).undistributed.toNumber()).to.equal(penalty);
+
+    await lock(alice, 1, 1_000, 2);
+    expect((await program.account.pool.fetch(pool)).undistributed.toNumber()).to.equal(0);
+    await harness.warp(2);
+    expect(await withdraw(alice, 1)).to.be.within(1_000 + penalty - 1, 1_000 + penalty);
+  });
+});
@@ -0,0 +1,59 @@
+use crate::errors::LoyaltyError;
+use crate::state::merchant::*;
+use crate::state::points_account::*;
+use crate::state::promotion::*;
+use anchor_lang::prelude::*;
+
+// The merchant pays for the customer's points account the first time, so customers
+// don't need any SOL to take part.
+pub fn award_points(ctx: Context<AwardPoints>, points: u64) -> Result<()> {
+    require_gt!(points, 0, LoyaltyError::ZeroPoints);
+    let points = match &ctx.accounts.promotion {
+        Some(promotion) => promotion.apply(points, Clock::get()?.unix_timestamp)?,
+        None => points,
+    };
+
+    let points_account = &mut ctx.accounts.points_account;
+    // the address already pins both down, setting them again on later awards changes nothing
+    points_account.merchant = ctx.accounts.merchant.key();
+    points_account.customer = ctx.accounts.customer.key();
+    points_account.bump = ctx.bumps.points_account;
+    points_account.earn(points)?;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.points_issued = merchant
+        .points_issued
+        .checked_add(points)
+        .ok_or(LoyaltyError::MathOverflow)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AwardPoints<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [Merchant::SEED_PREFIX, authority.key().as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PointsAccount::INIT_SPACE,
+        seeds = [PointsAccount::SEED_PREFIX, merchant.key().as_ref(), customer.key().as_ref()],
+        bump
+    )]
+    pub points_account: Account<'info, PointsAccount>,
+    // only this merchant's promotions, another merchant's would fail the seeds check
+    #[account(
+        seeds = [Promotion::SEED_PREFIX, merchant.key().as_ref(), promotion.id.to_le_bytes().as_ref()],
+        bump = promotion.bump
+    )]
+    pub promotion: Option<Account<'info, Promotion>>,
+    /// CHECK: only used as a seed, customers don't sign to receive points
+    pub customer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
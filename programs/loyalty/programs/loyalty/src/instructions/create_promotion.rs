@@ -0,0 +1,44 @@
+use crate::state::merchant::*;
+use crate::state::promotion::*;
+use anchor_lang::prelude::*;
+
+pub fn create_promotion(
+    ctx: Context<CreatePromotion>,
+    id: u64,
+    multiplier_bps: u16,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    let promotion = &mut ctx.accounts.promotion;
+    promotion.set_inner(Promotion {
+        merchant: ctx.accounts.merchant.key(),
+        id,
+        multiplier_bps,
+        start_ts,
+        end_ts,
+        bump: ctx.bumps.promotion,
+    });
+    promotion.validate()
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreatePromotion<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [Merchant::SEED_PREFIX, authority.key().as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Promotion::INIT_SPACE,
+        seeds = [Promotion::SEED_PREFIX, merchant.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub promotion: Account<'info, Promotion>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
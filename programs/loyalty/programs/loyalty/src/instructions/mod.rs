@@ -0,0 +1,11 @@
+pub use award_points::*;
+pub use create_promotion::*;
+pub use redeem_points::*;
+pub use register_merchant::*;
+pub use use_coupon::*;
+
+pub mod award_points;
+pub mod create_promotion;
+pub mod redeem_points;
+pub mod register_merchant;
+pub mod use_coupon;
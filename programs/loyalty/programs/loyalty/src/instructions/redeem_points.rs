@@ -0,0 +1,55 @@
+use crate::state::coupon::*;
+use crate::state::merchant::*;
+use crate::state::points_account::*;
+use anchor_lang::prelude::*;
+
+pub fn redeem_points(ctx: Context<RedeemPoints>, points: u64) -> Result<()> {
+    let points_account = &mut ctx.accounts.points_account;
+    points_account.spend(points)?;
+
+    ctx.accounts.coupon.set_inner(Coupon {
+        merchant: points_account.merchant,
+        customer: points_account.customer,
+        index: points_account.coupon_count,
+        points,
+        created_ts: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.coupon,
+    });
+    points_account.coupon_count += 1;
+
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.points_redeemed += points;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    #[account(
+        mut,
+        seeds = [Merchant::SEED_PREFIX, merchant.authority.as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+    #[account(
+        mut,
+        seeds = [PointsAccount::SEED_PREFIX, merchant.key().as_ref(), customer.key().as_ref()],
+        bump = points_account.bump
+    )]
+    pub points_account: Account<'info, PointsAccount>,
+    #[account(
+        init,
+        payer = customer,
+        space = 8 + Coupon::INIT_SPACE,
+        seeds = [
+            Coupon::SEED_PREFIX,
+            merchant.key().as_ref(),
+            customer.key().as_ref(),
+            points_account.coupon_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+    #[account(mut)]
+    pub customer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
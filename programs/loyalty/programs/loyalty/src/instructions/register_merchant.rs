@@ -0,0 +1,26 @@
+use crate::state::merchant::*;
+use anchor_lang::prelude::*;
+
+pub fn register_merchant(ctx: Context<RegisterMerchant>, name: String) -> Result<()> {
+    let merchant = &mut ctx.accounts.merchant;
+    merchant.authority = ctx.accounts.authority.key();
+    merchant.points_issued = 0;
+    merchant.points_redeemed = 0;
+    merchant.bump = ctx.bumps.merchant;
+    merchant.set_name(name)
+}
+
+#[derive(Accounts)]
+pub struct RegisterMerchant<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Merchant::INIT_SPACE,
+        seeds = [Merchant::SEED_PREFIX, authority.key().as_ref()],
+        bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,37 @@
+use crate::state::coupon::*;
+use crate::state::merchant::*;
+use anchor_lang::prelude::*;
+
+// Called by the merchant at checkout. Closing the coupon makes it impossible to use twice,
+// and gives the customer their rent back.
+pub fn use_coupon(_ctx: Context<UseCoupon>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UseCoupon<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [Merchant::SEED_PREFIX, authority.key().as_ref()],
+        bump = merchant.bump
+    )]
+    pub merchant: Account<'info, Merchant>,
+    #[account(
+        mut,
+        close = customer,
+        has_one = merchant,
+        has_one = customer,
+        seeds = [
+            Coupon::SEED_PREFIX,
+            merchant.key().as_ref(),
+            customer.key().as_ref(),
+            coupon.index.to_le_bytes().as_ref()
+        ],
+        bump = coupon.bump
+    )]
+    pub coupon: Account<'info, Coupon>,
+    /// CHECK: only receives the rent of the closed coupon, and is checked against `coupon.customer`
+    #[account(mut)]
+    pub customer: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+}
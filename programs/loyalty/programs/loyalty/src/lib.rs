@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("8s8ncfKMzuQDHJDCoQzFu1QzZpHuYR88PHC7BndSnq24");
+
+#[program]
+pub mod loyalty {
+    use super::*;
+
+    pub fn register_merchant(ctx: Context<RegisterMerchant>, name: String) -> Result<()> {
+        instructions::register_merchant::register_merchant(ctx, name)
+    }
+
+    pub fn create_promotion(
+        ctx: Context<CreatePromotion>,
+        id: u64,
+        multiplier_bps: u16,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::create_promotion::create_promotion(ctx, id, multiplier_bps, start_ts, end_ts)
+    }
+
+    pub fn award_points(ctx: Context<AwardPoints>, points: u64) -> Result<()> {
+        instructions::award_points::award_points(ctx, points)
+    }
+
+    pub fn redeem_points(ctx: Context<RedeemPoints>, points: u64) -> Result<()> {
+        instructions::redeem_points::redeem_points(ctx, points)
+    }
+
+    pub fn use_coupon(ctx: Context<UseCoupon>) -> Result<()> {
+        instructions::use_coupon::use_coupon(ctx)
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Points turned into something the merchant honours at checkout, at
+/// `[merchant, customer, index]`. Using it closes it.
+#[account]
+#[derive(InitSpace)]
+pub struct Coupon {
+    pub merchant: Pubkey,
+    pub customer: Pubkey,
+    pub index: u64,
+    // what the merchant makes of them is up to the merchant, a discount, a free coffee
+    pub points: u64,
+    pub created_ts: i64,
+    pub bump: u8,
+}
+
+impl Coupon {
+    pub const SEED_PREFIX: &'static [u8] = b"coupon";
+}
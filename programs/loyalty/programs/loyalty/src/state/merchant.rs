@@ -0,0 +1,27 @@
+use crate::errors::LoyaltyError;
+use anchor_lang::prelude::*;
+
+pub const MAX_NAME_LEN: usize = 32;
+
+/// The root of a merchant's namespace: every other account of this program has the
+/// merchant's address in its seeds, so merchants can never touch each other's points.
+#[account]
+#[derive(InitSpace)]
+pub struct Merchant {
+    pub authority: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
+    pub name: String,
+    pub points_issued: u64,
+    pub points_redeemed: u64,
+    pub bump: u8,
+}
+
+impl Merchant {
+    pub const SEED_PREFIX: &'static [u8] = b"merchant";
+
+    pub fn set_name(&mut self, name: String) -> Result<()> {
+        require_gte!(MAX_NAME_LEN, name.len(), LoyaltyError::NameTooLong);
+        self.name = name;
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+pub use coupon::*;
+pub use merchant::*;
+pub use points_account::*;
+pub use promotion::*;
+
+pub mod coupon;
+pub mod merchant;
+pub mod points_account;
+pub mod promotion;
@@ -0,0 +1,41 @@
+use crate::errors::LoyaltyError;
+use anchor_lang::prelude::*;
+
+/// A customer's points with one merchant, at `[merchant, customer]`.
+///
+/// Points are a plain number in an account only this program can write to, rather than
+/// tokens, so there is simply no way to transfer them.
+#[account]
+#[derive(InitSpace)]
+pub struct PointsAccount {
+    pub merchant: Pubkey,
+    pub customer: Pubkey,
+    pub balance: u64,
+    pub lifetime_earned: u64,
+    // index of the next coupon, part of its address
+    pub coupon_count: u64,
+    pub bump: u8,
+}
+
+impl PointsAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"points";
+
+    pub fn earn(&mut self, points: u64) -> Result<()> {
+        self.balance = self
+            .balance
+            .checked_add(points)
+            .ok_or(LoyaltyError::MathOverflow)?;
+        self.lifetime_earned = self
+            .lifetime_earned
+            .checked_add(points)
+            .ok_or(LoyaltyError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn spend(&mut self, points: u64) -> Result<()> {
+        require_gt!(points, 0, LoyaltyError::ZeroPoints);
+        require_gte!(self.balance, points, LoyaltyError::InsufficientPoints);
+        self.balance -= points;
+        Ok(())
+    }
+}
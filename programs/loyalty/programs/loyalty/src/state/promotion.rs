@@ -0,0 +1,37 @@
+use crate::errors::LoyaltyError;
+use anchor_lang::prelude::*;
+use common::bps;
+use common::time::{self, Phase};
+
+/// Multiplies the points awarded between `start_ts` and `end_ts`.
+#[account]
+#[derive(InitSpace)]
+pub struct Promotion {
+    pub merchant: Pubkey,
+    pub id: u64,
+    pub multiplier_bps: u16,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub bump: u8,
+}
+
+impl Promotion {
+    pub const SEED_PREFIX: &'static [u8] = b"promotion";
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.multiplier_bps > bps::MAX_BPS,
+            LoyaltyError::InvalidPromotion
+        );
+        require_gt!(self.end_ts, self.start_ts, LoyaltyError::InvalidPromotion);
+        Ok(())
+    }
+
+    pub fn apply(&self, points: u64, now: i64) -> Result<u64> {
+        require!(
+            time::phase(now, self.start_ts, self.end_ts) == Phase::Active,
+            LoyaltyError::PromotionNotActive
+        );
+        bps::apply(points, self.multiplier_bps).ok_or_else(|| error!(LoyaltyError::MathOverflow))
+    }
+}
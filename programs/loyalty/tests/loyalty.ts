@@ -0,0 +1,167 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { Harness, expectError, findAddress } from 'test-harness';
+import { Loyalty } from '../../../target/types/loyalty';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('loyalty', () => {
+  let harness: Harness;
+  let program: Program<Loyalty>;
+
+  const coffeeShop = Keypair.generate();
+  const bakery = Keypair.generate();
+  const customer = Keypair.generate();
+
+  const le = (n: number) => new BN(n).toArrayLike(Buffer, 'le', 8);
+  const merchantAddress = (authority: Keypair) => findAddress(
+    [Buffer.from('merchant'), authority.publicKey.toBuffer()],
+    program.programId
+  );
+  const pointsAddress = (authority: Keypair) =>
+    findAddress(
+      [Buffer.from('points'), merchantAddress(authority).toBuffer(), customer.publicKey.toBuffer()],
+      program.programId
+    );
+  const promotionAddress = (authority: Keypair, id: number) =>
+    findAddress([Buffer.from('promotion'), merchantAddress(authority).toBuffer(), le(id)], program.programId);
+  const couponAddress = (authority: Keypair, index: number) =>
+    findAddress(
+      [Buffer.from('coupon'), merchantAddress(authority).toBuffer(), customer.publicKey.toBuffer(), le(index)],
+      program.programId
+    );
+
+  async function createPromotion(authority: Keypair, id: number, multiplierBps: number, startTs: number, endTs: number) {
+    await program.methods
+      .createPromotion(new BN(id), multiplierBps, new BN(startTs), new BN(endTs))
+      .accounts({
+        merchant: merchantAddress(authority),
+        promotion: promotionAddress(authority, id),
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  async function awardPoints(authority: Keypair, points: number, promotion: PublicKey | null = null) {
+    await program.methods
+      .awardPoints(new BN(points))
+      .accounts({
+        merchant: merchantAddress(authority),
+        pointsAccount: pointsAddress(authority),
+        promotion,
+        customer: customer.publicKey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  async function balance(authority: Keypair) {
+    return (await program.account.pointsAccount.fetch(pointsAddress(authority))).balance.toNumber();
+  }
+
+  async function redeemPoints(authority: Keypair, points: number) {
+    const { couponCount } = await program.account.pointsAccount.fetch(pointsAddress(authority));
+    const coupon = couponAddress(authority, couponCount.toNumber());
+    await program.methods
+      .redeemPoints(new BN(points))
+      .accounts({
+        merchant: merchantAddress(authority),
+        pointsAccount: pointsAddress(authority),
+        coupon,
+        customer: customer.publicKey,
+      })
+      .signers([customer])
+      .rpc();
+    return coupon;
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Loyalty>('loyalty');
+    for (const keypair of [coffeeShop, bakery, customer]) {
+      await harness.fund(keypair.publicKey);
+    }
+    for (const [authority, name] of [
+      [coffeeShop, 'coffee shop'],
+      [bakery, 'bakery'],
+    ] as const) {
+      await program.methods
+        .registerMerchant(name)
+        .accounts({ merchant: merchantAddress(authority), authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+    }
+  });
+
+  it('earn points and turn them into a coupon!', async () => {
+    await expectError(awardPoints(coffeeShop, 0), 'ZeroPoints', 6003);
+    await awardPoints(coffeeShop, 100);
+    await awardPoints(coffeeShop, 50);
+    expect(await balance(coffeeShop)).to.equal(150);
+
+    await expectError(redeemPoints(coffeeShop, 200), 'InsufficientPoints', 6004);
+    const coupon = await redeemPoints(coffeeShop, 120);
+    expect(await balance(coffeeShop)).to.equal(30);
+    const couponState = await program.account.coupon.fetch(coupon);
+    expect(couponState.points.toNumber()).to.equal(120);
+
+    const useCoupon = () =>
+      program.methods
+        .useCoupon()
+        .accounts({
+          merchant: merchantAddress(coffeeShop),
+          coupon,
+          customer: customer.publicKey,
+          authority: coffeeShop.publicKey,
+        })
+        .signers([coffeeShop])
+        .rpc();
+    await useCoupon();
+    expect(await harness.account(coupon)).to.be.null;
+    // a used coupon is gone, it can't be used again
+    try {
+      await useCoupon();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      expect((_err as AnchorError).error.errorCode.code).to.equal('AccountNotInitialized');
+    }
+
+    const merchantState = await program.account.merchant.fetch(merchantAddress(coffeeShop));
+    expect(merchantState.pointsIssued.toNumber()).to.equal(150);
+    expect(merchantState.pointsRedeemed.toNumber()).to.equal(120);
+  });
+
+  it('double points, for a limited time', async () => {
+    const time = await harness.now();
+    await expectError(createPromotion(coffeeShop, 0, 10_000, time, time + 60), 'InvalidPromotion', 6001);
+    await createPromotion(coffeeShop, 0, 20_000, time - 10, time + 60);
+    await createPromotion(coffeeShop, 1, 30_000, time - 100, time - 50);
+
+    await awardPoints(coffeeShop, 10, promotionAddress(coffeeShop, 0));
+    expect(await balance(coffeeShop)).to.equal(50);
+    await expectError(awardPoints(coffeeShop, 10, promotionAddress(coffeeShop, 1)), 'PromotionNotActive', 6002);
+  });
+
+  it('every merchant has its own namespace', async () => {
+    await awardPoints(bakery, 7);
+    expect(await balance(bakery)).to.equal(7);
+    expect(await balance(coffeeShop)).to.equal(50);
+
+    // the bakery can't run the coffee shop's promotion
+    try {
+      await awardPoints(bakery, 10, promotionAddress(coffeeShop, 0));
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      expect((_err as AnchorError).error.errorCode.code).to.equal('ConstraintSeeds');
+    }
+  });
+});
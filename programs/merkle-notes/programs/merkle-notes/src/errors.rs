@@ -0,0 +1,8 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum MerkleNotesError {
+    InvalidDepth,
+    TreeFull,
+    InvalidProof,
+}
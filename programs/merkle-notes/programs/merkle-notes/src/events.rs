@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Everything needed to rebuild the leaves off-chain, since the tree account only keeps
+/// their roots. Indexers pick it up from the transaction logs.
+#[event]
+pub struct NoteAppended {
+    pub tree: Pubkey,
+    pub index: u32,
+    pub author: Pubkey,
+    pub note_hash: [u8; 32],
+    pub leaf: [u8; 32],
+}
@@ -0,0 +1,28 @@
+use crate::events::NoteAppended;
+use crate::merkle;
+use crate::state::note_tree::*;
+use anchor_lang::prelude::*;
+
+// Anyone may append. Binding the author into the leaf means a note can only ever be
+// proven as written by whoever signed for it.
+pub fn append_note(ctx: Context<AppendNote>, note_hash: [u8; 32]) -> Result<()> {
+    let author = ctx.accounts.author.key();
+    let leaf = merkle::hash_leaf(&[author.as_ref(), &note_hash]);
+    let index = ctx.accounts.tree.append(leaf)?;
+
+    emit!(NoteAppended {
+        tree: ctx.accounts.tree.key(),
+        index,
+        author,
+        note_hash,
+        leaf,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AppendNote<'info> {
+    #[account(mut)]
+    pub tree: Account<'info, NoteTree>,
+    pub author: Signer<'info>,
+}
@@ -0,0 +1,24 @@
+use crate::state::note_tree::*;
+use anchor_lang::prelude::*;
+
+pub fn create_tree(ctx: Context<CreateTree>, id: u64, depth: u8) -> Result<()> {
+    ctx.accounts
+        .tree
+        .init(ctx.accounts.creator.key(), id, depth, ctx.bumps.tree)
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateTree<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + NoteTree::INIT_SPACE,
+        seeds = [NoteTree::SEED_PREFIX, creator.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tree: Account<'info, NoteTree>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
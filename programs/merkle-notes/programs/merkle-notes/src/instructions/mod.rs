@@ -0,0 +1,7 @@
+pub use append_note::*;
+pub use create_tree::*;
+pub use verify_note::*;
+
+pub mod append_note;
+pub mod create_tree;
+pub mod verify_note;
@@ -0,0 +1,21 @@
+use crate::merkle;
+use crate::state::note_tree::*;
+use anchor_lang::prelude::*;
+
+// Fails unless `author` appended `note_hash` at `index`. Other programs can CPI into this
+// to gate their own instructions on a note, without the note ever being stored on-chain.
+pub fn verify_note(
+    ctx: Context<VerifyNote>,
+    author: Pubkey,
+    note_hash: [u8; 32],
+    index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let leaf = merkle::hash_leaf(&[author.as_ref(), &note_hash]);
+    ctx.accounts.tree.verify(leaf, index, &proof)
+}
+
+#[derive(Accounts)]
+pub struct VerifyNote<'info> {
+    pub tree: Account<'info, NoteTree>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod merkle;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("4XgqFbzua4CMju12z1F5k8RKifwBQ9YSbxjfDdywGzSe");
+
+#[program]
+pub mod merkle_notes {
+    use super::*;
+
+    pub fn create_tree(ctx: Context<CreateTree>, id: u64, depth: u8) -> Result<()> {
+        instructions::create_tree::create_tree(ctx, id, depth)
+    }
+
+    pub fn append_note(ctx: Context<AppendNote>, note_hash: [u8; 32]) -> Result<()> {
+        instructions::append_note::append_note(ctx, note_hash)
+    }
+
+    pub fn verify_note(
+        ctx: Context<VerifyNote>,
+        author: Pubkey,
+        note_hash: [u8; 32],
+        index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::verify_note::verify_note(ctx, author, note_hash, index, proof)
+    }
+}
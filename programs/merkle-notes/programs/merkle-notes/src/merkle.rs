@@ -0,0 +1,236 @@
+//! An append-only Merkle tree that only keeps its rightmost path on-chain.
+//!
+//! A full tree of depth `d` has `2^d` leaves, far too many to store in an account. But
+//! appending only ever touches the rightmost path, so for every level it's enough to
+//! remember the last left-hand node (`filled_subtrees`). Everything to the right of the
+//! next leaf is still empty, and the hash of an empty subtree of a given height is a
+//! constant (`zero_hash`).
+//!
+//! Leaves and inner nodes are hashed with different prefixes. Otherwise, the
+//! concatenation of two inner nodes could be passed off as a 64-byte leaf.
+
+use crate::errors::MerkleNotesError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+pub const MAX_DEPTH: usize = 20;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+pub type Node = [u8; 32];
+
+pub fn hash_leaf(data: &[&[u8]]) -> Node {
+    let mut parts = vec![LEAF_PREFIX];
+    parts.extend_from_slice(data);
+    hashv(&parts).to_bytes()
+}
+
+pub fn hash_pair(left: &Node, right: &Node) -> Node {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// The root of an empty subtree of the given height.
+pub fn zero_hash(height: usize) -> Node {
+    let mut node = Node::default();
+    for _ in 0..height {
+        node = hash_pair(&node, &node);
+    }
+    node
+}
+
+/// Recomputes the root from a leaf, its index and the siblings on its path, bottom up.
+pub fn compute_root(leaf: Node, index: u32, proof: &[Node]) -> Result<Node> {
+    require_gte!(MAX_DEPTH, proof.len(), MerkleNotesError::InvalidProof);
+    // an index that doesn't fit into the tree would otherwise wrap around to another leaf
+    require_gt!(
+        1u64 << proof.len(),
+        index as u64,
+        MerkleNotesError::InvalidProof
+    );
+
+    let mut node = leaf;
+    for (height, sibling) in proof.iter().enumerate() {
+        node = if index >> height & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+    }
+    Ok(node)
+}
+
+pub fn verify(root: &Node, leaf: Node, index: u32, proof: &[Node]) -> bool {
+    compute_root(leaf, index, proof).is_ok_and(|computed| &computed == root)
+}
+
+/// Appends `leaf` at `index`, updating `filled_subtrees`, and returns the new root.
+///
+/// `filled_subtrees[h]` is the last left-hand node at height `h`. It is only read while
+/// it still belongs to the path of the next leaf, so stale entries are never used.
+pub fn append(filled_subtrees: &mut [Node], leaf: Node, index: u32) -> Result<Node> {
+    let depth = filled_subtrees.len();
+    require_gt!(1u64 << depth, index as u64, MerkleNotesError::TreeFull);
+
+    let mut node = leaf;
+    let mut zero = Node::default();
+    for (height, filled) in filled_subtrees.iter_mut().enumerate() {
+        node = if index >> height & 1 == 0 {
+            *filled = node;
+            hash_pair(&node, &zero)
+        } else {
+            hash_pair(filled, &node)
+        };
+        zero = hash_pair(&zero, &zero);
+    }
+    Ok(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(i: u32) -> Node {
+        hash_leaf(&[&i.to_le_bytes()])
+    }
+
+    /// Every level of a tree with `count` leaves, padded with empty leaves, bottom up.
+    fn full_tree(depth: usize, count: u32) -> Vec<Vec<Node>> {
+        let mut level: Vec<Node> = (0..1u32 << depth)
+            .map(|i| if i < count { leaf(i) } else { Node::default() })
+            .collect();
+        let mut levels = vec![level.clone()];
+        while level.len() > 1 {
+            level = level.chunks(2).map(|p| hash_pair(&p[0], &p[1])).collect();
+            levels.push(level.clone());
+        }
+        levels
+    }
+
+    fn proof(levels: &[Vec<Node>], index: u32) -> Vec<Node> {
+        levels[..levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(height, level)| level[(index as usize >> height) ^ 1])
+            .collect()
+    }
+
+    #[test]
+    fn zero_hashes_are_empty_trees() {
+        for depth in 0..=5 {
+            assert_eq!(full_tree(depth, 0)[depth][0], zero_hash(depth));
+        }
+    }
+
+    #[test]
+    fn leaves_and_nodes_are_domain_separated() {
+        let (a, b) = (leaf(0), leaf(1));
+        assert_ne!(hash_leaf(&[&a, &b]), hash_pair(&a, &b));
+    }
+
+    #[test]
+    fn append_matches_full_tree() {
+        for depth in 0..=5 {
+            let mut filled = vec![Node::default(); depth];
+            for i in 0..1u32 << depth {
+                let root = append(&mut filled, leaf(i), i).unwrap();
+                assert_eq!(
+                    root,
+                    full_tree(depth, i + 1)[depth][0],
+                    "depth {depth}, leaf {i}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn append_to_full_tree_fails() {
+        for depth in 0..=5 {
+            let mut filled = vec![Node::default(); depth];
+            for i in 0..1u32 << depth {
+                append(&mut filled, leaf(i), i).unwrap();
+            }
+            assert!(append(&mut filled, leaf(0), 1 << depth).is_err());
+        }
+    }
+
+    #[test]
+    fn every_leaf_verifies_at_every_size() {
+        for depth in 0..=4 {
+            for count in 1..=1u32 << depth {
+                let levels = full_tree(depth, count);
+                let root = levels[depth][0];
+                for i in 0..count {
+                    assert!(verify(&root, leaf(i), i, &proof(&levels, i)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrong_leaf_or_index_fails() {
+        let depth = 4;
+        let levels = full_tree(depth, 1 << depth);
+        let root = levels[depth][0];
+        for i in 0..1u32 << depth {
+            let proof = proof(&levels, i);
+            for j in 0..1u32 << depth {
+                if j != i {
+                    assert!(!verify(&root, leaf(j), i, &proof));
+                    assert!(!verify(&root, leaf(i), j, &proof));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tampered_proof_fails() {
+        let depth = 4;
+        let levels = full_tree(depth, 11);
+        let root = levels[depth][0];
+        for i in 0..11 {
+            let proof = proof(&levels, i);
+            for height in 0..depth {
+                for bit in [0, 7] {
+                    let mut tampered = proof.clone();
+                    tampered[height][31] ^= 1 << bit;
+                    assert!(!verify(&root, leaf(i), i, &tampered));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wrong_proof_length_fails() {
+        let depth = 4;
+        let levels = full_tree(depth, 16);
+        let root = levels[depth][0];
+        let proof = proof(&levels, 5);
+        assert!(!verify(&root, leaf(5), 5, &proof[..depth - 1]));
+        let mut longer = proof.clone();
+        longer.push(Node::default());
+        assert!(!verify(&root, leaf(5), 5, &longer));
+    }
+
+    #[test]
+    fn index_outside_the_tree_fails() {
+        // 5 + 16 shares its lower four bits with 5, and must not alias it
+        let depth = 4;
+        let levels = full_tree(depth, 16);
+        let root = levels[depth][0];
+        assert!(!verify(&root, leaf(5), 5 + 16, &proof(&levels, 5)));
+        assert!(compute_root(leaf(0), 0, &vec![Node::default(); MAX_DEPTH + 1]).is_err());
+    }
+
+    #[test]
+    fn inner_node_is_not_a_leaf() {
+        // without the prefixes, a leaf over the two children of an inner node would
+        // hash to that node, and verify with the upper part of the node's proof
+        let depth = 3;
+        let levels = full_tree(depth, 8);
+        let root = levels[depth][0];
+        let proof = proof(&levels, 2);
+        let forged = hash_leaf(&[&levels[0][2], &levels[0][3]]);
+        assert!(!verify(&root, forged, 1, &proof[1..]));
+    }
+}
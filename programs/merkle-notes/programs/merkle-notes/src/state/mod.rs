@@ -0,0 +1,3 @@
+pub use note_tree::*;
+
+pub mod note_tree;
@@ -0,0 +1,134 @@
+use crate::errors::MerkleNotesError;
+use crate::merkle::{self, MAX_DEPTH};
+use anchor_lang::prelude::*;
+
+pub const ROOT_HISTORY_SIZE: usize = 16;
+
+/// A Merkle tree of notes of which only the roots and the rightmost path live on-chain.
+///
+/// A proof is built against the root at the time, but other users may keep appending
+/// in the meantime. The last `ROOT_HISTORY_SIZE` roots are therefore all accepted.
+#[account]
+#[derive(InitSpace)]
+pub struct NoteTree {
+    pub creator: Pubkey,
+    pub id: u64,
+    pub depth: u8,
+    pub next_index: u32,
+    // a ring buffer, `roots[next_index % ROOT_HISTORY_SIZE]` is the oldest root
+    pub roots: [[u8; 32]; ROOT_HISTORY_SIZE],
+    pub filled_subtrees: [[u8; 32]; MAX_DEPTH],
+    pub bump: u8,
+}
+
+impl NoteTree {
+    pub const SEED_PREFIX: &'static [u8] = b"note_tree";
+
+    pub fn init(&mut self, creator: Pubkey, id: u64, depth: u8, bump: u8) -> Result<()> {
+        require!(
+            (1..=MAX_DEPTH).contains(&(depth as usize)),
+            MerkleNotesError::InvalidDepth
+        );
+        self.creator = creator;
+        self.id = id;
+        self.depth = depth;
+        self.next_index = 0;
+        self.roots = [merkle::zero_hash(depth as usize); ROOT_HISTORY_SIZE];
+        self.filled_subtrees = Default::default();
+        self.bump = bump;
+        Ok(())
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.roots[(self.next_index as usize + ROOT_HISTORY_SIZE - 1) % ROOT_HISTORY_SIZE]
+    }
+
+    /// Appends a leaf and returns its index.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Result<u32> {
+        let index = self.next_index;
+        let root = merkle::append(
+            &mut self.filled_subtrees[..self.depth as usize],
+            leaf,
+            index,
+        )?;
+        self.roots[index as usize % ROOT_HISTORY_SIZE] = root;
+        self.next_index += 1;
+        Ok(index)
+    }
+
+    pub fn verify(&self, leaf: [u8; 32], index: u32, proof: &[[u8; 32]]) -> Result<()> {
+        require_eq!(
+            proof.len(),
+            self.depth as usize,
+            MerkleNotesError::InvalidProof
+        );
+        require_gt!(self.next_index, index, MerkleNotesError::InvalidProof);
+        let root = merkle::compute_root(leaf, index, proof)?;
+        // slots that were never written hold the empty root, which no leaf hashes up to
+        require!(self.roots.contains(&root), MerkleNotesError::InvalidProof);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_tree() -> NoteTree {
+        NoteTree::try_deserialize_unchecked(&mut &[0; 8 + NoteTree::INIT_SPACE][..]).unwrap()
+    }
+
+    /// The proof of `index` in a tree holding the given leaves.
+    fn proof(depth: usize, leaves: &[[u8; 32]], index: usize) -> Vec<[u8; 32]> {
+        let mut level = leaves.to_vec();
+        level.resize(1 << depth, [0; 32]);
+        let mut proof = vec![];
+        for height in 0..depth {
+            proof.push(level[(index >> height) ^ 1]);
+            level = level
+                .chunks(2)
+                .map(|p| merkle::hash_pair(&p[0], &p[1]))
+                .collect();
+        }
+        proof
+    }
+
+    #[test]
+    fn recent_roots_stay_valid() {
+        let depth = 5;
+        let mut tree = empty_tree();
+        tree.init(Pubkey::default(), 0, depth as u8, 0).unwrap();
+
+        let leaves: Vec<_> = (0u32..30)
+            .map(|i| merkle::hash_leaf(&[&i.to_le_bytes()]))
+            .collect();
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(tree.append(*leaf).unwrap(), i as u32);
+        }
+
+        // a proof of the first leaf, made when the tree held `size` leaves
+        for size in 1..=leaves.len() {
+            let proof = proof(depth, &leaves[..size], 0);
+            let result = tree.verify(leaves[0], 0, &proof);
+            assert_eq!(
+                result.is_ok(),
+                size > leaves.len() - ROOT_HISTORY_SIZE,
+                "size {size}"
+            );
+        }
+        // leaves that haven't been appended yet don't verify against the empty root
+        assert!(tree
+            .verify([0; 32], 30, &proof(depth, &leaves, 30))
+            .is_err());
+    }
+
+    #[test]
+    fn depth_is_bounded() {
+        let mut tree = empty_tree();
+        assert!(tree.init(Pubkey::default(), 0, 0, 0).is_err());
+        assert!(tree
+            .init(Pubkey::default(), 0, MAX_DEPTH as u8 + 1, 0)
+            .is_err());
+        assert!(tree.init(Pubkey::default(), 0, MAX_DEPTH as u8, 0).is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+import * as anchor from '@coral-xyz/anchor';
+import { BorshCoder, EventParser, Program } from '@coral-xyz/anchor';
+import { createHash } from 'crypto';
+import { expectError } from 'test-harness';
+import { MerkleNotes } from '../../../target/types/merkle_notes';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+const sha256 = (...parts: Buffer[]) => createHash('sha256').update(Buffer.concat(parts)).digest();
+const hashLeaf = (author: anchor.web3.PublicKey, noteHash: Buffer) =>
+  sha256(Buffer.from([0]), author.toBuffer(), noteHash);
+const hashPair = (left: Buffer, right: Buffer) => sha256(Buffer.from([1]), left, right);
+
+// the same tree the program keeps, but with every leaf
+function proof(leaves: Buffer[], depth: number, index: number) {
+  let level = [...leaves];
+  while (level.length < 2 ** depth) level.push(Buffer.alloc(32));
+  const siblings: number[][] = [];
+  for (let height = 0; height < depth; height++) {
+    siblings.push(Array.from(level[(index >> height) ^ 1]));
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) next.push(hashPair(level[i], level[i + 1]));
+    level = next;
+  }
+  return { siblings, root: level[0] };
+}
+
+// Runs against the local validator, not the bankrun harness: the leaves are read from the events
+// in the logs of past transactions, fetched with `getTransaction` as a client would.
+describe('merkle-notes', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.MerkleNotes as Program<MerkleNotes>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+  const author = (programProvider.wallet as anchor.Wallet).payer;
+  const eventParser = new EventParser(program.programId, new BorshCoder(program.idl));
+
+  const depth = 4;
+  const [tree] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('note_tree'), author.publicKey.toBuffer(), new BN(0).toArrayLike(Buffer, 'le', 8)],
+    program.programId
+  );
+  const notes = ['gm', 'the tree only keeps its roots', 'and the rightmost path', 'everything else is in the logs'];
+
+  async function appendNote(note: string) {
+    const signature = await program.methods
+      .appendNote(Array.from(sha256(Buffer.from(note))))
+      .accounts({ tree, author: author.publicKey })
+      .rpc({ commitment: 'confirmed' });
+    // an indexer would rebuild the leaves from these events
+    const tx = await connection.getTransaction(signature, { commitment: 'confirmed' });
+    const [event] = eventParser.parseLogs(tx.meta.logMessages);
+    return event.data as { index: number; leaf: number[] };
+  }
+
+  async function verifyNote(note: string, index: number, siblings: number[][]) {
+    await program.methods
+      .verifyNote(author.publicKey, Array.from(sha256(Buffer.from(note))), index, siblings)
+      .accounts({ tree })
+      .rpc();
+  }
+
+  it('append notes and prove them!', async () => {
+    await expectError(
+      program.methods
+        .createTree(new BN(0), 21)
+        .accounts({ tree, creator: author.publicKey })
+        .rpc(),
+      'InvalidDepth',
+      6000
+    );
+    await program.methods
+      .createTree(new BN(0), depth)
+      .accounts({ tree, creator: author.publicKey })
+      .rpc();
+
+    const leaves: Buffer[] = [];
+    for (const note of notes) {
+      const event = await appendNote(note);
+      expect(event.index).to.equal(leaves.length);
+      expect(Buffer.from(event.leaf)).to.deep.equal(hashLeaf(author.publicKey, sha256(Buffer.from(note))));
+      leaves.push(Buffer.from(event.leaf));
+    }
+
+    const treeState = await program.account.noteTree.fetch(tree);
+    expect(treeState.nextIndex).to.equal(notes.length);
+    const { root } = proof(leaves, depth, 0);
+    expect(Buffer.from(treeState.roots[notes.length - 1])).to.deep.equal(root);
+
+    for (const [index, note] of notes.entries()) {
+      await verifyNote(note, index, proof(leaves, depth, index).siblings);
+    }
+  });
+
+  it('wrong notes, indexes and proofs fail', async () => {
+    const leaves = notes.map((note) => hashLeaf(author.publicKey, sha256(Buffer.from(note))));
+    const { siblings } = proof(leaves, depth, 1);
+
+    await expectError(verifyNote('gn', 1, siblings), 'InvalidProof', 6002);
+    await expectError(verifyNote(notes[1], 2, siblings), 'InvalidProof', 6002);
+    await expectError(verifyNote(notes[1], 1, siblings.slice(1)), 'InvalidProof', 6002);
+
+    // a proof against an older root is still fine
+    await appendNote('one more');
+    await verifyNote(notes[1], 1, siblings);
+  });
+
+  it('a full tree takes no more notes', async () => {
+    for (let i = notes.length + 1; i < 2 ** depth; i++) {
+      await appendNote(`note ${i}`);
+    }
+    await expectError(appendNote('one too many'), 'TreeFull', 6001);
+  });
+});
@@ -0,0 +1,42 @@
+use crate::state::post::*;
+use crate::state::profile::*;
+use anchor_lang::prelude::*;
+
+pub fn create_post(ctx: Context<CreatePost>, content: String) -> Result<()> {
+    Post::validate_content(&content)?;
+
+    let profile = &mut ctx.accounts.profile;
+    ctx.accounts.post.set_inner(Post {
+        author: profile.author,
+        index: profile.post_count,
+        content,
+        created_ts: Clock::get()?.unix_timestamp,
+        edited_ts: None,
+        bump: ctx.bumps.post,
+    });
+    profile.post_count += 1;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(content: String)]
+pub struct CreatePost<'info> {
+    #[account(
+        mut,
+        seeds = [Profile::SEED_PREFIX, author.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    // `content` is known before the account is created, so it is sized to fit exactly
+    #[account(
+        init,
+        payer = author,
+        space = Post::space(&content),
+        seeds = [Post::SEED_PREFIX, author.key().as_ref(), profile.post_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
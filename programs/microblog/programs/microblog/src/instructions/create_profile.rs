@@ -0,0 +1,25 @@
+use crate::state::profile::*;
+use anchor_lang::prelude::*;
+
+pub fn create_profile(ctx: Context<CreateProfile>, name: String, bio: String) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    profile.author = ctx.accounts.author.key();
+    profile.post_count = 0;
+    profile.bump = ctx.bumps.profile;
+    profile.set(name, bio)
+}
+
+#[derive(Accounts)]
+pub struct CreateProfile<'info> {
+    #[account(
+        init,
+        payer = author,
+        space = 8 + Profile::INIT_SPACE,
+        seeds = [Profile::SEED_PREFIX, author.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,15 @@
+use crate::state::post::*;
+use anchor_lang::prelude::*;
+
+pub fn delete_post(_ctx: Context<DeletePost>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeletePost<'info> {
+    // all the work is done by `close`, which refunds the rent to the author
+    #[account(mut, has_one = author, close = author)]
+    pub post: Account<'info, Post>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+}
@@ -0,0 +1,32 @@
+use crate::state::post::*;
+use anchor_lang::prelude::*;
+
+pub fn edit_post(ctx: Context<EditPost>, content: String) -> Result<()> {
+    Post::validate_content(&content)?;
+
+    let post = &mut ctx.accounts.post;
+    post.content = content;
+    post.edited_ts = Some(Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(content: String)]
+pub struct EditPost<'info> {
+    // Grows or shrinks the account to the new content before the handler runs:
+    // the author tops up the rent when it grows and is refunded when it shrinks.
+    // `realloc::zero = false` is fine as the whole string is rewritten anyway.
+    #[account(
+        mut,
+        has_one = author,
+        seeds = [Post::SEED_PREFIX, author.key().as_ref(), post.index.to_le_bytes().as_ref()],
+        bump = post.bump,
+        realloc = Post::space(&content),
+        realloc::payer = author,
+        realloc::zero = false
+    )]
+    pub post: Account<'info, Post>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,11 @@
+pub use create_post::*;
+pub use create_profile::*;
+pub use delete_post::*;
+pub use edit_post::*;
+pub use update_profile::*;
+
+pub mod create_post;
+pub mod create_profile;
+pub mod delete_post;
+pub mod edit_post;
+pub mod update_profile;
@@ -0,0 +1,17 @@
+use crate::state::profile::*;
+use anchor_lang::prelude::*;
+
+pub fn update_profile(ctx: Context<UpdateProfile>, name: String, bio: String) -> Result<()> {
+    ctx.accounts.profile.set(name, bio)
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfile<'info> {
+    #[account(
+        mut,
+        seeds = [Profile::SEED_PREFIX, author.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    pub author: Signer<'info>,
+}
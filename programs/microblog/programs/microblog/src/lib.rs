@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("5MKUhqu9HjjkC5v5TJzNnrpwbKpFhtFxRBKcyR2nb4tw");
+
+#[program]
+pub mod microblog {
+    use super::*;
+
+    pub fn create_profile(ctx: Context<CreateProfile>, name: String, bio: String) -> Result<()> {
+        instructions::create_profile::create_profile(ctx, name, bio)
+    }
+
+    pub fn update_profile(ctx: Context<UpdateProfile>, name: String, bio: String) -> Result<()> {
+        instructions::update_profile::update_profile(ctx, name, bio)
+    }
+
+    pub fn create_post(ctx: Context<CreatePost>, content: String) -> Result<()> {
+        instructions::create_post::create_post(ctx, content)
+    }
+
+    pub fn edit_post(ctx: Context<EditPost>, content: String) -> Result<()> {
+        instructions::edit_post::edit_post(ctx, content)
+    }
+
+    pub fn delete_post(ctx: Context<DeletePost>) -> Result<()> {
+        instructions::delete_post::delete_post(ctx)
+    }
+}
@@ -0,0 +1,5 @@
+pub use post::*;
+pub use profile::*;
+
+pub mod post;
+pub mod profile;
@@ -0,0 +1,37 @@
+use crate::errors::MicroblogError;
+use anchor_lang::prelude::*;
+
+pub const MAX_CONTENT_LEN: usize = 280;
+
+// Unlike `Profile`, a post only pays rent for the content it actually holds
+// and is resized with `realloc` whenever it is edited.
+#[account]
+#[derive(InitSpace)]
+pub struct Post {
+    pub author: Pubkey,
+    pub index: u64,
+    #[max_len(MAX_CONTENT_LEN)]
+    pub content: String,
+    pub created_ts: i64,
+    pub edited_ts: Option<i64>,
+    pub bump: u8,
+}
+
+impl Post {
+    pub const SEED_PREFIX: &'static [u8] = b"post";
+
+    // `INIT_SPACE` reserves `MAX_CONTENT_LEN` bytes for the content, swap those for the real length
+    pub fn space(content: &str) -> usize {
+        8 + Post::INIT_SPACE - MAX_CONTENT_LEN + content.len()
+    }
+
+    pub fn validate_content(content: &str) -> Result<()> {
+        require!(!content.is_empty(), MicroblogError::EmptyContent);
+        require_gte!(
+            MAX_CONTENT_LEN,
+            content.len(),
+            MicroblogError::ContentTooLong
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use crate::errors::MicroblogError;
+use anchor_lang::prelude::*;
+
+// `max_len` counts bytes, not characters: an emoji takes up to 4 of them
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_BIO_LEN: usize = 160;
+
+// Allocated for the longest name and bio up front, so updates never need to resize it.
+#[account]
+#[derive(InitSpace)]
+pub struct Profile {
+    pub author: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_BIO_LEN)]
+    pub bio: String,
+    // index of the next post, never decremented so deleted posts' addresses aren't reused
+    pub post_count: u64,
+    pub bump: u8,
+}
+
+impl Profile {
+    pub const SEED_PREFIX: &'static [u8] = b"profile";
+
+    pub fn set(&mut self, name: String, bio: String) -> Result<()> {
+        require_gte!(MAX_NAME_LEN, name.len(), MicroblogError::NameTooLong);
+        require_gte!(MAX_BIO_LEN, bio.len(), MicroblogError::BioTooLong);
+        self.name = name;
+        self.bio = bio;
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, expectError } from 'test-harness';
+import { Microblog } from '../../../target/types/microblog';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('microblog', () => {
+  let harness: Harness;
+  let program: Program<Microblog>;
+  let author: anchor.web3.PublicKey;
+  let profile: anchor.web3.PublicKey;
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Microblog>('microblog');
+    author = harness.payer.publicKey;
+    [profile] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('profile'), author.toBuffer()],
+      program.programId
+    );
+  });
+
+  function findPost(index: number) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('post'), author.toBuffer(), new BN(index).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+  }
+
+  async function createPost(content: string) {
+    const { postCount } = await program.account.profile.fetch(profile);
+    const post = findPost(postCount.toNumber());
+    await program.methods.createPost(content).accounts({ profile, post, author }).rpc();
+    return post;
+  }
+
+  it('create and update a profile!', async () => {
+    await expectError(
+      program.methods.createProfile('a'.repeat(33), '').accounts({ profile, author }).rpc(),
+      'NameTooLong',
+      6000
+    );
+    await program.methods.createProfile('alice', 'gm').accounts({ profile, author }).rpc();
+
+    // `max_len` counts bytes: 41 four-byte emoji don't fit into 160 bytes
+    await expectError(
+      program.methods.updateProfile('alice', '🦀'.repeat(41)).accounts({ profile, author }).rpc(),
+      'BioTooLong',
+      6001
+    );
+    await program.methods.updateProfile('alice', '🦀'.repeat(40)).accounts({ profile, author }).rpc();
+
+    const profileState = await program.account.profile.fetch(profile);
+    expect(profileState.name).to.equal('alice');
+    expect(profileState.bio).to.equal('🦀'.repeat(40));
+  });
+
+  it('posts are sized to their content', async () => {
+    await expectError(createPost(''), 'EmptyContent', 6002);
+    await expectError(createPost('a'.repeat(281)), 'ContentTooLong', 6003);
+
+    const short = await createPost('hello');
+    const long = await createPost('hello world');
+    const shortSize = (await harness.account(short)).data.length;
+    const longSize = (await harness.account(long)).data.length;
+    expect(longSize - shortSize).to.equal(6);
+
+    const postState = await program.account.post.fetch(long);
+    expect(postState.index.toNumber()).to.equal(1);
+    expect(postState.content).to.equal('hello world');
+    expect(postState.editedTs).to.be.null;
+  });
+
+  it('edits realloc the post', async () => {
+    const post = findPost(0);
+    const sizeBefore = (await harness.account(post)).data.length;
+    const balanceBefore = await harness.lamports(author);
+
+    const content = 'hello, this post got a lot longer';
+    await program.methods.editPost(content).accounts({ post, author }).rpc();
+    let info = await harness.account(post);
+    expect(info.data.length).to.equal(sizeBefore + content.length - 'hello'.length);
+    // the author paid for the extra bytes
+    expect(info.lamports).to.equal(await harness.rentExemption(info.data.length));
+    expect(await harness.lamports(author)).to.be.lessThan(balanceBefore);
+
+    const postState = await program.account.post.fetch(post);
+    expect(postState.content).to.equal(content);
+    expect(postState.editedTs).not.to.be.null;
+
+    // shrinking refunds the rent
+    await program.methods.editPost('hi').accounts({ post, author }).rpc();
+    info = await harness.account(post);
+    expect(info.data.length).to.equal(sizeBefore - 3);
+    expect(info.lamports).to.equal(await harness.rentExemption(info.data.length));
+    expect((await program.account.post.fetch(post)).content).to.equal('hi');
+  });
+
+  it('only the author can delete a post', async () => {
+    const mallory = anchor.web3.Keypair.generate();
+    await harness.fund(mallory.publicKey);
+    const post = findPost(0);
+
+    try {
+      await program.methods.deletePost().accounts({ post, author: mallory.publicKey }).signers([mallory]).rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintHasOne');
+    }
+  });
+
+  it('delete a post', async () => {
+    const post = findPost(0);
+    await program.methods.deletePost().accounts({ post, author }).rpc();
+    expect(await harness.account(post)).to.be.null;
+
+    // indices keep counting up, a new post doesn't take over the deleted one's address
+    const next = await createPost('third');
+    expect(next.equals(findPost(2))).is.true;
+  });
+});
@@ -0,0 +1,28 @@
+use crate::state::data_v1::*;
+use anchor_lang::prelude::*;
+
+// What the first version of the program offered. It is only kept so the tests have
+// V1 accounts to migrate, a real upgrade would drop it.
+pub fn initialize_v1(ctx: Context<InitializeV1>, value: u64) -> Result<()> {
+    ctx.accounts.data.set_inner(DataV1 {
+        authority: ctx.accounts.authority.key(),
+        value,
+        bump: ctx.bumps.data,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeV1<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DataV1::INIT_SPACE,
+        seeds = [DataV1::SEED_PREFIX, authority.key().as_ref()],
+        bump
+    )]
+    pub data: Account<'info, DataV1>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,56 @@
+use crate::state::data_v1::*;
+use crate::state::data_v2::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+// The account changes type here, which `Account<T>` can't express: it would write the
+// V1 data back over the V2 data when the instruction exits. So the account is handled
+// by hand, each step being what Anchor would otherwise do for us.
+pub fn migrate(ctx: Context<Migrate>, label: String) -> Result<()> {
+    let data = ctx.accounts.data.to_account_info();
+
+    // checks the V1 discriminator, a second migration fails right here
+    let v1 = DataV1::try_deserialize(&mut &data.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        v1.authority,
+        ctx.accounts.authority.key(),
+        ErrorCode::ConstraintHasOne
+    );
+    let v2 = DataV2::from_v1(v1, label, Clock::get()?.unix_timestamp)?;
+
+    // the larger account needs more rent, which the authority pays
+    let space = 8 + DataV2::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(data.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: data.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    data.realloc(space, false)?;
+
+    let mut bytes = data.try_borrow_mut_data()?;
+    v2.try_serialize(&mut &mut bytes[..])
+}
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// CHECK: owned by this program and deserialized as `DataV1` in the instruction
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [DataV1::SEED_PREFIX, authority.key().as_ref()],
+        bump
+    )]
+    pub data: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
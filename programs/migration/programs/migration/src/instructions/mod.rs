@@ -0,0 +1,7 @@
+pub use initialize_v1::*;
+pub use migrate::*;
+pub use update::*;
+
+pub mod initialize_v1;
+pub mod migrate;
+pub mod update;
@@ -0,0 +1,25 @@
+use crate::errors::MigrationError;
+use crate::state::data_v2::*;
+use anchor_lang::prelude::*;
+
+// Only takes V2 accounts. Anything not migrated yet fails the discriminator check.
+pub fn update(ctx: Context<Update>, value: u64, label: String) -> Result<()> {
+    require_gte!(MAX_LABEL_LEN, label.len(), MigrationError::LabelTooLong);
+    let data = &mut ctx.accounts.data;
+    data.value = value;
+    data.label = label;
+    data.updated_ts = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Update<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [DataV2::SEED_PREFIX, authority.key().as_ref()],
+        bump = data.bump
+    )]
+    pub data: Account<'info, DataV2>,
+    pub authority: Signer<'info>,
+}
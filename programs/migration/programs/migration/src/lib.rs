@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("DLk2oMHhkx8AbzUEe8cfFdciWut37mTYjpNxvJ46HJFD");
+
+#[program]
+pub mod migration {
+    use super::*;
+
+    pub fn initialize_v1(ctx: Context<InitializeV1>, value: u64) -> Result<()> {
+        instructions::initialize_v1::initialize_v1(ctx, value)
+    }
+
+    pub fn migrate(ctx: Context<Migrate>, label: String) -> Result<()> {
+        instructions::migrate::migrate(ctx, label)
+    }
+
+    pub fn update(ctx: Context<Update>, value: u64, label: String) -> Result<()> {
+        instructions::update::update(ctx, value, label)
+    }
+}
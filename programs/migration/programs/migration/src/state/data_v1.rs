@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// The account as the first version of the program created it.
+///
+/// The type has to stay around after the upgrade: its discriminator is how `migrate`
+/// recognizes accounts that haven't been migrated yet, and its layout how it reads them.
+#[account]
+#[derive(InitSpace)]
+pub struct DataV1 {
+    pub authority: Pubkey,
+    pub value: u64,
+    pub bump: u8,
+}
+
+impl DataV1 {
+    pub const SEED_PREFIX: &'static [u8] = b"data";
+}
@@ -0,0 +1,36 @@
+use super::data_v1::DataV1;
+use crate::errors::MigrationError;
+use anchor_lang::prelude::*;
+
+pub const MAX_LABEL_LEN: usize = 32;
+
+/// The current version of the account, at the same address as `DataV1`.
+///
+/// Renaming the type gives it a new discriminator, so a V1 account can never be
+/// mistaken for a V2 one, even though both start with the same fields.
+#[account]
+#[derive(InitSpace)]
+pub struct DataV2 {
+    pub authority: Pubkey,
+    pub value: u64,
+    pub bump: u8,
+    #[max_len(MAX_LABEL_LEN)]
+    pub label: String,
+    pub updated_ts: i64,
+}
+
+impl DataV2 {
+    pub const SEED_PREFIX: &'static [u8] = DataV1::SEED_PREFIX;
+
+    /// Maps every V1 field to its V2 counterpart, and fills in the new ones.
+    pub fn from_v1(v1: DataV1, label: String, now: i64) -> Result<Self> {
+        require_gte!(MAX_LABEL_LEN, label.len(), MigrationError::LabelTooLong);
+        Ok(DataV2 {
+            authority: v1.authority,
+            value: v1.value,
+            bump: v1.bump,
+            label,
+            updated_ts: now,
+        })
+    }
+}
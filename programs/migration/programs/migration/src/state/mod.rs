@@ -0,0 +1,5 @@
+pub use data_v1::*;
+pub use data_v2::*;
+
+pub mod data_v1;
+pub mod data_v2;
@@ -0,0 +1,99 @@
+import * as anchor from '@coral-xyz/anchor';
+import { BorshAccountsCoder, Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { Harness, expectError } from 'test-harness';
+import { Migration } from '../../../target/types/migration';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('migration', () => {
+  let harness: Harness;
+  let program: Program<Migration>;
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Migration>('migration');
+  });
+
+  function dataAddress(authority: Keypair) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('data'), authority.publicKey.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  async function initializeV1(authority: Keypair, value: number) {
+    await harness.fund(authority.publicKey);
+    await program.methods
+      .initializeV1(new BN(value))
+      .accounts({ data: dataAddress(authority), authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+  }
+
+  async function migrate(authority: Keypair, label: string, data = dataAddress(authority)) {
+    await program.methods
+      .migrate(label)
+      .accounts({ data, authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+  }
+
+  async function update(authority: Keypair, value: number, label: string) {
+    await program.methods
+      .update(new BN(value), label)
+      .accounts({ data: dataAddress(authority), authority: authority.publicKey })
+      .signers([authority])
+      .rpc();
+  }
+
+  it('migrate a V1 account to V2!', async () => {
+    const authority = Keypair.generate();
+    const data = dataAddress(authority);
+    await initializeV1(authority, 42);
+
+    // the account starts with the discriminator of the old type
+    const before = await harness.account(data);
+    expect(Buffer.from(before.data.subarray(0, 8))).to.deep.equal(BorshAccountsCoder.accountDiscriminator('DataV1'));
+    const lamportsBefore = before.lamports;
+
+    // the new instructions don't accept it yet
+    await expectError(update(authority, 43, 'too early'), 'AccountDiscriminatorMismatch', 3002);
+
+    await expectError(migrate(authority, 'x'.repeat(33)), 'LabelTooLong', 6000);
+    await migrate(authority, 'migrated');
+
+    const after = await harness.account(data);
+    expect(Buffer.from(after.data.subarray(0, 8))).to.deep.equal(BorshAccountsCoder.accountDiscriminator('DataV2'));
+    expect(after.data.length).to.be.greaterThan(before.data.length);
+    expect(after.lamports).to.equal(await harness.rentExemption(after.data.length));
+    expect(after.lamports).to.be.greaterThan(lamportsBefore);
+
+    // the old fields made it across, the new ones are filled in
+    const dataState = await program.account.dataV2.fetch(data);
+    expect(dataState.authority).to.deep.equal(authority.publicKey);
+    expect(dataState.value.toNumber()).to.equal(42);
+    expect(dataState.label).to.equal('migrated');
+    expect(dataState.updatedTs.toNumber()).to.be.greaterThan(0);
+
+    await update(authority, 43, 'updated');
+    expect((await program.account.dataV2.fetch(data)).value.toNumber()).to.equal(43);
+  });
+
+  it("migrations happen once, and only by the account's authority", async () => {
+    const authority = Keypair.generate();
+    const other = Keypair.generate();
+    await initializeV1(authority, 7);
+    await initializeV1(other, 8);
+
+    // the address is derived from the signer, so another account doesn't pass the seeds check
+    await expectError(migrate(other, 'not mine', dataAddress(authority)), 'ConstraintSeeds', 2006);
+
+    await migrate(authority, 'once');
+    await expectError(migrate(authority, 'twice'), 'AccountDiscriminatorMismatch', 3002);
+  });
+});
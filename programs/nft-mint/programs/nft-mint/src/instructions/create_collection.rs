@@ -0,0 +1,132 @@
+use crate::metadata::nft_data;
+use crate::state::collection_authority::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{self, CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+pub fn create_collection(
+    ctx: Context<CreateCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let data = nft_data(name, symbol, uri, None)?;
+
+    let authority = &mut ctx.accounts.authority;
+    authority.collection_mint = ctx.accounts.collection_mint.key();
+    authority.minted = 0;
+    authority.bump = ctx.bumps.authority;
+
+    let collection_mint = ctx.accounts.collection_mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CollectionAuthority::SEED_PREFIX,
+        collection_mint.as_ref(),
+        &[authority.bump],
+    ]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    metadata::create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                mint_authority: authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        data,
+        true,
+        true,
+        None,
+    )?;
+
+    // a max supply of 0 makes the collection a one of a kind,
+    // the mint authority moves to the master edition account
+    metadata::create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                update_authority: authority.to_account_info(),
+                mint_authority: authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(0),
+    )
+}
+
+#[derive(Accounts)]
+pub struct CreateCollection<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CollectionAuthority::INIT_SPACE,
+        seeds = [CollectionAuthority::SEED_PREFIX, collection_mint.key().as_ref()],
+        bump
+    )]
+    pub authority: Account<'info, CollectionAuthority>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+    // the collection NFT itself stays with the collection authority
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = collection_mint,
+        associated_token::authority = authority
+    )]
+    pub collection_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: created and checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: created and checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,119 @@
+use crate::metadata::nft_data;
+use crate::state::collection_authority::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{self, CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+pub fn mint_nft(ctx: Context<MintNft>, name: String, symbol: String, uri: String) -> Result<()> {
+    let authority = &mut ctx.accounts.authority;
+    let data = nft_data(name, symbol, uri, Some(authority.collection_mint))?;
+    authority.minted += 1;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CollectionAuthority::SEED_PREFIX,
+        authority.collection_mint.as_ref(),
+        &[authority.bump],
+    ]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    metadata::create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                update_authority: authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        data,
+        true,
+        true,
+        None,
+    )?;
+
+    metadata::create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                update_authority: authority.to_account_info(),
+                mint_authority: authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        Some(0),
+    )
+}
+
+#[derive(Accounts)]
+pub struct MintNft<'info> {
+    #[account(
+        mut,
+        seeds = [CollectionAuthority::SEED_PREFIX, authority.collection_mint.as_ref()],
+        bump = authority.bump
+    )]
+    pub authority: Account<'info, CollectionAuthority>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = authority,
+        mint::freeze_authority = authority
+    )]
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+    pub recipient: SystemAccount<'info>,
+    /// CHECK: created and checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    /// CHECK: created and checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
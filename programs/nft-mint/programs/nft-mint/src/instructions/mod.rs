@@ -0,0 +1,7 @@
+pub use create_collection::*;
+pub use mint_nft::*;
+pub use verify_collection::*;
+
+pub mod create_collection;
+pub mod mint_nft;
+pub mod verify_collection;
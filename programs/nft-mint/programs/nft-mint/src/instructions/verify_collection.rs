@@ -0,0 +1,66 @@
+use crate::errors::NftMintError;
+use crate::state::collection_authority::*;
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{self, Metadata, MetadataAccount};
+use anchor_spl::token::Mint;
+
+pub fn verify_collection(ctx: Context<VerifyCollection>) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        CollectionAuthority::SEED_PREFIX,
+        authority.collection_mint.as_ref(),
+        &[authority.bump],
+    ]];
+
+    metadata::verify_collection(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            metadata::VerifyCollection {
+                payer: ctx.accounts.payer.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                collection_authority: authority.to_account_info(),
+                collection_mint: ctx.accounts.collection_mint.to_account_info(),
+                collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+                collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        None,
+    )
+}
+
+#[derive(Accounts)]
+pub struct VerifyCollection<'info> {
+    #[account(
+        seeds = [CollectionAuthority::SEED_PREFIX, collection_mint.key().as_ref()],
+        bump = authority.bump,
+        has_one = collection_mint
+    )]
+    pub authority: Account<'info, CollectionAuthority>,
+    pub collection_mint: Box<Account<'info, Mint>>,
+    // the NFT being verified, it must have been minted into this collection
+    #[account(
+        mut,
+        constraint = metadata.collection.as_ref().map(|collection| collection.key)
+            == Some(collection_mint.key()) @ NftMintError::NotInCollection
+    )]
+    pub metadata: Box<Account<'info, MetadataAccount>>,
+    /// CHECK: checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+    /// CHECK: checked by the token metadata program
+    #[account(
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
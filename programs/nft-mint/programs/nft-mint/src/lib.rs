@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod metadata;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("GsaqazyUdYhT6cG98j1r5JKQimZyfhWY4Tc54nMbKA3X");
+
+#[program]
+pub mod nft_mint {
+    use super::*;
+
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_collection::create_collection(ctx, name, symbol, uri)
+    }
+
+    pub fn mint_nft(
+        ctx: Context<MintNft>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::mint_nft::mint_nft(ctx, name, symbol, uri)
+    }
+
+    pub fn verify_collection(ctx: Context<VerifyCollection>) -> Result<()> {
+        instructions::verify_collection::verify_collection(ctx)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::errors::NftMintError;
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::{Collection, DataV2};
+use anchor_spl::metadata::mpl_token_metadata::{
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+
+/// Builds the metadata of a new token, failing early with our own errors
+/// instead of the less descriptive ones of the token metadata program.
+pub fn nft_data(
+    name: String,
+    symbol: String,
+    uri: String,
+    collection: Option<Pubkey>,
+) -> Result<DataV2> {
+    require_gte!(MAX_NAME_LENGTH, name.len(), NftMintError::NameTooLong);
+    require_gte!(MAX_SYMBOL_LENGTH, symbol.len(), NftMintError::SymbolTooLong);
+    require_gte!(MAX_URI_LENGTH, uri.len(), NftMintError::UriTooLong);
+
+    Ok(DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        // only the collection authority can flip `verified`, see `verify_collection`
+        collection: collection.map(|key| Collection {
+            verified: false,
+            key,
+        }),
+        uses: None,
+    })
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Mint authority, update authority and collection authority of a collection
+/// and of every NFT minted into it.
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionAuthority {
+    pub collection_mint: Pubkey,
+    pub minted: u64,
+    pub bump: u8,
+}
+
+impl CollectionAuthority {
+    pub const SEED_PREFIX: &'static [u8] = b"collection_authority";
+}
@@ -0,0 +1,3 @@
+pub use collection_authority::*;
+
+pub mod collection_authority;
@@ -0,0 +1,148 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { getAccount, getAssociatedTokenAddressSync, getMint } from '@solana/spl-token';
+import { NftMint } from '../../../target/types/nft_mint';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const TOKEN_METADATA_PROGRAM_ID = new anchor.web3.PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
+// Runs against the local validator, not the bankrun harness: the token metadata program is cloned
+// from mainnet by the `[test.validator]` section of Anchor.toml, the harness only has the
+// programs of the workspace.
+describe('nft-mint', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.NftMint as Program<NftMint>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+  const payer = programProvider.wallet;
+
+  const collectionMint = anchor.web3.Keypair.generate();
+  const authority = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('collection_authority'), collectionMint.publicKey.toBuffer()],
+    program.programId
+  )[0];
+
+  function metadataAddress(mint: anchor.web3.PublicKey) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+  }
+
+  function masterEditionAddress(mint: anchor.web3.PublicKey) {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from('edition')],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+  }
+
+  // the byte layout of a metadata account: key, update authority, mint, then the name string
+  async function metadataName(mint: anchor.web3.PublicKey) {
+    const data = (await connection.getAccountInfo(metadataAddress(mint))).data;
+    const nameLength = data.readUInt32LE(65);
+    return data.subarray(69, 69 + nameLength).toString().replace(/\0/g, '');
+  }
+
+  async function mintNft(name: string) {
+    const mint = anchor.web3.Keypair.generate();
+    await program.methods
+      .mintNft(name, 'BOOK', 'https://example.com/nft.json')
+      .accounts({
+        authority,
+        mint: mint.publicKey,
+        tokenAccount: getAssociatedTokenAddressSync(mint.publicKey, payer.publicKey),
+        recipient: payer.publicKey,
+        metadata: metadataAddress(mint.publicKey),
+        masterEdition: masterEditionAddress(mint.publicKey),
+        payer: payer.publicKey,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+    return mint.publicKey;
+  }
+
+  it('create collection!', async () => {
+    await program.methods
+      .createCollection('Anchor Book', 'BOOK', 'https://example.com/collection.json')
+      .accounts({
+        authority,
+        collectionMint: collectionMint.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(collectionMint.publicKey, authority, true),
+        metadata: metadataAddress(collectionMint.publicKey),
+        masterEdition: masterEditionAddress(collectionMint.publicKey),
+        payer: payer.publicKey,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .signers([collectionMint])
+      .rpc();
+
+    const mint = await getMint(connection, collectionMint.publicKey);
+    expect(Number(mint.supply)).to.equal(1);
+    // the master edition took over the mint authority
+    expect(mint.mintAuthority).to.eql(masterEditionAddress(collectionMint.publicKey));
+    expect(await metadataName(collectionMint.publicKey)).to.equal('Anchor Book');
+  });
+
+  it('mint and verify an nft!', async () => {
+    const mint = await mintNft('Anchor Book #1');
+
+    const tokenAccount = await getAccount(connection, getAssociatedTokenAddressSync(mint, payer.publicKey));
+    expect(Number(tokenAccount.amount)).to.equal(1);
+    expect(await metadataName(mint)).to.equal('Anchor Book #1');
+    expect((await program.account.collectionAuthority.fetch(authority)).minted.toNumber()).to.equal(1);
+
+    await program.methods
+      .verifyCollection()
+      .accounts({
+        authority,
+        collectionMint: collectionMint.publicKey,
+        metadata: metadataAddress(mint),
+        collectionMetadata: metadataAddress(collectionMint.publicKey),
+        collectionMasterEdition: masterEditionAddress(collectionMint.publicKey),
+        payer: payer.publicKey,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it('collection nft cannot verify itself', async () => {
+    try {
+      await program.methods
+        .verifyCollection()
+        .accounts({
+          authority,
+          collectionMint: collectionMint.publicKey,
+          metadata: metadataAddress(collectionMint.publicKey),
+          collectionMetadata: metadataAddress(collectionMint.publicKey),
+          collectionMasterEdition: masterEditionAddress(collectionMint.publicKey),
+          payer: payer.publicKey,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        })
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotInCollection');
+      expect(err.error.errorCode.number).to.equal(6003);
+    }
+  });
+
+  it('name too long', async () => {
+    try {
+      await mintNft('a'.repeat(33));
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NameTooLong');
+      expect(err.error.errorCode.number).to.equal(6000);
+    }
+  });
+});
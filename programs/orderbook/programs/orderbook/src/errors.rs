@@ -0,0 +1,13 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum OrderbookError {
+    IdenticalMints,
+    InvalidPrice,
+    ZeroQuantity,
+    OrderBookFull,
+    OrderNotFound,
+    NotOrderOwner,
+    NothingToMatch,
+    MathOverflow,
+}
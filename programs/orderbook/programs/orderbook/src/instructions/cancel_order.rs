@@ -0,0 +1,86 @@
+use crate::state::market::*;
+use crate::state::order_book::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+/// Takes the order off the book and pays out its fills together with whatever
+/// is still locked up. This is also how a fully filled order is settled.
+pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+    let order = ctx
+        .accounts
+        .order_book
+        .load_mut()?
+        .remove(order_id, ctx.accounts.owner.key)?;
+    let (base, quote) = order.payout();
+
+    let market = &ctx.accounts.market;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Market::SEED_PREFIX,
+        market.base_mint.as_ref(),
+        market.quote_mint.as_ref(),
+        &[market.bump],
+    ]];
+
+    for (amount, from, to) in [
+        (
+            base,
+            &ctx.accounts.base_vault,
+            &ctx.accounts.owner_base_account,
+        ),
+        (
+            quote,
+            &ctx.accounts.quote_vault,
+            &ctx.accounts.owner_quote_account,
+        ),
+    ] {
+        if amount > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: market.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(has_one = order_book)]
+    pub market: Account<'info, Market>,
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+    #[account(
+        mut,
+        seeds = [Market::BASE_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.base_vault_bump
+    )]
+    pub base_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [Market::QUOTE_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.quote_vault_bump
+    )]
+    pub quote_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.base_mint,
+        token::authority = owner
+    )]
+    pub owner_base_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.quote_mint,
+        token::authority = owner
+    )]
+    pub owner_quote_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,69 @@
+use crate::errors::OrderbookError;
+use crate::state::market::*;
+use crate::state::order_book::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+    require_keys_neq!(
+        ctx.accounts.base_mint.key(),
+        ctx.accounts.quote_mint.key(),
+        OrderbookError::IdenticalMints
+    );
+
+    ctx.accounts.market.set_inner(Market {
+        base_mint: ctx.accounts.base_mint.key(),
+        quote_mint: ctx.accounts.quote_mint.key(),
+        order_book: ctx.accounts.order_book.key(),
+        next_order_id: 0,
+        bump: ctx.bumps.market,
+        base_vault_bump: ctx.bumps.base_vault,
+        quote_vault_bump: ctx.bumps.quote_vault,
+    });
+
+    // `load_init` only works on an account whose discriminator is still zeroed,
+    // `zero` on the account below makes sure of that
+    ctx.accounts.order_book.load_init()?.market = ctx.accounts.market.key();
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Market::INIT_SPACE,
+        seeds = [Market::SEED_PREFIX, base_mint.key().as_ref(), quote_mint.key().as_ref()],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+    // Accounts created by a program through CPI are capped at 10KB, so the book
+    // is created beforehand by the client with a plain system program instruction,
+    // in the same transaction, and only initialized here.
+    #[account(zero)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [Market::BASE_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump,
+        token::mint = base_mint,
+        token::authority = market
+    )]
+    pub base_vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [Market::QUOTE_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = market
+    )]
+    pub quote_vault: Account<'info, TokenAccount>,
+    pub base_mint: Account<'info, Mint>,
+    pub quote_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
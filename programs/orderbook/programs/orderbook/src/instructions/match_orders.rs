@@ -0,0 +1,28 @@
+use crate::errors::OrderbookError;
+use crate::state::market::*;
+use crate::state::order_book::*;
+use anchor_lang::prelude::*;
+
+/// Permissionless crank. Every match scans the whole book, so the caller picks
+/// how many matches fit into their transaction's compute budget and simply
+/// calls again if there is more to match.
+pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
+    let mut order_book = ctx.accounts.order_book.load_mut()?;
+
+    let mut matched = 0;
+    while matched < limit && order_book.match_next() {
+        matched += 1;
+    }
+    require_gt!(matched, 0, OrderbookError::NothingToMatch);
+
+    msg!("matched {} times", matched);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MatchOrders<'info> {
+    #[account(has_one = order_book)]
+    pub market: Account<'info, Market>,
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+}
@@ -0,0 +1,9 @@
+pub use cancel_order::*;
+pub use initialize_market::*;
+pub use match_orders::*;
+pub use place_order::*;
+
+pub mod cancel_order;
+pub mod initialize_market;
+pub mod match_orders;
+pub mod place_order;
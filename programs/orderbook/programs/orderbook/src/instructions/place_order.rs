@@ -0,0 +1,89 @@
+use crate::errors::OrderbookError;
+use crate::state::market::*;
+use crate::state::order_book::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn place_order(ctx: Context<PlaceOrder>, side: Side, price: u64, quantity: u64) -> Result<()> {
+    require_gt!(price, 0, OrderbookError::InvalidPrice);
+    require_gt!(quantity, 0, OrderbookError::ZeroQuantity);
+
+    let market = &mut ctx.accounts.market;
+    let id = market.next_order_id;
+    market.next_order_id += 1;
+
+    ctx.accounts.order_book.load_mut()?.place(Order {
+        owner: ctx.accounts.owner.key(),
+        id,
+        price,
+        quantity,
+        remaining: quantity,
+        base_filled: 0,
+        quote_filled: 0,
+        side: side as u8,
+        in_use: 1,
+        _padding: [0; 6],
+    })?;
+
+    // bids lock up the quote they'd pay, asks the base they sell
+    let (from, to, amount) = match side {
+        Side::Bid => (
+            &ctx.accounts.owner_quote_account,
+            &ctx.accounts.quote_vault,
+            price
+                .checked_mul(quantity)
+                .ok_or(OrderbookError::MathOverflow)?,
+        ),
+        Side::Ask => (
+            &ctx.accounts.owner_base_account,
+            &ctx.accounts.base_vault,
+            quantity,
+        ),
+    };
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct PlaceOrder<'info> {
+    #[account(mut, has_one = order_book)]
+    pub market: Account<'info, Market>,
+    #[account(mut)]
+    pub order_book: AccountLoader<'info, OrderBook>,
+    #[account(
+        mut,
+        seeds = [Market::BASE_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.base_vault_bump
+    )]
+    pub base_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [Market::QUOTE_VAULT_SEED_PREFIX, market.key().as_ref()],
+        bump = market.quote_vault_bump
+    )]
+    pub quote_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.base_mint,
+        token::authority = owner
+    )]
+    pub owner_base_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = market.quote_mint,
+        token::authority = owner
+    )]
+    pub owner_quote_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
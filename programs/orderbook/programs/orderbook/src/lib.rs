@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+use state::order_book::Side;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("BzbpYi2y4wHvsWoL2AJi7V1yrc8VSvs1WYFt8BJEw1xs");
+
+#[program]
+pub mod orderbook {
+    use super::*;
+
+    pub fn initialize_market(ctx: Context<InitializeMarket>) -> Result<()> {
+        instructions::initialize_market::initialize_market(ctx)
+    }
+
+    pub fn place_order(
+        ctx: Context<PlaceOrder>,
+        side: Side,
+        price: u64,
+        quantity: u64,
+    ) -> Result<()> {
+        instructions::place_order::place_order(ctx, side, price, quantity)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>, order_id: u64) -> Result<()> {
+        instructions::cancel_order::cancel_order(ctx, order_id)
+    }
+
+    pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
+        instructions::match_orders::match_orders(ctx, limit)
+    }
+}
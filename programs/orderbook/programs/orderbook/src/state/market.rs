@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Market {
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub order_book: Pubkey,
+    pub next_order_id: u64,
+    pub bump: u8,
+    pub base_vault_bump: u8,
+    pub quote_vault_bump: u8,
+}
+
+impl Market {
+    pub const SEED_PREFIX: &'static [u8] = b"market";
+    pub const BASE_VAULT_SEED_PREFIX: &'static [u8] = b"base_vault";
+    pub const QUOTE_VAULT_SEED_PREFIX: &'static [u8] = b"quote_vault";
+}
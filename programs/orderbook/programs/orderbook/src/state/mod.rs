@@ -0,0 +1,5 @@
+pub use market::*;
+pub use order_book::*;
+
+pub mod market;
+pub mod order_book;
@@ -0,0 +1,137 @@
+use crate::errors::OrderbookError;
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+
+pub const MAX_ORDERS: usize = 2048;
+
+/// At roughly 180KB, the book is far too large to be deserialized onto the
+/// 4KB stack or even the 32KB heap of a program. With zero copy, the account
+/// data is reinterpreted in place instead, so only the orders an instruction
+/// actually touches are ever read.
+#[account(zero_copy)]
+pub struct OrderBook {
+    pub market: Pubkey,
+    // a slab: free slots are zeroed, new orders take the first free one
+    pub orders: [Order; MAX_ORDERS],
+}
+
+// zero copy types must not contain padding, which is why `side` is a `u8`
+// and the struct is padded to a multiple of 8 bytes by hand
+#[zero_copy]
+pub struct Order {
+    pub owner: Pubkey,
+    pub id: u64,
+    // in quote atoms per base atom
+    pub price: u64,
+    // in base atoms
+    pub quantity: u64,
+    pub remaining: u64,
+    // base bought by a bid, or sold by an ask
+    pub base_filled: u64,
+    // quote paid by a bid, or received by an ask
+    pub quote_filled: u64,
+    pub side: u8,
+    pub in_use: u8,
+    pub _padding: [u8; 6],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+impl Order {
+    pub fn side(&self) -> Side {
+        if self.side == Side::Bid as u8 {
+            Side::Bid
+        } else {
+            Side::Ask
+        }
+    }
+
+    /// What closing the order pays out to its owner, as (base, quote):
+    /// everything it got from fills plus whatever is still locked.
+    pub fn payout(&self) -> (u64, u64) {
+        match self.side() {
+            // a bid locked `price * quantity` quote, fills at a better price leave some over
+            Side::Bid => (
+                self.base_filled,
+                self.price * self.quantity - self.quote_filled,
+            ),
+            Side::Ask => (self.remaining, self.quote_filled),
+        }
+    }
+}
+
+impl OrderBook {
+    pub fn place(&mut self, order: Order) -> Result<()> {
+        let slot = self
+            .orders
+            .iter_mut()
+            .find(|order| order.in_use == 0)
+            .ok_or(OrderbookError::OrderBookFull)?;
+        *slot = order;
+        Ok(())
+    }
+
+    /// Frees the slot of an order and returns it.
+    pub fn remove(&mut self, id: u64, owner: &Pubkey) -> Result<Order> {
+        let order = self
+            .orders
+            .iter_mut()
+            .find(|order| order.in_use == 1 && order.id == id)
+            .ok_or(OrderbookError::OrderNotFound)?;
+        require_keys_eq!(order.owner, *owner, OrderbookError::NotOrderOwner);
+
+        let removed = *order;
+        *order = Order::zeroed();
+        Ok(removed)
+    }
+
+    /// The open order on `side` that is next in line: best price first,
+    /// then the oldest, i.e. the one with the lowest id.
+    fn best(&self, side: Side) -> Option<usize> {
+        let open =
+            self.orders.iter().enumerate().filter(|(_, order)| {
+                order.in_use == 1 && order.remaining > 0 && order.side() == side
+            });
+        match side {
+            Side::Bid => open
+                .max_by_key(|(_, order)| (order.price, std::cmp::Reverse(order.id)))
+                .map(|(index, _)| index),
+            Side::Ask => open
+                .min_by_key(|(_, order)| (order.price, order.id))
+                .map(|(index, _)| index),
+        }
+    }
+
+    /// Fills the best bid against the best ask if their prices cross.
+    /// Returns whether there was anything to match.
+    pub fn match_next(&mut self) -> bool {
+        let (Some(bid), Some(ask)) = (self.best(Side::Bid), self.best(Side::Ask)) else {
+            return false;
+        };
+        if self.orders[bid].price < self.orders[ask].price {
+            return false;
+        }
+
+        // the order that was resting on the book first sets the price
+        let price = if self.orders[bid].id < self.orders[ask].id {
+            self.orders[bid].price
+        } else {
+            self.orders[ask].price
+        };
+        let quantity = self.orders[bid].remaining.min(self.orders[ask].remaining);
+        // can't overflow, it is at most what the bid locked up
+        let cost = price * quantity;
+
+        for index in [bid, ask] {
+            let order = &mut self.orders[index];
+            order.remaining -= quantity;
+            order.base_filled += quantity;
+            order.quote_filled += cost;
+        }
+        true
+    }
+}
@@ -0,0 +1,211 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Orderbook } from '../../../target/types/orderbook';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { SystemProgram } = anchor.web3;
+
+// discriminator + market + 2048 orders of 88 bytes each
+const ORDER_BOOK_SIZE = 8 + 32 + 2048 * 88;
+
+describe('orderbook', () => {
+  let harness: Harness;
+  let program: Program<Orderbook>;
+  let payer: anchor.web3.Keypair;
+
+  const orderBook = anchor.web3.Keypair.generate();
+  let market: anchor.web3.PublicKey;
+  let baseVault: anchor.web3.PublicKey;
+  let quoteVault: anchor.web3.PublicKey;
+  let baseMint: anchor.web3.PublicKey;
+  let quoteMint: anchor.web3.PublicKey;
+
+  type Trader = {
+    keypair: anchor.web3.Keypair;
+    base: anchor.web3.PublicKey;
+    quote: anchor.web3.PublicKey;
+  };
+
+  async function newTrader(): Promise<Trader> {
+    const keypair = await harness.fundedKeypair();
+    const base = await harness.createTokenAccount(baseMint, keypair.publicKey, 1_000);
+    const quote = await harness.createTokenAccount(quoteMint, keypair.publicKey, 100_000);
+    return { keypair, base, quote };
+  }
+
+  function traderAccounts(trader: Trader) {
+    return {
+      market,
+      orderBook: orderBook.publicKey,
+      baseVault,
+      quoteVault,
+      ownerBaseAccount: trader.base,
+      ownerQuoteAccount: trader.quote,
+      owner: trader.keypair.publicKey,
+    };
+  }
+
+  async function placeOrder(trader: Trader, side: 'bid' | 'ask', price: number, quantity: number) {
+    const id = (await program.account.market.fetch(market)).nextOrderId;
+    await program.methods
+      .placeOrder(side === 'bid' ? { bid: {} } : { ask: {} }, new BN(price), new BN(quantity))
+      .accounts(traderAccounts(trader))
+      .signers([trader.keypair])
+      .rpc();
+    return id;
+  }
+
+  async function cancelOrder(trader: Trader, id: anchor.BN) {
+    await program.methods
+      .cancelOrder(id)
+      .accounts(traderAccounts(trader))
+      .signers([trader.keypair])
+      .rpc();
+  }
+
+  async function matchOrders(limit: number) {
+    await program.methods.matchOrders(limit).accounts({ market, orderBook: orderBook.publicKey }).rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Orderbook>('orderbook');
+    payer = harness.payer;
+
+    baseMint = await harness.createMint(payer.publicKey);
+    quoteMint = await harness.createMint(payer.publicKey);
+    market = findAddress([Buffer.from('market'), baseMint.toBuffer(), quoteMint.toBuffer()], program.programId);
+    baseVault = findAddress([Buffer.from('base_vault'), market.toBuffer()], program.programId);
+    quoteVault = findAddress([Buffer.from('quote_vault'), market.toBuffer()], program.programId);
+  });
+
+  it('initialize market!', async () => {
+    await program.methods
+      .initializeMarket()
+      .accounts({
+        market,
+        orderBook: orderBook.publicKey,
+        baseVault,
+        quoteVault,
+        baseMint,
+        quoteMint,
+        payer: payer.publicKey,
+      })
+      .preInstructions([
+        // too large to be created by the program, see `InitializeMarket`
+        SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: orderBook.publicKey,
+          space: ORDER_BOOK_SIZE,
+          lamports: await harness.rentExemption(ORDER_BOOK_SIZE),
+          programId: program.programId,
+        }),
+      ])
+      .signers([orderBook])
+      .rpc();
+
+    const book = await program.account.orderBook.fetch(orderBook.publicKey);
+    expect(book.market).to.eql(market);
+    expect(book.orders.length).to.equal(2048);
+  });
+
+  it('match crossing orders at the resting price!', async () => {
+    const seller = await newTrader();
+    const buyer = await newTrader();
+
+    // resting asks, the cheaper one goes first
+    const expensiveAsk = await placeOrder(seller, 'ask', 12, 100);
+    const cheapAsk = await placeOrder(seller, 'ask', 10, 100);
+    // this bid crosses both asks
+    const bid = await placeOrder(buyer, 'bid', 12, 150);
+    expect(await harness.tokenBalance(buyer.quote)).to.equal(100_000 - 12 * 150);
+
+    try {
+      await cancelOrder(seller, bid);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotOrderOwner');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+
+    await matchOrders(10);
+
+    // 100 at 10 from the cheap ask and 50 at 12 from the expensive one
+    await cancelOrder(buyer, bid);
+    expect(await harness.tokenBalance(buyer.base)).to.equal(1_000 + 150);
+    expect(await harness.tokenBalance(buyer.quote)).to.equal(100_000 - 100 * 10 - 50 * 12);
+
+    await cancelOrder(seller, cheapAsk);
+    await cancelOrder(seller, expensiveAsk);
+    expect(await harness.tokenBalance(seller.base)).to.equal(1_000 - 150);
+    expect(await harness.tokenBalance(seller.quote)).to.equal(100_000 + 100 * 10 + 50 * 12);
+
+    expect(await harness.tokenBalance(baseVault)).to.equal(0);
+    expect(await harness.tokenBalance(quoteVault)).to.equal(0);
+  });
+
+  it('nothing to match', async () => {
+    const seller = await newTrader();
+    const buyer = await newTrader();
+    const ask = await placeOrder(seller, 'ask', 20, 10);
+    const bid = await placeOrder(buyer, 'bid', 19, 10);
+
+    try {
+      await matchOrders(10);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NothingToMatch');
+      expect(err.error.errorCode.number).to.equal(6006);
+    }
+
+    // cancelling unfilled orders returns everything that was locked
+    await cancelOrder(seller, ask);
+    await cancelOrder(buyer, bid);
+    expect(await harness.tokenBalance(seller.base)).to.equal(1_000);
+    expect(await harness.tokenBalance(buyer.quote)).to.equal(100_000);
+
+    try {
+      await cancelOrder(buyer, bid);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('OrderNotFound');
+    }
+  });
+
+  it('crank respects the match limit', async () => {
+    const seller = await newTrader();
+    const buyer = await newTrader();
+    const asks = [];
+    for (let i = 0; i < 3; i++) {
+      asks.push(await placeOrder(seller, 'ask', 5, 10));
+    }
+    const bid = await placeOrder(buyer, 'bid', 5, 30);
+
+    await matchOrders(2);
+    let book = await program.account.orderBook.fetch(orderBook.publicKey);
+    const bidOrder = book.orders.find((order) => order.inUse === 1 && order.id.eq(bid));
+    expect(bidOrder.remaining.toNumber()).to.equal(10);
+
+    await matchOrders(2);
+    book = await program.account.orderBook.fetch(orderBook.publicKey);
+    expect(book.orders.find((order) => order.inUse === 1 && order.id.eq(bid)).remaining.toNumber()).to.equal(0);
+
+    await cancelOrder(buyer, bid);
+    for (const ask of asks) {
+      await cancelOrder(seller, ask);
+    }
+    expect(await harness.tokenBalance(buyer.base)).to.equal(1_030);
+    expect(await harness.tokenBalance(seller.quote)).to.equal(100_150);
+  });
+});
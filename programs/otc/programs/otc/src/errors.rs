@@ -0,0 +1,10 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum OtcError {
+    ZeroAmount,
+    IdenticalMints,
+    ExpiryInThePast,
+    OrderExpired,
+    MathOverflow,
+}
@@ -0,0 +1,66 @@
+use crate::state::order::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+// The maker may cancel at any time, expired or not, and gets back what hasn't been filled.
+pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+    let order = &ctx.accounts.order;
+    let maker = order.maker;
+    let id_bytes = order.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Order::SEED_PREFIX,
+        maker.as_ref(),
+        id_bytes.as_ref(),
+        &[order.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.maker_token_account_a.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        order.remaining_a,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.maker.to_account_info(),
+            authority: ctx.accounts.order.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker,
+        seeds = [Order::SEED_PREFIX, maker.key().as_ref(), order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, Order>,
+    #[account(
+        mut,
+        seeds = [Order::VAULT_SEED_PREFIX, order.key().as_ref()],
+        bump = order.vault_bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = order.token_mint_a,
+        token::authority = maker
+    )]
+    pub maker_token_account_a: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
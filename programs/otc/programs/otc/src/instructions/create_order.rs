@@ -0,0 +1,85 @@
+use crate::errors::OtcError;
+use crate::state::order::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn create_order(
+    ctx: Context<CreateOrder>,
+    id: u64,
+    amount_a: u64,
+    amount_b: u64,
+    expiry_ts: i64,
+) -> Result<()> {
+    require_gt!(amount_a, 0, OtcError::ZeroAmount);
+    require_gt!(amount_b, 0, OtcError::ZeroAmount);
+    require_keys_neq!(
+        ctx.accounts.token_mint_a.key(),
+        ctx.accounts.token_mint_b.key(),
+        OtcError::IdenticalMints
+    );
+    require_gt!(
+        expiry_ts,
+        Clock::get()?.unix_timestamp,
+        OtcError::ExpiryInThePast
+    );
+
+    ctx.accounts.order.set_inner(Order {
+        id,
+        maker: ctx.accounts.maker.key(),
+        token_mint_a: ctx.accounts.token_mint_a.key(),
+        token_mint_b: ctx.accounts.token_mint_b.key(),
+        amount_a,
+        amount_b,
+        remaining_a: amount_a,
+        received_b: 0,
+        expiry_ts,
+        bump: ctx.bumps.order,
+        vault_bump: ctx.bumps.vault,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.maker_token_account_a.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.maker.to_account_info(),
+            },
+        ),
+        amount_a,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateOrder<'info> {
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + Order::INIT_SPACE,
+        seeds = [Order::SEED_PREFIX, maker.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub order: Account<'info, Order>,
+    #[account(
+        init,
+        payer = maker,
+        token::mint = token_mint_a,
+        token::authority = order,
+        seeds = [Order::VAULT_SEED_PREFIX, order.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub token_mint_a: Account<'info, Mint>,
+    pub token_mint_b: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = token_mint_a,
+        token::authority = maker
+    )]
+    pub maker_token_account_a: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,112 @@
+use crate::state::order::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+pub fn fill_order(ctx: Context<FillOrder>, amount_a: u64) -> Result<()> {
+    let (fill_a, fill_b) = ctx
+        .accounts
+        .order
+        .fill(amount_a, Clock::get()?.unix_timestamp)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.taker_token_account_b.to_account_info(),
+                to: ctx.accounts.maker_token_account_b.to_account_info(),
+                authority: ctx.accounts.taker.to_account_info(),
+            },
+        ),
+        fill_b,
+    )?;
+
+    let order = &ctx.accounts.order;
+    let maker = order.maker;
+    let id_bytes = order.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Order::SEED_PREFIX,
+        maker.as_ref(),
+        id_bytes.as_ref(),
+        &[order.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.taker_token_account_a.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        fill_a,
+    )?;
+
+    // the last fill cleans up after itself and hands the rent back to the maker
+    if ctx.accounts.order.is_filled() {
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault.to_account_info(),
+                destination: ctx.accounts.maker.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        ctx.accounts
+            .order
+            .close(ctx.accounts.maker.to_account_info())?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = token_mint_a,
+        has_one = token_mint_b,
+        seeds = [Order::SEED_PREFIX, maker.key().as_ref(), order.id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, Order>,
+    #[account(
+        mut,
+        seeds = [Order::VAULT_SEED_PREFIX, order.key().as_ref()],
+        bump = order.vault_bump
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+    pub token_mint_a: Box<Account<'info, Mint>>,
+    pub token_mint_b: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = taker
+    )]
+    pub taker_token_account_a: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = token_mint_b,
+        token::authority = taker
+    )]
+    pub taker_token_account_b: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = maker
+    )]
+    pub maker_token_account_b: Box<Account<'info, TokenAccount>>,
+    /// CHECK: only receives the rent of the closed order and vault, and is checked against `order.maker`
+    #[account(mut)]
+    pub maker: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub taker: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
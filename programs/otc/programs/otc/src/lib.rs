@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("8GxKxH8WFu55GVqV2ZvsGp2DEeCXYFMuBX2DaRnBLCKv");
+
+#[program]
+pub mod otc {
+    use super::*;
+
+    pub fn create_order(
+        ctx: Context<CreateOrder>,
+        id: u64,
+        amount_a: u64,
+        amount_b: u64,
+        expiry_ts: i64,
+    ) -> Result<()> {
+        instructions::create_order::create_order(ctx, id, amount_a, amount_b, expiry_ts)
+    }
+
+    pub fn fill_order(ctx: Context<FillOrder>, amount_a: u64) -> Result<()> {
+        instructions::fill_order::fill_order(ctx, amount_a)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        instructions::cancel_order::cancel_order(ctx)
+    }
+}
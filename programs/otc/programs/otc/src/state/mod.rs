@@ -0,0 +1,3 @@
+pub use order::*;
+
+pub mod order;
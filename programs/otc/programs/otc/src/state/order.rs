@@ -0,0 +1,57 @@
+use crate::errors::OtcError;
+use anchor_lang::prelude::*;
+use common::math;
+
+// The maker sells `amount_a` of token A for `amount_b` of token B,
+// in as many fills as takers like, always at the same ratio.
+#[account]
+#[derive(InitSpace)]
+pub struct Order {
+    pub id: u64,
+    pub maker: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub amount_a: u64,
+    pub amount_b: u64,
+    // token A still in the vault
+    pub remaining_a: u64,
+    // token B paid by all fills so far
+    pub received_b: u64,
+    pub expiry_ts: i64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Order {
+    pub const SEED_PREFIX: &'static [u8] = b"order";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expiry_ts
+    }
+
+    pub fn is_filled(&self) -> bool {
+        self.remaining_a == 0
+    }
+
+    // Fills up to `amount_a` and returns the token A received and token B owed by the taker.
+    //
+    // Rounding each fill's price on its own would let takers shave off a fraction of an atom
+    // per fill, and splitting an order into 1-atom fills would make that add up. Instead, the
+    // price of everything filled so far is rounded up in the maker's favour and the fill pays
+    // the difference, so all fills together pay exactly `amount_b`, however the order is split.
+    pub fn fill(&mut self, amount_a: u64, now: i64) -> Result<(u64, u64)> {
+        require_gt!(amount_a, 0, OtcError::ZeroAmount);
+        require!(!self.is_expired(now), OtcError::OrderExpired);
+
+        let fill_a = amount_a.min(self.remaining_a);
+        let filled_a = self.amount_a - self.remaining_a + fill_a;
+        let owed_b = math::mul_div_ceil(filled_a, self.amount_b, self.amount_a)
+            .ok_or(OtcError::MathOverflow)?;
+        let fill_b = owed_b - self.received_b;
+
+        self.remaining_a -= fill_a;
+        self.received_b += fill_b;
+        Ok((fill_a, fill_b))
+    }
+}
@@ -0,0 +1,135 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { Harness, expectError } from 'test-harness';
+import { Otc } from '../../../target/types/otc';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('otc', () => {
+  let harness: Harness;
+  let program: Program<Otc>;
+  let payer: anchor.web3.Keypair;
+
+  const maker = anchor.web3.Keypair.generate();
+  const taker = anchor.web3.Keypair.generate();
+  let tokenMintA: anchor.web3.PublicKey;
+  let tokenMintB: anchor.web3.PublicKey;
+  let makerTokenAccountA: anchor.web3.PublicKey;
+  let makerTokenAccountB: anchor.web3.PublicKey;
+  let takerTokenAccountA: anchor.web3.PublicKey;
+  let takerTokenAccountB: anchor.web3.PublicKey;
+
+  function orderAccounts(id: number) {
+    const [order] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('order'), maker.publicKey.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const [vault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('vault'), order.toBuffer()],
+      program.programId
+    );
+    return { order, vault };
+  }
+
+  async function createOrder(id: number, amountA: number, amountB: number, expiryTs: number) {
+    await program.methods
+      .createOrder(new BN(id), new BN(amountA), new BN(amountB), new BN(expiryTs))
+      .accounts({
+        ...orderAccounts(id),
+        tokenMintA,
+        tokenMintB,
+        makerTokenAccountA,
+        maker: maker.publicKey,
+      })
+      .signers([maker])
+      .rpc();
+  }
+
+  async function fillOrder(id: number, amountA: number) {
+    await program.methods
+      .fillOrder(new BN(amountA))
+      .accounts({
+        ...orderAccounts(id),
+        tokenMintA,
+        tokenMintB,
+        takerTokenAccountA,
+        takerTokenAccountB,
+        makerTokenAccountB,
+        maker: maker.publicKey,
+        taker: taker.publicKey,
+      })
+      .signers([taker])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Otc>('otc');
+    payer = harness.payer;
+
+    for (const keypair of [maker, taker]) {
+      await harness.fund(keypair.publicKey, LAMPORTS_PER_SOL);
+    }
+    tokenMintA = await harness.createMint(payer.publicKey);
+    tokenMintB = await harness.createMint(payer.publicKey);
+    makerTokenAccountA = await harness.createTokenAccount(tokenMintA, maker.publicKey, 1_000);
+    takerTokenAccountB = await harness.createTokenAccount(tokenMintB, taker.publicKey, 10_000);
+    // created by the first fill
+    makerTokenAccountB = getAssociatedTokenAddressSync(tokenMintB, maker.publicKey);
+    takerTokenAccountA = getAssociatedTokenAddressSync(tokenMintA, taker.publicKey);
+  });
+
+  it('fill an order in parts!', async () => {
+    const { order, vault } = orderAccounts(0);
+    await expectError(createOrder(0, 300, 1_000, (await harness.now()) - 1), 'ExpiryInThePast', 6002);
+    await createOrder(0, 300, 1_000, (await harness.now()) + 60);
+
+    // 1000 / 3 isn't a whole number, the first third is rounded up in the maker's favour
+    await fillOrder(0, 100);
+    expect(await harness.tokenBalance(takerTokenAccountA)).to.equal(100);
+    expect(await harness.tokenBalance(makerTokenAccountB)).to.equal(334);
+
+    // ... which the second third makes up for
+    await fillOrder(0, 100);
+    expect(await harness.tokenBalance(makerTokenAccountB)).to.equal(667);
+    const orderState = await program.account.order.fetch(order);
+    expect(orderState.remainingA.toNumber()).to.equal(100);
+    expect(orderState.receivedB.toNumber()).to.equal(667);
+
+    // asking for more than is left fills the rest, the maker ends up with exactly the price asked for
+    await fillOrder(0, 500);
+    expect(await harness.tokenBalance(takerTokenAccountA)).to.equal(300);
+    expect(await harness.tokenBalance(makerTokenAccountB)).to.equal(1_000);
+    expect(await harness.tokenBalance(takerTokenAccountB)).to.equal(9_000);
+
+    // the last fill closed the order and its vault
+    expect(await harness.account(order)).to.be.null;
+    expect(await harness.account(vault)).to.be.null;
+  });
+
+  it('no fills after expiry, the maker cancels', async () => {
+    const { order, vault } = orderAccounts(1);
+    await createOrder(1, 200, 100, (await harness.now()) + 3);
+    await expectError(fillOrder(1, 0), 'ZeroAmount', 6000);
+    await fillOrder(1, 50);
+    expect(await harness.tokenBalance(makerTokenAccountA)).to.equal(500);
+
+    await harness.warp(4);
+    await expectError(fillOrder(1, 50), 'OrderExpired', 6003);
+
+    await program.methods
+      .cancelOrder()
+      .accounts({ order, vault, makerTokenAccountA, maker: maker.publicKey })
+      .signers([maker])
+      .rpc();
+    expect(await harness.tokenBalance(makerTokenAccountA)).to.equal(650);
+    expect(await harness.account(order)).to.be.null;
+    expect(await harness.account(vault)).to.be.null;
+  });
+});
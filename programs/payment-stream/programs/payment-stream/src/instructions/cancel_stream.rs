@@ -0,0 +1,80 @@
+use crate::state::stream::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+    let stream = &ctx.accounts.stream;
+
+    // the recipient keeps everything that streamed until now,
+    // the sender gets back what hasn't streamed yet
+    let recipient_amount = stream.withdrawable_amount(Clock::get()?.unix_timestamp);
+    let sender_amount = ctx.accounts.vault.amount - recipient_amount;
+
+    let id_bytes = stream.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Stream::SEED_PREFIX,
+        stream.sender.as_ref(),
+        stream.recipient.as_ref(),
+        id_bytes.as_ref(),
+        &[stream.bump],
+    ]];
+
+    for (to, amount) in [
+        (&ctx.accounts.recipient_token_account, recipient_amount),
+        (&ctx.accounts.sender_token_account, sender_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: stream.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.sender.to_account_info(),
+            authority: stream.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(mut, has_one = sender, close = sender)]
+    pub stream: Account<'info, Stream>,
+    #[account(
+        mut,
+        seeds = [Stream::VAULT_SEED_PREFIX, stream.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    // the recipient doesn't sign, but the payout must still land in a wallet they own
+    #[account(
+        mut,
+        token::mint = stream.mint,
+        token::authority = stream.recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = stream.mint,
+        token::authority = sender
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,85 @@
+use crate::errors::PaymentStreamError;
+use crate::state::stream::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn create_stream(
+    ctx: Context<CreateStream>,
+    id: u64,
+    rate_per_second: u64,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    require_gt!(rate_per_second, 0, PaymentStreamError::ZeroRate);
+    require_gt!(end_ts, start_ts, PaymentStreamError::InvalidTimeRange);
+    require_gt!(
+        end_ts,
+        Clock::get()?.unix_timestamp,
+        PaymentStreamError::StreamAlreadyEnded
+    );
+
+    let stream = &mut ctx.accounts.stream;
+    stream.set_inner(Stream {
+        sender: ctx.accounts.sender.key(),
+        recipient: ctx.accounts.recipient.key(),
+        mint: ctx.accounts.mint.key(),
+        id,
+        rate_per_second,
+        start_ts,
+        end_ts,
+        withdrawn_amount: 0,
+        bump: ctx.bumps.stream,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.sender_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.sender.to_account_info(),
+            },
+        ),
+        stream.total_amount()?,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateStream<'info> {
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + Stream::INIT_SPACE,
+        seeds = [
+            Stream::SEED_PREFIX,
+            sender.key().as_ref(),
+            recipient.key().as_ref(),
+            id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+    #[account(
+        init,
+        payer = sender,
+        token::mint = mint,
+        token::authority = stream,
+        seeds = [Stream::VAULT_SEED_PREFIX, stream.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender
+    )]
+    pub sender_token_account: Account<'info, TokenAccount>,
+    /// CHECK: any wallet can receive a stream, it is only used as a seed and stored
+    pub recipient: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
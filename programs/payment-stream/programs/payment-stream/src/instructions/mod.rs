@@ -0,0 +1,7 @@
+pub use cancel_stream::*;
+pub use create_stream::*;
+pub use withdraw_streamed::*;
+
+pub mod cancel_stream;
+pub mod create_stream;
+pub mod withdraw_streamed;
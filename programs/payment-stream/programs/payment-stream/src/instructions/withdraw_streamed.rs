@@ -0,0 +1,54 @@
+use crate::errors::PaymentStreamError;
+use crate::state::stream::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn withdraw_streamed(ctx: Context<WithdrawStreamed>) -> Result<()> {
+    let stream = &mut ctx.accounts.stream;
+
+    let amount = stream.withdrawable_amount(Clock::get()?.unix_timestamp);
+    require_gt!(amount, 0, PaymentStreamError::NothingToWithdraw);
+    stream.withdrawn_amount += amount;
+
+    let id_bytes = stream.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Stream::SEED_PREFIX,
+        stream.sender.as_ref(),
+        stream.recipient.as_ref(),
+        id_bytes.as_ref(),
+        &[stream.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: stream.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStreamed<'info> {
+    #[account(mut, has_one = recipient)]
+    pub stream: Account<'info, Stream>,
+    #[account(
+        mut,
+        seeds = [Stream::VAULT_SEED_PREFIX, stream.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = stream.mint,
+        token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub recipient: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
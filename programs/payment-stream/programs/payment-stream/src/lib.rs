@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("9XgEpWaARqaYtefJina3dJcUPY5bTfACkVSbf3rPUFbA");
+
+#[program]
+pub mod payment_stream {
+    use super::*;
+
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        id: u64,
+        rate_per_second: u64,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::create_stream::create_stream(ctx, id, rate_per_second, start_ts, end_ts)
+    }
+
+    pub fn withdraw_streamed(ctx: Context<WithdrawStreamed>) -> Result<()> {
+        instructions::withdraw_streamed::withdraw_streamed(ctx)
+    }
+
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        instructions::cancel_stream::cancel_stream(ctx)
+    }
+}
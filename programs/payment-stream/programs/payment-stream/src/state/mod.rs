@@ -0,0 +1,3 @@
+pub use stream::*;
+
+pub mod stream;
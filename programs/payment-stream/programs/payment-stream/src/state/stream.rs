@@ -0,0 +1,45 @@
+use crate::errors::PaymentStreamError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub id: u64,
+    pub rate_per_second: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub withdrawn_amount: u64,
+    pub bump: u8,
+}
+
+impl Stream {
+    pub const SEED_PREFIX: &'static [u8] = b"stream";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    /// Everything the sender has to deposit up front.
+    pub fn total_amount(&self) -> Result<u64> {
+        let duration = self
+            .end_ts
+            .checked_sub(self.start_ts)
+            .ok_or(PaymentStreamError::MathOverflow)?;
+        // negative if the stream ends before it starts
+        let duration = u64::try_from(duration).map_err(|_| PaymentStreamError::InvalidTimeRange)?;
+        self.rate_per_second
+            .checked_mul(duration)
+            .ok_or_else(|| PaymentStreamError::MathOverflow.into())
+    }
+
+    /// Tokens that have streamed to the recipient by `now`, withdrawn or not.
+    pub fn streamed_amount(&self, now: i64) -> u64 {
+        let elapsed = now.clamp(self.start_ts, self.end_ts) - self.start_ts;
+        // cannot overflow, it is at most `total_amount`, which was checked on creation
+        self.rate_per_second * elapsed as u64
+    }
+
+    pub fn withdrawable_amount(&self, now: i64) -> u64 {
+        self.streamed_amount(now) - self.withdrawn_amount
+    }
+}
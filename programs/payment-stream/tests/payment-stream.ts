@@ -0,0 +1,184 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { PaymentStream } from '../../../target/types/payment_stream';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('payment-stream', () => {
+  let harness: Harness;
+  let program: Program<PaymentStream>;
+  let payer: anchor.web3.Keypair;
+  // the provider wallet streams the payments
+  let sender: anchor.web3.Keypair;
+  let nextId = 0;
+
+  let mint: anchor.web3.PublicKey;
+  let senderTokenAccount: anchor.web3.PublicKey;
+
+  async function createStream(ratePerSecond: number, startOffset: number, endOffset: number) {
+    const id = new BN(nextId++);
+    const recipient = anchor.web3.Keypair.generate();
+    const recipientTokenAccount = await harness.createTokenAccount(mint, recipient.publicKey);
+    const stream = findAddress(
+      [
+        Buffer.from('stream'),
+        sender.publicKey.toBuffer(),
+        recipient.publicKey.toBuffer(),
+        id.toArrayLike(Buffer, 'le', 8),
+      ],
+      program.programId
+    );
+    const vault = findAddress([Buffer.from('vault'), stream.toBuffer()], program.programId);
+    const start = (await harness.now()) + startOffset;
+
+    await program.methods
+      .createStream(id, new BN(ratePerSecond), new BN(start), new BN(start - startOffset + endOffset))
+      .accounts({
+        stream,
+        vault,
+        mint,
+        senderTokenAccount,
+        recipient: recipient.publicKey,
+        sender: sender.publicKey,
+      })
+      .rpc();
+
+    return { recipient, recipientTokenAccount, stream, vault };
+  }
+
+  async function withdrawStreamed({ recipient, recipientTokenAccount, stream, vault }) {
+    await program.methods
+      .withdrawStreamed()
+      .accounts({
+        stream,
+        vault,
+        recipientTokenAccount,
+        recipient: recipient.publicKey,
+      })
+      .signers([recipient])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<PaymentStream>('payment_stream');
+    payer = harness.payer;
+    sender = harness.payer;
+
+    mint = await harness.createMint(payer.publicKey);
+    senderTokenAccount = await harness.createTokenAccount(mint, sender.publicKey, 1_000_000);
+  });
+
+  it('stream and withdraw!', async () => {
+    const senderBalance = await harness.tokenBalance(senderTokenAccount);
+    const paymentStream = await createStream(10, 0, 3);
+
+    const streamState = await program.account.stream.fetch(paymentStream.stream);
+    expect(streamState.ratePerSecond.toNumber()).to.equal(10);
+    expect(streamState.endTs.sub(streamState.startTs).toNumber()).to.equal(3);
+    expect(await harness.tokenBalance(paymentStream.vault)).to.equal(30);
+    expect(await harness.tokenBalance(senderTokenAccount)).to.equal(senderBalance - 30);
+
+    await harness.warp(1);
+    await withdrawStreamed(paymentStream);
+    expect(await harness.tokenBalance(paymentStream.recipientTokenAccount)).to.equal(10);
+
+    // past the end, the rest is available
+    await harness.warp(3);
+    await withdrawStreamed(paymentStream);
+    expect(await harness.tokenBalance(paymentStream.recipientTokenAccount)).to.equal(30);
+    expect((await program.account.stream.fetch(paymentStream.stream)).withdrawnAmount.toNumber()).to.equal(30);
+
+    try {
+      await withdrawStreamed(paymentStream);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NothingToWithdraw');
+      expect(err.error.errorCode.number).to.equal(6003);
+      expect(err.program.equals(program.programId)).is.true;
+    }
+  });
+
+  it('nothing streams before the start', async () => {
+    const paymentStream = await createStream(10, 100, 200);
+
+    try {
+      await withdrawStreamed(paymentStream);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NothingToWithdraw');
+    }
+  });
+
+  it('cancel splits the remainder', async () => {
+    const senderBalance = await harness.tokenBalance(senderTokenAccount);
+    const paymentStream = await createStream(1, 0, 1_000);
+    await harness.warp(2);
+
+    await program.methods
+      .cancelStream()
+      .accounts({
+        stream: paymentStream.stream,
+        vault: paymentStream.vault,
+        recipientTokenAccount: paymentStream.recipientTokenAccount,
+        senderTokenAccount,
+        sender: sender.publicKey,
+      })
+      .rpc();
+
+    // two seconds at one token per second
+    expect(await harness.tokenBalance(paymentStream.recipientTokenAccount)).to.equal(2);
+    expect(await harness.tokenBalance(senderTokenAccount)).to.equal(senderBalance - 2);
+    expect(await harness.account(paymentStream.stream)).to.be.null;
+    expect(await harness.account(paymentStream.vault)).to.be.null;
+  });
+
+  it('cancel pays the recipient, not whoever the sender names', async () => {
+    const paymentStream = await createStream(1, 0, 1_000);
+    const senderOwnedAccount = await harness.createTokenAccount(
+      mint,
+      sender.publicKey,
+      0,
+      anchor.web3.Keypair.generate().publicKey
+    );
+
+    try {
+      await program.methods
+        .cancelStream()
+        .accounts({
+          stream: paymentStream.stream,
+          vault: paymentStream.vault,
+          recipientTokenAccount: senderOwnedAccount,
+          senderTokenAccount,
+          sender: sender.publicKey,
+        })
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintTokenOwner');
+    }
+  });
+
+  it('rejects an empty time range', async () => {
+    try {
+      await createStream(10, 10, 10);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InvalidTimeRange');
+      expect(err.error.errorCode.number).to.equal(6001);
+    }
+  });
+});
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("FWTwy8YHfUJjgde65VRMvpkxMPstB6ofzTY2md7Zqy6G");
+
+/// A stand-in for a Pyth price feed on localnet, where no oracle is publishing.
+///
+/// Its fields are those of a Pyth price: the value is `price * 10^expo`, and the true
+/// value lies within `conf * 10^expo` of it, according to the publishers.
+#[program]
+pub mod mock_oracle {
+    use super::*;
+
+    pub fn create_feed(ctx: Context<CreateFeed>, expo: i32) -> Result<()> {
+        ctx.accounts.feed.set_inner(PriceFeed {
+            authority: ctx.accounts.authority.key(),
+            price: 0,
+            conf: 0,
+            expo,
+            publish_time: 0,
+        });
+        Ok(())
+    }
+
+    // The publish time is an argument, so tests can simulate a price that went stale.
+    pub fn set_price(
+        ctx: Context<SetPrice>,
+        price: i64,
+        conf: u64,
+        publish_time: i64,
+    ) -> Result<()> {
+        let feed = &mut ctx.accounts.feed;
+        feed.price = price;
+        feed.conf = conf;
+        feed.publish_time = publish_time;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    pub authority: Pubkey,
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+#[derive(Accounts)]
+pub struct CreateFeed<'info> {
+    #[account(init, payer = authority, space = 8 + PriceFeed::INIT_SPACE)]
+    pub feed: Account<'info, PriceFeed>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    #[account(mut, has_one = authority)]
+    pub feed: Account<'info, PriceFeed>,
+    pub authority: Signer<'info>,
+}
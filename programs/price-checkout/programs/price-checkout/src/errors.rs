@@ -0,0 +1,12 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum PriceCheckoutError {
+    ZeroPrice,
+    InvalidConfidenceLimit,
+    StalePrice,
+    InvalidOraclePrice,
+    ConfidenceTooWide,
+    InsufficientPayment,
+    MathOverflow,
+}
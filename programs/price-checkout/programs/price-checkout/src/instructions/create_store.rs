@@ -0,0 +1,46 @@
+use crate::errors::PriceCheckoutError;
+use crate::state::store::*;
+use anchor_lang::prelude::*;
+use common::bps::MAX_BPS;
+use mock_oracle::PriceFeed;
+
+pub fn create_store(
+    ctx: Context<CreateStore>,
+    price_usd: u64,
+    max_staleness: u64,
+    max_confidence_bps: u16,
+) -> Result<()> {
+    require_gt!(price_usd, 0, PriceCheckoutError::ZeroPrice);
+    require!(
+        (1..=MAX_BPS).contains(&max_confidence_bps),
+        PriceCheckoutError::InvalidConfidenceLimit
+    );
+
+    ctx.accounts.store.set_inner(Store {
+        merchant: ctx.accounts.merchant.key(),
+        price_feed: ctx.accounts.price_feed.key(),
+        price_usd,
+        max_staleness,
+        max_confidence_bps,
+        sales: 0,
+        bump: ctx.bumps.store,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateStore<'info> {
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Store::INIT_SPACE,
+        seeds = [Store::SEED_PREFIX, merchant.key().as_ref()],
+        bump
+    )]
+    pub store: Account<'info, Store>,
+    // `Account` checks that the feed is owned by the oracle program, so it can't be forged
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,7 @@
+pub use create_store::*;
+pub use purchase::*;
+pub use withdraw::*;
+
+pub mod create_store;
+pub mod purchase;
+pub mod withdraw;
@@ -0,0 +1,54 @@
+use crate::errors::PriceCheckoutError;
+use crate::oracle;
+use crate::state::store::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use mock_oracle::PriceFeed;
+
+// The buyer sends `payment`, an upper bound on what they are willing to pay, since the price
+// may move between signing and execution. Whatever exceeds the price is refunded right away.
+pub fn purchase(ctx: Context<Purchase>, payment: u64) -> Result<()> {
+    let store = &ctx.accounts.store;
+    let price = oracle::usd_to_lamports(
+        &ctx.accounts.price_feed,
+        store.price_usd,
+        Clock::get()?.unix_timestamp,
+        store.max_staleness,
+        store.max_confidence_bps,
+    )?;
+    require_gte!(payment, price, PriceCheckoutError::InsufficientPayment);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.store.to_account_info(),
+            },
+        ),
+        payment,
+    )?;
+
+    // the store is owned by this program, so it can hand out lamports without a CPI
+    let refund = payment - price;
+    ctx.accounts.store.sub_lamports(refund)?;
+    ctx.accounts.buyer.add_lamports(refund)?;
+
+    ctx.accounts.store.sales += 1;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Purchase<'info> {
+    #[account(
+        mut,
+        has_one = price_feed,
+        seeds = [Store::SEED_PREFIX, store.merchant.as_ref()],
+        bump = store.bump
+    )]
+    pub store: Account<'info, Store>,
+    pub price_feed: Account<'info, PriceFeed>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
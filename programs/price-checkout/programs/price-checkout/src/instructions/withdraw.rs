@@ -0,0 +1,26 @@
+use crate::state::store::*;
+use anchor_lang::prelude::*;
+
+// Takes out everything but the rent, which keeps the store open.
+pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    let store = ctx.accounts.store.to_account_info();
+    let rent = Rent::get()?.minimum_balance(store.data_len());
+    let revenue = store.lamports() - rent;
+
+    ctx.accounts.store.sub_lamports(revenue)?;
+    ctx.accounts.merchant.add_lamports(revenue)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        has_one = merchant,
+        seeds = [Store::SEED_PREFIX, merchant.key().as_ref()],
+        bump = store.bump
+    )]
+    pub store: Account<'info, Store>,
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+}
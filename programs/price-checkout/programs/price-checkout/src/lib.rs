@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod oracle;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("5uTTVjyyffUfPFi9YPgdKNHfgJaBRr377xejfSnnzgBT");
+
+#[program]
+pub mod price_checkout {
+    use super::*;
+
+    pub fn create_store(
+        ctx: Context<CreateStore>,
+        price_usd: u64,
+        max_staleness: u64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        instructions::create_store::create_store(ctx, price_usd, max_staleness, max_confidence_bps)
+    }
+
+    pub fn purchase(ctx: Context<Purchase>, payment: u64) -> Result<()> {
+        instructions::purchase::purchase(ctx, payment)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        instructions::withdraw::withdraw(ctx)
+    }
+}
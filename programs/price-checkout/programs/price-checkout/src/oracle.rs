@@ -0,0 +1,69 @@
+//! Turning an oracle price into an amount of lamports.
+//!
+//! The checks follow Pyth's recommendations and apply to any oracle that reports a
+//! confidence interval:
+//! - a price that hasn't been updated for a while may be far off, so it is rejected,
+//! - a wide confidence interval means the publishers disagree, so it is rejected too,
+//! - within the interval, the price is taken at the end that favours the program.
+//!
+//! With a real Pyth feed, `pyth_sdk_solana::state::SolanaPriceAccount::account_info_to_feed`
+//! and `get_price_no_older_than` yield the same `price`, `conf` and `expo` as the mock.
+
+use crate::errors::PriceCheckoutError;
+use anchor_lang::prelude::*;
+use common::bps::BPS_DENOMINATOR;
+use mock_oracle::PriceFeed;
+
+/// The number of decimals of a USD amount, so 1_000_000 is one dollar.
+pub const USD_DECIMALS: u32 = 6;
+const LAMPORTS_DECIMALS: u32 = 9;
+
+/// The price of `usd` in lamports, rounded up.
+pub fn usd_to_lamports(
+    feed: &PriceFeed,
+    usd: u64,
+    now: i64,
+    max_staleness: u64,
+    max_confidence_bps: u16,
+) -> Result<u64> {
+    require_gte!(
+        max_staleness as i64,
+        now.saturating_sub(feed.publish_time),
+        PriceCheckoutError::StalePrice
+    );
+    require_gt!(feed.price, 0, PriceCheckoutError::InvalidOraclePrice);
+    let price = feed.price as u128;
+    let conf = feed.conf as u128;
+    require_gte!(
+        price * max_confidence_bps as u128,
+        conf * BPS_DENOMINATOR as u128,
+        PriceCheckoutError::ConfidenceTooWide
+    );
+
+    // the lowest SOL price the oracle deems plausible, which asks the buyer for the most lamports
+    let price = price - conf;
+    require_gt!(price, 0, PriceCheckoutError::InvalidOraclePrice);
+
+    // lamports = usd / 10^USD_DECIMALS / (price * 10^expo) * 10^LAMPORTS_DECIMALS
+    let exponent = LAMPORTS_DECIMALS as i64 - USD_DECIMALS as i64 - feed.expo as i64;
+    let (numerator, denominator) = if exponent >= 0 {
+        (
+            pow10(exponent as u32).and_then(|p| p.checked_mul(usd as u128)),
+            Some(price),
+        )
+    } else {
+        (
+            Some(usd as u128),
+            pow10(exponent.unsigned_abs() as u32).and_then(|p| p.checked_mul(price)),
+        )
+    };
+    let numerator = numerator.ok_or(PriceCheckoutError::MathOverflow)?;
+    let denominator = denominator.ok_or(PriceCheckoutError::MathOverflow)?;
+
+    u64::try_from(numerator.div_ceil(denominator))
+        .map_err(|_| error!(PriceCheckoutError::MathOverflow))
+}
+
+fn pow10(exponent: u32) -> Option<u128> {
+    10u128.checked_pow(exponent)
+}
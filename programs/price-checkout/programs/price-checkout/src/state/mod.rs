@@ -0,0 +1,3 @@
+pub use store::*;
+
+pub mod store;
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Sells one product for a fixed USD price, paid in SOL.
+///
+/// The store account itself collects the payments, on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct Store {
+    pub merchant: Pubkey,
+    pub price_feed: Pubkey,
+    // with `USD_DECIMALS` decimals
+    pub price_usd: u64,
+    // in seconds
+    pub max_staleness: u64,
+    pub max_confidence_bps: u16,
+    pub sales: u64,
+    pub bump: u8,
+}
+
+impl Store {
+    pub const SEED_PREFIX: &'static [u8] = b"store";
+}
@@ -0,0 +1,128 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { expectError } from 'test-harness';
+import { MockOracle } from '../../../target/types/mock_oracle';
+import { PriceCheckout } from '../../../target/types/price_checkout';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+// Runs against the local validator, not the bankrun harness: the merchant's balance is checked
+// against the fee of the withdrawal, which comes from `getTransaction`.
+describe('price-checkout', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const oracleProgram = anchor.workspace.MockOracle as Program<MockOracle>;
+  const program = anchor.workspace.PriceCheckout as Program<PriceCheckout>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+  const merchant = (programProvider.wallet as anchor.Wallet).payer;
+  const buyer = Keypair.generate();
+  const feed = Keypair.generate();
+
+  const [store] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('store'), merchant.publicKey.toBuffer()],
+    program.programId
+  );
+
+  // $3.00, with 6 decimals
+  const priceUsd = 3_000_000;
+  // SOL prices with 8 decimals, as Pyth publishes them
+  const expo = -8;
+
+  async function now() {
+    return connection.getBlockTime(await connection.getSlot());
+  }
+
+  async function setPrice(price: number, conf: number, publishTime?: number) {
+    await oracleProgram.methods
+      .setPrice(new BN(price), new BN(conf), new BN(publishTime ?? (await now())))
+      .accounts({ feed: feed.publicKey, authority: merchant.publicKey })
+      .rpc();
+  }
+
+  // what the program charges: the lower end of the confidence interval, rounded up
+  function expectedLamports(price: number, conf: number) {
+    const numerator = BigInt(priceUsd) * 10n ** BigInt(9 - 6 - expo);
+    const denominator = BigInt(price - conf);
+    return Number((numerator + denominator - 1n) / denominator);
+  }
+
+  async function purchase(payment: number) {
+    await program.methods
+      .purchase(new BN(payment))
+      .accounts({ store, priceFeed: feed.publicKey, buyer: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+  }
+
+  before(async () => {
+    await connection.confirmTransaction(await connection.requestAirdrop(buyer.publicKey, 10 * LAMPORTS_PER_SOL));
+    await oracleProgram.methods
+      .createFeed(expo)
+      .accounts({ feed: feed.publicKey, authority: merchant.publicKey })
+      .signers([feed])
+      .rpc();
+  });
+
+  it('pay for a $3 product in SOL!', async () => {
+    await expectError(
+      program.methods
+        .createStore(new BN(priceUsd), new BN(60), 0)
+        .accounts({ store, priceFeed: feed.publicKey, merchant: merchant.publicKey })
+        .rpc(),
+      'InvalidConfidenceLimit',
+      6001
+    );
+    // prices older than a minute or less certain than 1% are refused
+    await program.methods
+      .createStore(new BN(priceUsd), new BN(60), 100)
+      .accounts({ store, priceFeed: feed.publicKey, merchant: merchant.publicKey })
+      .rpc();
+
+    // $150 ± $0.15
+    await setPrice(15_000_000_000, 15_000_000);
+    const price = expectedLamports(15_000_000_000, 15_000_000);
+    expect(price).to.equal(20_020_021);
+
+    const storeBefore = await connection.getBalance(store);
+    await expectError(purchase(price - 1), 'InsufficientPayment', 6005);
+
+    // twice the price, half of it comes back
+    await purchase(2 * price);
+    expect(await connection.getBalance(store)).to.equal(storeBefore + price);
+    expect((await program.account.store.fetch(store)).sales.toNumber()).to.equal(1);
+
+    // the price of SOL doubles, the product costs half as much
+    await setPrice(30_000_000_000, 0);
+    await purchase(price);
+    expect(await connection.getBalance(store)).to.equal(storeBefore + price + 10_000_000);
+
+    const merchantBefore = await connection.getBalance(merchant.publicKey);
+    const signature = await program.methods
+      .withdraw()
+      .accounts({ store, merchant: merchant.publicKey })
+      .rpc({ commitment: 'confirmed' });
+    const fee = (await connection.getTransaction(signature, { commitment: 'confirmed' })).meta.fee;
+    expect(await connection.getBalance(store)).to.equal(storeBefore);
+    expect(await connection.getBalance(merchant.publicKey)).to.equal(merchantBefore + price + 10_000_000 - fee);
+  });
+
+  it('stale or uncertain prices are refused', async () => {
+    await setPrice(15_000_000_000, 0, (await now()) - 120);
+    await expectError(purchase(LAMPORTS_PER_SOL), 'StalePrice', 6002);
+
+    // ± 2% is beyond the 1% the store accepts
+    await setPrice(15_000_000_000, 300_000_000);
+    await expectError(purchase(LAMPORTS_PER_SOL), 'ConfidenceTooWide', 6004);
+
+    await setPrice(-1, 0);
+    await expectError(purchase(LAMPORTS_PER_SOL), 'InvalidOraclePrice', 6003);
+  });
+});
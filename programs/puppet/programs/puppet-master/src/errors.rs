@@ -0,0 +1,6 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum PuppetMasterError {
+    PuppetNotUpdated,
+}
@@ -0,0 +1,5 @@
+pub use pull_strings::*;
+pub use pull_strings_as_pda::*;
+
+pub mod pull_strings;
+pub mod pull_strings_as_pda;
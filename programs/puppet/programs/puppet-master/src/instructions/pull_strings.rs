@@ -0,0 +1,43 @@
+use crate::errors::PuppetMasterError;
+use anchor_lang::prelude::*;
+use puppet::cpi::accounts::SetData;
+use puppet::program::Puppet;
+use puppet::state::Data;
+
+pub fn pull_strings(ctx: Context<PullStrings>, data: u64) -> Result<u64> {
+    // `get` deserializes whatever `set_data` handed to `set_return_data`
+    let previous = puppet::cpi::set_data(ctx.accounts.set_data_ctx(), data)?.get();
+
+    // `puppet` was deserialized before the CPI changed it, it has to be reloaded to see the new value
+    ctx.accounts.puppet.reload()?;
+    require_eq!(
+        ctx.accounts.puppet.data,
+        data,
+        PuppetMasterError::PuppetNotUpdated
+    );
+
+    msg!("puppet data changed from {} to {}", previous, data);
+    Ok(previous)
+}
+
+#[derive(Accounts)]
+pub struct PullStrings<'info> {
+    #[account(mut)]
+    pub puppet: Account<'info, Data>,
+    pub puppet_program: Program<'info, Puppet>,
+    // Even though the puppet program already checks that authority is a signer
+    // using the Signer type here is still required because the anchor ts client
+    // can not infer signers from programs called via CPIs
+    pub authority: Signer<'info>,
+}
+
+impl<'info> PullStrings<'info> {
+    pub fn set_data_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetData<'info>> {
+        let cpi_program = self.puppet_program.to_account_info();
+        let cpi_accounts = SetData {
+            puppet: self.puppet.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::AUTHORITY_SEED;
+use anchor_lang::prelude::*;
+use puppet::cpi::accounts::SetData;
+use puppet::program::Puppet;
+use puppet::state::Data;
+
+pub fn pull_strings_as_pda(ctx: Context<PullStringsAsPda>, data: u64) -> Result<u64> {
+    // The runtime marks `authority` as a signer of the CPI because it is the PDA
+    // derived from these seeds and this program's id.
+    let signer_seeds: &[&[&[u8]]] = &[&[AUTHORITY_SEED, &[ctx.bumps.authority]]];
+    let previous =
+        puppet::cpi::set_data(ctx.accounts.set_data_ctx().with_signer(signer_seeds), data)?.get();
+
+    msg!("puppet data changed from {} to {}", previous, data);
+    Ok(previous)
+}
+
+#[derive(Accounts)]
+pub struct PullStringsAsPda<'info> {
+    #[account(mut)]
+    pub puppet: Account<'info, Data>,
+    pub puppet_program: Program<'info, Puppet>,
+    /// CHECK: holds no data, it only signs the CPI
+    #[account(seeds = [AUTHORITY_SEED], bump)]
+    pub authority: UncheckedAccount<'info>,
+}
+
+impl<'info> PullStringsAsPda<'info> {
+    pub fn set_data_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetData<'info>> {
+        let cpi_program = self.puppet_program.to_account_info();
+        let cpi_accounts = SetData {
+            puppet: self.puppet.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
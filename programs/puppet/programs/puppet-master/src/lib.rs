@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("E7n7kb2hmoG9sgzNfRqcuvzYQDMu4JP4zfm3fGr6qEaT");
+
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+
+/// The caller of the CPI example. It changes the puppet's data either with a
+/// wallet's signature that it extends to the puppet program, or as its own PDA.
+#[program]
+pub mod puppet_master {
+    use super::*;
+
+    pub fn pull_strings(ctx: Context<PullStrings>, data: u64) -> Result<u64> {
+        instructions::pull_strings::pull_strings(ctx, data)
+    }
+
+    pub fn pull_strings_as_pda(ctx: Context<PullStringsAsPda>, data: u64) -> Result<u64> {
+        instructions::pull_strings_as_pda::pull_strings_as_pda(ctx, data)
+    }
+}
@@ -0,0 +1,16 @@
+use crate::state::data::*;
+use anchor_lang::prelude::*;
+
+pub fn initialize(ctx: Context<Initialize>, authority: Pubkey) -> Result<()> {
+    ctx.accounts.puppet.set_inner(Data { authority, data: 0 });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = user, space = 8 + Data::INIT_SPACE)]
+    pub puppet: Account<'info, Data>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
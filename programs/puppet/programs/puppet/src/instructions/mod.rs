@@ -0,0 +1,5 @@
+pub use initialize::*;
+pub use set_data::*;
+
+pub mod initialize;
+pub mod set_data;
@@ -0,0 +1,18 @@
+use crate::state::data::*;
+use anchor_lang::prelude::*;
+
+// Returns the value it replaced. Anchor hands it back to the caller through
+// `set_return_data`, where a calling program reads it with `.get()`.
+pub fn set_data(ctx: Context<SetData>, data: u64) -> Result<u64> {
+    let puppet = &mut ctx.accounts.puppet;
+    let previous = puppet.data;
+    puppet.data = data;
+    Ok(previous)
+}
+
+#[derive(Accounts)]
+pub struct SetData<'info> {
+    #[account(mut, has_one = authority)]
+    pub puppet: Account<'info, Data>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("5ijFsNjczwLuVmeTrq4DgVietfWntiS4po3TNqjitFNY");
+
+/// The callee of the CPI example: it knows nothing about `puppet_master`
+/// and only checks that whoever changes the data is its authority.
+#[program]
+pub mod puppet {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>, authority: Pubkey) -> Result<()> {
+        instructions::initialize::initialize(ctx, authority)
+    }
+
+    pub fn set_data(ctx: Context<SetData>, data: u64) -> Result<u64> {
+        instructions::set_data::set_data(ctx, data)
+    }
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Data {
+    // a wallet, or a PDA of the program that pulls the strings
+    pub authority: Pubkey,
+    pub data: u64,
+}
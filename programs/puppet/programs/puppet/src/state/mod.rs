@@ -0,0 +1,3 @@
+pub use data::*;
+
+pub mod data;
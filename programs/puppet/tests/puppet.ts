@@ -0,0 +1,118 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { Harness } from 'test-harness';
+import { Puppet } from '../../../target/types/puppet';
+import { PuppetMaster } from '../../../target/types/puppet_master';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('puppet', () => {
+  let harness: Harness;
+  let puppetProgram: Program<Puppet>;
+  let puppetMasterProgram: Program<PuppetMaster>;
+  let pdaAuthority: anchor.web3.PublicKey;
+
+  before(async () => {
+    harness = await Harness.start();
+    puppetProgram = harness.program<Puppet>('puppet');
+    puppetMasterProgram = harness.program<PuppetMaster>('puppet_master');
+    [pdaAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('authority')],
+      puppetMasterProgram.programId
+    );
+  });
+
+  async function initializePuppet(authority: anchor.web3.PublicKey) {
+    const puppetKeypair = Keypair.generate();
+    await puppetProgram.methods
+      .initialize(authority)
+      .accounts({
+        puppet: puppetKeypair.publicKey,
+        user: harness.payer.publicKey,
+      })
+      .signers([puppetKeypair])
+      .rpc();
+    return puppetKeypair.publicKey;
+  }
+
+  // the last `Program return` log of the transaction is the outermost instruction's return data
+  function returnValue(logs: string[]) {
+    const prefix = `Program return: ${puppetMasterProgram.programId} `;
+    const log = logs.filter((l) => l.startsWith(prefix)).pop();
+    return new BN(Buffer.from(log.slice(prefix.length), 'base64'), 'le');
+  }
+
+  it('Does CPI!', async () => {
+    const authorityKeypair = Keypair.generate();
+    const puppet = await initializePuppet(authorityKeypair.publicKey);
+
+    const pullStrings = (data: number) =>
+      puppetMasterProgram.methods.pullStrings(new BN(data)).accounts({
+        puppetProgram: puppetProgram.programId,
+        puppet,
+        authority: authorityKeypair.publicKey,
+      });
+
+    await pullStrings(42).signers([authorityKeypair]).rpc();
+    expect((await puppetProgram.account.data.fetch(puppet)).data.toNumber()).to.equal(42);
+
+    // the puppet's return value travels through the puppet master back to the client
+    const { raw } = await pullStrings(43).signers([authorityKeypair]).simulate();
+    expect(returnValue(raw as string[]).toNumber()).to.equal(42);
+  });
+
+  it('Rejects a wrong authority', async () => {
+    const authorityKeypair = Keypair.generate();
+    const puppet = await initializePuppet(authorityKeypair.publicKey);
+    const impostor = Keypair.generate();
+
+    try {
+      await puppetMasterProgram.methods
+        .pullStrings(new BN(42))
+        .accounts({
+          puppetProgram: puppetProgram.programId,
+          puppet,
+          authority: impostor.publicKey,
+        })
+        .signers([impostor])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      // the error comes from the puppet program, the puppet master just passes it on
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintHasOne');
+      expect(err.program.equals(puppetProgram.programId)).is.true;
+    }
+  });
+
+  it('Does CPI as a PDA!', async () => {
+    const puppet = await initializePuppet(pdaAuthority);
+
+    // nobody signs for the authority, the puppet master does so during the CPI
+    await puppetMasterProgram.methods
+      .pullStringsAsPda(new BN(7))
+      .accounts({
+        puppetProgram: puppetProgram.programId,
+        puppet,
+        authority: pdaAuthority,
+      })
+      .rpc();
+    expect((await puppetProgram.account.data.fetch(puppet)).data.toNumber()).to.equal(7);
+
+    const { raw } = await puppetMasterProgram.methods
+      .pullStringsAsPda(new BN(8))
+      .accounts({
+        puppetProgram: puppetProgram.programId,
+        puppet,
+        authority: pdaAuthority,
+      })
+      .simulate();
+    expect(returnValue(raw as string[]).toNumber()).to.equal(7);
+  });
+});
@@ -0,0 +1,16 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum RaffleError {
+    ZeroAmount,
+    EndInThePast,
+    RaffleEnded,
+    RaffleNotEnded,
+    NotEnoughTickets,
+    NoTicketsSold,
+    TicketsSold,
+    AlreadySettled,
+    NotSettled,
+    NotWinningTicket,
+    MathOverflow,
+}
@@ -0,0 +1,61 @@
+use crate::state::raffle::*;
+use crate::state::ticket_range::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn buy_tickets(ctx: Context<BuyTickets>, count: u32) -> Result<()> {
+    let raffle = &mut ctx.accounts.raffle;
+    let start = raffle.tickets_sold;
+    let price = raffle.sell(count, Clock::get()?.unix_timestamp)?;
+
+    ctx.accounts.tickets.set_inner(TicketRange {
+        raffle: raffle.key(),
+        buyer: ctx.accounts.buyer.key(),
+        start,
+        count,
+        bump: ctx.bumps.tickets,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer_token_account.to_account_info(),
+                to: ctx.accounts.proceeds_vault.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        ),
+        price,
+    )
+}
+
+#[derive(Accounts)]
+pub struct BuyTickets<'info> {
+    #[account(mut)]
+    pub raffle: Account<'info, Raffle>,
+    // keyed by the first ticket of the range, which is unique per raffle
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + TicketRange::INIT_SPACE,
+        seeds = [TicketRange::SEED_PREFIX, raffle.key().as_ref(), raffle.tickets_sold.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tickets: Account<'info, TicketRange>,
+    #[account(
+        mut,
+        seeds = [Raffle::PROCEEDS_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump = raffle.proceeds_vault_bump
+    )]
+    pub proceeds_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = raffle.ticket_mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,68 @@
+use crate::errors::RaffleError;
+use crate::state::raffle::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+pub fn cancel_raffle(ctx: Context<CancelRaffle>) -> Result<()> {
+    let raffle = &ctx.accounts.raffle;
+    // once a ticket is sold, the organizer can't back out anymore
+    require_eq!(raffle.tickets_sold, 0, RaffleError::TicketsSold);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Raffle::SEED_PREFIX,
+        raffle.organizer.as_ref(),
+        &raffle.id.to_le_bytes(),
+        &[raffle.bump],
+    ]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.prize_vault.to_account_info(),
+                to: ctx.accounts.organizer_prize_account.to_account_info(),
+                authority: raffle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        raffle.prize_amount,
+    )?;
+    for vault in [&ctx.accounts.prize_vault, &ctx.accounts.proceeds_vault] {
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.organizer.to_account_info(),
+                authority: raffle.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelRaffle<'info> {
+    #[account(mut, has_one = organizer, close = organizer)]
+    pub raffle: Account<'info, Raffle>,
+    #[account(
+        mut,
+        seeds = [Raffle::PRIZE_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump = raffle.prize_vault_bump
+    )]
+    pub prize_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [Raffle::PROCEEDS_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump = raffle.proceeds_vault_bump
+    )]
+    pub proceeds_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = raffle.prize_mint,
+        token::authority = organizer
+    )]
+    pub organizer_prize_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
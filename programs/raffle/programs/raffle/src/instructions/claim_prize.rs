@@ -0,0 +1,70 @@
+use crate::errors::RaffleError;
+use crate::state::raffle::*;
+use crate::state::ticket_range::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
+    let raffle = &ctx.accounts.raffle;
+    let winning_ticket = raffle.winning_ticket.ok_or(RaffleError::NotSettled)?;
+    require!(
+        ctx.accounts.tickets.contains(winning_ticket),
+        RaffleError::NotWinningTicket
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Raffle::SEED_PREFIX,
+        raffle.organizer.as_ref(),
+        &raffle.id.to_le_bytes(),
+        &[raffle.bump],
+    ]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.prize_vault.to_account_info(),
+                to: ctx.accounts.winner_prize_account.to_account_info(),
+                authority: raffle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        raffle.prize_amount,
+    )?;
+    // the emptied vault's rent goes back to the organizer, who paid for it;
+    // closing it also makes a second claim impossible
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.prize_vault.to_account_info(),
+            destination: ctx.accounts.organizer.to_account_info(),
+            authority: raffle.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct ClaimPrize<'info> {
+    #[account(has_one = organizer)]
+    pub raffle: Account<'info, Raffle>,
+    #[account(mut, has_one = raffle, has_one = buyer, close = buyer)]
+    pub tickets: Account<'info, TicketRange>,
+    #[account(
+        mut,
+        seeds = [Raffle::PRIZE_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump = raffle.prize_vault_bump
+    )]
+    pub prize_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = raffle.prize_mint,
+        token::authority = buyer
+    )]
+    pub winner_prize_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// CHECK: checked against `raffle.organizer`
+    #[account(mut)]
+    pub organizer: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,94 @@
+use crate::errors::RaffleError;
+use crate::state::raffle::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn create_raffle(
+    ctx: Context<CreateRaffle>,
+    id: u64,
+    prize_amount: u64,
+    ticket_price: u64,
+    max_tickets: u32,
+    end_ts: i64,
+) -> Result<()> {
+    require_gt!(prize_amount, 0, RaffleError::ZeroAmount);
+    require_gt!(ticket_price, 0, RaffleError::ZeroAmount);
+    require_gt!(max_tickets, 0, RaffleError::ZeroAmount);
+    require_gt!(
+        end_ts,
+        Clock::get()?.unix_timestamp,
+        RaffleError::EndInThePast
+    );
+
+    ctx.accounts.raffle.set_inner(Raffle {
+        organizer: ctx.accounts.organizer.key(),
+        id,
+        prize_mint: ctx.accounts.prize_mint.key(),
+        prize_amount,
+        ticket_mint: ctx.accounts.ticket_mint.key(),
+        ticket_price,
+        max_tickets,
+        tickets_sold: 0,
+        end_ts,
+        winning_ticket: None,
+        bump: ctx.bumps.raffle,
+        prize_vault_bump: ctx.bumps.prize_vault,
+        proceeds_vault_bump: ctx.bumps.proceeds_vault,
+    });
+
+    // the prize is escrowed up front, so buyers know it exists
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.organizer_prize_account.to_account_info(),
+                to: ctx.accounts.prize_vault.to_account_info(),
+                authority: ctx.accounts.organizer.to_account_info(),
+            },
+        ),
+        prize_amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateRaffle<'info> {
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Raffle::INIT_SPACE,
+        seeds = [Raffle::SEED_PREFIX, organizer.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub raffle: Account<'info, Raffle>,
+    #[account(
+        init,
+        payer = organizer,
+        seeds = [Raffle::PRIZE_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump,
+        token::mint = prize_mint,
+        token::authority = raffle
+    )]
+    pub prize_vault: Account<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = organizer,
+        seeds = [Raffle::PROCEEDS_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump,
+        token::mint = ticket_mint,
+        token::authority = raffle
+    )]
+    pub proceeds_vault: Account<'info, TokenAccount>,
+    pub prize_mint: Account<'info, Mint>,
+    pub ticket_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = prize_mint,
+        token::authority = organizer
+    )]
+    pub organizer_prize_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
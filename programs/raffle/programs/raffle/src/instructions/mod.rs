@@ -0,0 +1,13 @@
+pub use buy_tickets::*;
+pub use cancel_raffle::*;
+pub use claim_prize::*;
+pub use create_raffle::*;
+pub use settle_raffle::*;
+pub use withdraw_proceeds::*;
+
+pub mod buy_tickets;
+pub mod cancel_raffle;
+pub mod claim_prize;
+pub mod create_raffle;
+pub mod settle_raffle;
+pub mod withdraw_proceeds;
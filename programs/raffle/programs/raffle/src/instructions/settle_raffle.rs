@@ -0,0 +1,27 @@
+use crate::state::raffle::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+pub fn settle_raffle(ctx: Context<SettleRaffle>) -> Result<()> {
+    // SlotHashes is too large to deserialize on-chain, so the most recent hash is read
+    // straight from the account data: a u64 length, then (slot: u64, hash: [u8; 32]) entries.
+    let data = ctx.accounts.slot_hashes.try_borrow_data()?;
+    let recent_hash = &data[16..48];
+
+    let winning_ticket = ctx
+        .accounts
+        .raffle
+        .settle(recent_hash, Clock::get()?.unix_timestamp)?;
+    msg!("winning ticket: {}", winning_ticket);
+    Ok(())
+}
+
+// Permissionless, anyone may settle once the raffle has ended.
+#[derive(Accounts)]
+pub struct SettleRaffle<'info> {
+    #[account(mut)]
+    pub raffle: Account<'info, Raffle>,
+    /// CHECK: the address is checked, the data is read manually
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
@@ -0,0 +1,59 @@
+use crate::errors::RaffleError;
+use crate::state::raffle::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+pub fn withdraw_proceeds(ctx: Context<WithdrawProceeds>) -> Result<()> {
+    let raffle = &ctx.accounts.raffle;
+    // the organizer is only paid once the winner is fixed, the prize can't be pulled anymore anyway
+    require!(raffle.winning_ticket.is_some(), RaffleError::NotSettled);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Raffle::SEED_PREFIX,
+        raffle.organizer.as_ref(),
+        &raffle.id.to_le_bytes(),
+        &[raffle.bump],
+    ]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.proceeds_vault.to_account_info(),
+                to: ctx.accounts.organizer_token_account.to_account_info(),
+                authority: raffle.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.proceeds_vault.amount,
+    )?;
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.proceeds_vault.to_account_info(),
+            destination: ctx.accounts.organizer.to_account_info(),
+            authority: raffle.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct WithdrawProceeds<'info> {
+    #[account(has_one = organizer)]
+    pub raffle: Account<'info, Raffle>,
+    #[account(
+        mut,
+        seeds = [Raffle::PROCEEDS_VAULT_SEED_PREFIX, raffle.key().as_ref()],
+        bump = raffle.proceeds_vault_bump
+    )]
+    pub proceeds_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = raffle.ticket_mint,
+        token::authority = organizer
+    )]
+    pub organizer_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
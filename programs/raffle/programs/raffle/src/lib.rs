@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("2WipQscrBGHJexWJY4FGbxHSNPYXYPhD9UPgoHaDuQcN");
+
+#[program]
+pub mod raffle {
+    use super::*;
+
+    pub fn create_raffle(
+        ctx: Context<CreateRaffle>,
+        id: u64,
+        prize_amount: u64,
+        ticket_price: u64,
+        max_tickets: u32,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::create_raffle::create_raffle(
+            ctx,
+            id,
+            prize_amount,
+            ticket_price,
+            max_tickets,
+            end_ts,
+        )
+    }
+
+    pub fn buy_tickets(ctx: Context<BuyTickets>, count: u32) -> Result<()> {
+        instructions::buy_tickets::buy_tickets(ctx, count)
+    }
+
+    pub fn settle_raffle(ctx: Context<SettleRaffle>) -> Result<()> {
+        instructions::settle_raffle::settle_raffle(ctx)
+    }
+
+    pub fn claim_prize(ctx: Context<ClaimPrize>) -> Result<()> {
+        instructions::claim_prize::claim_prize(ctx)
+    }
+
+    pub fn withdraw_proceeds(ctx: Context<WithdrawProceeds>) -> Result<()> {
+        instructions::withdraw_proceeds::withdraw_proceeds(ctx)
+    }
+
+    pub fn cancel_raffle(ctx: Context<CancelRaffle>) -> Result<()> {
+        instructions::cancel_raffle::cancel_raffle(ctx)
+    }
+}
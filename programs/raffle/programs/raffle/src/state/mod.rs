@@ -0,0 +1,5 @@
+pub use raffle::*;
+pub use ticket_range::*;
+
+pub mod raffle;
+pub mod ticket_range;
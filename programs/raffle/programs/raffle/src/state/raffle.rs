@@ -0,0 +1,73 @@
+use crate::errors::RaffleError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use common::time;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Raffle {
+    pub organizer: Pubkey,
+    pub id: u64,
+    // a token amount, or 1 for an NFT
+    pub prize_mint: Pubkey,
+    pub prize_amount: u64,
+    pub ticket_mint: Pubkey,
+    pub ticket_price: u64,
+    pub max_tickets: u32,
+    pub tickets_sold: u32,
+    pub end_ts: i64,
+    pub winning_ticket: Option<u32>,
+    pub bump: u8,
+    pub prize_vault_bump: u8,
+    pub proceeds_vault_bump: u8,
+}
+
+impl Raffle {
+    pub const SEED_PREFIX: &'static [u8] = b"raffle";
+    pub const PRIZE_VAULT_SEED_PREFIX: &'static [u8] = b"prize_vault";
+    pub const PROCEEDS_VAULT_SEED_PREFIX: &'static [u8] = b"proceeds_vault";
+
+    pub fn has_ended(&self, now: i64) -> bool {
+        time::has_ended(now, self.end_ts)
+    }
+
+    pub fn sell(&mut self, count: u32, now: i64) -> Result<u64> {
+        require_gt!(count, 0, RaffleError::ZeroAmount);
+        require!(!self.has_ended(now), RaffleError::RaffleEnded);
+        let tickets_sold = self
+            .tickets_sold
+            .checked_add(count)
+            .ok_or(RaffleError::MathOverflow)?;
+        require_gte!(
+            self.max_tickets,
+            tickets_sold,
+            RaffleError::NotEnoughTickets
+        );
+        self.tickets_sold = tickets_sold;
+        self.ticket_price
+            .checked_mul(count as u64)
+            .ok_or_else(|| error!(RaffleError::MathOverflow))
+    }
+
+    // Picks the winning ticket from `seed`, which the caller takes from the SlotHashes sysvar.
+    //
+    // This is NOT secure randomness and only fits raffles where little is at stake:
+    // - the slot leader produces the hash and can skip its slot if it dislikes the outcome,
+    // - anyone can compute the result for the current slot off-chain and only send
+    //   `settle_raffle` once it favours them (or buy more tickets right before the end).
+    // Committing to a future slot before the raffle ends narrows the second attack but not
+    // the first; anything of real value should use a VRF oracle instead.
+    pub fn settle(&mut self, seed: &[u8], now: i64) -> Result<u32> {
+        require!(self.has_ended(now), RaffleError::RaffleNotEnded);
+        require!(self.winning_ticket.is_none(), RaffleError::AlreadySettled);
+        require_gt!(self.tickets_sold, 0, RaffleError::NoTicketsSold);
+
+        // Mix in the raffle itself so that raffles settled in the same slot differ.
+        let hash = hashv(&[seed, self.organizer.as_ref(), &self.id.to_le_bytes()]);
+        let value = u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap());
+        // The modulo bias is negligible as long as `tickets_sold` is far below 2^64.
+        let winning_ticket = (value % self.tickets_sold as u64) as u32;
+        self.winning_ticket = Some(winning_ticket);
+        Ok(winning_ticket)
+    }
+}
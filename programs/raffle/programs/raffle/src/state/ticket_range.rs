@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+// One purchase: tickets `start..start + count` belong to `buyer`.
+// Storing ranges instead of one account per ticket keeps bulk purchases cheap.
+#[account]
+#[derive(InitSpace)]
+pub struct TicketRange {
+    pub raffle: Pubkey,
+    pub buyer: Pubkey,
+    pub start: u32,
+    pub count: u32,
+    pub bump: u8,
+}
+
+impl TicketRange {
+    pub const SEED_PREFIX: &'static [u8] = b"tickets";
+
+    pub fn contains(&self, ticket: u32) -> bool {
+        ticket >= self.start && ticket - self.start < self.count
+    }
+}
@@ -0,0 +1,229 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, expectError, findAddress } from 'test-harness';
+import { Raffle } from '../../../target/types/raffle';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL, SYSVAR_SLOT_HASHES_PUBKEY } = anchor.web3;
+
+const TICKET_PRICE = 100;
+
+describe('raffle', () => {
+  let harness: Harness;
+  let program: Program<Raffle>;
+  let payer: anchor.web3.Keypair;
+
+  const organizer = anchor.web3.Keypair.generate();
+  let prizeMint: anchor.web3.PublicKey;
+  let ticketMint: anchor.web3.PublicKey;
+  let organizerPrizeAccount: anchor.web3.PublicKey;
+  let organizerTicketAccount: anchor.web3.PublicKey;
+
+  type Buyer = {
+    keypair: anchor.web3.Keypair;
+    ticketAccount: anchor.web3.PublicKey;
+    prizeAccount: anchor.web3.PublicKey;
+    ranges: anchor.web3.PublicKey[];
+  };
+
+  function raffleAccounts(id: number) {
+    const raffle = findAddress(
+      [Buffer.from('raffle'), organizer.publicKey.toBuffer(), new BN(id).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    return {
+      raffle,
+      prizeVault: findAddress([Buffer.from('prize_vault'), raffle.toBuffer()], program.programId),
+      proceedsVault: findAddress([Buffer.from('proceeds_vault'), raffle.toBuffer()], program.programId),
+    };
+  }
+
+  async function newBuyer(): Promise<Buyer> {
+    const keypair = await harness.fundedKeypair(LAMPORTS_PER_SOL);
+    return {
+      keypair,
+      ticketAccount: await harness.createTokenAccount(ticketMint, keypair.publicKey, 10 * TICKET_PRICE),
+      prizeAccount: await harness.createTokenAccount(prizeMint, keypair.publicKey),
+      ranges: [],
+    };
+  }
+
+  async function createRaffle(id: number, endTs: number, prizeAmount = 1) {
+    const { raffle, prizeVault, proceedsVault } = raffleAccounts(id);
+    await program.methods
+      .createRaffle(new BN(id), new BN(prizeAmount), new BN(TICKET_PRICE), 10, new BN(endTs))
+      .accounts({
+        raffle,
+        prizeVault,
+        proceedsVault,
+        prizeMint,
+        ticketMint,
+        organizerPrizeAccount,
+        organizer: organizer.publicKey,
+      })
+      .signers([organizer])
+      .rpc();
+  }
+
+  async function buyTickets(id: number, buyer: Buyer, count: number) {
+    const { raffle, proceedsVault } = raffleAccounts(id);
+    const ticketsSold = (await program.account.raffle.fetch(raffle)).ticketsSold;
+    const tickets = findAddress(
+      [Buffer.from('tickets'), raffle.toBuffer(), new BN(ticketsSold).toArrayLike(Buffer, 'le', 4)],
+      program.programId
+    );
+    await program.methods
+      .buyTickets(count)
+      .accounts({
+        raffle,
+        tickets,
+        proceedsVault,
+        buyerTokenAccount: buyer.ticketAccount,
+        buyer: buyer.keypair.publicKey,
+      })
+      .signers([buyer.keypair])
+      .rpc();
+    buyer.ranges.push(tickets);
+  }
+
+  async function claimPrize(id: number, buyer: Buyer, tickets: anchor.web3.PublicKey) {
+    const { raffle, prizeVault } = raffleAccounts(id);
+    await program.methods
+      .claimPrize()
+      .accounts({
+        raffle,
+        tickets,
+        prizeVault,
+        winnerPrizeAccount: buyer.prizeAccount,
+        buyer: buyer.keypair.publicKey,
+        organizer: organizer.publicKey,
+      })
+      .signers([buyer.keypair])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Raffle>('raffle');
+    payer = harness.payer;
+
+    await harness.fund(organizer.publicKey, LAMPORTS_PER_SOL);
+    // the prize is an NFT: a mint with no decimals and a single token per raffle
+    prizeMint = await harness.createMint(payer.publicKey);
+    ticketMint = await harness.createMint(payer.publicKey, 6);
+    organizerPrizeAccount = await harness.createTokenAccount(prizeMint, organizer.publicKey, 3);
+    organizerTicketAccount = await harness.createTokenAccount(ticketMint, organizer.publicKey);
+  });
+
+  it('buy tickets, settle and claim!', async () => {
+    const id = 0;
+    const { raffle, prizeVault, proceedsVault } = raffleAccounts(id);
+    await createRaffle(id, (await harness.now()) + 5);
+    expect(await harness.tokenBalance(prizeVault)).to.equal(1);
+
+    const alice = await newBuyer();
+    const bob = await newBuyer();
+    await buyTickets(id, alice, 3);
+    await buyTickets(id, bob, 4);
+    await buyTickets(id, alice, 2);
+    await expectError(buyTickets(id, bob, 2), 'NotEnoughTickets', 6004);
+    expect(await harness.tokenBalance(proceedsVault)).to.equal(9 * TICKET_PRICE);
+
+    await expectError(
+      program.methods.settleRaffle().accounts({ raffle, slotHashes: SYSVAR_SLOT_HASHES_PUBKEY }).rpc(),
+      'RaffleNotEnded',
+      6003
+    );
+    await harness.warp(6);
+    await expectError(buyTickets(id, bob, 1), 'RaffleEnded', 6002);
+
+    // permissionless
+    await program.methods.settleRaffle().accounts({ raffle, slotHashes: SYSVAR_SLOT_HASHES_PUBKEY }).rpc();
+    await expectError(
+      program.methods.settleRaffle().accounts({ raffle, slotHashes: SYSVAR_SLOT_HASHES_PUBKEY }).rpc(),
+      'AlreadySettled',
+      6007
+    );
+
+    const winningTicket = (await program.account.raffle.fetch(raffle)).winningTicket;
+    expect(winningTicket).to.be.lessThan(9);
+
+    let winner: Buyer;
+    let winningRange: anchor.web3.PublicKey;
+    let losingRange: anchor.web3.PublicKey;
+    for (const buyer of [alice, bob]) {
+      for (const range of buyer.ranges) {
+        const { start, count } = await program.account.ticketRange.fetch(range);
+        if (winningTicket >= start && winningTicket < start + count) {
+          winner = buyer;
+          winningRange = range;
+        } else if (losingRange === undefined) {
+          losingRange = range;
+        }
+      }
+    }
+    const loser = winner === alice ? bob : alice;
+
+    // a range that doesn't hold the winning ticket
+    const losingBuyer = alice.ranges.includes(losingRange) ? alice : bob;
+    await expectError(claimPrize(id, losingBuyer, losingRange), 'NotWinningTicket', 6009);
+
+    // the winning range can't be used by somebody else
+    try {
+      await claimPrize(id, loser, winningRange);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintHasOne');
+    }
+
+    await claimPrize(id, winner, winningRange);
+    expect(await harness.tokenBalance(winner.prizeAccount)).to.equal(1);
+    expect(await harness.account(prizeVault)).to.be.null;
+
+    await program.methods
+      .withdrawProceeds()
+      .accounts({
+        raffle,
+        proceedsVault,
+        organizerTokenAccount: organizerTicketAccount,
+        organizer: organizer.publicKey,
+      })
+      .signers([organizer])
+      .rpc();
+    expect(await harness.tokenBalance(organizerTicketAccount)).to.equal(9 * TICKET_PRICE);
+  });
+
+  it('cancel a raffle nobody joined', async () => {
+    const cancel = (id: number) => {
+      const { raffle, prizeVault, proceedsVault } = raffleAccounts(id);
+      return program.methods
+        .cancelRaffle()
+        .accounts({
+          raffle,
+          prizeVault,
+          proceedsVault,
+          organizerPrizeAccount,
+          organizer: organizer.publicKey,
+        })
+        .signers([organizer])
+        .rpc();
+    };
+
+    // the organizer must not be able to pull the prize from under the buyers
+    await createRaffle(1, (await harness.now()) + 60);
+    await buyTickets(1, await newBuyer(), 1);
+    await expectError(cancel(1), 'TicketsSold', 6006);
+
+    await createRaffle(2, (await harness.now()) + 60);
+    expect(await harness.tokenBalance(organizerPrizeAccount)).to.equal(0);
+    await cancel(2);
+    expect(await harness.tokenBalance(organizerPrizeAccount)).to.equal(1);
+    expect(await harness.account(raffleAccounts(2).raffle)).to.be.null;
+  });
+});
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct WalkSettled {
+    pub house: Pubkey,
+    pub player: Pubkey,
+    pub stake: u64,
+    pub position: i32,
+    pub payout: u64,
+}
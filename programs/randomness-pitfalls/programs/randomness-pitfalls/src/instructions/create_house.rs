@@ -0,0 +1,38 @@
+use crate::state::house::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn create_house(ctx: Context<CreateHouse>, oracle: Pubkey, funding: u64) -> Result<()> {
+    ctx.accounts.house.set_inner(House {
+        authority: ctx.accounts.authority.key(),
+        oracle,
+        request_count: 0,
+        bump: ctx.bumps.house,
+    });
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.house.to_account_info(),
+            },
+        ),
+        funding,
+    )
+}
+
+#[derive(Accounts)]
+pub struct CreateHouse<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + House::INIT_SPACE,
+        seeds = [House::SEED_PREFIX, authority.key().as_ref()],
+        bump
+    )]
+    pub house: Account<'info, House>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
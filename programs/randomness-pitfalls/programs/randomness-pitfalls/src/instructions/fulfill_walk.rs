@@ -0,0 +1,56 @@
+use crate::events::WalkSettled;
+use crate::random;
+use crate::state::house::*;
+use crate::state::walk_request::*;
+use crate::vrf;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+// Anyone may relay the oracle's signature, it is what decides the walk.
+pub fn fulfill_walk(ctx: Context<FulfillWalk>) -> Result<()> {
+    let request = &ctx.accounts.request;
+    let randomness = vrf::randomness(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.house.oracle,
+        request.key().as_ref(),
+    )?;
+    let position = random::walk(&randomness);
+    let payout = House::payout(request.stake, position)?;
+    House::pay(
+        &ctx.accounts.house,
+        &ctx.accounts.player.to_account_info(),
+        payout,
+    )?;
+
+    emit!(WalkSettled {
+        house: ctx.accounts.house.key(),
+        player: request.player,
+        stake: request.stake,
+        position,
+        payout,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FulfillWalk<'info> {
+    #[account(
+        mut,
+        seeds = [House::SEED_PREFIX, house.authority.as_ref()],
+        bump = house.bump
+    )]
+    pub house: Account<'info, House>,
+    #[account(
+        mut,
+        close = player,
+        has_one = house,
+        has_one = player
+    )]
+    pub request: Account<'info, WalkRequest>,
+    /// CHECK: checked by `has_one`, only receives the payout and the request's rent
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    /// CHECK: the address is checked, it holds the instructions of the current transaction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
@@ -0,0 +1,9 @@
+pub use create_house::*;
+pub use fulfill_walk::*;
+pub use request_walk::*;
+pub use walk_insecure::*;
+
+pub mod create_house;
+pub mod fulfill_walk;
+pub mod request_walk;
+pub mod walk_insecure;
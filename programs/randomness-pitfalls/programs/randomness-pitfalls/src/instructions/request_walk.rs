@@ -0,0 +1,51 @@
+use crate::errors::WalkError;
+use crate::state::house::*;
+use crate::state::walk_request::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+// The stake goes to the house before any randomness exists, so there is nothing to react to.
+pub fn request_walk(ctx: Context<RequestWalk>, stake: u64) -> Result<()> {
+    require_gt!(stake, 0, WalkError::ZeroStake);
+
+    let house = &mut ctx.accounts.house;
+    ctx.accounts.request.set_inner(WalkRequest {
+        house: house.key(),
+        player: ctx.accounts.player.key(),
+        stake,
+        bump: ctx.bumps.request,
+    });
+    house.request_count += 1;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.house.to_account_info(),
+            },
+        ),
+        stake,
+    )
+}
+
+#[derive(Accounts)]
+pub struct RequestWalk<'info> {
+    #[account(
+        mut,
+        seeds = [House::SEED_PREFIX, house.authority.as_ref()],
+        bump = house.bump
+    )]
+    pub house: Account<'info, House>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + WalkRequest::INIT_SPACE,
+        seeds = [WalkRequest::SEED_PREFIX, house.key().as_ref(), house.request_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub request: Account<'info, WalkRequest>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
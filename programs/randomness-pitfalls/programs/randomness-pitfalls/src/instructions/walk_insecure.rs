@@ -0,0 +1,59 @@
+use crate::errors::WalkError;
+use crate::events::WalkSettled;
+use crate::random;
+use crate::state::house::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program::{self, Transfer};
+
+// Draws and settles the walk in one go. See `random::slot_hash_randomness` for why this
+// loses the house its money.
+pub fn walk_insecure(ctx: Context<WalkInsecure>, stake: u64) -> Result<()> {
+    require_gt!(stake, 0, WalkError::ZeroStake);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.house.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+
+    let player = ctx.accounts.player.key();
+    let randomness = random::slot_hash_randomness(&ctx.accounts.slot_hashes, &player)?;
+    let position = random::walk(&randomness);
+    let payout = House::payout(stake, position)?;
+    House::pay(
+        &ctx.accounts.house,
+        &ctx.accounts.player.to_account_info(),
+        payout,
+    )?;
+
+    emit!(WalkSettled {
+        house: ctx.accounts.house.key(),
+        player,
+        stake,
+        position,
+        payout,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WalkInsecure<'info> {
+    #[account(
+        mut,
+        seeds = [House::SEED_PREFIX, house.authority.as_ref()],
+        bump = house.bump
+    )]
+    pub house: Account<'info, House>,
+    #[account(mut)]
+    pub player: Signer<'info>,
+    /// CHECK: the address is checked, the data is read manually
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
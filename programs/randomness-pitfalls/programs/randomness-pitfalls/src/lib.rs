@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod random;
+pub mod state;
+pub mod vrf;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("FugM3KHqphCNokYivTDa9VTszfPT2aUnoEu9iAj25Xqg");
+
+/// A random walk game played against a house, in two flavours: `walk_insecure` draws its
+/// randomness from the SlotHashes sysvar and can be gamed (see the `walk-attacker` program),
+/// `request_walk` and `fulfill_walk` take it from an oracle after the stake is committed.
+#[program]
+pub mod randomness_pitfalls {
+    use super::*;
+
+    pub fn create_house(ctx: Context<CreateHouse>, oracle: Pubkey, funding: u64) -> Result<()> {
+        instructions::create_house::create_house(ctx, oracle, funding)
+    }
+
+    pub fn walk_insecure(ctx: Context<WalkInsecure>, stake: u64) -> Result<()> {
+        instructions::walk_insecure::walk_insecure(ctx, stake)
+    }
+
+    pub fn request_walk(ctx: Context<RequestWalk>, stake: u64) -> Result<()> {
+        instructions::request_walk::request_walk(ctx, stake)
+    }
+
+    pub fn fulfill_walk(ctx: Context<FulfillWalk>) -> Result<()> {
+        instructions::fulfill_walk::fulfill_walk(ctx)
+    }
+}
@@ -0,0 +1,33 @@
+//! The game itself, and the tempting but broken way of feeding it randomness.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+pub const STEPS: u32 = 32;
+
+/// Where a walk of `STEPS` steps ends, taking one step left or right per bit of `randomness`.
+pub fn walk(randomness: &[u8; 32]) -> i32 {
+    let bits = u32::from_le_bytes(randomness[..4].try_into().unwrap());
+    2 * bits.count_ones() as i32 - STEPS as i32
+}
+
+/// A walk wins if it ends right of where it started. Ending exactly at the start is the
+/// house's edge.
+pub fn wins(position: i32) -> bool {
+    position > 0
+}
+
+/// DON'T DO THIS.
+///
+/// The most recent entry of SlotHashes is the hash of an earlier slot, so it is already known
+/// while the transaction is being built, and it is the same for every transaction in the slot.
+/// Anything running in the same transaction can repeat this computation and only play when it
+/// wins, or play and abort the transaction when it lost. Mixing in the player's key or the
+/// clock changes nothing, as those are just as public.
+pub fn slot_hash_randomness(slot_hashes: &AccountInfo, player: &Pubkey) -> Result<[u8; 32]> {
+    // SlotHashes is too large to deserialize on-chain, so the most recent hash is read
+    // straight from the account data: a u64 length, then (slot: u64, hash: [u8; 32]) entries.
+    let data = slot_hashes.try_borrow_data()?;
+    let recent_hash = &data[16..48];
+    Ok(hashv(&[recent_hash, player.as_ref()]).to_bytes())
+}
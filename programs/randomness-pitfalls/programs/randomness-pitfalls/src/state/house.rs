@@ -0,0 +1,45 @@
+use crate::errors::WalkError;
+use crate::random;
+use anchor_lang::prelude::*;
+
+/// Holds the lamports that walks are paid out of.
+#[account]
+#[derive(InitSpace)]
+pub struct House {
+    pub authority: Pubkey,
+    // the key whose signatures randomize `fulfill_walk`
+    pub oracle: Pubkey,
+    pub request_count: u64,
+    pub bump: u8,
+}
+
+impl House {
+    pub const SEED_PREFIX: &'static [u8] = b"house";
+
+    /// A win pays back twice the stake, a loss nothing.
+    pub fn payout(stake: u64, position: i32) -> Result<u64> {
+        if !random::wins(position) {
+            return Ok(0);
+        }
+        stake
+            .checked_mul(2)
+            .ok_or_else(|| error!(WalkError::MathOverflow))
+    }
+
+    /// Pays `amount` out of the house, which has to stay rent exempt.
+    pub fn pay<'info>(
+        house: &Account<'info, House>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let reserve = Rent::get()?.minimum_balance(8 + House::INIT_SPACE);
+        require_gte!(
+            house.get_lamports().saturating_sub(reserve),
+            amount,
+            WalkError::HouseCannotCover
+        );
+        house.sub_lamports(amount)?;
+        to.add_lamports(amount)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// A walk whose stake is already with the house, waiting for the oracle. Its address is what
+/// the oracle signs.
+#[account]
+#[derive(InitSpace)]
+pub struct WalkRequest {
+    pub house: Pubkey,
+    pub player: Pubkey,
+    pub stake: u64,
+    pub bump: u8,
+}
+
+impl WalkRequest {
+    pub const SEED_PREFIX: &'static [u8] = b"walk_request";
+}
@@ -0,0 +1,81 @@
+//! Randomness from an oracle, proven with an ed25519 signature.
+//!
+//! The oracle signs the address of a walk request once the stake is locked in, and the hash of
+//! that signature is the randomness. Nobody without the oracle's key can predict it, and the
+//! player can no longer back out when they learn it. The transaction carries the signature in
+//! an instruction of the native ed25519 program, which fails the whole transaction if it is
+//! invalid, so the program only has to find it through the instructions sysvar.
+//!
+//! A plain signature is a stand-in for a real VRF: ed25519 signing is deterministic, but an
+//! oracle could still sign with a different nonce until it likes the outcome. VRF oracles
+//! (e.g. Switchboard or ORAO) prove that their output is the only possible one.
+
+use crate::errors::WalkError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+const OFFSETS_START: usize = 2;
+const OFFSETS_LEN: usize = 14;
+
+/// The hash of `oracle`'s signature over `message`, from an ed25519 instruction placed before
+/// the current one.
+pub fn randomness(
+    instructions_sysvar: &AccountInfo,
+    oracle: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; 32]> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    for index in 0..current {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if instruction.program_id == ed25519_program::ID {
+            if let Some(signature) = find_signature(&instruction.data, oracle, message)? {
+                return Ok(hash(signature).to_bytes());
+            }
+        }
+    }
+    err!(WalkError::NoOracleSignature)
+}
+
+// The data starts with the number of signatures and a padding byte, followed by one set of
+// offsets per signature, each pointing at a signature, a public key and a message.
+fn find_signature<'a>(data: &'a [u8], oracle: &Pubkey, message: &[u8]) -> Result<Option<&'a [u8]>> {
+    let count = *data.first().ok_or(WalkError::InvalidSignatureInstruction)? as usize;
+    for i in 0..count {
+        let start = OFFSETS_START + i * OFFSETS_LEN;
+        let offsets = data
+            .get(start..start + OFFSETS_LEN)
+            .ok_or(WalkError::InvalidSignatureInstruction)?;
+        let read = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+        let (signature_offset, signature_ix) = (read(0) as usize, read(2));
+        let (pubkey_offset, pubkey_ix) = (read(4) as usize, read(6));
+        let (message_offset, message_len, message_ix) =
+            (read(8) as usize, read(10) as usize, read(12));
+
+        // the offsets may point into other instructions, whose data we didn't check, so only
+        // signatures with everything inside this one instruction (`u16::MAX`) are trusted
+        require!(
+            signature_ix == u16::MAX && pubkey_ix == u16::MAX && message_ix == u16::MAX,
+            WalkError::InvalidSignatureInstruction
+        );
+
+        let pubkey = data
+            .get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+            .ok_or(WalkError::InvalidSignatureInstruction)?;
+        let signed = data
+            .get(message_offset..message_offset + message_len)
+            .ok_or(WalkError::InvalidSignatureInstruction)?;
+        if pubkey == oracle.as_ref() && signed == message {
+            let signature = data
+                .get(signature_offset..signature_offset + SIGNATURE_LEN)
+                .ok_or(WalkError::InvalidSignatureInstruction)?;
+            return Ok(Some(signature));
+        }
+    }
+    Ok(None)
+}
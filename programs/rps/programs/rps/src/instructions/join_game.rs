@@ -0,0 +1,32 @@
+use crate::errors::RpsError;
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn join_game(ctx: Context<JoinGame>, commitment: [u8; 32]) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    game.join(commitment, Clock::get()?.unix_timestamp)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_two.to_account_info(),
+                to: game.to_account_info(),
+            },
+        ),
+        game.stake,
+    )
+}
+
+#[derive(Accounts)]
+pub struct JoinGame<'info> {
+    #[account(
+        mut,
+        constraint = game.players[1] == player_two.key() @ RpsError::NotAPlayer
+    )]
+    pub game: Account<'info, Game>,
+    #[account(mut)]
+    pub player_two: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
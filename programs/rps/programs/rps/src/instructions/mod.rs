@@ -0,0 +1,11 @@
+pub use cancel_game::*;
+pub use create_game::*;
+pub use join_game::*;
+pub use reveal::*;
+pub use settle::*;
+
+pub mod cancel_game;
+pub mod create_game;
+pub mod join_game;
+pub mod reveal;
+pub mod settle;
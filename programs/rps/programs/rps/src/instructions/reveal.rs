@@ -0,0 +1,15 @@
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn reveal(ctx: Context<Reveal>, hand: Hand, salt: [u8; 32]) -> Result<()> {
+    let game = &mut ctx.accounts.game;
+    let index = game.player_index(ctx.accounts.player.key)?;
+    game.reveal(index, hand, &salt, Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct Reveal<'info> {
+    #[account(mut)]
+    pub game: Account<'info, Game>,
+    pub player: Signer<'info>,
+}
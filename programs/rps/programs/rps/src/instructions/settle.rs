@@ -0,0 +1,32 @@
+use crate::errors::RpsError;
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn settle(ctx: Context<Settle>) -> Result<()> {
+    let payouts = ctx.accounts.game.payouts(Clock::get()?.unix_timestamp)?;
+
+    ctx.accounts.game.sub_lamports(payouts[0] + payouts[1])?;
+    ctx.accounts.player_one.add_lamports(payouts[0])?;
+    ctx.accounts.player_two.add_lamports(payouts[1])?;
+    Ok(())
+}
+
+// permissionless, so the winner of a timeout can always claim
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    // player one created the game, so they get its rent back
+    #[account(mut, close = player_one)]
+    pub game: Account<'info, Game>,
+    /// CHECK: checked against the game's players
+    #[account(
+        mut,
+        constraint = game.players[0] == player_one.key() @ RpsError::NotAPlayer
+    )]
+    pub player_one: UncheckedAccount<'info>,
+    /// CHECK: checked against the game's players
+    #[account(
+        mut,
+        constraint = game.players[1] == player_two.key() @ RpsError::NotAPlayer
+    )]
+    pub player_two: UncheckedAccount<'info>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+use state::game::Hand;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("G9EUGYGVpe2rQczFk1FdbTKjsgjdKUT9cjwAXzSNk4uq");
+
+#[program]
+pub mod rps {
+    use super::*;
+
+    pub fn create_game(
+        ctx: Context<CreateGame>,
+        id: u64,
+        player_two: Pubkey,
+        stake: u64,
+        commitment: [u8; 32],
+        reveal_timeout: i64,
+    ) -> Result<()> {
+        instructions::create_game::create_game(
+            ctx,
+            id,
+            player_two,
+            stake,
+            commitment,
+            reveal_timeout,
+        )
+    }
+
+    pub fn cancel_game(ctx: Context<CancelGame>) -> Result<()> {
+        instructions::cancel_game::cancel_game(ctx)
+    }
+
+    pub fn join_game(ctx: Context<JoinGame>, commitment: [u8; 32]) -> Result<()> {
+        instructions::join_game::join_game(ctx, commitment)
+    }
+
+    pub fn reveal(ctx: Context<Reveal>, hand: Hand, salt: [u8; 32]) -> Result<()> {
+        instructions::reveal::reveal(ctx, hand, salt)
+    }
+
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        instructions::settle::settle(ctx)
+    }
+}
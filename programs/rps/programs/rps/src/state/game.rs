@@ -0,0 +1,95 @@
+use crate::errors::RpsError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Game {
+    pub players: [Pubkey; 2],
+    pub id: u64,
+    // in lamports, per player
+    pub stake: u64,
+    pub commitments: [[u8; 32]; 2],
+    pub hands: [Option<Hand>; 2],
+    pub joined: bool,
+    // how long both players have to reveal once the second one has joined
+    pub reveal_timeout: i64,
+    pub reveal_deadline: i64,
+    pub bump: u8,
+}
+
+impl Game {
+    pub const SEED_PREFIX: &'static [u8] = b"game";
+
+    /// The commitment a player submits for `hand`. Hashing in the player's key
+    /// stops the second player from simply copying the first one's commitment,
+    /// and the random `salt` stops anyone from trying all three hands.
+    pub fn commitment(hand: Hand, salt: &[u8; 32], player: &Pubkey) -> [u8; 32] {
+        hashv(&[&[hand as u8], salt, player.as_ref()]).to_bytes()
+    }
+
+    pub fn player_index(&self, player: &Pubkey) -> Result<usize> {
+        self.players
+            .iter()
+            .position(|p| p == player)
+            .ok_or_else(|| error!(RpsError::NotAPlayer))
+    }
+
+    pub fn join(&mut self, commitment: [u8; 32], now: i64) -> Result<()> {
+        require!(!self.joined, RpsError::AlreadyJoined);
+        self.commitments[1] = commitment;
+        self.joined = true;
+        self.reveal_deadline = now + self.reveal_timeout;
+        Ok(())
+    }
+
+    pub fn reveal(&mut self, index: usize, hand: Hand, salt: &[u8; 32], now: i64) -> Result<()> {
+        require!(self.joined, RpsError::NotJoined);
+        require_gte!(self.reveal_deadline, now, RpsError::RevealWindowClosed);
+        require!(self.hands[index].is_none(), RpsError::AlreadyRevealed);
+        require!(
+            Self::commitment(hand, salt, &self.players[index]) == self.commitments[index],
+            RpsError::CommitmentMismatch
+        );
+        self.hands[index] = Some(hand);
+        Ok(())
+    }
+
+    /// How the pot of both stakes is split between the players.
+    pub fn payouts(&self, now: i64) -> Result<[u64; 2]> {
+        require!(self.joined, RpsError::NotJoined);
+        let pot = self.stake * 2;
+        match self.hands {
+            [Some(first), Some(second)] if first.beats(second) => Ok([pot, 0]),
+            [Some(first), Some(second)] if second.beats(first) => Ok([0, pot]),
+            [Some(_), Some(_)] => Ok([self.stake, self.stake]),
+            _ => {
+                require_gt!(now, self.reveal_deadline, RpsError::GameStillRunning);
+                // whoever did not reveal in time forfeits, most likely because they saw they lost
+                match self.hands {
+                    [Some(_), None] => Ok([pot, 0]),
+                    [None, Some(_)] => Ok([0, pot]),
+                    _ => Ok([self.stake, self.stake]),
+                }
+            }
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Copy, Clone, PartialEq, Eq)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Hand {
+    pub fn beats(self, other: Hand) -> bool {
+        matches!(
+            (self, other),
+            (Hand::Rock, Hand::Scissors)
+                | (Hand::Paper, Hand::Rock)
+                | (Hand::Scissors, Hand::Paper)
+        )
+    }
+}
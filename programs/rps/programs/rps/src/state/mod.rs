@@ -0,0 +1,3 @@
+pub use game::*;
+
+pub mod game;
@@ -0,0 +1,190 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { createHash, randomBytes } from 'crypto';
+import { Harness } from 'test-harness';
+import { Rps } from '../../../target/types/rps';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+const HANDS = { rock: 0, paper: 1, scissors: 2 };
+type HandName = keyof typeof HANDS;
+
+describe('rps', () => {
+  let harness: Harness;
+  let program: Program<Rps>;
+  const STAKE = LAMPORTS_PER_SOL;
+  let nextId = 0;
+
+  // must match `Game::commitment`
+  function commit(hand: HandName, salt: Buffer, player: anchor.web3.PublicKey) {
+    return [
+      ...createHash('sha256')
+        .update(Buffer.concat([Buffer.from([HANDS[hand]]), salt, player.toBuffer()]))
+        .digest(),
+    ];
+  }
+
+  async function newPlayer() {
+    return harness.fundedKeypair(10 * LAMPORTS_PER_SOL);
+  }
+
+  async function setup(handOne: HandName, handTwo: HandName, revealTimeout = 60) {
+    const playerOne = await newPlayer();
+    const playerTwo = await newPlayer();
+    const saltOne = randomBytes(32);
+    const saltTwo = randomBytes(32);
+
+    const id = new BN(nextId++);
+    const game = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), playerOne.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+
+    await program.methods
+      .createGame(id, playerTwo.publicKey, new BN(STAKE), commit(handOne, saltOne, playerOne.publicKey), new BN(revealTimeout))
+      .accounts({ game, playerOne: playerOne.publicKey })
+      .signers([playerOne])
+      .rpc();
+
+    await program.methods
+      .joinGame(commit(handTwo, saltTwo, playerTwo.publicKey))
+      .accounts({ game, playerTwo: playerTwo.publicKey })
+      .signers([playerTwo])
+      .rpc();
+
+    return {
+      game,
+      playerOne,
+      playerTwo,
+      revealOne: async () => await reveal(game, playerOne, handOne, saltOne),
+      revealTwo: async () => await reveal(game, playerTwo, handTwo, saltTwo),
+    };
+  }
+
+  async function reveal(game: anchor.web3.PublicKey, player: anchor.web3.Keypair, hand: HandName, salt: Buffer) {
+    await program.methods
+      .reveal({ [hand]: {} } as any, [...salt])
+      .accounts({ game, player: player.publicKey })
+      .signers([player])
+      .rpc();
+  }
+
+  async function settle(game: anchor.web3.PublicKey, playerOne: anchor.web3.Keypair, playerTwo: anchor.web3.Keypair) {
+    const before = [await harness.lamports(playerOne.publicKey), await harness.lamports(playerTwo.publicKey)];
+    await program.methods
+      .settle()
+      .accounts({ game, playerOne: playerOne.publicKey, playerTwo: playerTwo.publicKey })
+      .rpc();
+    return [
+      (await harness.lamports(playerOne.publicKey)) - before[0],
+      (await harness.lamports(playerTwo.publicKey)) - before[1],
+    ];
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Rps>('rps');
+  });
+
+  it('paper beats rock!', async () => {
+    const { game, playerOne, playerTwo, revealOne, revealTwo } = await setup('rock', 'paper');
+    await revealOne();
+    await revealTwo();
+
+    const [one, two] = await settle(game, playerOne, playerTwo);
+    // player one only gets the rent of the game account back
+    expect(one).to.be.lessThan(STAKE / 100);
+    expect(two).to.equal(2 * STAKE);
+    expect(await harness.account(game)).to.be.null;
+  });
+
+  it('tie returns both stakes', async () => {
+    const { game, playerOne, playerTwo, revealOne, revealTwo } = await setup('scissors', 'scissors');
+    await revealTwo();
+    await revealOne();
+
+    const [one, two] = await settle(game, playerOne, playerTwo);
+    expect(one).to.be.greaterThan(STAKE);
+    expect(two).to.equal(STAKE);
+  });
+
+  it('cannot reveal a different hand', async () => {
+    const { game, playerOne } = await setup('rock', 'paper');
+
+    try {
+      await reveal(game, playerOne, 'scissors', randomBytes(32));
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('CommitmentMismatch');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+  });
+
+  it('cannot settle before both revealed or the timeout', async () => {
+    const { game, playerOne, playerTwo, revealOne } = await setup('rock', 'paper');
+    await revealOne();
+
+    try {
+      await settle(game, playerOne, playerTwo);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('GameStillRunning');
+      expect(err.error.errorCode.number).to.equal(6007);
+    }
+  });
+
+  it('refusing to reveal forfeits the stake', async () => {
+    // player two would lose, so they never reveal
+    const { game, playerOne, playerTwo, revealOne, revealTwo } = await setup('rock', 'scissors', 2);
+    await revealOne();
+    await harness.warp(3);
+
+    try {
+      await revealTwo();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('RevealWindowClosed');
+      expect(err.error.errorCode.number).to.equal(6006);
+    }
+
+    const [one, two] = await settle(game, playerOne, playerTwo);
+    expect(one).to.be.greaterThan(2 * STAKE);
+    expect(two).to.equal(0);
+  });
+
+  it('cancel before the opponent joins', async () => {
+    const playerOne = await newPlayer();
+    const id = new BN(nextId++);
+    const game = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), playerOne.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+
+    await program.methods
+      .createGame(id, anchor.web3.Keypair.generate().publicKey, new BN(STAKE), commit('rock', randomBytes(32), playerOne.publicKey), new BN(60))
+      .accounts({ game, playerOne: playerOne.publicKey })
+      .signers([playerOne])
+      .rpc();
+    const balance = await harness.lamports(playerOne.publicKey);
+
+    await program.methods
+      .cancelGame()
+      .accounts({ game, playerOne: playerOne.publicKey })
+      .signers([playerOne])
+      .rpc();
+
+    expect(await harness.lamports(playerOne.publicKey)).to.be.greaterThan(balance + STAKE - 10_000);
+    expect(await harness.account(game)).to.be.null;
+  });
+});
@@ -0,0 +1,13 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum SplitterError {
+    NoPayees,
+    TooManyPayees,
+    SharesMismatch,
+    ZeroShares,
+    DuplicatePayee,
+    NotAPayee,
+    NothingToRelease,
+    MathOverflow,
+}
@@ -0,0 +1,66 @@
+use crate::state::splitter::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn create_splitter(
+    ctx: Context<CreateSplitter>,
+    id: u64,
+    payees: Vec<Pubkey>,
+    shares: Vec<u32>,
+) -> Result<()> {
+    Splitter::validate(&payees, &shares)?;
+
+    ctx.accounts.splitter.set_inner(Splitter {
+        creator: ctx.accounts.creator.key(),
+        id,
+        total_shares: shares.iter().map(|&shares| shares as u64).sum(),
+        total_released: 0,
+        payees: payees
+            .into_iter()
+            .zip(shares)
+            .map(|(address, shares)| Payee {
+                address,
+                shares,
+                released: 0,
+            })
+            .collect(),
+        bump: ctx.bumps.splitter,
+        vault_bump: ctx.bumps.vault,
+    });
+
+    // Like in the crowdfunding example, the vault is a plain system account that
+    // keeps its rent-exempt minimum forever, only the lamports above it get split.
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        Rent::get()?.minimum_balance(0),
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateSplitter<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Splitter::INIT_SPACE,
+        seeds = [Splitter::SEED_PREFIX, creator.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub splitter: Account<'info, Splitter>,
+    // SOL deposits are plain transfers to this address
+    #[account(
+        mut,
+        seeds = [Splitter::VAULT_SEED_PREFIX, splitter.key().as_ref()],
+        bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub use create_splitter::*;
+pub use open_token_vault::*;
+pub use release::*;
+pub use release_token::*;
+
+pub mod create_splitter;
+pub mod open_token_vault;
+pub mod release;
+pub mod release_token;
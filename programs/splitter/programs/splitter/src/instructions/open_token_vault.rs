@@ -0,0 +1,43 @@
+use crate::state::splitter::*;
+use crate::state::token_split::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn open_token_vault(ctx: Context<OpenTokenVault>) -> Result<()> {
+    ctx.accounts.token_split.set_inner(TokenSplit {
+        splitter: ctx.accounts.splitter.key(),
+        mint: ctx.accounts.mint.key(),
+        total_released: 0,
+        released: vec![0; ctx.accounts.splitter.payees.len()],
+        bump: ctx.bumps.token_split,
+    });
+    Ok(())
+}
+
+// anyone can open a vault for another mint, deposits are plain token transfers to it
+#[derive(Accounts)]
+pub struct OpenTokenVault<'info> {
+    pub splitter: Account<'info, Splitter>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + TokenSplit::INIT_SPACE,
+        seeds = [TokenSplit::SEED_PREFIX, splitter.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub token_split: Account<'info, TokenSplit>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [TokenSplit::VAULT_SEED_PREFIX, token_split.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = token_split
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use crate::state::splitter::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn release(ctx: Context<Release>) -> Result<()> {
+    let splitter = &mut ctx.accounts.splitter;
+    let index = splitter.payee_index(ctx.accounts.payee.key)?;
+
+    let balance = ctx.accounts.vault.lamports() - Rent::get()?.minimum_balance(0);
+    let amount = splitter.pending_payment(
+        index,
+        balance + splitter.total_released,
+        splitter.payees[index].released,
+    )?;
+    splitter.payees[index].released += amount;
+    splitter.total_released += amount;
+
+    let splitter_key = splitter.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Splitter::VAULT_SEED_PREFIX,
+        splitter_key.as_ref(),
+        &[splitter.vault_bump],
+    ]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.payee.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Release<'info> {
+    #[account(mut)]
+    pub splitter: Account<'info, Splitter>,
+    #[account(
+        mut,
+        seeds = [Splitter::VAULT_SEED_PREFIX, splitter.key().as_ref()],
+        bump = splitter.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    #[account(mut)]
+    pub payee: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use crate::state::splitter::*;
+use crate::state::token_split::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn release_token(ctx: Context<ReleaseToken>) -> Result<()> {
+    let splitter = &ctx.accounts.splitter;
+    let token_split = &mut ctx.accounts.token_split;
+    let index = splitter.payee_index(ctx.accounts.payee.key)?;
+
+    let amount = splitter.pending_payment(
+        index,
+        ctx.accounts.vault.amount + token_split.total_released,
+        token_split.released[index],
+    )?;
+    token_split.released[index] += amount;
+    token_split.total_released += amount;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        TokenSplit::SEED_PREFIX,
+        token_split.splitter.as_ref(),
+        token_split.mint.as_ref(),
+        &[token_split.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.payee_token_account.to_account_info(),
+                authority: token_split.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ReleaseToken<'info> {
+    pub splitter: Account<'info, Splitter>,
+    #[account(mut, has_one = splitter)]
+    pub token_split: Account<'info, TokenSplit>,
+    #[account(
+        mut,
+        seeds = [TokenSplit::VAULT_SEED_PREFIX, token_split.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = token_split.mint,
+        token::authority = payee
+    )]
+    pub payee_token_account: Account<'info, TokenAccount>,
+    pub payee: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
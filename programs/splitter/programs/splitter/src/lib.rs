@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("6RR7MmwAZQjYYb3rnohjivLVgs7dje3bzF7DwaJwWwAw");
+
+#[program]
+pub mod splitter {
+    use super::*;
+
+    pub fn create_splitter(
+        ctx: Context<CreateSplitter>,
+        id: u64,
+        payees: Vec<Pubkey>,
+        shares: Vec<u32>,
+    ) -> Result<()> {
+        instructions::create_splitter::create_splitter(ctx, id, payees, shares)
+    }
+
+    pub fn release(ctx: Context<Release>) -> Result<()> {
+        instructions::release::release(ctx)
+    }
+
+    pub fn open_token_vault(ctx: Context<OpenTokenVault>) -> Result<()> {
+        instructions::open_token_vault::open_token_vault(ctx)
+    }
+
+    pub fn release_token(ctx: Context<ReleaseToken>) -> Result<()> {
+        instructions::release_token::release_token(ctx)
+    }
+}
@@ -0,0 +1,5 @@
+pub use splitter::*;
+pub use token_split::*;
+
+pub mod splitter;
+pub mod token_split;
@@ -0,0 +1,65 @@
+use crate::errors::SplitterError;
+use anchor_lang::prelude::*;
+use common::math;
+
+pub const MAX_PAYEES: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Payee {
+    pub address: Pubkey,
+    pub shares: u32,
+    // lamports paid out to this payee so far
+    pub released: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Splitter {
+    pub creator: Pubkey,
+    pub id: u64,
+    pub total_shares: u64,
+    // lamports paid out to all payees so far
+    pub total_released: u64,
+    #[max_len(MAX_PAYEES)]
+    pub payees: Vec<Payee>,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl Splitter {
+    pub const SEED_PREFIX: &'static [u8] = b"splitter";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    pub fn validate(payees: &[Pubkey], shares: &[u32]) -> Result<()> {
+        require!(!payees.is_empty(), SplitterError::NoPayees);
+        require_gte!(MAX_PAYEES, payees.len(), SplitterError::TooManyPayees);
+        require_eq!(payees.len(), shares.len(), SplitterError::SharesMismatch);
+        require!(!shares.contains(&0), SplitterError::ZeroShares);
+        for (i, payee) in payees.iter().enumerate() {
+            require!(!payees[..i].contains(payee), SplitterError::DuplicatePayee);
+        }
+        Ok(())
+    }
+
+    pub fn payee_index(&self, address: &Pubkey) -> Result<usize> {
+        self.payees
+            .iter()
+            .position(|payee| payee.address == *address)
+            .ok_or_else(|| error!(SplitterError::NotAPayee))
+    }
+
+    /// What the payee at `index` is owed out of everything the splitter has ever
+    /// received, minus what it has already been paid. Deposits are never tracked
+    /// individually, so this works no matter how or when funds arrive.
+    pub fn pending_payment(&self, index: usize, total_received: u64, released: u64) -> Result<u64> {
+        let entitled = math::mul_div(
+            total_received,
+            self.payees[index].shares as u64,
+            self.total_shares,
+        )
+        .ok_or(SplitterError::MathOverflow)?;
+        let pending = entitled.saturating_sub(released);
+        require_gt!(pending, 0, SplitterError::NothingToRelease);
+        Ok(pending)
+    }
+}
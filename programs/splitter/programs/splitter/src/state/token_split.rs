@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use super::MAX_PAYEES;
+
+/// Payout bookkeeping of a splitter for a single mint.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenSplit {
+    pub splitter: Pubkey,
+    pub mint: Pubkey,
+    pub total_released: u64,
+    // indexed like `Splitter::payees`
+    #[max_len(MAX_PAYEES)]
+    pub released: Vec<u64>,
+    pub bump: u8,
+}
+
+impl TokenSplit {
+    pub const SEED_PREFIX: &'static [u8] = b"token_split";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"token_vault";
+}
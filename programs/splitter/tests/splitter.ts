@@ -0,0 +1,176 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Splitter } from '../../../target/types/splitter';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { SystemProgram, Transaction } = anchor.web3;
+
+describe('splitter', () => {
+  let harness: Harness;
+  let program: Program<Splitter>;
+  let payer: anchor.web3.Keypair;
+  let creator: anchor.web3.Keypair;
+  let nextId = 0;
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Splitter>('splitter');
+    payer = harness.payer;
+    creator = harness.payer;
+  });
+
+  async function newPayee() {
+    return harness.fundedKeypair();
+  }
+
+  async function createSplitter(payees: anchor.web3.PublicKey[], shares: number[]) {
+    const id = new BN(nextId++);
+    const splitter = findAddress(
+      [Buffer.from('splitter'), creator.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const vault = findAddress([Buffer.from('vault'), splitter.toBuffer()], program.programId);
+
+    await program.methods
+      .createSplitter(id, payees, shares)
+      .accounts({ splitter, vault, creator: creator.publicKey })
+      .rpc();
+
+    return { splitter, vault };
+  }
+
+  async function deposit(vault: anchor.web3.PublicKey, lamports: number) {
+    await harness.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({ fromPubkey: creator.publicKey, toPubkey: vault, lamports })
+      )
+    );
+  }
+
+  async function release(splitter: anchor.web3.PublicKey, vault: anchor.web3.PublicKey, payee: anchor.web3.Keypair) {
+    const before = await harness.lamports(payee.publicKey);
+    await program.methods
+      .release()
+      .accounts({ splitter, vault, payee: payee.publicKey })
+      .signers([payee])
+      .rpc();
+    // the transaction fee is paid by the provider wallet
+    return (await harness.lamports(payee.publicKey)) - before;
+  }
+
+  it('splits sol by shares!', async () => {
+    const alice = await newPayee();
+    const bob = await newPayee();
+    const { splitter, vault } = await createSplitter([alice.publicKey, bob.publicKey], [1, 3]);
+
+    await deposit(vault, 400_000);
+    expect(await release(splitter, vault, alice)).to.equal(100_000);
+
+    // a second deposit only pays out the new share
+    await deposit(vault, 400_000);
+    expect(await release(splitter, vault, alice)).to.equal(100_000);
+    expect(await release(splitter, vault, bob)).to.equal(600_000);
+
+    const splitterState = await program.account.splitter.fetch(splitter);
+    expect(splitterState.totalReleased.toNumber()).to.equal(800_000);
+    expect(splitterState.payees[0].released.toNumber()).to.equal(200_000);
+    expect(splitterState.payees[1].released.toNumber()).to.equal(600_000);
+  });
+
+  it('no double payouts', async () => {
+    const alice = await newPayee();
+    const { splitter, vault } = await createSplitter([alice.publicKey, creator.publicKey], [1, 1]);
+    await deposit(vault, 100_000);
+    await release(splitter, vault, alice);
+
+    try {
+      await release(splitter, vault, alice);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NothingToRelease');
+      expect(err.error.errorCode.number).to.equal(6006);
+    }
+  });
+
+  it('only payees can release', async () => {
+    const alice = await newPayee();
+    const mallory = await newPayee();
+    const { splitter, vault } = await createSplitter([alice.publicKey], [1]);
+    await deposit(vault, 100_000);
+
+    try {
+      await release(splitter, vault, mallory);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotAPayee');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+  });
+
+  it('rejects invalid payees', async () => {
+    const alice = await newPayee();
+    const cases: [anchor.web3.PublicKey[], number[], string][] = [
+      [[], [], 'NoPayees'],
+      [[alice.publicKey], [1, 2], 'SharesMismatch'],
+      [[alice.publicKey], [0], 'ZeroShares'],
+      [[alice.publicKey, alice.publicKey], [1, 1], 'DuplicatePayee'],
+    ];
+
+    for (const [payees, shares, code] of cases) {
+      try {
+        await createSplitter(payees, shares);
+        chai.assert(false, "should've failed but didn't ");
+      } catch (_err) {
+        expect(_err).to.be.instanceOf(AnchorError);
+        const err: AnchorError = _err;
+        expect(err.error.errorCode.code).to.equal(code);
+      }
+    }
+  });
+
+  it('splits spl tokens by shares!', async () => {
+    const alice = await newPayee();
+    const bob = await newPayee();
+    const { splitter } = await createSplitter([alice.publicKey, bob.publicKey], [2, 3]);
+
+    const mint = await harness.createMint(payer.publicKey);
+    const tokenSplit = findAddress(
+      [Buffer.from('token_split'), splitter.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+    const tokenVault = findAddress([Buffer.from('token_vault'), tokenSplit.toBuffer()], program.programId);
+
+    await program.methods
+      .openTokenVault()
+      .accounts({ splitter, tokenSplit, vault: tokenVault, mint, payer: payer.publicKey })
+      .rpc();
+    await harness.mintTo(tokenVault, 1_000);
+
+    const aliceTokenAccount = await harness.createTokenAccount(mint, alice.publicKey);
+    const bobTokenAccount = await harness.createTokenAccount(mint, bob.publicKey);
+    const releaseToken = async (payee: anchor.web3.Keypair, payeeTokenAccount: anchor.web3.PublicKey) =>
+      await program.methods
+        .releaseToken()
+        .accounts({ splitter, tokenSplit, vault: tokenVault, payeeTokenAccount, payee: payee.publicKey })
+        .signers([payee])
+        .rpc();
+
+    await releaseToken(alice, aliceTokenAccount);
+    await harness.mintTo(tokenVault, 1_000);
+    await releaseToken(alice, aliceTokenAccount);
+    await releaseToken(bob, bobTokenAccount);
+
+    expect(await harness.tokenBalance(aliceTokenAccount)).to.equal(800);
+    expect(await harness.tokenBalance(bobTokenAccount)).to.equal(1_200);
+    expect(await harness.tokenBalance(tokenVault)).to.equal(0);
+  });
+});
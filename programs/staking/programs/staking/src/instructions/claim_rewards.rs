@@ -0,0 +1,53 @@
+use crate::errors::StakingError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    pool.update(Clock::get()?.unix_timestamp)?;
+    stake_account.settle(pool)?;
+
+    let rewards = stake_account.rewards_earned;
+    require_gt!(rewards, 0, StakingError::NothingToClaim);
+    stake_account.rewards_earned = 0;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.owner_reward_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[&pool.signer_seeds()],
+        ),
+        rewards,
+    )
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = owner,
+        seeds = [StakeAccount::SEED_PREFIX, pool.key().as_ref(), owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(mut, address = pool.reward_vault)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.reward_mint,
+        token::authority = owner
+    )]
+    pub owner_reward_token_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,56 @@
+use crate::state::pool::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn initialize_pool(ctx: Context<InitializePool>, reward_rate: u64) -> Result<()> {
+    ctx.accounts.pool.set_inner(Pool {
+        authority: ctx.accounts.authority.key(),
+        stake_mint: ctx.accounts.stake_mint.key(),
+        reward_mint: ctx.accounts.reward_mint.key(),
+        stake_vault: ctx.accounts.stake_vault.key(),
+        reward_vault: ctx.accounts.reward_vault.key(),
+        reward_rate,
+        total_staked: 0,
+        reward_per_token_stored: 0,
+        last_update_ts: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.pool,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Pool::INIT_SPACE,
+        seeds = [Pool::SEED_PREFIX, stake_mint.key().as_ref(), reward_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+    pub stake_mint: Account<'info, Mint>,
+    pub reward_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        payer = authority,
+        token::mint = stake_mint,
+        token::authority = pool,
+        seeds = [Pool::STAKE_VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    // anyone may top this vault up with a plain token transfer
+    #[account(
+        init,
+        payer = authority,
+        token::mint = reward_mint,
+        token::authority = pool,
+        seeds = [Pool::REWARD_VAULT_SEED_PREFIX, pool.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
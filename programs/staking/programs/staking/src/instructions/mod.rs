@@ -0,0 +1,9 @@
+pub use claim_rewards::*;
+pub use initialize_pool::*;
+pub use stake::*;
+pub use unstake::*;
+
+pub mod claim_rewards;
+pub mod initialize_pool;
+pub mod stake;
+pub mod unstake;
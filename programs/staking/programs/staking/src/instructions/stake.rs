@@ -0,0 +1,68 @@
+use crate::errors::StakingError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, StakingError::ZeroAmount);
+
+    let pool = &mut ctx.accounts.pool;
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    // first stake of this owner in this pool
+    if stake_account.owner == Pubkey::default() {
+        stake_account.pool = pool.key();
+        stake_account.owner = ctx.accounts.owner.key();
+        stake_account.bump = ctx.bumps.stake_account;
+    }
+
+    pool.update(Clock::get()?.unix_timestamp)?;
+    stake_account.settle(pool)?;
+
+    stake_account.amount = stake_account
+        .amount
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+    pool.total_staked = pool
+        .total_staked
+        .checked_add(amount)
+        .ok_or(StakingError::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_stake_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + StakeAccount::INIT_SPACE,
+        seeds = [StakeAccount::SEED_PREFIX, pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(mut, address = pool.stake_vault)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.stake_mint,
+        token::authority = owner
+    )]
+    pub owner_stake_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,61 @@
+use crate::errors::StakingError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, StakingError::ZeroAmount);
+
+    let pool = &mut ctx.accounts.pool;
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    require_gte!(
+        stake_account.amount,
+        amount,
+        StakingError::InsufficientStake
+    );
+
+    pool.update(Clock::get()?.unix_timestamp)?;
+    stake_account.settle(pool)?;
+
+    // cannot underflow, both were checked against `amount` above
+    stake_account.amount -= amount;
+    pool.total_staked -= amount;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.owner_stake_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[&pool.signer_seeds()],
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    #[account(
+        mut,
+        has_one = pool,
+        has_one = owner,
+        seeds = [StakeAccount::SEED_PREFIX, pool.key().as_ref(), owner.key().as_ref()],
+        bump = stake_account.bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    #[account(mut, address = pool.stake_vault)]
+    pub stake_vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = pool.stake_mint,
+        token::authority = owner
+    )]
+    pub owner_stake_token_account: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("Bn1iTNoR3Kip9f6nBa6PdPspJtgEfBt9RfuiZj6ahXVb");
+
+#[program]
+pub mod staking {
+    use super::*;
+
+    pub fn initialize_pool(ctx: Context<InitializePool>, reward_rate: u64) -> Result<()> {
+        instructions::initialize_pool::initialize_pool(ctx, reward_rate)
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
+        instructions::stake::stake(ctx, amount)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::unstake::unstake(ctx, amount)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        instructions::claim_rewards::claim_rewards(ctx)
+    }
+}
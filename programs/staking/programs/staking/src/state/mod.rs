@@ -0,0 +1,5 @@
+pub use pool::*;
+pub use stake_account::*;
+
+pub mod pool;
+pub mod stake_account;
@@ -0,0 +1,63 @@
+use crate::errors::StakingError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub stake_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub stake_vault: Pubkey,
+    pub reward_vault: Pubkey,
+    // reward tokens paid out per second, shared by all stakers
+    pub reward_rate: u64,
+    pub total_staked: u64,
+    // rewards a single staked token has accumulated since the pool was created,
+    // scaled by `PRECISION` so that small rates don't round down to zero
+    pub reward_per_token_stored: u128,
+    pub last_update_ts: i64,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const SEED_PREFIX: &'static [u8] = b"pool";
+    pub const STAKE_VAULT_SEED_PREFIX: &'static [u8] = b"stake_vault";
+    pub const REWARD_VAULT_SEED_PREFIX: &'static [u8] = b"reward_vault";
+    pub const PRECISION: u128 = 1_000_000_000_000;
+
+    pub fn reward_per_token(&self, now: i64) -> Result<u128> {
+        if self.total_staked == 0 {
+            return Ok(self.reward_per_token_stored);
+        }
+
+        // a clock that went backwards must never reduce anybody's rewards
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u128;
+
+        let accrued = elapsed
+            .checked_mul(self.reward_rate as u128)
+            .and_then(|rewards| rewards.checked_mul(Self::PRECISION))
+            .and_then(|scaled| scaled.checked_div(self.total_staked as u128))
+            .ok_or(StakingError::MathOverflow)?;
+
+        self.reward_per_token_stored
+            .checked_add(accrued)
+            .ok_or_else(|| StakingError::MathOverflow.into())
+    }
+
+    /// Moves the accumulator up to `now`. Has to run before `total_staked` changes,
+    /// otherwise the elapsed time would be paid out at the new stake size.
+    pub fn update(&mut self, now: i64) -> Result<()> {
+        self.reward_per_token_stored = self.reward_per_token(now)?;
+        self.last_update_ts = now;
+        Ok(())
+    }
+
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            Self::SEED_PREFIX,
+            self.stake_mint.as_ref(),
+            self.reward_mint.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
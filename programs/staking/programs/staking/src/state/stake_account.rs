@@ -0,0 +1,38 @@
+use crate::errors::StakingError;
+use crate::state::pool::Pool;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    // the pool's `reward_per_token_stored` at the time `rewards_earned` was last settled
+    pub reward_per_token_paid: u128,
+    pub rewards_earned: u64,
+    pub bump: u8,
+}
+
+impl StakeAccount {
+    pub const SEED_PREFIX: &'static [u8] = b"stake";
+
+    /// Settles everything earned since the last interaction into `rewards_earned`.
+    /// The pool must already have been updated to the current time.
+    pub fn settle(&mut self, pool: &Pool) -> Result<()> {
+        let pending = pool
+            .reward_per_token_stored
+            .checked_sub(self.reward_per_token_paid)
+            .and_then(|delta| delta.checked_mul(self.amount as u128))
+            .map(|scaled| scaled / Pool::PRECISION)
+            .ok_or(StakingError::MathOverflow)?;
+
+        self.rewards_earned = u64::try_from(pending)
+            .ok()
+            .and_then(|pending| self.rewards_earned.checked_add(pending))
+            .ok_or(StakingError::MathOverflow)?;
+        self.reward_per_token_paid = pool.reward_per_token_stored;
+
+        Ok(())
+    }
+}
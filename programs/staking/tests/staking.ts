@@ -0,0 +1,211 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Staking } from '../../../target/types/staking';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('staking', () => {
+  let harness: Harness;
+  let program: Program<Staking>;
+  let payer: anchor.web3.Keypair;
+  const staker = anchor.web3.Keypair.generate();
+
+  let stakeMint: anchor.web3.PublicKey;
+  let stakerStakeTokenAccount: anchor.web3.PublicKey;
+  let stakerRewardTokenAccount: anchor.web3.PublicKey;
+
+  async function initializePool(rewardRate: number) {
+    const rewardMint = await harness.createMint(payer.publicKey);
+    const pool = findAddress([Buffer.from('pool'), stakeMint.toBuffer(), rewardMint.toBuffer()], program.programId);
+    const stakeVault = findAddress([Buffer.from('stake_vault'), pool.toBuffer()], program.programId);
+    const rewardVault = findAddress([Buffer.from('reward_vault'), pool.toBuffer()], program.programId);
+
+    await program.methods
+      .initializePool(new BN(rewardRate))
+      .accounts({
+        pool,
+        stakeMint,
+        rewardMint,
+        stakeVault,
+        rewardVault,
+        authority: payer.publicKey,
+      })
+      .rpc();
+
+    // fund the reward vault like any other token account
+    await harness.mintTo(rewardVault, 1_000_000);
+    stakerRewardTokenAccount = await harness.createTokenAccount(rewardMint, staker.publicKey);
+
+    return { pool, rewardMint, stakeVault, rewardVault };
+  }
+
+  async function stake(pool: anchor.web3.PublicKey, stakeVault: anchor.web3.PublicKey, amount: number) {
+    await program.methods
+      .stake(new BN(amount))
+      .accounts({
+        pool,
+        stakeAccount: findAddress(
+          [Buffer.from('stake'), pool.toBuffer(), staker.publicKey.toBuffer()],
+          program.programId
+        ),
+        stakeVault,
+        ownerStakeTokenAccount: stakerStakeTokenAccount,
+        owner: staker.publicKey,
+      })
+      .signers([staker])
+      .rpc();
+  }
+
+  async function claimRewards(pool: anchor.web3.PublicKey, rewardVault: anchor.web3.PublicKey) {
+    await program.methods
+      .claimRewards()
+      .accounts({
+        pool,
+        stakeAccount: findAddress(
+          [Buffer.from('stake'), pool.toBuffer(), staker.publicKey.toBuffer()],
+          program.programId
+        ),
+        rewardVault,
+        ownerRewardTokenAccount: stakerRewardTokenAccount,
+        owner: staker.publicKey,
+      })
+      .signers([staker])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Staking>('staking');
+    payer = harness.payer;
+
+    await harness.fund(staker.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+    stakeMint = await harness.createMint(payer.publicKey);
+    stakerStakeTokenAccount = await harness.createTokenAccount(stakeMint, staker.publicKey, 1_000);
+  });
+
+  it('stake, earn and claim!', async () => {
+    const { pool, stakeVault, rewardVault } = await initializePool(10);
+
+    let poolState = await program.account.pool.fetch(pool);
+    expect(poolState.rewardRate.toNumber()).to.equal(10);
+    expect(poolState.totalStaked.toNumber()).to.equal(0);
+    expect(poolState.stakeVault).to.eql(stakeVault);
+    expect(poolState.rewardVault).to.eql(rewardVault);
+
+    await stake(pool, stakeVault, 100);
+
+    poolState = await program.account.pool.fetch(pool);
+    expect(poolState.totalStaked.toNumber()).to.equal(100);
+    expect(await harness.tokenBalance(stakeVault)).to.equal(100);
+
+    await harness.warp(3);
+    await claimRewards(pool, rewardVault);
+
+    // as the only staker, every second of the reward rate goes to us
+    const stakeAccount = await program.account.stakeAccount.fetch(
+      findAddress([Buffer.from('stake'), pool.toBuffer(), staker.publicKey.toBuffer()], program.programId)
+    );
+    expect(await harness.tokenBalance(stakerRewardTokenAccount)).to.equal(30);
+    expect(stakeAccount.rewardsEarned.toNumber()).to.equal(0);
+    expect(stakeAccount.amount.toNumber()).to.equal(100);
+  });
+
+  it('rewards are shared pro rata', async () => {
+    const { pool, stakeVault, rewardVault } = await initializePool(100);
+    const otherStaker = await harness.fundedKeypair(anchor.web3.LAMPORTS_PER_SOL / 10);
+    const otherStakeTokenAccount = await harness.createTokenAccount(stakeMint, otherStaker.publicKey, 300);
+
+    await stake(pool, stakeVault, 100);
+    await program.methods
+      .stake(new BN(300))
+      .accounts({
+        pool,
+        stakeAccount: findAddress(
+          [Buffer.from('stake'), pool.toBuffer(), otherStaker.publicKey.toBuffer()],
+          program.programId
+        ),
+        stakeVault,
+        ownerStakeTokenAccount: otherStakeTokenAccount,
+        owner: otherStaker.publicKey,
+      })
+      .signers([otherStaker])
+      .rpc();
+
+    const before = await program.account.pool.fetch(pool);
+    await harness.warp(2);
+    await claimRewards(pool, rewardVault);
+    const after = await program.account.pool.fetch(pool);
+
+    // from here on we hold a quarter of the pool, so we earn 25 per second
+    const elapsed = after.lastUpdateTs.sub(before.lastUpdateTs).toNumber();
+    expect(elapsed).to.equal(2);
+    expect(await harness.tokenBalance(stakerRewardTokenAccount)).to.equal(25 * elapsed);
+  });
+
+  it('unstake', async () => {
+    const { pool, stakeVault } = await initializePool(0);
+    const stakeAccount = findAddress(
+      [Buffer.from('stake'), pool.toBuffer(), staker.publicKey.toBuffer()],
+      program.programId
+    );
+    const balanceBefore = await harness.tokenBalance(stakerStakeTokenAccount);
+
+    await stake(pool, stakeVault, 50);
+    await program.methods
+      .unstake(new BN(20))
+      .accounts({
+        pool,
+        stakeAccount,
+        stakeVault,
+        ownerStakeTokenAccount: stakerStakeTokenAccount,
+        owner: staker.publicKey,
+      })
+      .signers([staker])
+      .rpc();
+
+    expect((await program.account.stakeAccount.fetch(stakeAccount)).amount.toNumber()).to.equal(30);
+    expect((await program.account.pool.fetch(pool)).totalStaked.toNumber()).to.equal(30);
+    expect(await harness.tokenBalance(stakerStakeTokenAccount)).to.equal(balanceBefore - 30);
+
+    try {
+      await program.methods
+        .unstake(new BN(31))
+        .accounts({
+          pool,
+          stakeAccount,
+          stakeVault,
+          ownerStakeTokenAccount: stakerStakeTokenAccount,
+          owner: staker.publicKey,
+        })
+        .signers([staker])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InsufficientStake');
+      expect(err.error.errorCode.number).to.equal(6001);
+      expect(err.program.equals(program.programId)).is.true;
+    }
+  });
+
+  it('nothing to claim without rewards', async () => {
+    const { pool, stakeVault, rewardVault } = await initializePool(0);
+    await stake(pool, stakeVault, 10);
+
+    try {
+      await claimRewards(pool, rewardVault);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NothingToClaim');
+      expect(err.error.errorCode.number).to.equal(6002);
+    }
+  });
+});
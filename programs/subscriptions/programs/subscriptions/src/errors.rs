@@ -0,0 +1,12 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum SubscriptionsError {
+    ZeroFee,
+    InvalidPeriod,
+    InvalidGracePeriod,
+    ZeroPeriods,
+    NotDueYet,
+    SubscriptionLapsed,
+    MathOverflow,
+}
@@ -0,0 +1,35 @@
+use crate::state::subscription::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Revoke, Token, TokenAccount};
+
+pub fn cancel(ctx: Context<Cancel>) -> Result<()> {
+    // only revoke our own delegation, the subscriber may have approved someone else since
+    if ctx.accounts.subscriber_token_account.delegate
+        == Some(ctx.accounts.subscription.key()).into()
+    {
+        token::revoke(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.subscriber_token_account.to_account_info(),
+                authority: ctx.accounts.subscriber.to_account_info(),
+            },
+        ))?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Cancel<'info> {
+    #[account(
+        mut,
+        has_one = subscriber,
+        has_one = subscriber_token_account,
+        close = subscriber
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(mut)]
+    pub subscriber_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,45 @@
+use crate::state::plan::*;
+use crate::state::subscription::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn charge(ctx: Context<Charge>) -> Result<()> {
+    let plan = &ctx.accounts.plan;
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.advance(Clock::get()?.unix_timestamp, plan.period, plan.grace_period)?;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Subscription::SEED_PREFIX,
+        subscription.plan.as_ref(),
+        subscription.subscriber.as_ref(),
+        &[subscription.bump],
+    ]];
+
+    // fails if the subscriber revoked the delegation or used up the allowance
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.subscriber_token_account.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: subscription.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        plan.fee,
+    )
+}
+
+// a permissionless crank, anyone can trigger a due charge
+#[derive(Accounts)]
+pub struct Charge<'info> {
+    #[account(has_one = treasury)]
+    pub plan: Account<'info, Plan>,
+    #[account(mut, has_one = plan, has_one = subscriber_token_account)]
+    pub subscription: Account<'info, Subscription>,
+    #[account(mut)]
+    pub subscriber_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub treasury: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
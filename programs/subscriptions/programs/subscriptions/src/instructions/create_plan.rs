@@ -0,0 +1,50 @@
+use crate::errors::SubscriptionsError;
+use crate::state::plan::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+pub fn create_plan(
+    ctx: Context<CreatePlan>,
+    id: u64,
+    fee: u64,
+    period: i64,
+    grace_period: i64,
+) -> Result<()> {
+    require_gt!(fee, 0, SubscriptionsError::ZeroFee);
+    require_gt!(period, 0, SubscriptionsError::InvalidPeriod);
+    require!(
+        (1..=period).contains(&grace_period),
+        SubscriptionsError::InvalidGracePeriod
+    );
+
+    ctx.accounts.plan.set_inner(Plan {
+        merchant: ctx.accounts.merchant.key(),
+        id,
+        mint: ctx.accounts.mint.key(),
+        treasury: ctx.accounts.treasury.key(),
+        fee,
+        period,
+        grace_period,
+        bump: ctx.bumps.plan,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreatePlan<'info> {
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Plan::INIT_SPACE,
+        seeds = [Plan::SEED_PREFIX, merchant.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub plan: Account<'info, Plan>,
+    pub mint: Account<'info, Mint>,
+    #[account(token::mint = mint)]
+    pub treasury: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
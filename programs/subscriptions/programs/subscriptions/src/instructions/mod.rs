@@ -0,0 +1,9 @@
+pub use cancel::*;
+pub use charge::*;
+pub use create_plan::*;
+pub use subscribe::*;
+
+pub mod cancel;
+pub mod charge;
+pub mod create_plan;
+pub mod subscribe;
@@ -0,0 +1,76 @@
+use crate::errors::SubscriptionsError;
+use crate::state::plan::*;
+use crate::state::subscription::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Token, TokenAccount, Transfer};
+
+pub fn subscribe(ctx: Context<Subscribe>, periods: u64) -> Result<()> {
+    require_gt!(periods, 0, SubscriptionsError::ZeroPeriods);
+    let plan = &ctx.accounts.plan;
+    let now = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.subscription.set_inner(Subscription {
+        plan: plan.key(),
+        subscriber: ctx.accounts.subscriber.key(),
+        subscriber_token_account: ctx.accounts.subscriber_token_account.key(),
+        next_charge_ts: now + plan.period,
+        charges: 1,
+        bump: ctx.bumps.subscription,
+    });
+
+    // the first period is paid up front
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.subscriber_token_account.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.subscriber.to_account_info(),
+            },
+        ),
+        plan.fee,
+    )?;
+
+    // The subscription PDA may pull the fees of the following `periods` periods,
+    // the subscriber never hands out more than that. A token account has a
+    // single delegate, so this replaces any previous approval on it.
+    token::approve(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Approve {
+                to: ctx.accounts.subscriber_token_account.to_account_info(),
+                delegate: ctx.accounts.subscription.to_account_info(),
+                authority: ctx.accounts.subscriber.to_account_info(),
+            },
+        ),
+        plan.fee
+            .checked_mul(periods)
+            .ok_or(SubscriptionsError::MathOverflow)?,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(has_one = treasury)]
+    pub plan: Account<'info, Plan>,
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [Subscription::SEED_PREFIX, plan.key().as_ref(), subscriber.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    #[account(
+        mut,
+        token::mint = plan.mint,
+        token::authority = subscriber
+    )]
+    pub subscriber_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub treasury: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("9JxCJqeHmjhumPaG6yLc1hFbzBELAxnmE1TstrKQ8jgE");
+
+#[program]
+pub mod subscriptions {
+    use super::*;
+
+    pub fn create_plan(
+        ctx: Context<CreatePlan>,
+        id: u64,
+        fee: u64,
+        period: i64,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::create_plan::create_plan(ctx, id, fee, period, grace_period)
+    }
+
+    pub fn subscribe(ctx: Context<Subscribe>, periods: u64) -> Result<()> {
+        instructions::subscribe::subscribe(ctx, periods)
+    }
+
+    pub fn charge(ctx: Context<Charge>) -> Result<()> {
+        instructions::charge::charge(ctx)
+    }
+
+    pub fn cancel(ctx: Context<Cancel>) -> Result<()> {
+        instructions::cancel::cancel(ctx)
+    }
+}
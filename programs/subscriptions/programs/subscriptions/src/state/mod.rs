@@ -0,0 +1,5 @@
+pub use plan::*;
+pub use subscription::*;
+
+pub mod plan;
+pub mod subscription;
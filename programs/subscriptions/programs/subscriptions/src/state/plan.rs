@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Plan {
+    pub merchant: Pubkey,
+    pub id: u64,
+    pub mint: Pubkey,
+    // the token account fees are paid into
+    pub treasury: Pubkey,
+    pub fee: u64,
+    // in seconds
+    pub period: i64,
+    // how long after a missed charge the subscription still counts as active
+    pub grace_period: i64,
+    pub bump: u8,
+}
+
+impl Plan {
+    pub const SEED_PREFIX: &'static [u8] = b"plan";
+}
@@ -0,0 +1,35 @@
+use crate::errors::SubscriptionsError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub plan: Pubkey,
+    pub subscriber: Pubkey,
+    pub subscriber_token_account: Pubkey,
+    pub next_charge_ts: i64,
+    pub charges: u64,
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub const SEED_PREFIX: &'static [u8] = b"subscription";
+
+    /// Paid up, or behind on a payment by no more than the grace period.
+    pub fn is_active(&self, now: i64, grace_period: i64) -> bool {
+        now < self.next_charge_ts + grace_period
+    }
+
+    /// Moves the subscription to the next billing period if the current one is due.
+    /// Periods stay aligned to the original schedule, no matter how late the crank runs.
+    pub fn advance(&mut self, now: i64, period: i64, grace_period: i64) -> Result<()> {
+        require_gte!(now, self.next_charge_ts, SubscriptionsError::NotDueYet);
+        require!(
+            self.is_active(now, grace_period),
+            SubscriptionsError::SubscriptionLapsed
+        );
+        self.next_charge_ts += period;
+        self.charges += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Subscriptions } from '../../../target/types/subscriptions';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('subscriptions', () => {
+  let harness: Harness;
+  let program: Program<Subscriptions>;
+  let payer: anchor.web3.Keypair;
+  // the provider wallet sells the subscriptions
+  let merchant: anchor.web3.Keypair;
+  const FEE = 100;
+  let nextId = 0;
+
+  let mint: anchor.web3.PublicKey;
+  let treasury: anchor.web3.PublicKey;
+
+  async function createPlan(period: number, gracePeriod: number) {
+    const id = new BN(nextId++);
+    const plan = findAddress(
+      [Buffer.from('plan'), merchant.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    await program.methods
+      .createPlan(id, new BN(FEE), new BN(period), new BN(gracePeriod))
+      .accounts({ plan, mint, treasury, merchant: merchant.publicKey })
+      .rpc();
+    return plan;
+  }
+
+  async function subscribe(plan: anchor.web3.PublicKey, periods: number) {
+    const subscriber = await harness.fundedKeypair(LAMPORTS_PER_SOL);
+    const subscriberTokenAccount = await harness.createTokenAccount(mint, subscriber.publicKey, 10 * FEE);
+
+    const subscription = findAddress(
+      [Buffer.from('subscription'), plan.toBuffer(), subscriber.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .subscribe(new BN(periods))
+      .accounts({
+        plan,
+        subscription,
+        subscriberTokenAccount,
+        treasury,
+        subscriber: subscriber.publicKey,
+      })
+      .signers([subscriber])
+      .rpc();
+
+    return { subscriber, subscription, subscriberTokenAccount };
+  }
+
+  async function charge(
+    plan: anchor.web3.PublicKey,
+    subscription: anchor.web3.PublicKey,
+    subscriberTokenAccount: anchor.web3.PublicKey
+  ) {
+    await program.methods.charge().accounts({ plan, subscription, subscriberTokenAccount, treasury }).rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Subscriptions>('subscriptions');
+    payer = harness.payer;
+    merchant = harness.payer;
+
+    mint = await harness.createMint(payer.publicKey);
+    treasury = await harness.createTokenAccount(mint, merchant.publicKey);
+  });
+
+  it('subscribe and charge every period!', async () => {
+    const plan = await createPlan(2, 2);
+    const treasuryBalance = await harness.tokenBalance(treasury);
+    const { subscription, subscriberTokenAccount } = await subscribe(plan, 3);
+
+    expect(await harness.tokenBalance(treasury)).to.equal(treasuryBalance + FEE);
+    const tokenAccount = await harness.tokenAccount(subscriberTokenAccount);
+    expect(tokenAccount.delegate).to.eql(subscription);
+    expect(Number(tokenAccount.delegatedAmount)).to.equal(3 * FEE);
+
+    try {
+      await charge(plan, subscription, subscriberTokenAccount);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotDueYet');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+
+    await harness.warp(3);
+    await charge(plan, subscription, subscriberTokenAccount);
+
+    expect(await harness.tokenBalance(treasury)).to.equal(treasuryBalance + 2 * FEE);
+    expect((await program.account.subscription.fetch(subscription)).charges.toNumber()).to.equal(2);
+    expect(Number((await harness.tokenAccount(subscriberTokenAccount)).delegatedAmount)).to.equal(2 * FEE);
+  });
+
+  it('lapses after the grace period', async () => {
+    const plan = await createPlan(2, 1);
+    const { subscription, subscriberTokenAccount } = await subscribe(plan, 3);
+
+    await harness.warp(4);
+
+    try {
+      await charge(plan, subscription, subscriberTokenAccount);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('SubscriptionLapsed');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+  });
+
+  it('cancel revokes the delegation', async () => {
+    const plan = await createPlan(60, 10);
+    const { subscriber, subscription, subscriberTokenAccount } = await subscribe(plan, 3);
+
+    await program.methods
+      .cancel()
+      .accounts({ subscription, subscriberTokenAccount, subscriber: subscriber.publicKey })
+      .signers([subscriber])
+      .rpc();
+
+    expect(await harness.account(subscription)).to.be.null;
+    const tokenAccount = await harness.tokenAccount(subscriberTokenAccount);
+    expect(tokenAccount.delegate).to.be.null;
+    expect(Number(tokenAccount.delegatedAmount)).to.equal(0);
+  });
+
+  it('invalid plans', async () => {
+    try {
+      await createPlan(0, 0);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InvalidPeriod');
+    }
+
+    try {
+      await createPlan(10, 11);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InvalidGracePeriod');
+    }
+  });
+});
@@ -0,0 +1,175 @@
+//! Play tic-tac-toe from the terminal.
+//!
+//! ```sh
+//! tic-tac-toe-cli new-game <OPPONENT>       # you play X
+//! tic-tac-toe-cli -k <KEYPAIR> join <GAME>  # your opponent plays O
+//! tic-tac-toe-cli play 1 1
+//! tic-tac-toe-cli show
+//! ```
+//!
+//! The last game created or joined is remembered in `~/.config/tic-tac-toe/game`, so `play`
+//! and `show` only need `--game` for another one.
+
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use anchor_client::solana_sdk::system_program;
+use anchor_client::{Client, Cluster};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::rc::Rc;
+use tic_tac_toe::state::game::{Game, GameState, Sign, Tile};
+
+#[derive(Parser)]
+#[command(about = "Play tic-tac-toe on Solana from the terminal")]
+struct Cli {
+    /// localnet, devnet or the url of an RPC node
+    #[arg(short = 'u', long, default_value = "localnet")]
+    cluster: Cluster,
+    /// The wallet to play with, which also pays the fees
+    #[arg(short, long, default_value = "~/.config/solana/id.json")]
+    keypair: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Start a game against OPPONENT, who moves second
+    NewGame { opponent: Pubkey },
+    /// Make GAME the current game, if this wallet plays in it
+    Join { game: Pubkey },
+    /// Put your sign on a tile, rows and columns count from 0
+    Play {
+        row: u8,
+        column: u8,
+        #[arg(long)]
+        game: Option<Pubkey>,
+    },
+    /// Print the board
+    Show {
+        #[arg(long)]
+        game: Option<Pubkey>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let keypair_path = expand_home(&cli.keypair)?;
+    let wallet = Rc::new(
+        read_keypair_file(&keypair_path)
+            .map_err(|err| anyhow!("{}: {err}", keypair_path.display()))?,
+    );
+    let client =
+        Client::new_with_options(cli.cluster, wallet.clone(), CommitmentConfig::confirmed());
+    let program = client.program(tic_tac_toe::ID)?;
+
+    match cli.command {
+        Command::NewGame { opponent } => {
+            let game = Keypair::new();
+            program
+                .request()
+                .accounts(tic_tac_toe::accounts::SetupGame {
+                    game: game.pubkey(),
+                    player_one: wallet.pubkey(),
+                    system_program: system_program::ID,
+                })
+                .args(tic_tac_toe::instruction::SetupGame {
+                    player_two: opponent,
+                })
+                .signer(&game)
+                .send()?;
+            save_current_game(&game.pubkey())?;
+            println!("Created game {}, you play X", game.pubkey());
+            println!(
+                "Your opponent joins with: tic-tac-toe-cli join {}",
+                game.pubkey()
+            );
+        }
+        Command::Join { game } => {
+            let state: Game = program.account(game)?;
+            let Some(index) = state.players().iter().position(|p| *p == wallet.pubkey()) else {
+                bail!("{} doesn't play in game {game}", wallet.pubkey());
+            };
+            save_current_game(&game)?;
+            println!("Joined game {game}, you play {}", ["X", "O"][index]);
+            print_game(&state, &wallet.pubkey());
+        }
+        Command::Play { row, column, game } => {
+            let game = game.map_or_else(load_current_game, Ok)?;
+            let state: Game = program.account(game)?;
+            if state.is_active() && state.current_player() != wallet.pubkey() {
+                bail!("it's not your turn");
+            }
+            program
+                .request()
+                .accounts(tic_tac_toe::accounts::Play {
+                    game,
+                    player: wallet.pubkey(),
+                    chat: None,
+                })
+                .args(tic_tac_toe::instruction::Play {
+                    tile: Tile::new(row, column),
+                })
+                .send()?;
+            print_game(&program.account(game)?, &wallet.pubkey());
+        }
+        Command::Show { game } => {
+            let game = game.map_or_else(load_current_game, Ok)?;
+            print_game(&program.account(game)?, &wallet.pubkey());
+        }
+    }
+    Ok(())
+}
+
+fn print_game(game: &Game, wallet: &Pubkey) {
+    println!();
+    for (i, row) in game.board().iter().enumerate() {
+        let tiles: Vec<&str> = row
+            .iter()
+            .map(|tile| match tile {
+                Some(Sign::X) => "X",
+                Some(Sign::O) => "O",
+                None => " ",
+            })
+            .collect();
+        if i > 0 {
+            println!("---+---+---");
+        }
+        println!(" {} ", tiles.join(" | "));
+    }
+    println!();
+
+    match game.state() {
+        GameState::Active if game.current_player() == *wallet => println!("Your turn"),
+        GameState::Active => println!("Waiting for {}", game.current_player()),
+        GameState::Tie => println!("It's a tie"),
+        GameState::Won { winner } if winner == wallet => println!("You won!"),
+        GameState::Won { winner } => println!("{winner} won"),
+    }
+}
+
+fn expand_home(path: &str) -> Result<PathBuf> {
+    match path.strip_prefix("~/") {
+        Some(rest) => Ok(PathBuf::from(std::env::var("HOME")?).join(rest)),
+        None => Ok(PathBuf::from(path)),
+    }
+}
+
+fn current_game_path() -> Result<PathBuf> {
+    expand_home("~/.config/tic-tac-toe/game")
+}
+
+fn save_current_game(game: &Pubkey) -> Result<()> {
+    let path = current_game_path()?;
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(path, game.to_string())?;
+    Ok(())
+}
+
+fn load_current_game() -> Result<Pubkey> {
+    let game = std::fs::read_to_string(current_game_path()?)
+        .context("no current game, create one with `new-game` or pass `--game`")?;
+    Ok(game.trim().parse()?)
+}
@@ -0,0 +1,101 @@
+//! Sets up a game against a fresh keypair, plays it to the end and prints the board.
+//!
+//! Run it against a local validator with the program deployed, e.g. while `anchor localnet`
+//! is running: `cargo run -p tic-tac-toe-client`.
+
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use anchor_client::solana_sdk::system_program;
+use anchor_client::{Client, Cluster, Program};
+use anyhow::{anyhow, Result};
+use std::rc::Rc;
+use tic_tac_toe::state::game::{Game, GameState, Sign, Tile};
+
+// player one takes the top row while player two plays the middle one
+const MOVES: [(u8, u8); 5] = [(0, 0), (1, 0), (0, 1), (1, 1), (0, 2)];
+
+fn main() -> Result<()> {
+    let wallet = std::env::var("HOME")? + "/.config/solana/id.json";
+    let player_one = Rc::new(read_keypair_file(&wallet).map_err(|err| anyhow!("{wallet}: {err}"))?);
+    let player_two = Keypair::new();
+
+    let client = Client::new_with_options(
+        Cluster::Localnet,
+        player_one.clone(),
+        CommitmentConfig::confirmed(),
+    );
+    let program = client.program(tic_tac_toe::ID)?;
+
+    let game = Keypair::new();
+    program
+        .request()
+        .accounts(tic_tac_toe::accounts::SetupGame {
+            game: game.pubkey(),
+            player_one: player_one.pubkey(),
+            system_program: system_program::ID,
+        })
+        .args(tic_tac_toe::instruction::SetupGame {
+            player_two: player_two.pubkey(),
+        })
+        .signer(&game)
+        .send()?;
+    println!("game {} set up", game.pubkey());
+
+    // player one pays the fees of every move, player two only signs theirs
+    for (i, (row, column)) in MOVES.into_iter().enumerate() {
+        let player: &dyn Signer = if i % 2 == 0 {
+            player_one.as_ref()
+        } else {
+            &player_two
+        };
+        play(&program, &game.pubkey(), player, row, column)?;
+    }
+
+    let game: Game = program.account(game.pubkey())?;
+    print_game(&game);
+    Ok(())
+}
+
+fn play(
+    program: &Program<Rc<Keypair>>,
+    game: &Pubkey,
+    player: &dyn Signer,
+    row: u8,
+    column: u8,
+) -> Result<()> {
+    program
+        .request()
+        .accounts(tic_tac_toe::accounts::Play {
+            game: *game,
+            player: player.pubkey(),
+            chat: None,
+        })
+        .args(tic_tac_toe::instruction::Play {
+            tile: Tile::new(row, column),
+        })
+        .signer(player)
+        .send()?;
+    println!("{} played ({row}, {column})", player.pubkey());
+    Ok(())
+}
+
+fn print_game(game: &Game) {
+    for row in game.board() {
+        let tiles: Vec<&str> = row
+            .iter()
+            .map(|tile| match tile {
+                Some(Sign::X) => "X",
+                Some(Sign::O) => "O",
+                None => " ",
+            })
+            .collect();
+        println!(" {} ", tiles.join(" | "));
+    }
+
+    match game.state() {
+        GameState::Active => println!("still going, turn {}", game.turn()),
+        GameState::Tie => println!("it's a tie"),
+        GameState::Won { winner } => println!("{winner} won"),
+    }
+}
@@ -0,0 +1,71 @@
+//! Posts comments through the program's entrypoint, with any of a few accounts in each role,
+//! the accounts tampered with and accounts squatting on the chat's address, and checks that
+//! `init_if_needed` only ever creates the chat of a game, at its address and with the space
+//! it needs.
+//!
+//! `cargo +nightly fuzz run chat`
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use tic_tac_toe_fuzz::{Env, Key, Planted, Tamper, TileArg};
+
+#[derive(Arbitrary, Debug)]
+enum Step {
+    SetupGame {
+        game: Key,
+        player_one: Key,
+        player_two: Key,
+        tamper: Vec<Tamper>,
+    },
+    PostComment {
+        game: Key,
+        chat: Key,
+        sender: Key,
+        text: String,
+        tamper: Vec<Tamper>,
+    },
+    // moves can pass the chat along, which play reads but doesn't need
+    Play {
+        game: Key,
+        // the player whose turn it is if none
+        player: Option<Key>,
+        chat: Option<Key>,
+        tile: TileArg,
+        tamper: Vec<Tamper>,
+    },
+    Warp(i64),
+    Plant(Planted),
+}
+
+fuzz_target!(|steps: Vec<Step>| {
+    let mut env = Env::new();
+    for step in steps {
+        match step {
+            Step::SetupGame {
+                game,
+                player_one,
+                player_two,
+                tamper,
+            } => env.setup_game(game, player_one, player_two, tamper),
+            Step::PostComment {
+                game,
+                chat,
+                sender,
+                text,
+                tamper,
+            } => env.post_comment(game, chat, sender, text, tamper),
+            Step::Play {
+                game,
+                player,
+                chat,
+                tile,
+                tamper,
+            } => env.play(game, player, chat, tile, tamper),
+            Step::Warp(unix_timestamp) => env.warp(unix_timestamp),
+            Step::Plant(planted) => env.plant(planted),
+        }
+        env.check_accounts();
+    }
+});
@@ -0,0 +1,59 @@
+//! Sets up games and plays moves through the program's entrypoint, with any of a few accounts
+//! in each role and the accounts tampered with, and checks that a move only goes through when
+//! the player whose turn it is makes it on a free tile of their own game.
+//!
+//! `cargo +nightly fuzz run game`
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use tic_tac_toe_fuzz::{AccountArg, Env, Key, Planted, Tamper, TileArg};
+
+#[derive(Arbitrary, Debug)]
+enum Step {
+    SetupGame {
+        game: Key,
+        player_one: Key,
+        player_two: Key,
+        tamper: Vec<Tamper>,
+    },
+    Play {
+        game: Key,
+        // the player whose turn it is if none
+        player: Option<Key>,
+        chat: Option<Key>,
+        tile: TileArg,
+        tamper: Vec<Tamper>,
+    },
+    // any accounts, and instruction data that doesn't have to decode
+    Raw {
+        accounts: Vec<AccountArg>,
+        data: Vec<u8>,
+    },
+    Plant(Planted),
+}
+
+fuzz_target!(|steps: Vec<Step>| {
+    let mut env = Env::new();
+    for step in steps {
+        match step {
+            Step::SetupGame {
+                game,
+                player_one,
+                player_two,
+                tamper,
+            } => env.setup_game(game, player_one, player_two, tamper),
+            Step::Play {
+                game,
+                player,
+                chat,
+                tile,
+                tamper,
+            } => env.play(game, player, chat, tile, tamper),
+            Step::Raw { accounts, data } => env.raw(&accounts, data),
+            Step::Plant(planted) => env.plant(planted),
+        }
+        env.check_accounts();
+    }
+});
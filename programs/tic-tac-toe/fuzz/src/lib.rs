@@ -0,0 +1,453 @@
+//! Runs fuzzed tic-tac-toe instructions through the program's entrypoint and checks what
+//! has to hold whenever one of them succeeds.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::{system_program, Discriminator, InstructionData, ToAccountMetas};
+use arbitrary::Arbitrary;
+use runtime::{Account, Runtime};
+use tic_tac_toe::state::chat::{Chat, MAX_COMMENT_LENGTH};
+use tic_tac_toe::state::game::{Game, GameState, Sign, Tile};
+
+mod runtime;
+
+const PLAYERS: u8 = 3;
+const GAMES: u8 = 2;
+
+/// Any program but tic-tac-toe and the system program.
+const OTHER_PROGRAM: Pubkey = Pubkey::new_from_array([0xee; 32]);
+
+/// The accounts a fuzzed instruction can pass, so that inputs refer to the same few accounts
+/// again and again instead of fresh random keys.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Key {
+    Player(u8),
+    /// A keypair account a game can be set up in.
+    Game(u8),
+    /// The chat address of a game.
+    Chat(u8),
+    SystemProgram,
+    TicTacToe,
+}
+
+impl Key {
+    fn pubkey(self) -> Pubkey {
+        match self {
+            Key::Player(index) => Pubkey::new_from_array([1 + index % PLAYERS; 32]),
+            Key::Game(index) => Pubkey::new_from_array([0x10 + index % GAMES; 32]),
+            Key::Chat(index) => chat_address(&Key::Game(index).pubkey()),
+            Key::SystemProgram => system_program::ID,
+            Key::TicTacToe => tic_tac_toe::ID,
+        }
+    }
+}
+
+// only keypairs can sign a transaction
+fn can_sign(key: &Pubkey) -> bool {
+    let players = (0..PLAYERS).map(Key::Player);
+    let games = (0..GAMES).map(Key::Game);
+    players.chain(games).any(|keypair| keypair.pubkey() == *key)
+}
+
+fn chat_address(game: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"chat", game.as_ref()], &tic_tac_toe::ID).0
+}
+
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct AccountArg {
+    key: Key,
+    signer: bool,
+    writable: bool,
+}
+
+impl AccountArg {
+    fn meta(self) -> AccountMeta {
+        let pubkey = self.key.pubkey();
+        let is_signer = self.signer && can_sign(&pubkey);
+        if self.writable {
+            AccountMeta::new(pubkey, is_signer)
+        } else {
+            AccountMeta::new_readonly(pubkey, is_signer)
+        }
+    }
+}
+
+/// A change to the accounts of an instruction that is otherwise put together like a client
+/// would, so that inputs get past the checks on the accounts about as often as they trip them.
+/// Indices wrap around the accounts there are.
+#[derive(Arbitrary, Debug)]
+pub enum Tamper {
+    Unsign(u8),
+    Readonly(u8),
+    Replace(u8, AccountArg),
+    Remove(u8),
+    Swap(u8, u8),
+    Append(AccountArg),
+}
+
+fn tamper(mut accounts: Vec<AccountMeta>, tampers: Vec<Tamper>) -> Vec<AccountMeta> {
+    // a transaction only carries the signatures of keypairs
+    for account in &mut accounts {
+        account.is_signer &= can_sign(&account.pubkey);
+    }
+    for tamper in tampers {
+        let len = accounts.len();
+        let index = |index: u8| index as usize % len;
+        match tamper {
+            Tamper::Append(account) => accounts.push(account.meta()),
+            _ if len == 0 => {}
+            Tamper::Unsign(i) => accounts[index(i)].is_signer = false,
+            Tamper::Readonly(i) => accounts[index(i)].is_writable = false,
+            Tamper::Replace(i, account) => accounts[index(i)] = account.meta(),
+            Tamper::Remove(i) => {
+                accounts.remove(index(i));
+            }
+            Tamper::Swap(i, j) => accounts.swap(index(i), index(j)),
+        }
+    }
+    accounts
+}
+
+/// A tile on the board, or any row and column for the bounds checks.
+#[derive(Arbitrary, Debug)]
+pub enum TileArg {
+    OnBoard(u8, u8),
+    Any(u8, u8),
+}
+
+impl TileArg {
+    fn row_column(&self) -> (u8, u8) {
+        match *self {
+            TileArg::OnBoard(row, column) => (row % 3, column % 3),
+            TileArg::Any(row, column) => (row, column),
+        }
+    }
+}
+
+/// The types of account the program owns, to put their discriminator in front of planted data.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Kind {
+    Game,
+    Chat,
+}
+
+/// An account someone other than tic-tac-toe created, e.g. one posing as a game or squatting
+/// on the address of a chat.
+#[derive(Arbitrary, Debug)]
+pub struct Planted {
+    key: Key,
+    foreign: bool,
+    lamports: u64,
+    kind: Option<Kind>,
+    data: Vec<u8>,
+}
+
+/// The tic-tac-toe program with a few funded players.
+pub struct Env {
+    runtime: Runtime,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        let mut runtime = Runtime::new();
+        for index in 0..PLAYERS {
+            let player = Account {
+                lamports: 10_000_000_000,
+                ..Account::default()
+            };
+            runtime.set_account(Key::Player(index).pubkey(), player);
+        }
+        Self { runtime }
+    }
+
+    pub fn warp(&mut self, unix_timestamp: i64) {
+        runtime::set_unix_timestamp(unix_timestamp);
+    }
+
+    /// Puts down an account the program doesn't own. Accounts the program or a loader own
+    /// can't be forged, so those are left alone.
+    pub fn plant(&mut self, planted: Planted) {
+        let key = planted.key.pubkey();
+        if let Some(account) = self.runtime.account(&key) {
+            if account.owner == tic_tac_toe::ID || account.executable {
+                return;
+            }
+        }
+        let mut data = match planted.kind {
+            Some(Kind::Game) => Game::DISCRIMINATOR.to_vec(),
+            Some(Kind::Chat) => Chat::DISCRIMINATOR.to_vec(),
+            None => Vec::new(),
+        };
+        data.extend(planted.data);
+        let account = Account {
+            lamports: planted.lamports,
+            data,
+            owner: if planted.foreign {
+                OTHER_PROGRAM
+            } else {
+                system_program::ID
+            },
+            executable: false,
+        };
+        self.runtime.set_account(key, account);
+    }
+
+    fn owned_data(&self, key: &Pubkey) -> Option<&[u8]> {
+        self.runtime
+            .account(key)
+            .filter(|account| account.owner == tic_tac_toe::ID)
+            .map(|account| account.data.as_slice())
+    }
+
+    fn game(&self, key: &Pubkey) -> Option<Game> {
+        Game::try_deserialize(&mut self.owned_data(key)?).ok()
+    }
+
+    fn chat(&self, key: &Pubkey) -> Option<Chat> {
+        Chat::try_deserialize(&mut self.owned_data(key)?).ok()
+    }
+
+    fn process(
+        &mut self,
+        accounts: &[AccountMeta],
+        data: Vec<u8>,
+    ) -> std::result::Result<Vec<u8>, InstructionError> {
+        let instruction = Instruction {
+            program_id: tic_tac_toe::ID,
+            accounts: accounts.to_vec(),
+            data,
+        };
+        self.runtime.process(&instruction)
+    }
+
+    /// Sends instruction data that doesn't have to decode.
+    pub fn raw(&mut self, accounts: &[AccountArg], data: Vec<u8>) {
+        let accounts: Vec<_> = accounts.iter().map(|account| account.meta()).collect();
+        let _ = self.process(&accounts, data);
+    }
+
+    pub fn setup_game(
+        &mut self,
+        game: Key,
+        player_one: Key,
+        player_two: Key,
+        tampers: Vec<Tamper>,
+    ) {
+        let accounts = tic_tac_toe::accounts::SetupGame {
+            game: game.pubkey(),
+            player_one: player_one.pubkey(),
+            system_program: system_program::ID,
+        };
+        let accounts = tamper(accounts.to_account_metas(None), tampers);
+        let player_two = player_two.pubkey();
+        let existed = accounts
+            .first()
+            .map(|game| self.owned_data(&game.pubkey).is_some());
+        let data = tic_tac_toe::instruction::SetupGame { player_two }.data();
+        if self.process(&accounts, data).is_err() {
+            return;
+        }
+
+        let (game, player_one) = (&accounts[0], &accounts[1]);
+        assert_eq!(
+            existed,
+            Some(false),
+            "set up a game over an existing account"
+        );
+        assert!(
+            signed(&accounts, game),
+            "created a game account without its signature"
+        );
+        assert!(signed(&accounts, player_one), "player one didn't sign");
+        let game = self
+            .game(&game.pubkey)
+            .expect("setup_game didn't create a game");
+        assert_eq!(game.players(), [player_one.pubkey, player_two]);
+        assert_eq!(game.turn(), 1);
+        assert!(game.is_active());
+        assert!(game.board() == [[None; 3]; 3]);
+    }
+
+    pub fn play(
+        &mut self,
+        game: Key,
+        player: Option<Key>,
+        chat: Option<Key>,
+        tile: TileArg,
+        tampers: Vec<Tamper>,
+    ) {
+        let game = game.pubkey();
+        // whoever's turn it is, unless the input picks someone
+        let player = match player {
+            Some(player) => player.pubkey(),
+            None => self
+                .game(&game)
+                .map_or(Key::Player(0).pubkey(), |game| game.current_player()),
+        };
+        let accounts = tic_tac_toe::accounts::Play {
+            game,
+            player,
+            chat: chat.map(Key::pubkey),
+        };
+        let accounts = tamper(accounts.to_account_metas(None), tampers);
+        let before = accounts.first().and_then(|game| self.game(&game.pubkey));
+        let (row, column) = tile.row_column();
+        let data = tic_tac_toe::instruction::Play {
+            tile: Tile::new(row, column),
+        }
+        .data();
+        let Ok(return_data) = self.process(&accounts, data) else {
+            return;
+        };
+
+        let (game, player) = (&accounts[0], &accounts[1]);
+        let before = before.expect("played on an account that wasn't a game");
+        assert!(signed(&accounts, player), "the player didn't sign");
+        assert_eq!(before.current_player(), player.pubkey, "played out of turn");
+        let after = self.game(&game.pubkey).unwrap();
+        let sign = [Sign::X, Sign::O][(before.turn() as usize - 1) % 2];
+        let (row, column) = (row as usize, column as usize);
+        for (r, (before, after)) in before.board().iter().zip(after.board().iter()).enumerate() {
+            for (c, (before, after)) in before.iter().zip(after).enumerate() {
+                if (r, c) == (row, column) {
+                    assert!(before.is_none(), "overwrote ({r}, {c})");
+                    assert!(*after == Some(sign));
+                } else {
+                    assert!(after == before, "changed ({r}, {c})");
+                }
+            }
+        }
+        let returned = GameState::try_from_slice(&return_data).expect("returned no game state");
+        assert!(
+            returned == *after.state(),
+            "returned a different state than it saved"
+        );
+
+        // the chat is optional, passing the program's address leaves it out
+        if let Some(chat) = accounts
+            .get(2)
+            .filter(|chat| chat.pubkey != tic_tac_toe::ID)
+        {
+            assert_eq!(chat.pubkey, chat_address(&game.pubkey), "took another chat");
+            assert!(
+                self.chat(&chat.pubkey).is_some(),
+                "took a chat that isn't one"
+            );
+        }
+    }
+
+    pub fn post_comment(
+        &mut self,
+        game: Key,
+        chat: Key,
+        sender: Key,
+        text: String,
+        tampers: Vec<Tamper>,
+    ) {
+        let accounts = tic_tac_toe::accounts::PostComment {
+            game: game.pubkey(),
+            chat: chat.pubkey(),
+            sender: sender.pubkey(),
+            system_program: system_program::ID,
+        };
+        let accounts = tamper(accounts.to_account_metas(None), tampers);
+        let before = accounts.get(1).and_then(|chat| self.chat(&chat.pubkey));
+        let sender_lamports = accounts
+            .get(2)
+            .and_then(|sender| self.runtime.account(&sender.pubkey))
+            .map(|sender| sender.lamports);
+        let valid = !text.is_empty() && text.len() <= MAX_COMMENT_LENGTH;
+        let data = tic_tac_toe::instruction::PostComment { text }.data();
+        if self.process(&accounts, data).is_err() {
+            return;
+        }
+
+        let (game, chat, sender) = (&accounts[0], &accounts[1], &accounts[2]);
+        assert!(valid, "posted an empty or too long comment");
+        assert!(
+            self.game(&game.pubkey).is_some(),
+            "commented on something that isn't a game"
+        );
+        assert_eq!(
+            chat.pubkey,
+            chat_address(&game.pubkey),
+            "wrote to another chat"
+        );
+        assert!(signed(&accounts, sender), "the sender didn't sign");
+        let after = self
+            .chat(&chat.pubkey)
+            .expect("post_comment didn't leave a chat");
+        assert_eq!(after.game(), game.pubkey);
+        let count = before.as_ref().map_or(0, |before| before.comment_count());
+        assert_eq!(after.comment_count(), count + 1);
+        // init_if_needed only charges for a chat it creates
+        if before.is_some() {
+            let sender_after = self
+                .runtime
+                .account(&sender.pubkey)
+                .map(|sender| sender.lamports);
+            assert_eq!(
+                sender_after, sender_lamports,
+                "charged for an existing chat"
+            );
+        }
+    }
+
+    /// Checks the accounts the program owns, which must all be games and chats that fit
+    /// into the space the program gave them and make sense on their own.
+    pub fn check_accounts(&self) {
+        let owned = self
+            .runtime
+            .accounts()
+            .filter(|(_, account)| account.owner == tic_tac_toe::ID);
+        for (key, account) in owned {
+            if let Some(game) = self.game(key) {
+                assert_eq!(account.data.len(), Game::MAXIMUM_SIZE + 8);
+                check_game(&game);
+            } else if let Some(chat) = self.chat(key) {
+                assert_eq!(account.data.len(), Chat::MAXIMUM_SIZE + 8);
+                assert_eq!(
+                    *key,
+                    chat_address(&chat.game()),
+                    "chat at the wrong address"
+                );
+                assert!(self.game(&chat.game()).is_some(), "chat without a game");
+                assert!(chat.comment_count() > 0, "chat without comments");
+            } else {
+                panic!("{key} is neither a game nor a chat");
+            }
+        }
+    }
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// the runtime gives all metas of an account the privileges of any of them
+fn signed(accounts: &[AccountMeta], account: &AccountMeta) -> bool {
+    accounts
+        .iter()
+        .any(|meta| meta.pubkey == account.pubkey && meta.is_signer)
+}
+
+fn check_game(game: &Game) {
+    assert!((1..=9).contains(&game.turn()), "turn {}", game.turn());
+    // one sign per turn, the last turn doesn't advance
+    let filled = game
+        .board()
+        .iter()
+        .flatten()
+        .filter(|tile| tile.is_some())
+        .count();
+    let turns_played = game.turn() as usize - game.is_active() as usize;
+    assert_eq!(filled, turns_played);
+    if let GameState::Won { winner } = game.state() {
+        assert!(
+            game.players().contains(winner),
+            "{winner} won without playing"
+        );
+    }
+}
@@ -0,0 +1,411 @@
+//! Just enough of the Solana runtime to run the tic-tac-toe program off-chain.
+//!
+//! Accounts are serialized into the same input buffer the BPF loader hands a program, so
+//! `entry` deserializes, reallocates and writes them exactly as it does on-chain. Calls into
+//! the system program, the clock and the rent sysvar go through syscall stubs. After the
+//! program returns, the changes are checked against the runtime's rules and only kept if the
+//! instruction succeeded, like in a transaction.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::entrypoint::{
+    self, ProgramResult, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER, SUCCESS,
+};
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::solana_program::program_stubs::{self, SyscallStubs};
+use anchor_lang::solana_program::system_instruction::SystemInstruction;
+use anchor_lang::system_program;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Mutex, Once};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Account {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+pub struct Runtime {
+    accounts: BTreeMap<Pubkey, Account>,
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+        set_unix_timestamp(0);
+
+        // owns the builtin programs, like the system program
+        let native_loader =
+            Pubkey::from_str("NativeLoader1111111111111111111111111111111").unwrap();
+        let mut accounts = BTreeMap::new();
+        for (key, owner) in [
+            (system_program::ID, native_loader),
+            (tic_tac_toe::ID, bpf_loader_upgradeable::ID),
+        ] {
+            let program = Account {
+                lamports: 1,
+                owner,
+                executable: true,
+                ..Account::default()
+            };
+            accounts.insert(key, program);
+        }
+        Self { accounts }
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.get(key)
+    }
+
+    pub fn accounts(&self) -> impl Iterator<Item = (&Pubkey, &Account)> {
+        self.accounts.iter()
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: Account) {
+        self.accounts.insert(key, account);
+    }
+
+    /// Runs an instruction of the tic-tac-toe program and returns its return data. Every
+    /// signer in `instruction.accounts` is taken to have signed the transaction.
+    pub fn process(
+        &mut self,
+        instruction: &Instruction,
+    ) -> std::result::Result<Vec<u8>, InstructionError> {
+        assert_eq!(instruction.program_id, tic_tac_toe::ID);
+        // a duplicate refers to the first one by a one byte index
+        if instruction.accounts.len() > u8::MAX as usize {
+            return Err(InstructionError::MaxAccountsExceeded);
+        }
+
+        // duplicates are serialized once, with the privileges of all their metas
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut privileges: Vec<(bool, bool)> = Vec::new();
+        let mut positions = Vec::new();
+        for meta in &instruction.accounts {
+            let position = match keys.iter().position(|key| *key == meta.pubkey) {
+                Some(position) => position,
+                None => {
+                    keys.push(meta.pubkey);
+                    privileges.push((false, false));
+                    keys.len() - 1
+                }
+            };
+            privileges[position].0 |= meta.is_signer;
+            privileges[position].1 |= meta.is_writable;
+            positions.push(position);
+        }
+        // missing accounts are empty ones the system program owns, its address is all zeroes
+        let before: Vec<Account> = keys
+            .iter()
+            .map(|key| self.accounts.get(key).cloned().unwrap_or_default())
+            .collect();
+
+        let mut input = Input::default();
+        input.write_u64(instruction.accounts.len() as u64);
+        let mut offsets = vec![0; keys.len()];
+        let mut serialized = vec![false; keys.len()];
+        for &position in &positions {
+            if serialized[position] {
+                let first = positions.iter().position(|p| *p == position).unwrap();
+                input.write(&[first as u8]);
+                input.write(&[0; 7]);
+                continue;
+            }
+            serialized[position] = true;
+            let account = &before[position];
+            let (is_signer, is_writable) = privileges[position];
+            input.write(&[
+                NON_DUP_MARKER,
+                is_signer as u8,
+                is_writable as u8,
+                account.executable as u8,
+            ]);
+            input.write(&[0; 4]);
+            input.write(keys[position].as_ref());
+            offsets[position] = input.len();
+            input.write(account.owner.as_ref());
+            input.write_u64(account.lamports);
+            input.write_u64(account.data.len() as u64);
+            input.write(&account.data);
+            input.write(&[0; MAX_PERMITTED_DATA_INCREASE]);
+            input.align();
+            input.write_u64(0);
+        }
+        input.write_u64(instruction.data.len() as u64);
+        input.write(&instruction.data);
+        input.write(tic_tac_toe::ID.as_ref());
+
+        RETURN_DATA.lock().unwrap().clear();
+        let mut buffer = input.into_aligned();
+        {
+            // SAFETY: the buffer is laid out like the loader's and outlives the account infos
+            let (program_id, accounts, data) =
+                unsafe { entrypoint::deserialize(buffer.as_mut_ptr() as *mut u8) };
+            tic_tac_toe::entry(program_id, &accounts, data)
+                .map_err(|error| InstructionError::from(u64::from(error)))?;
+        }
+
+        let bytes =
+            unsafe { std::slice::from_raw_parts(buffer.as_ptr() as *const u8, buffer.len() * 8) };
+        let after: Vec<Account> = offsets
+            .iter()
+            .zip(&before)
+            .map(|(&offset, before)| {
+                let read_u64 =
+                    |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+                let data_len = read_u64(offset + 40) as usize;
+                Account {
+                    owner: Pubkey::try_from(&bytes[offset..offset + 32]).unwrap(),
+                    lamports: read_u64(offset + 32),
+                    data: bytes[offset + 48..offset + 48 + data_len].to_vec(),
+                    executable: before.executable,
+                }
+            })
+            .collect();
+
+        check_changes(&before, &after, &privileges)?;
+        for (key, account) in keys.into_iter().zip(after) {
+            if account.lamports == 0
+                && account.data.is_empty()
+                && account.owner == system_program::ID
+            {
+                self.accounts.remove(&key);
+            } else {
+                self.accounts.insert(key, account);
+            }
+        }
+        Ok(std::mem::take(&mut *RETURN_DATA.lock().unwrap()))
+    }
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The rules the runtime enforces on what an instruction did to its accounts. Unlike the
+/// runtime, which also checks around each cross-program invocation, this only compares the
+/// accounts before and after, so the system program's part is taken on trust from the stubs.
+fn check_changes(
+    before: &[Account],
+    after: &[Account],
+    privileges: &[(bool, bool)],
+) -> std::result::Result<(), InstructionError> {
+    let total = |accounts: &[Account]| -> u128 {
+        accounts
+            .iter()
+            .map(|account| account.lamports as u128)
+            .sum()
+    };
+    if total(before) != total(after) {
+        return Err(InstructionError::UnbalancedInstruction);
+    }
+    let rent = Rent::default();
+    for ((before, after), &(_, is_writable)) in before.iter().zip(after).zip(privileges) {
+        if before == after {
+            continue;
+        }
+        if !is_writable {
+            return Err(InstructionError::ReadonlyDataModified);
+        }
+        // only the owner can take lamports and change data, the system program owns
+        // accounts until it assigns them
+        let owned = before.owner == tic_tac_toe::ID;
+        let unallocated = before.owner == system_program::ID && before.data.is_empty();
+        if after.lamports < before.lamports && !owned && before.owner != system_program::ID {
+            return Err(InstructionError::ExternalAccountLamportSpend);
+        }
+        if after.data != before.data && !owned && !unallocated {
+            return Err(InstructionError::ExternalAccountDataModified);
+        }
+        if after.owner != before.owner && !owned && !unallocated {
+            return Err(InstructionError::ModifiedProgramId);
+        }
+        if after.owner == tic_tac_toe::ID && !rent.is_exempt(after.lamports, after.data.len()) {
+            return Err(InstructionError::InsufficientFunds);
+        }
+    }
+    Ok(())
+}
+
+/// The input buffer of an instruction, see `entrypoint::deserialize`.
+#[derive(Default)]
+struct Input(Vec<u8>);
+
+impl Input {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn align(&mut self) {
+        let padding = self.0.len().next_multiple_of(8) - self.0.len();
+        self.write(&[0; 8][..padding]);
+    }
+
+    // the program reads the u64s in the buffer in place
+    fn into_aligned(self) -> Vec<u64> {
+        self.0
+            .chunks(8)
+            .map(|chunk| {
+                let mut word = [0; 8];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u64::from_le_bytes(word)
+            })
+            .collect()
+    }
+}
+
+static UNIX_TIMESTAMP: AtomicI64 = AtomicI64::new(0);
+static RETURN_DATA: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+pub fn set_unix_timestamp(unix_timestamp: i64) {
+    UNIX_TIMESTAMP.store(unix_timestamp, Ordering::Relaxed);
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    // the program logs every error, which would drown out the fuzzer's output
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: UNIX_TIMESTAMP.load(Ordering::Relaxed),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        *RETURN_DATA.lock().unwrap() = data.to_vec();
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        // all the program ever calls
+        if instruction.program_id != system_program::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        invoke_system_program(instruction, account_infos, signers_seeds)
+    }
+}
+
+/// The parts of the system program that `init` and `init_if_needed` use.
+fn invoke_system_program(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let signed_by_program = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &tic_tac_toe::ID))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    // the accounts of the instruction, which can't have more privileges than the caller
+    // gave them, except for the addresses the program signs for
+    let account =
+        |index: usize| -> std::result::Result<(&AccountInfo, &AccountMeta), ProgramError> {
+            let meta = instruction
+                .accounts
+                .get(index)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let info = account_infos
+                .iter()
+                .find(|info| *info.key == meta.pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if meta.is_writable && !info.is_writable {
+                return Err(ProgramError::InvalidArgument);
+            }
+            if meta.is_signer && !info.is_signer && !signed_by_program.contains(info.key) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            Ok((info, meta))
+        };
+    let signer = |index: usize| -> std::result::Result<&AccountInfo, ProgramError> {
+        let (info, meta) = account(index)?;
+        if !meta.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Ok(info)
+    };
+
+    fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+        if !from.data_is_empty() || *from.owner != system_program::ID {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let from_lamports = from
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        let to_lamports = to
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **from.try_borrow_mut_lamports()? = from_lamports;
+        **to.try_borrow_mut_lamports()? = to_lamports;
+        Ok(())
+    }
+
+    fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+        if !account.data_is_empty() || *account.owner != system_program::ID {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        account.realloc(space as usize, true)
+    }
+
+    fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+        if account.owner != owner {
+            account.assign(owner);
+        }
+        Ok(())
+    }
+
+    let instruction = bincode::deserialize(&instruction.data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    match instruction {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let (from, to) = (signer(0)?, signer(1)?);
+            if to.lamports() > 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            allocate(to, space)?;
+            assign(to, &owner)?;
+            transfer(from, to, lamports)
+        }
+        SystemInstruction::Transfer { lamports } => transfer(signer(0)?, account(1)?.0, lamports),
+        SystemInstruction::Allocate { space } => allocate(signer(0)?, space),
+        SystemInstruction::Assign { owner } => assign(signer(0)?, &owner),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -7,4 +7,6 @@ pub enum TicTacToeError {
     GameAlreadyOver,
     NotPlayersTurn,
     GameAlreadyStarted,
+    CommentEmpty,
+    CommentTooLong,
 }
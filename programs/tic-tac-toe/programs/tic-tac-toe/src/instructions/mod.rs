@@ -1,5 +1,7 @@
 pub use play::*;
+pub use post_comment::*;
 pub use setup_game::*;
 
 pub mod play;
+pub mod post_comment;
 pub mod setup_game;
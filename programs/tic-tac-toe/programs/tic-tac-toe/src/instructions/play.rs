@@ -1,8 +1,11 @@
 use crate::errors::TicTacToeError;
+use crate::state::chat::*;
 use crate::state::game::*;
 use anchor_lang::prelude::*;
 
-pub fn play(ctx: Context<Play>, tile: Tile) -> Result<()> {
+/// Returns the state of the game after the move, so clients can tell whether it ended
+/// without fetching the account.
+pub fn play(ctx: Context<Play>, tile: Tile) -> Result<GameState> {
     let game = &mut ctx.accounts.game;
 
     require_keys_eq!(
@@ -11,7 +14,8 @@ pub fn play(ctx: Context<Play>, tile: Tile) -> Result<()> {
         TicTacToeError::NotPlayersTurn
     );
 
-    game.play(&tile)
+    game.play(&tile)?;
+    Ok(game.state().clone())
 }
 
 #[derive(Accounts)]
@@ -19,4 +23,8 @@ pub struct Play<'info> {
     #[account(mut)]
     pub game: Account<'info, Game>,
     pub player: Signer<'info>,
+    // optional and only read: the move doesn't need it, but clients may pass the game's chat
+    // along, e.g. from an address lookup table holding the accounts of the game
+    #[account(seeds = [b"chat", game.key().as_ref()], bump)]
+    pub chat: Option<Account<'info, Chat>>,
 }
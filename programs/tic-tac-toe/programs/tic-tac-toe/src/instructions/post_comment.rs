@@ -0,0 +1,31 @@
+use crate::state::chat::*;
+use crate::state::game::*;
+use anchor_lang::prelude::*;
+
+pub fn post_comment(ctx: Context<PostComment>, text: String) -> Result<()> {
+    let comment = Comment::new(
+        ctx.accounts.sender.key(),
+        text,
+        Clock::get()?.unix_timestamp,
+    );
+    ctx.accounts
+        .chat
+        .post(ctx.accounts.game.key(), ctx.bumps.chat, comment)
+}
+
+#[derive(Accounts)]
+pub struct PostComment<'info> {
+    pub game: Account<'info, Game>,
+    // anyone can comment, not just the players
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = Chat::MAXIMUM_SIZE + 8,
+        seeds = [b"chat", game.key().as_ref()],
+        bump
+    )]
+    pub chat: Account<'info, Chat>,
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use instructions::*;
-use state::game::Tile;
+use state::game::{GameState, Tile};
 
 pub mod errors;
 pub mod instructions;
@@ -17,7 +17,11 @@ pub mod tic_tac_toe {
         instructions::setup_game::setup_game(ctx, player_two)
     }
 
-    pub fn play(ctx: Context<Play>, tile: Tile) -> Result<()> {
+    pub fn play(ctx: Context<Play>, tile: Tile) -> Result<GameState> {
         instructions::play::play(ctx, tile)
     }
+
+    pub fn post_comment(ctx: Context<PostComment>, text: String) -> Result<()> {
+        instructions::post_comment::post_comment(ctx, text)
+    }
 }
@@ -0,0 +1,69 @@
+use crate::errors::TicTacToeError;
+use anchor_lang::prelude::*;
+
+pub const CHAT_CAPACITY: usize = 16;
+pub const MAX_COMMENT_LENGTH: usize = 64;
+
+#[account]
+pub struct Chat {
+    game: Pubkey,           // 32
+    comment_count: u64,     // 8
+    comments: Vec<Comment>, // 4 + CHAT_CAPACITY * Comment::MAXIMUM_SIZE
+    bump: u8,               // 1
+}
+
+impl Chat {
+    pub const MAXIMUM_SIZE: usize = 32 + 8 + (4 + CHAT_CAPACITY * Comment::MAXIMUM_SIZE) + 1;
+
+    pub fn game(&self) -> Pubkey {
+        self.game
+    }
+
+    pub fn comment_count(&self) -> u64 {
+        self.comment_count
+    }
+
+    pub fn post(&mut self, game: Pubkey, bump: u8, comment: Comment) -> Result<()> {
+        require!(!comment.text.is_empty(), TicTacToeError::CommentEmpty);
+        require!(
+            comment.text.len() <= MAX_COMMENT_LENGTH,
+            TicTacToeError::CommentTooLong
+        );
+
+        // the first comment creates the chat
+        if self.comment_count == 0 {
+            self.game = game;
+            self.bump = bump;
+        }
+
+        // once full, each comment replaces the oldest one, which is always
+        // the one right after the newest
+        let index = (self.comment_count % CHAT_CAPACITY as u64) as usize;
+        if self.comments.len() < CHAT_CAPACITY {
+            self.comments.push(comment);
+        } else {
+            self.comments[index] = comment;
+        }
+        self.comment_count += 1;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Comment {
+    sender: Pubkey, // 32
+    text: String,   // 4 + MAX_COMMENT_LENGTH
+    timestamp: i64, // 8
+}
+
+impl Comment {
+    pub const MAXIMUM_SIZE: usize = 32 + (4 + MAX_COMMENT_LENGTH) + 8;
+
+    pub fn new(sender: Pubkey, text: String, timestamp: i64) -> Self {
+        Self {
+            sender,
+            text,
+            timestamp,
+        }
+    }
+}
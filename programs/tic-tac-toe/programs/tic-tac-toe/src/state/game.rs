@@ -5,14 +5,14 @@ use num_traits::*;
 
 #[account]
 pub struct Game {
-    players: [Pubkey; 2],          // (32 * 2)
-    turn: u8,                      // 1
-    board: [[Option<Sign>; 3]; 3], // 9 * (1 + 1) = 18
-    state: GameState,              // 32 + 1
+    players: [Pubkey; 2], // (32 * 2)
+    turn: u8,             // 1
+    board: u32,           // 4, two bits per tile, see `tile`
+    state: GameState,     // 32 + 1
 }
 
 impl Game {
-    pub const MAXIMUM_SIZE: usize = (32 * 2) + 1 + (9 * (1 + 1)) + (32 + 1);
+    pub const MAXIMUM_SIZE: usize = (32 * 2) + 1 + 4 + (32 + 1);
 
     pub fn start(&mut self, players: [Pubkey; 2]) -> Result<()> {
         require_eq!(self.turn, 0, TicTacToeError::GameAlreadyStarted);
@@ -21,6 +21,45 @@ impl Game {
         Ok(())
     }
 
+    pub fn players(&self) -> [Pubkey; 2] {
+        self.players
+    }
+
+    pub fn turn(&self) -> u8 {
+        self.turn
+    }
+
+    /// The sign on a tile. Tiles take two bits of `board` each, row by row from the
+    /// lowest bits: 0 for an empty tile, 1 for X and 2 for O.
+    pub fn tile(&self, row: usize, column: usize) -> Option<Sign> {
+        let bits = (self.board >> Self::tile_shift(row, column)) & 0b11;
+        bits.checked_sub(1).and_then(Sign::from_u32)
+    }
+
+    // only called on empty tiles, whose bits are still 0
+    fn set_tile(&mut self, row: usize, column: usize, sign: Sign) {
+        self.board |= (sign.to_u32().unwrap() + 1) << Self::tile_shift(row, column);
+    }
+
+    fn tile_shift(row: usize, column: usize) -> usize {
+        2 * (3 * row + column)
+    }
+
+    /// All tiles, unpacked.
+    pub fn board(&self) -> [[Option<Sign>; 3]; 3] {
+        let mut board = [[None; 3]; 3];
+        for (row, tiles) in board.iter_mut().enumerate() {
+            for (column, tile) in tiles.iter_mut().enumerate() {
+                *tile = self.tile(row, column);
+            }
+        }
+        board
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
     pub fn is_active(&self) -> bool {
         self.state == GameState::Active
     }
@@ -40,11 +79,14 @@ impl Game {
             tile @ Tile {
                 row: 0..=2,
                 column: 0..=2,
-            } => match self.board[tile.row as usize][tile.column as usize] {
+            } => match self.tile(tile.row as usize, tile.column as usize) {
                 Some(_) => return Err(TicTacToeError::TileAlreadySet.into()),
                 None => {
-                    self.board[tile.row as usize][tile.column as usize] =
-                        Some(Sign::from_usize(self.current_player_index()).unwrap());
+                    self.set_tile(
+                        tile.row as usize,
+                        tile.column as usize,
+                        Sign::from_usize(self.current_player_index()).unwrap(),
+                    );
                 }
             },
             _ => return Err(TicTacToeError::TileOutOfBounds.into()),
@@ -61,9 +103,10 @@ impl Game {
 
     fn is_winning_trio(&self, trio: [(usize, usize); 3]) -> bool {
         let [first, second, third] = trio;
-        self.board[first.0][first.1].is_some()
-            && self.board[first.0][first.1] == self.board[second.0][second.1]
-            && self.board[first.0][first.1] == self.board[third.0][third.1]
+        let sign = self.tile(first.0, first.1);
+        sign.is_some()
+            && sign == self.tile(second.0, second.1)
+            && sign == self.tile(third.0, third.1)
     }
 
     fn update_state(&mut self) {
@@ -98,7 +141,7 @@ impl Game {
         // so if there are unfilled tiles left, it's still active
         for row in 0..=2 {
             for column in 0..=2 {
-                if self.board[row][column].is_none() {
+                if self.tile(row, column).is_none() {
                     return;
                 }
             }
@@ -131,3 +174,165 @@ pub struct Tile {
     row: u8,
     column: u8,
 }
+
+impl Tile {
+    pub fn new(row: u8, column: u8) -> Self {
+        Self { row, column }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const PLAYERS: [Pubkey; 2] = [
+        Pubkey::new_from_array([1; 32]),
+        Pubkey::new_from_array([2; 32]),
+    ];
+
+    fn new_game() -> Game {
+        let mut game = Game {
+            players: [Pubkey::default(); 2],
+            turn: 0,
+            board: 0,
+            state: GameState::Active,
+        };
+        game.start(PLAYERS).unwrap();
+        game
+    }
+
+    fn tile(index: usize) -> Tile {
+        Tile::new((index / 3) as u8, (index % 3) as u8)
+    }
+
+    // Every set of three distinct collinear tiles, found without the list of rows, columns
+    // and diagonals that `update_state` relies on.
+    fn all_lines() -> Vec<[(usize, usize); 3]> {
+        let tiles: Vec<(usize, usize)> = (0..9).map(|i| (i / 3, i % 3)).collect();
+        let mut lines = vec![];
+        for a in 0..9 {
+            for b in a + 1..9 {
+                for c in b + 1..9 {
+                    let [(r0, c0), (r1, c1), (r2, c2)] = [tiles[a], tiles[b], tiles[c]];
+                    let cross = (r1 as i32 - r0 as i32) * (c2 as i32 - c0 as i32)
+                        - (c1 as i32 - c0 as i32) * (r2 as i32 - r0 as i32);
+                    if cross == 0 {
+                        lines.push([tiles[a], tiles[b], tiles[c]]);
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    fn brute_force_winners(board: &[[Option<Sign>; 3]; 3]) -> Vec<Sign> {
+        let mut winners = vec![];
+        for [a, b, c] in all_lines() {
+            if let Some(sign) = board[a.0][a.1] {
+                if board[b.0][b.1] == Some(sign) && board[c.0][c.1] == Some(sign) {
+                    winners.push(sign);
+                }
+            }
+        }
+        winners.dedup();
+        winners
+    }
+
+    fn filled(game: &Game) -> usize {
+        game.board()
+            .iter()
+            .flatten()
+            .filter(|tile| tile.is_some())
+            .count()
+    }
+
+    // a shuffled order of all tiles, and how many of them to play
+    fn moves() -> impl Strategy<Value = (Vec<usize>, usize)> {
+        (
+            Just((0..9).collect::<Vec<usize>>()).prop_shuffle(),
+            0..=9usize,
+        )
+    }
+
+    #[test]
+    fn every_board_survives_packing() {
+        // all 3^9 ways to fill the tiles, most of which can't come up in a game
+        for mut n in 0..3usize.pow(9) {
+            let mut game = new_game();
+            let mut board = [[None; 3]; 3];
+            for index in 0..9 {
+                let sign = [None, Some(Sign::X), Some(Sign::O)][n % 3];
+                n /= 3;
+                if let Some(sign) = sign {
+                    game.set_tile(index / 3, index % 3, sign);
+                }
+                board[index / 3][index % 3] = sign;
+            }
+            assert!(game.board() == board);
+            assert!(game.board < 1 << 18);
+        }
+    }
+
+    #[test]
+    fn brute_force_finds_eight_lines() {
+        assert_eq!(all_lines().len(), 8);
+    }
+
+    proptest! {
+        #[test]
+        fn legal_games_stay_consistent((order, count) in moves()) {
+            let mut game = new_game();
+            // the board as it was stored before it got packed
+            let mut expected_board = [[None; 3]; 3];
+            for &index in &order[..count] {
+                if !game.is_active() {
+                    prop_assert!(game.play(&tile(index)).is_err());
+                    break;
+                }
+                let player = game.current_player();
+                let sign = Sign::from_usize(game.current_player_index()).unwrap();
+                game.play(&tile(index)).unwrap();
+                expected_board[index / 3][index % 3] = Some(sign);
+                prop_assert!(game.board() == expected_board);
+
+                // one sign per turn, the turn doesn't advance past the last move
+                let turns_played = game.turn as usize - game.is_active() as usize;
+                prop_assert_eq!(filled(&game), turns_played);
+
+                let winners = brute_force_winners(&game.board());
+                prop_assert!(winners.len() <= 1, "two winners");
+                match &game.state {
+                    GameState::Won { winner } => {
+                        prop_assert_eq!(winners.len(), 1);
+                        prop_assert_eq!(*winner, player);
+                    }
+                    GameState::Tie => {
+                        prop_assert!(winners.is_empty());
+                        prop_assert_eq!(filled(&game), 9);
+                    }
+                    GameState::Active => {
+                        prop_assert!(winners.is_empty());
+                        prop_assert!(filled(&game) < 9);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn winning_trios_match_brute_force(tiles in prop::array::uniform9(prop::option::of(any::<bool>()))) {
+            let mut game = new_game();
+            for (index, tile) in tiles.iter().enumerate() {
+                if let Some(o) = *tile {
+                    game.set_tile(index / 3, index % 3, if o { Sign::O } else { Sign::X });
+                }
+            }
+
+            for line in all_lines() {
+                let [a, b, c] = line.map(|(row, column)| game.tile(row, column));
+                let expected = a.is_some() && a == b && b == c;
+                prop_assert_eq!(game.is_winning_trio(line), expected);
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub use chat::*;
 pub use game::*;
 
+pub mod chat;
 pub mod game;
@@ -0,0 +1,93 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { TicTacToe } from '../../../target/types/tic_tac_toe';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+// Unlike the other tic-tac-toe tests, this one runs against the local validator instead of the
+// bankrun harness. It shows the flow a client goes through with a real RPC node: fetching the
+// table with `getAddressLookupTable` and waiting for the slot after the table was extended before
+// using it. The bankrun provider's connection doesn't offer `getAddressLookupTable`, and warping
+// past the activation slot would skip the part readers need to see.
+describe('tic-tac-toe with an address lookup table', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.TicTacToe as Program<TicTacToe>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const connection = programProvider.connection;
+
+  it('plays with an address lookup table!', async () => {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    const playerOne = programProvider.wallet;
+    const playerTwo = anchor.web3.Keypair.generate();
+    await program.methods
+      .setupGame(playerTwo.publicKey)
+      .accounts({
+        game: gameKeypair.publicKey,
+        playerOne: playerOne.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+    const [chat] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('chat'), gameKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .postComment('gl hf')
+      .accounts({ game: gameKeypair.publicKey, chat, sender: playerOne.publicKey })
+      .rpc();
+
+    // a lookup table holds addresses a transaction can refer to by a one byte index
+    // instead of all 32 bytes, here the accounts every move of the game uses
+    const [createTable, lookupTableAddress] = anchor.web3.AddressLookupTableProgram.createLookupTable({
+      authority: playerOne.publicKey,
+      payer: playerOne.publicKey,
+      recentSlot: await connection.getSlot('finalized'),
+    });
+    const extendTable = anchor.web3.AddressLookupTableProgram.extendLookupTable({
+      lookupTable: lookupTableAddress,
+      authority: playerOne.publicKey,
+      payer: playerOne.publicKey,
+      addresses: [gameKeypair.publicKey, chat],
+    });
+    await programProvider.sendAndConfirm(new anchor.web3.Transaction().add(createTable, extendTable));
+
+    // new addresses can only be looked up from the slot after they were added
+    const extendedAt = await connection.getSlot();
+    while (await connection.getSlot() <= extendedAt) {
+      await new Promise((resolve) => setTimeout(resolve, 100));
+    }
+    const lookupTable = (await connection.getAddressLookupTable(lookupTableAddress)).value;
+
+    // Anchor builds the instruction as usual, only the transaction around it differs. Legacy
+    // transactions can't use lookup tables, v0 ones can.
+    const instruction = await program.methods
+      .play({ row: 1, column: 1 })
+      .accounts({ game: gameKeypair.publicKey, player: playerOne.publicKey, chat })
+      .instruction();
+    const message = new anchor.web3.TransactionMessage({
+      payerKey: playerOne.publicKey,
+      recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+      instructions: [instruction],
+    }).compileToV0Message([lookupTable]);
+
+    // signers and invoked programs always have to be listed in full, everything else is looked up
+    expect(message.staticAccountKeys).to.deep.include(playerOne.publicKey);
+    expect(message.staticAccountKeys).to.deep.include(program.programId);
+    expect(message.staticAccountKeys).not.to.deep.include(gameKeypair.publicKey);
+    expect(message.staticAccountKeys).not.to.deep.include(chat);
+    expect(message.addressTableLookups).to.eql([
+      { accountKey: lookupTableAddress, writableIndexes: [0], readonlyIndexes: [1] },
+    ]);
+
+    await programProvider.sendAndConfirm(new anchor.web3.VersionedTransaction(message));
+
+    const gameState = await program.account.game.fetch(gameKeypair.publicKey);
+    expect(gameState.turn).to.equal(2);
+    // an X, 0b01, in the middle tile, the fifth from the lowest bits
+    expect(gameState.board).to.equal(0b01 << (2 * 4));
+  });
+});
@@ -1,39 +1,64 @@
-import * as anchor from '@project-serum/anchor';
-import { AnchorError, Program } from '@project-serum/anchor';
-import { TicTacToe } from '../target/types/tic_tac_toe';
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness } from 'test-harness';
+import { TicTacToe } from '../../../target/types/tic_tac_toe';
 import chai from 'chai';
 import chaiAsPromised from 'chai-as-promised';
 import { expect } from 'chai';
 chai.use(chaiAsPromised);
 
+// the board is stored as two bits per tile, row by row from the lowest bits
+function unpackBoard(board: number) {
+  return [0, 1, 2].map(row => [0, 1, 2].map(column => {
+    switch ((board >> (2 * (3 * row + column))) & 0b11) {
+      case 1: return { x: {} };
+      case 2: return { o: {} };
+      default: return null;
+    }
+  }));
+}
+
+// `play` returns the game state after the move, it's the return data logged last
+function returnedState(program: Program<TicTacToe>, logs: string[]) {
+  const prefix = `Program return: ${program.programId} `;
+  const log = logs.filter((l) => l.startsWith(prefix)).pop();
+  return program.coder.types.decode('GameState', Buffer.from(log.slice(prefix.length), 'base64'));
+}
+
 async function play(program: Program<TicTacToe>, game, player, tile, expectedTurn, expectedGameState, expectedBoard) {
-  await program.methods
+  const method = program.methods
     .play(tile)
     .accounts({
       player: player.publicKey,
       game
     })
-    .signers(player instanceof (anchor.Wallet as any) ? [] : [player])
-    .rpc();
+    .signers([player]);
+
+  // a rejected move fails the simulation too, but only `rpc` turns that into an `AnchorError`
+  const simulation = await method.simulate().catch(() => undefined);
+  await method.rpc();
+  expect(returnedState(program, simulation.raw as string[])).to.eql(expectedGameState);
 
   const gameState = await program.account.game.fetch(game);
   expect(gameState.turn).to.equal(expectedTurn);
   expect(gameState.state).to.eql(expectedGameState);
-  expect(gameState.board)
+  expect(unpackBoard(gameState.board))
     .to
     .eql(expectedBoard);
 }
 
 describe('tic-tac-toe', () => {
-  // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  let harness: Harness;
+  let program: Program<TicTacToe>;
 
-  const program = anchor.workspace.TicTacToe as Program<TicTacToe>;
-  const programProvider = program.provider as anchor.AnchorProvider;
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<TicTacToe>('tic_tac_toe');
+  });
 
   it('setup game!', async() => {
     const gameKeypair = anchor.web3.Keypair.generate();
-    const playerOne = programProvider.wallet;
+    const playerOne = harness.payer;
     const playerTwo = anchor.web3.Keypair.generate();
     await program.methods
       .setupGame(playerTwo.publicKey)
@@ -50,14 +75,14 @@ describe('tic-tac-toe', () => {
       .to
       .eql([playerOne.publicKey, playerTwo.publicKey]);
     expect(gameState.state).to.eql({ active: {} });
-    expect(gameState.board)
+    expect(unpackBoard(gameState.board))
       .to
       .eql([[null,null,null],[null,null,null],[null,null,null]]);
   });
 
   it('player one wins!', async () => {
     const gameKeypair = anchor.web3.Keypair.generate();
-    const playerOne = programProvider.wallet;
+    const playerOne = harness.payer;
     const playerTwo = anchor.web3.Keypair.generate();
     await program.methods
       .setupGame(playerTwo.publicKey)
@@ -74,7 +99,7 @@ describe('tic-tac-toe', () => {
       .to
       .eql([playerOne.publicKey, playerTwo.publicKey]);
     expect(gameState.state).to.eql({ active: {} });
-    expect(gameState.board)
+    expect(unpackBoard(gameState.board))
       .to
       .eql([[null,null,null],[null,null,null],[null,null,null]]);
 
@@ -98,9 +123,6 @@ describe('tic-tac-toe', () => {
         program,
         gameKeypair.publicKey,
         playerOne, // same player in subsequent turns
-        // change sth about the tx because
-        // duplicate tx that come in too fast
-        // after each other may get dropped
         {row: 1, column: 0},
         2,
         { active: {}, },
@@ -243,7 +265,7 @@ describe('tic-tac-toe', () => {
 
   it('tie', async () => {
     const gameKeypair = anchor.web3.Keypair.generate();
-    const playerOne = programProvider.wallet;
+    const playerOne = harness.payer;
     const playerTwo = anchor.web3.Keypair.generate();
     await program.methods
       .setupGame(playerTwo.publicKey)
@@ -260,7 +282,7 @@ describe('tic-tac-toe', () => {
       .to
       .eql([playerOne.publicKey, playerTwo.publicKey]);
     expect(gameState.state).to.eql({ active: {} });
-    expect(gameState.board)
+    expect(unpackBoard(gameState.board))
       .to
       .eql([[null,null,null],[null,null,null],[null,null,null]]);
 
@@ -391,4 +413,70 @@ describe('tic-tac-toe', () => {
       ]
     );
   })
+
+  it('spectators chat!', async () => {
+    const gameKeypair = anchor.web3.Keypair.generate();
+    const playerOne = harness.payer;
+    const playerTwo = anchor.web3.Keypair.generate();
+    const spectator = anchor.web3.Keypair.generate();
+    await program.methods
+      .setupGame(playerTwo.publicKey)
+      .accounts({
+        game: gameKeypair.publicKey,
+        playerOne: playerOne.publicKey,
+      })
+      .signers([gameKeypair])
+      .rpc();
+
+    const [chat] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from('chat'), gameKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+    const postComment = (text: string) =>
+      program.methods
+        .postComment(text)
+        .accounts({
+          game: gameKeypair.publicKey,
+          chat,
+          sender: playerOne.publicKey,
+        })
+        .rpc();
+
+    await postComment('gl hf');
+    // the spectator doesn't pay for anything, the chat already exists
+    await program.methods
+      .postComment('X is going to lose this one')
+      .accounts({
+        game: gameKeypair.publicKey,
+        chat,
+        sender: spectator.publicKey,
+      })
+      .signers([spectator])
+      .rpc();
+
+    let chatState = await program.account.chat.fetch(chat);
+    expect(chatState.commentCount.toNumber()).to.equal(2);
+    expect(chatState.comments.map(comment => [comment.sender, comment.text]))
+      .to
+      .eql([[playerOne.publicKey, 'gl hf'], [spectator.publicKey, 'X is going to lose this one']]);
+
+    try {
+      await postComment('a'.repeat(65));
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.number).to.equal(6006);
+    }
+
+    // 16 comments fit, the 17th and 18th replace the two oldest
+    for (let i = 2; i < 18; i++) {
+      await postComment(`comment ${i}`);
+    }
+    chatState = await program.account.chat.fetch(chat);
+    expect(chatState.commentCount.toNumber()).to.equal(18);
+    expect(chatState.comments.map(comment => comment.text))
+      .to
+      .eql(['comment 16', 'comment 17', ...[...Array(14)].map((_, i) => `comment ${i + 2}`)]);
+  });
 });
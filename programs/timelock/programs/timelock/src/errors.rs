@@ -0,0 +1,12 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum TimelockError {
+    ZeroAmount,
+    UnlockInThePast,
+    StillLocked,
+    AlreadyUnlocked,
+    CannotShortenLock,
+    NotGuardian,
+    VaultsStillOpen,
+}
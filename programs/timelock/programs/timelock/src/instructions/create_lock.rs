@@ -0,0 +1,61 @@
+use crate::errors::TimelockError;
+use crate::state::lock::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn create_lock(
+    ctx: Context<CreateLock>,
+    id: u64,
+    beneficiary: Pubkey,
+    guardian: Option<Pubkey>,
+    unlock_ts: i64,
+    lamports: u64,
+) -> Result<()> {
+    require_gt!(
+        unlock_ts,
+        Clock::get()?.unix_timestamp,
+        TimelockError::UnlockInThePast
+    );
+
+    ctx.accounts.lock.set_inner(Lock {
+        depositor: ctx.accounts.depositor.key(),
+        beneficiary,
+        guardian,
+        id,
+        unlock_ts,
+        lamports,
+        open_vaults: 0,
+        bump: ctx.bumps.lock,
+    });
+
+    // a lock may hold only tokens, deposited afterwards
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.lock.to_account_info(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateLock<'info> {
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + Lock::INIT_SPACE,
+        seeds = [Lock::SEED_PREFIX, depositor.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub lock: Account<'info, Lock>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,58 @@
+use crate::errors::TimelockError;
+use crate::state::lock::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, TimelockError::ZeroAmount);
+    let lock = &mut ctx.accounts.lock;
+    require!(
+        !lock.is_unlocked(Clock::get()?.unix_timestamp),
+        TimelockError::AlreadyUnlocked
+    );
+
+    // Vaults are closed as soon as they are emptied and deposits are never zero,
+    // so an empty vault here is one that `init_if_needed` just created.
+    if ctx.accounts.vault.amount == 0 {
+        lock.open_vaults += 1;
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct DepositTokens<'info> {
+    #[account(mut, has_one = depositor)]
+    pub lock: Account<'info, Lock>,
+    // one vault per mint
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        seeds = [Lock::VAULT_SEED_PREFIX, lock.key().as_ref(), mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = lock
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,19 @@
+use crate::errors::TimelockError;
+use crate::state::lock::*;
+use anchor_lang::prelude::*;
+
+pub fn extend_lock(ctx: Context<ExtendLock>, unlock_ts: i64) -> Result<()> {
+    ctx.accounts
+        .lock
+        .extend(unlock_ts, Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    #[account(
+        mut,
+        constraint = lock.guardian == Some(guardian.key()) @ TimelockError::NotGuardian
+    )]
+    pub lock: Account<'info, Lock>,
+    pub guardian: Signer<'info>,
+}
@@ -0,0 +1,11 @@
+pub use create_lock::*;
+pub use deposit_tokens::*;
+pub use extend_lock::*;
+pub use withdraw::*;
+pub use withdraw_tokens::*;
+
+pub mod create_lock;
+pub mod deposit_tokens;
+pub mod extend_lock;
+pub mod withdraw;
+pub mod withdraw_tokens;
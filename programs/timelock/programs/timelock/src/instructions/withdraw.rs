@@ -0,0 +1,30 @@
+use crate::errors::TimelockError;
+use crate::state::lock::*;
+use anchor_lang::prelude::*;
+
+pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+    let lock = &ctx.accounts.lock;
+    require!(
+        lock.is_unlocked(Clock::get()?.unix_timestamp),
+        TimelockError::StillLocked
+    );
+    // closing the lock with tokens left in a vault would strand them for good
+    require_eq!(lock.open_vaults, 0, TimelockError::VaultsStillOpen);
+
+    // the locked lamports go to the beneficiary, `close` returns the rent to the depositor
+    let lamports = lock.lamports;
+    ctx.accounts.lock.sub_lamports(lamports)?;
+    ctx.accounts.beneficiary.add_lamports(lamports)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one = beneficiary, has_one = depositor, close = depositor)]
+    pub lock: Account<'info, Lock>,
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    /// CHECK: checked against `lock.depositor`
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+}
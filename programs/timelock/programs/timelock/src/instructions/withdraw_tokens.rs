@@ -0,0 +1,68 @@
+use crate::errors::TimelockError;
+use crate::state::lock::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount, Transfer};
+
+pub fn withdraw_tokens(ctx: Context<WithdrawTokens>) -> Result<()> {
+    let lock = &mut ctx.accounts.lock;
+    require!(
+        lock.is_unlocked(Clock::get()?.unix_timestamp),
+        TimelockError::StillLocked
+    );
+    lock.open_vaults -= 1;
+
+    let id_bytes = lock.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Lock::SEED_PREFIX,
+        lock.depositor.as_ref(),
+        id_bytes.as_ref(),
+        &[lock.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: lock.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.vault.amount,
+    )?;
+
+    // the depositor paid for the vault, so they get its rent back
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.depositor.to_account_info(),
+            authority: lock.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTokens<'info> {
+    #[account(mut, has_one = beneficiary, has_one = depositor)]
+    pub lock: Account<'info, Lock>,
+    #[account(
+        mut,
+        seeds = [Lock::VAULT_SEED_PREFIX, lock.key().as_ref(), vault.mint.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = vault.mint,
+        token::authority = beneficiary
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    pub beneficiary: Signer<'info>,
+    /// CHECK: checked against `lock.depositor`
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+}
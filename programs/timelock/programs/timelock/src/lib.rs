@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("rBtfysVkxjA5YRGirZCTRyCGpUMoTda9qci3Gpfq5Pq");
+
+#[program]
+pub mod timelock {
+    use super::*;
+
+    pub fn create_lock(
+        ctx: Context<CreateLock>,
+        id: u64,
+        beneficiary: Pubkey,
+        guardian: Option<Pubkey>,
+        unlock_ts: i64,
+        lamports: u64,
+    ) -> Result<()> {
+        instructions::create_lock::create_lock(ctx, id, beneficiary, guardian, unlock_ts, lamports)
+    }
+
+    pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
+        instructions::deposit_tokens::deposit_tokens(ctx, amount)
+    }
+
+    pub fn extend_lock(ctx: Context<ExtendLock>, unlock_ts: i64) -> Result<()> {
+        instructions::extend_lock::extend_lock(ctx, unlock_ts)
+    }
+
+    pub fn withdraw_tokens(ctx: Context<WithdrawTokens>) -> Result<()> {
+        instructions::withdraw_tokens::withdraw_tokens(ctx)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
+        instructions::withdraw::withdraw(ctx)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::errors::TimelockError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Lock {
+    pub depositor: Pubkey,
+    pub beneficiary: Pubkey,
+    // may push `unlock_ts` back, never forward
+    pub guardian: Option<Pubkey>,
+    pub id: u64,
+    pub unlock_ts: i64,
+    // locked lamports, held by this account on top of its rent
+    pub lamports: u64,
+    // token vaults that still have to be emptied before the lock can be closed
+    pub open_vaults: u8,
+    pub bump: u8,
+}
+
+impl Lock {
+    pub const SEED_PREFIX: &'static [u8] = b"lock";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    pub fn is_unlocked(&self, now: i64) -> bool {
+        now >= self.unlock_ts
+    }
+
+    pub fn extend(&mut self, unlock_ts: i64, now: i64) -> Result<()> {
+        require!(!self.is_unlocked(now), TimelockError::AlreadyUnlocked);
+        require_gt!(unlock_ts, self.unlock_ts, TimelockError::CannotShortenLock);
+        self.unlock_ts = unlock_ts;
+        Ok(())
+    }
+}
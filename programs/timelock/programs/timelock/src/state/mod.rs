@@ -0,0 +1,3 @@
+pub use lock::*;
+
+pub mod lock;
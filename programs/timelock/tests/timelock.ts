@@ -0,0 +1,205 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Timelock } from '../../../target/types/timelock';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('timelock', () => {
+  let harness: Harness;
+  let program: Program<Timelock>;
+  let payer: anchor.web3.Keypair;
+  // the provider wallet creates the locks
+  let depositor: anchor.web3.Keypair;
+  let nextId = 0;
+
+  let mint: anchor.web3.PublicKey;
+  let depositorTokenAccount: anchor.web3.PublicKey;
+
+  async function newUser() {
+    return harness.fundedKeypair(LAMPORTS_PER_SOL);
+  }
+
+  async function createLock(
+    beneficiary: anchor.web3.PublicKey,
+    guardian: anchor.web3.PublicKey | null,
+    secondsToUnlock: number,
+    lamports: number
+  ) {
+    const id = new BN(nextId++);
+    const lock = findAddress(
+      [Buffer.from('lock'), depositor.publicKey.toBuffer(), id.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    await program.methods
+      .createLock(id, beneficiary, guardian, new BN((await harness.now()) + secondsToUnlock), new BN(lamports))
+      .accounts({ lock, depositor: depositor.publicKey })
+      .rpc();
+    return lock;
+  }
+
+  async function depositTokens(lock: anchor.web3.PublicKey, amount: number) {
+    const vault = findAddress([Buffer.from('vault'), lock.toBuffer(), mint.toBuffer()], program.programId);
+    await program.methods
+      .depositTokens(new BN(amount))
+      .accounts({ lock, vault, mint, depositorTokenAccount, depositor: depositor.publicKey })
+      .rpc();
+    return vault;
+  }
+
+  async function withdraw(lock: anchor.web3.PublicKey, beneficiary: anchor.web3.Keypair) {
+    await program.methods
+      .withdraw()
+      .accounts({ lock, beneficiary: beneficiary.publicKey, depositor: depositor.publicKey })
+      .signers([beneficiary])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Timelock>('timelock');
+    payer = harness.payer;
+    depositor = harness.payer;
+
+    mint = await harness.createMint(payer.publicKey);
+    depositorTokenAccount = await harness.createTokenAccount(mint, depositor.publicKey, 1_000);
+  });
+
+  it('lock and withdraw sol and tokens!', async () => {
+    const beneficiary = await newUser();
+    const lock = await createLock(beneficiary.publicKey, null, 3, LAMPORTS_PER_SOL);
+    const vault = await depositTokens(lock, 100);
+    await depositTokens(lock, 50);
+    expect((await program.account.lock.fetch(lock)).openVaults).to.equal(1);
+
+    try {
+      await withdraw(lock, beneficiary);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('StillLocked');
+      expect(err.error.errorCode.number).to.equal(6002);
+    }
+
+    await harness.warp(4);
+
+    try {
+      await withdraw(lock, beneficiary);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('VaultsStillOpen');
+      expect(err.error.errorCode.number).to.equal(6006);
+    }
+
+    const beneficiaryTokenAccount = await harness.createTokenAccount(mint, beneficiary.publicKey);
+    await program.methods
+      .withdrawTokens()
+      .accounts({
+        lock,
+        vault,
+        beneficiaryTokenAccount,
+        beneficiary: beneficiary.publicKey,
+        depositor: depositor.publicKey,
+      })
+      .signers([beneficiary])
+      .rpc();
+    expect(await harness.tokenBalance(beneficiaryTokenAccount)).to.equal(150);
+    expect(await harness.account(vault)).to.be.null;
+
+    const balance = await harness.lamports(beneficiary.publicKey);
+    await withdraw(lock, beneficiary);
+    expect(await harness.lamports(beneficiary.publicKey)).to.equal(balance + LAMPORTS_PER_SOL);
+    expect(await harness.account(lock)).to.be.null;
+  });
+
+  it('guardian can only extend', async () => {
+    const beneficiary = await newUser();
+    const guardian = await newUser();
+    const lock = await createLock(beneficiary.publicKey, guardian.publicKey, 60, LAMPORTS_PER_SOL);
+    const unlockTs = (await program.account.lock.fetch(lock)).unlockTs;
+
+    const extendLock = async (unlockTs: anchor.BN, signer: anchor.web3.Keypair) =>
+      await program.methods
+        .extendLock(unlockTs)
+        .accounts({ lock, guardian: signer.publicKey })
+        .signers([signer])
+        .rpc();
+
+    await extendLock(unlockTs.addn(60), guardian);
+    expect((await program.account.lock.fetch(lock)).unlockTs.toNumber()).to.equal(unlockTs.toNumber() + 60);
+
+    try {
+      await extendLock(unlockTs, guardian);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('CannotShortenLock');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+
+    try {
+      await extendLock(unlockTs.addn(120), beneficiary);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotGuardian');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+  });
+
+  it('locks without a guardian cannot be extended', async () => {
+    const beneficiary = await newUser();
+    const lock = await createLock(beneficiary.publicKey, null, 60, 0);
+
+    try {
+      await program.methods
+        .extendLock(new BN((await harness.now()) + 120))
+        .accounts({ lock, guardian: depositor.publicKey })
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotGuardian');
+    }
+  });
+
+  it('unlock must be in the future', async () => {
+    const beneficiary = await newUser();
+    try {
+      await createLock(beneficiary.publicKey, null, -10, LAMPORTS_PER_SOL);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('UnlockInThePast');
+      expect(err.error.errorCode.number).to.equal(6001);
+    }
+  });
+
+  it('only the beneficiary can withdraw', async () => {
+    const beneficiary = await newUser();
+    const mallory = await newUser();
+    const lock = await createLock(beneficiary.publicKey, null, 1, LAMPORTS_PER_SOL);
+    await harness.warp(2);
+
+    try {
+      await withdraw(lock, mallory);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintHasOne');
+    }
+  });
+});
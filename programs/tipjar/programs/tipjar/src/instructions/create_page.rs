@@ -0,0 +1,26 @@
+use crate::state::page::*;
+use anchor_lang::prelude::*;
+
+pub fn create_page(ctx: Context<CreatePage>, name: String) -> Result<()> {
+    let page = &mut ctx.accounts.page;
+    page.creator = ctx.accounts.creator.key();
+    page.tip_count = 0;
+    page.messages = vec![];
+    page.bump = ctx.bumps.page;
+    page.set_name(name)
+}
+
+#[derive(Accounts)]
+pub struct CreatePage<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Page::INIT_SPACE,
+        seeds = [Page::SEED_PREFIX, creator.key().as_ref()],
+        bump
+    )]
+    pub page: Account<'info, Page>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,11 @@
+pub use create_page::*;
+pub use tip_sol::*;
+pub use tip_token::*;
+pub use withdraw_sol::*;
+pub use withdraw_token::*;
+
+pub mod create_page;
+pub mod tip_sol;
+pub mod tip_token;
+pub mod withdraw_sol;
+pub mod withdraw_token;
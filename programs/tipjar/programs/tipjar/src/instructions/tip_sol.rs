@@ -0,0 +1,37 @@
+use crate::state::page::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+pub fn tip_sol(ctx: Context<TipSol>, amount: u64, message: String) -> Result<()> {
+    ctx.accounts.page.record(TipMessage {
+        tipper: ctx.accounts.tipper.key(),
+        mint: None,
+        amount,
+        message,
+        timestamp: Clock::get()?.unix_timestamp,
+    })?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.tipper.to_account_info(),
+                to: ctx.accounts.page.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct TipSol<'info> {
+    #[account(
+        mut,
+        seeds = [Page::SEED_PREFIX, page.creator.as_ref()],
+        bump = page.bump
+    )]
+    pub page: Account<'info, Page>,
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,56 @@
+use crate::state::page::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+// The first tip in a given token pays for the page's token account.
+pub fn tip_token(ctx: Context<TipToken>, amount: u64, message: String) -> Result<()> {
+    ctx.accounts.page.record(TipMessage {
+        tipper: ctx.accounts.tipper.key(),
+        mint: Some(ctx.accounts.mint.key()),
+        amount,
+        message,
+        timestamp: Clock::get()?.unix_timestamp,
+    })?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.tipper_token_account.to_account_info(),
+                to: ctx.accounts.page_token_account.to_account_info(),
+                authority: ctx.accounts.tipper.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct TipToken<'info> {
+    #[account(
+        mut,
+        seeds = [Page::SEED_PREFIX, page.creator.as_ref()],
+        bump = page.bump
+    )]
+    pub page: Account<'info, Page>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = tipper,
+        associated_token::mint = mint,
+        associated_token::authority = page
+    )]
+    pub page_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = tipper
+    )]
+    pub tipper_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+use crate::errors::TipjarError;
+use crate::state::page::*;
+use anchor_lang::prelude::*;
+
+// Everything but the rent, which keeps the page and its messages around.
+pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+    let page = ctx.accounts.page.to_account_info();
+    let rent = Rent::get()?.minimum_balance(page.data_len());
+    let tips = page.lamports() - rent;
+    require_gt!(tips, 0, TipjarError::NothingToWithdraw);
+
+    // the page is owned by this program, so it can hand out lamports without a CPI
+    ctx.accounts.page.sub_lamports(tips)?;
+    ctx.accounts.creator.add_lamports(tips)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSol<'info> {
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [Page::SEED_PREFIX, creator.key().as_ref()],
+        bump = page.bump
+    )]
+    pub page: Account<'info, Page>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
@@ -0,0 +1,50 @@
+use crate::errors::TipjarError;
+use crate::state::page::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn withdraw_token(ctx: Context<WithdrawToken>) -> Result<()> {
+    let amount = ctx.accounts.page_token_account.amount;
+    require_gt!(amount, 0, TipjarError::NothingToWithdraw);
+
+    let page = &ctx.accounts.page;
+    let signer_seeds: &[&[&[u8]]] = &[&[Page::SEED_PREFIX, page.creator.as_ref(), &[page.bump]]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.page_token_account.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.page.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    #[account(
+        has_one = creator,
+        seeds = [Page::SEED_PREFIX, creator.key().as_ref()],
+        bump = page.bump
+    )]
+    pub page: Account<'info, Page>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = page
+    )]
+    pub page_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("6m7juPiNJDQGWHZGjeD9ApXuGJSy74QU558koHdUwx2s");
+
+#[program]
+pub mod tipjar {
+    use super::*;
+
+    pub fn create_page(ctx: Context<CreatePage>, name: String) -> Result<()> {
+        instructions::create_page::create_page(ctx, name)
+    }
+
+    pub fn tip_sol(ctx: Context<TipSol>, amount: u64, message: String) -> Result<()> {
+        instructions::tip_sol::tip_sol(ctx, amount, message)
+    }
+
+    pub fn tip_token(ctx: Context<TipToken>, amount: u64, message: String) -> Result<()> {
+        instructions::tip_token::tip_token(ctx, amount, message)
+    }
+
+    pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+        instructions::withdraw_sol::withdraw_sol(ctx)
+    }
+
+    pub fn withdraw_token(ctx: Context<WithdrawToken>) -> Result<()> {
+        instructions::withdraw_token::withdraw_token(ctx)
+    }
+}
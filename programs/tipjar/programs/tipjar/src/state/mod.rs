@@ -0,0 +1,3 @@
+pub use page::*;
+
+pub mod page;
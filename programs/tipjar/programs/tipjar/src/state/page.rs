@@ -0,0 +1,61 @@
+use crate::errors::TipjarError;
+use anchor_lang::prelude::*;
+
+// `max_len` counts bytes, not characters: an emoji takes up to 4 of them
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_MESSAGE_LEN: usize = 64;
+pub const MAX_MESSAGES: usize = 8;
+
+/// A creator's page. It holds the SOL tips itself, on top of its rent, and
+/// the token tips in associated token accounts it is the authority of.
+#[account]
+#[derive(InitSpace)]
+pub struct Page {
+    pub creator: Pubkey,
+    #[max_len(MAX_NAME_LEN)]
+    pub name: String,
+    pub tip_count: u64,
+    // the last `MAX_MESSAGES` tips, the oldest is overwritten once it's full
+    #[max_len(MAX_MESSAGES)]
+    pub messages: Vec<TipMessage>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct TipMessage {
+    pub tipper: Pubkey,
+    // `None` for SOL
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    #[max_len(MAX_MESSAGE_LEN)]
+    pub message: String,
+    pub timestamp: i64,
+}
+
+impl Page {
+    pub const SEED_PREFIX: &'static [u8] = b"page";
+
+    pub fn set_name(&mut self, name: String) -> Result<()> {
+        require_gte!(MAX_NAME_LEN, name.len(), TipjarError::NameTooLong);
+        self.name = name;
+        Ok(())
+    }
+
+    pub fn record(&mut self, tip: TipMessage) -> Result<()> {
+        require_gt!(tip.amount, 0, TipjarError::ZeroAmount);
+        require_gte!(
+            MAX_MESSAGE_LEN,
+            tip.message.len(),
+            TipjarError::MessageTooLong
+        );
+
+        // the account was allocated for `MAX_MESSAGES` from the start, so growing never needs a realloc
+        if self.messages.len() < MAX_MESSAGES {
+            self.messages.push(tip);
+        } else {
+            self.messages[(self.tip_count % MAX_MESSAGES as u64) as usize] = tip;
+        }
+        self.tip_count += 1;
+        Ok(())
+    }
+}
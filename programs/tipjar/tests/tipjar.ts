@@ -0,0 +1,133 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { Harness, expectError } from 'test-harness';
+import { Tipjar } from '../../../target/types/tipjar';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+const { LAMPORTS_PER_SOL } = anchor.web3;
+
+describe('tipjar', () => {
+  let harness: Harness;
+  let program: Program<Tipjar>;
+
+  const creator = Keypair.generate();
+  const tipper = Keypair.generate();
+  let page: anchor.web3.PublicKey;
+  let mint: anchor.web3.PublicKey;
+  let pageTokenAccount: anchor.web3.PublicKey;
+  let tipperTokenAccount: anchor.web3.PublicKey;
+  let creatorTokenAccount: anchor.web3.PublicKey;
+
+  async function tipSol(amount: number, message: string) {
+    await program.methods
+      .tipSol(new BN(amount), message)
+      .accounts({ page, tipper: tipper.publicKey })
+      .signers([tipper])
+      .rpc();
+  }
+
+  async function tipToken(amount: number, message: string) {
+    await program.methods
+      .tipToken(new BN(amount), message)
+      .accounts({ page, mint, pageTokenAccount, tipperTokenAccount, tipper: tipper.publicKey })
+      .signers([tipper])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Tipjar>('tipjar');
+    [page] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('page'), creator.publicKey.toBuffer()],
+      program.programId
+    );
+    for (const keypair of [creator, tipper]) {
+      await harness.fund(keypair.publicKey, 2 * LAMPORTS_PER_SOL);
+    }
+    mint = await harness.createMint(harness.payer.publicKey);
+    tipperTokenAccount = await harness.createTokenAccount(mint, tipper.publicKey, 1_000);
+    creatorTokenAccount = await harness.createTokenAccount(mint, creator.publicKey);
+    // created by the first token tip
+    pageTokenAccount = getAssociatedTokenAddressSync(mint, page, true);
+  });
+
+  it('create a page and tip it!', async () => {
+    await expectError(
+      program.methods
+        .createPage('x'.repeat(33))
+        .accounts({ page, creator: creator.publicKey })
+        .signers([creator])
+        .rpc(),
+      'NameTooLong',
+      6000
+    );
+    await program.methods
+      .createPage('alice builds')
+      .accounts({ page, creator: creator.publicKey })
+      .signers([creator])
+      .rpc();
+
+    await expectError(tipSol(0, 'nothing'), 'ZeroAmount', 6002);
+    await expectError(tipSol(1_000, 'x'.repeat(65)), 'MessageTooLong', 6001);
+
+    const before = await harness.lamports(page);
+    await tipSol(LAMPORTS_PER_SOL / 10, 'great post 🙌');
+    await tipToken(100, 'have some tokens');
+    expect(await harness.lamports(page)).to.equal(before + LAMPORTS_PER_SOL / 10);
+    expect(await harness.tokenBalance(pageTokenAccount)).to.equal(100);
+
+    const pageState = await program.account.page.fetch(page);
+    expect(pageState.tipCount.toNumber()).to.equal(2);
+    expect(pageState.messages[0].message).to.equal('great post 🙌');
+    expect(pageState.messages[0].mint).to.be.null;
+    expect(pageState.messages[1].mint).to.deep.equal(mint);
+    expect(pageState.messages[1].amount.toNumber()).to.equal(100);
+  });
+
+  it('only the last 8 messages are kept', async () => {
+    for (let i = 0; i < 8; i++) {
+      await tipSol(1_000, `tip ${i}`);
+    }
+    const pageState = await program.account.page.fetch(page);
+    expect(pageState.tipCount.toNumber()).to.equal(10);
+    // the two oldest slots were overwritten by the two newest tips
+    expect(pageState.messages.map((m) => m.message)).to.deep.equal([
+      'tip 6',
+      'tip 7',
+      'tip 0',
+      'tip 1',
+      'tip 2',
+      'tip 3',
+      'tip 4',
+      'tip 5',
+    ]);
+  });
+
+  it('the creator withdraws', async () => {
+    const pageBefore = await harness.lamports(page);
+    const rent = await harness.rentExemption((await harness.account(page)).data.length);
+    await program.methods
+      .withdrawSol()
+      .accounts({ page, creator: creator.publicKey })
+      .signers([creator])
+      .rpc();
+    expect(await harness.lamports(page)).to.equal(rent);
+    expect(pageBefore - rent).to.equal(LAMPORTS_PER_SOL / 10 + 8 * 1_000);
+
+    const withdrawToken = () =>
+      program.methods
+        .withdrawToken()
+        .accounts({ page, mint, pageTokenAccount, creatorTokenAccount, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+    await withdrawToken();
+    expect(await harness.tokenBalance(creatorTokenAccount)).to.equal(100);
+    await expectError(withdrawToken(), 'NothingToWithdraw', 6003);
+  });
+});
@@ -0,0 +1,7 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum TodoError {
+    TextTooLong,
+    UnknownTodo,
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TodoAdded {
+    pub list: Pubkey,
+    pub id: u64,
+    pub text: String,
+}
+
+#[event]
+pub struct TodoEdited {
+    pub list: Pubkey,
+    pub id: u64,
+    pub text: String,
+}
+
+#[event]
+pub struct TodoCompleted {
+    pub list: Pubkey,
+    pub id: u64,
+}
+
+#[event]
+pub struct TodoRemoved {
+    pub list: Pubkey,
+    pub id: u64,
+}
@@ -0,0 +1,66 @@
+use crate::events::*;
+use crate::state::todo_list::*;
+use anchor_lang::prelude::*;
+
+// `emit_cpi!` puts the event into the data of an instruction the program calls on itself.
+// Unlike `emit!`, which only logs it, that can't be cut off when the logs get too long, and
+// RPC nodes keep it with the transaction's inner instructions.
+
+pub fn add_todo(ctx: Context<ChangeTodo>, text: String) -> Result<()> {
+    TodoList::validate_text(&text)?;
+
+    let id = ctx.accounts.list.take_id();
+    emit_cpi!(TodoAdded {
+        list: ctx.accounts.list.key(),
+        id,
+        text,
+    });
+    Ok(())
+}
+
+pub fn edit_todo(ctx: Context<ChangeTodo>, id: u64, text: String) -> Result<()> {
+    TodoList::validate_text(&text)?;
+    ctx.accounts.list.check_id(id)?;
+
+    emit_cpi!(TodoEdited {
+        list: ctx.accounts.list.key(),
+        id,
+        text,
+    });
+    Ok(())
+}
+
+pub fn complete_todo(ctx: Context<ChangeTodo>, id: u64) -> Result<()> {
+    ctx.accounts.list.check_id(id)?;
+
+    emit_cpi!(TodoCompleted {
+        list: ctx.accounts.list.key(),
+        id,
+    });
+    Ok(())
+}
+
+pub fn remove_todo(ctx: Context<ChangeTodo>, id: u64) -> Result<()> {
+    ctx.accounts.list.check_id(id)?;
+
+    emit_cpi!(TodoRemoved {
+        list: ctx.accounts.list.key(),
+        id,
+    });
+    Ok(())
+}
+
+// `#[event_cpi]` adds the `event_authority` PDA that signs the self-CPI, and the program
+// itself, to the accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ChangeTodo<'info> {
+    #[account(
+        mut,
+        seeds = [TodoList::SEED_PREFIX, owner.key().as_ref()],
+        bump = list.bump,
+        has_one = owner
+    )]
+    pub list: Account<'info, TodoList>,
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,26 @@
+use crate::state::todo_list::*;
+use anchor_lang::prelude::*;
+
+pub fn create_list(ctx: Context<CreateList>) -> Result<()> {
+    ctx.accounts.list.set_inner(TodoList {
+        owner: ctx.accounts.owner.key(),
+        next_id: 0,
+        bump: ctx.bumps.list,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateList<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + TodoList::INIT_SPACE,
+        seeds = [TodoList::SEED_PREFIX, owner.key().as_ref()],
+        bump
+    )]
+    pub list: Account<'info, TodoList>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
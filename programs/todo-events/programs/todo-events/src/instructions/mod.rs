@@ -0,0 +1,5 @@
+pub use change_todo::*;
+pub use create_list::*;
+
+pub mod change_todo;
+pub mod create_list;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("BrXGv1BPaNqh4NPdnTjK1mLRNy6FW2fCtNj5jN7s2uQ1");
+
+/// A todo list whose todos only exist in the ledger. The program keeps a counter to hand out
+/// ids and emits every change as an event, which an indexer replays to rebuild the list.
+#[program]
+pub mod todo_events {
+    use super::*;
+
+    pub fn create_list(ctx: Context<CreateList>) -> Result<()> {
+        instructions::create_list::create_list(ctx)
+    }
+
+    pub fn add_todo(ctx: Context<ChangeTodo>, text: String) -> Result<()> {
+        instructions::change_todo::add_todo(ctx, text)
+    }
+
+    pub fn edit_todo(ctx: Context<ChangeTodo>, id: u64, text: String) -> Result<()> {
+        instructions::change_todo::edit_todo(ctx, id, text)
+    }
+
+    pub fn complete_todo(ctx: Context<ChangeTodo>, id: u64) -> Result<()> {
+        instructions::change_todo::complete_todo(ctx, id)
+    }
+
+    pub fn remove_todo(ctx: Context<ChangeTodo>, id: u64) -> Result<()> {
+        instructions::change_todo::remove_todo(ctx, id)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::errors::TodoError;
+use anchor_lang::prelude::*;
+
+pub const MAX_TEXT_LEN: usize = 280;
+
+/// All the state there is. The todos themselves are only ever in events.
+#[account]
+#[derive(InitSpace)]
+pub struct TodoList {
+    pub owner: Pubkey,
+    pub next_id: u64,
+    pub bump: u8,
+}
+
+impl TodoList {
+    pub const SEED_PREFIX: &'static [u8] = b"todo_list";
+
+    pub fn validate_text(text: &str) -> Result<()> {
+        require_gte!(MAX_TEXT_LEN, text.len(), TodoError::TextTooLong);
+        Ok(())
+    }
+
+    // Nothing on-chain remembers which todos were removed or completed, the program can only
+    // tell that an id was handed out. Replaying a change to a removed todo is for the indexer
+    // to ignore.
+    pub fn check_id(&self, id: u64) -> Result<()> {
+        require_gt!(self.next_id, id, TodoError::UnknownTodo);
+        Ok(())
+    }
+
+    pub fn take_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
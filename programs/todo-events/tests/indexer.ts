@@ -0,0 +1,63 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { PublicKey, TransactionResponse } from '@solana/web3.js';
+import { TodoEvents } from '../../../target/types/todo_events';
+
+export type Todo = { text: string; done: boolean };
+
+// sha256("anchor:event")[..8], which the data of every event CPI starts with
+const EVENT_IX_TAG = Buffer.from('e445a52e51cb9a1d', 'hex');
+
+// Rebuilds a todo list from the events of every transaction that touched it.
+export async function indexTodos(program: Program<TodoEvents>, list: PublicKey) {
+  const connection = program.provider.connection;
+  // newest first and at most 1000 of them, a real indexer would page through with `before`
+  const signatures = await connection.getSignaturesForAddress(list, undefined, 'confirmed');
+
+  const todos = new Map<number, Todo>();
+  for (const { signature, err } of signatures.reverse()) {
+    if (err) {
+      continue;
+    }
+    const tx = await connection.getTransaction(signature, { commitment: 'confirmed' });
+    for (const event of eventsOf(program, tx)) {
+      apply(todos, event);
+    }
+  }
+  return todos;
+}
+
+// Events are the data of inner instructions from the program to itself. Only the program
+// can sign for its event authority, so nobody else can fake one.
+function eventsOf(program: Program<TodoEvents>, tx: TransactionResponse) {
+  const accountKeys = tx.transaction.message.accountKeys;
+  return tx.meta.innerInstructions
+    .flatMap(({ instructions }) => instructions)
+    .filter((ix) => accountKeys[ix.programIdIndex].equals(program.programId))
+    .map((ix) => Buffer.from(anchor.utils.bytes.bs58.decode(ix.data)))
+    .filter((data) => data.subarray(0, 8).equals(EVENT_IX_TAG))
+    .map((data) => program.coder.events.decode(data.subarray(8).toString('base64')))
+    .filter((event) => event !== null);
+}
+
+function apply(todos: Map<number, Todo>, event: { name: string; data: any }) {
+  const id = event.data.id.toNumber();
+  switch (event.name) {
+    case 'TodoAdded':
+      todos.set(id, { text: event.data.text, done: false });
+      break;
+    case 'TodoEdited':
+      if (todos.has(id)) {
+        todos.get(id).text = event.data.text;
+      }
+      break;
+    case 'TodoCompleted':
+      if (todos.has(id)) {
+        todos.get(id).done = true;
+      }
+      break;
+    case 'TodoRemoved':
+      todos.delete(id);
+      break;
+  }
+}
@@ -0,0 +1,56 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program } from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import { expectError } from 'test-harness';
+import { TodoEvents } from '../../../target/types/todo_events';
+import { indexTodos } from './indexer';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+// Runs against the local validator, not the bankrun harness: the indexer rebuilds the list from
+// `getSignaturesForAddress` and `getTransaction`, the history only an RPC node keeps.
+describe('todo-events', () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.TodoEvents as Program<TodoEvents>;
+  const programProvider = program.provider as anchor.AnchorProvider;
+  const owner = (programProvider.wallet as anchor.Wallet).payer;
+
+  const [list] = PublicKey.findProgramAddressSync(
+    [Buffer.from('todo_list'), owner.publicKey.toBuffer()],
+    program.programId
+  );
+  const [eventAuthority] = PublicKey.findProgramAddressSync([Buffer.from('__event_authority')], program.programId);
+  const accounts = { list, owner: owner.publicKey, eventAuthority, program: program.programId };
+
+  it('the list lives in the ledger!', async () => {
+    await program.methods.createList().accounts({ list, owner: owner.publicKey }).rpc();
+
+    const rpc = { commitment: 'confirmed' as const };
+    await program.methods.addTodo('write the chapter').accounts(accounts).rpc(rpc);
+    await program.methods.addTodo('review the chapter').accounts(accounts).rpc(rpc);
+    await program.methods.addTodo('celebrate').accounts(accounts).rpc(rpc);
+    await program.methods.editTodo(new BN(1), 'review the chapter twice').accounts(accounts).rpc(rpc);
+    await program.methods.completeTodo(new BN(0)).accounts(accounts).rpc(rpc);
+    await program.methods.removeTodo(new BN(2)).accounts(accounts).rpc(rpc);
+    await program.methods.addTodo('celebrate later').accounts(accounts).rpc(rpc);
+
+    await expectError(program.methods.completeTodo(new BN(4)).accounts(accounts).rpc(), 'UnknownTodo', 6001);
+    await expectError(program.methods.addTodo('a'.repeat(281)).accounts(accounts).rpc(), 'TextTooLong', 6000);
+
+    // the account only knows how many ids it handed out
+    expect((await program.account.todoList.fetch(list)).nextId.toNumber()).to.equal(4);
+
+    const todos = await indexTodos(program, list);
+    expect(Object.fromEntries(todos)).to.deep.equal({
+      0: { text: 'write the chapter', done: true },
+      1: { text: 'review the chapter twice', done: false },
+      3: { text: 'celebrate later', done: false },
+    });
+  });
+});
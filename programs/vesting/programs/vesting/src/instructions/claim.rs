@@ -0,0 +1,51 @@
+use crate::errors::VestingError;
+use crate::state::vesting_schedule::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn claim(ctx: Context<Claim>) -> Result<()> {
+    let schedule = &mut ctx.accounts.schedule;
+
+    let amount = schedule.claimable_amount(Clock::get()?.unix_timestamp);
+    require_gt!(amount, 0, VestingError::NothingToClaim);
+    schedule.released_amount += amount;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: schedule.to_account_info(),
+            },
+            &[&[
+                VestingSchedule::SEED_PREFIX,
+                schedule.creator.as_ref(),
+                schedule.beneficiary.as_ref(),
+                schedule.mint.as_ref(),
+                &[schedule.bump],
+            ]],
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut, has_one = beneficiary)]
+    pub schedule: Account<'info, VestingSchedule>,
+    #[account(
+        mut,
+        seeds = [VestingSchedule::VAULT_SEED_PREFIX, schedule.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = schedule.mint,
+        token::authority = beneficiary
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+    pub beneficiary: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
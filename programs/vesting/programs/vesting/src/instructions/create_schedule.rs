@@ -0,0 +1,79 @@
+use crate::state::vesting_schedule::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+pub fn create_schedule(
+    ctx: Context<CreateSchedule>,
+    beneficiary: Pubkey,
+    total_amount: u64,
+    cliff: i64,
+    duration: i64,
+    revocable: bool,
+) -> Result<()> {
+    VestingSchedule::validate(total_amount, cliff, duration)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.schedule.set_inner(VestingSchedule {
+        creator: ctx.accounts.creator.key(),
+        beneficiary,
+        mint: ctx.accounts.mint.key(),
+        total_amount,
+        released_amount: 0,
+        start_ts: now,
+        cliff_ts: now + cliff,
+        end_ts: now + duration,
+        revocable,
+        revoked: false,
+        bump: ctx.bumps.schedule,
+    });
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.creator_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            },
+        ),
+        total_amount,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(beneficiary: Pubkey)]
+pub struct CreateSchedule<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + VestingSchedule::INIT_SPACE,
+        seeds = [
+            VestingSchedule::SEED_PREFIX,
+            creator.key().as_ref(),
+            beneficiary.as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub schedule: Account<'info, VestingSchedule>,
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = schedule,
+        seeds = [VestingSchedule::VAULT_SEED_PREFIX, schedule.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
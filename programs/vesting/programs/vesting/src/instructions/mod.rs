@@ -0,0 +1,7 @@
+pub use claim::*;
+pub use create_schedule::*;
+pub use revoke::*;
+
+pub mod claim;
+pub mod create_schedule;
+pub mod revoke;
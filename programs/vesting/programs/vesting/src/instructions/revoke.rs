@@ -0,0 +1,52 @@
+use crate::state::vesting_schedule::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+    let schedule = &mut ctx.accounts.schedule;
+
+    // what has vested so far stays in the vault for the beneficiary to claim
+    let unvested = schedule.revoke(Clock::get()?.unix_timestamp)?;
+    if unvested == 0 {
+        return Ok(());
+    }
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: schedule.to_account_info(),
+            },
+            &[&[
+                VestingSchedule::SEED_PREFIX,
+                schedule.creator.as_ref(),
+                schedule.beneficiary.as_ref(),
+                schedule.mint.as_ref(),
+                &[schedule.bump],
+            ]],
+        ),
+        unvested,
+    )
+}
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    #[account(mut, has_one = creator)]
+    pub schedule: Account<'info, VestingSchedule>,
+    #[account(
+        mut,
+        seeds = [VestingSchedule::VAULT_SEED_PREFIX, schedule.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = schedule.mint,
+        token::authority = creator
+    )]
+    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
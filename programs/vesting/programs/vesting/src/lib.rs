@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("ZqczMPovUwMvwZDRqDyAvaM6VbKZm1YN8tkJBXaQJ1w");
+
+#[program]
+pub mod vesting {
+    use super::*;
+
+    pub fn create_schedule(
+        ctx: Context<CreateSchedule>,
+        beneficiary: Pubkey,
+        total_amount: u64,
+        cliff: i64,
+        duration: i64,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::create_schedule::create_schedule(
+            ctx,
+            beneficiary,
+            total_amount,
+            cliff,
+            duration,
+            revocable,
+        )
+    }
+
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        instructions::claim::claim(ctx)
+    }
+
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        instructions::revoke::revoke(ctx)
+    }
+}
@@ -0,0 +1,3 @@
+pub use vesting_schedule::*;
+
+pub mod vesting_schedule;
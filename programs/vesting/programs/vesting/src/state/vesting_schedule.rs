@@ -0,0 +1,176 @@
+use crate::errors::VestingError;
+use anchor_lang::prelude::*;
+use common::math;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VestingSchedule {
+    pub creator: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub released_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+    pub revocable: bool,
+    pub revoked: bool,
+    pub bump: u8,
+}
+
+impl VestingSchedule {
+    pub const SEED_PREFIX: &'static [u8] = b"schedule";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+
+    pub fn validate(total_amount: u64, cliff: i64, duration: i64) -> Result<()> {
+        require_gt!(total_amount, 0, VestingError::ZeroAmount);
+        require_gt!(duration, 0, VestingError::InvalidDuration);
+        require!(cliff >= 0, VestingError::InvalidDuration);
+        require_gte!(duration, cliff, VestingError::CliffAfterEnd);
+        Ok(())
+    }
+
+    /// Tokens unlocked at `now`, including the ones already released.
+    /// Unlocking is linear from `start_ts`, but nothing is unlocked before the cliff.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        // revoking freezes the schedule at whatever had vested by then
+        if self.revoked || now >= self.end_ts {
+            return self.total_amount;
+        }
+        if now < self.cliff_ts {
+            return 0;
+        }
+
+        let elapsed = (now - self.start_ts) as u64;
+        let duration = (self.end_ts - self.start_ts) as u64;
+        // rounds down, the remainder is paid out in full at `end_ts`. `elapsed` is shorter
+        // than `duration`, so the result is less than `total_amount` and always fits.
+        math::mul_div(self.total_amount, elapsed, duration).unwrap()
+    }
+
+    pub fn claimable_amount(&self, now: i64) -> u64 {
+        self.vested_amount(now) - self.released_amount
+    }
+
+    /// Stops vesting at `now` and returns the amount that goes back to the creator.
+    pub fn revoke(&mut self, now: i64) -> Result<u64> {
+        require!(self.revocable, VestingError::NotRevocable);
+        require!(!self.revoked, VestingError::AlreadyRevoked);
+
+        let vested = self.vested_amount(now);
+        let unvested = self.total_amount - vested;
+        self.total_amount = vested;
+        self.revoked = true;
+        Ok(unvested)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const START: i64 = 1_000;
+
+    fn schedule(total_amount: u64, cliff: i64, duration: i64) -> VestingSchedule {
+        VestingSchedule::validate(total_amount, cliff, duration).unwrap();
+        VestingSchedule {
+            creator: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            mint: Pubkey::default(),
+            total_amount,
+            released_amount: 0,
+            start_ts: START,
+            cliff_ts: START + cliff,
+            end_ts: START + duration,
+            revocable: true,
+            revoked: false,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let schedule = schedule(1_000, 100, 1_000);
+        assert_eq!(schedule.vested_amount(START - 1), 0);
+        assert_eq!(schedule.vested_amount(START), 0);
+        assert_eq!(schedule.vested_amount(START + 99), 0);
+    }
+
+    #[test]
+    fn cliff_releases_everything_accrued_since_start() {
+        let schedule = schedule(1_000, 100, 1_000);
+        assert_eq!(schedule.vested_amount(START + 100), 100);
+        assert_eq!(schedule.vested_amount(START + 101), 101);
+    }
+
+    #[test]
+    fn zero_cliff_vests_linearly_from_start() {
+        let schedule = schedule(1_000, 0, 1_000);
+        assert_eq!(schedule.vested_amount(START), 0);
+        assert_eq!(schedule.vested_amount(START + 1), 1);
+        assert_eq!(schedule.vested_amount(START + 500), 500);
+    }
+
+    #[test]
+    fn cliff_at_end_is_a_single_unlock() {
+        let schedule = schedule(1_000, 1_000, 1_000);
+        assert_eq!(schedule.vested_amount(START + 999), 0);
+        assert_eq!(schedule.vested_amount(START + 1_000), 1_000);
+    }
+
+    #[test]
+    fn rounds_down_until_the_final_second() {
+        let schedule = schedule(10, 0, 3);
+        assert_eq!(schedule.vested_amount(START + 1), 3);
+        assert_eq!(schedule.vested_amount(START + 2), 6);
+        assert_eq!(schedule.vested_amount(START + 3), 10);
+        assert_eq!(schedule.vested_amount(i64::MAX), 10);
+    }
+
+    #[test]
+    fn large_amounts_do_not_overflow() {
+        let schedule = schedule(u64::MAX, 0, 1 << 40);
+        assert_eq!(schedule.vested_amount(START + (1 << 39)), u64::MAX / 2);
+    }
+
+    #[test]
+    fn claimable_excludes_released_tokens() {
+        let mut schedule = schedule(1_000, 0, 1_000);
+        schedule.released_amount = schedule.claimable_amount(START + 250);
+        assert_eq!(schedule.released_amount, 250);
+        assert_eq!(schedule.claimable_amount(START + 250), 0);
+        assert_eq!(schedule.claimable_amount(START + 1_000), 750);
+    }
+
+    #[test]
+    fn revoking_freezes_the_vested_amount() {
+        let mut schedule = schedule(1_000, 100, 1_000);
+        assert_eq!(schedule.revoke(START + 400).unwrap(), 600);
+        assert_eq!(schedule.vested_amount(START + 400), 400);
+        assert_eq!(schedule.vested_amount(START + 2_000), 400);
+        assert!(schedule.revoke(START + 500).is_err());
+    }
+
+    #[test]
+    fn revoking_before_the_cliff_returns_everything() {
+        let mut schedule = schedule(1_000, 100, 1_000);
+        assert_eq!(schedule.revoke(START + 50).unwrap(), 1_000);
+        assert_eq!(schedule.claimable_amount(START + 2_000), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(VestingSchedule::validate(0, 0, 10).is_err());
+        assert!(VestingSchedule::validate(10, 0, 0).is_err());
+        assert!(VestingSchedule::validate(10, -1, 10).is_err());
+        assert!(VestingSchedule::validate(10, 11, 10).is_err());
+        assert!(VestingSchedule::validate(10, 10, 10).is_ok());
+    }
+
+    #[test]
+    fn irrevocable_schedules_cannot_be_revoked() {
+        let mut schedule = schedule(10, 0, 10);
+        schedule.revocable = false;
+        assert!(schedule.revoke(START).is_err());
+    }
+}
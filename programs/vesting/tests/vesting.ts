@@ -0,0 +1,173 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Vesting } from '../../../target/types/vesting';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('vesting', () => {
+  let harness: Harness;
+  let program: Program<Vesting>;
+  let payer: anchor.web3.Keypair;
+  // the provider wallet creates the schedules
+  let creator: anchor.web3.Keypair;
+
+  let mint: anchor.web3.PublicKey;
+  let creatorTokenAccount: anchor.web3.PublicKey;
+
+  async function createSchedule(totalAmount: number, cliff: number, duration: number, revocable: boolean) {
+    const beneficiary = anchor.web3.Keypair.generate();
+    const beneficiaryTokenAccount = await harness.createTokenAccount(mint, beneficiary.publicKey);
+    const schedule = findAddress(
+      [Buffer.from('schedule'), creator.publicKey.toBuffer(), beneficiary.publicKey.toBuffer(), mint.toBuffer()],
+      program.programId
+    );
+    const vault = findAddress([Buffer.from('vault'), schedule.toBuffer()], program.programId);
+
+    await program.methods
+      .createSchedule(beneficiary.publicKey, new BN(totalAmount), new BN(cliff), new BN(duration), revocable)
+      .accounts({
+        schedule,
+        vault,
+        mint,
+        creatorTokenAccount,
+        creator: creator.publicKey,
+      })
+      .rpc();
+
+    return { beneficiary, beneficiaryTokenAccount, schedule, vault };
+  }
+
+  async function claim({ beneficiary, beneficiaryTokenAccount, schedule, vault }) {
+    await program.methods
+      .claim()
+      .accounts({
+        schedule,
+        vault,
+        beneficiaryTokenAccount,
+        beneficiary: beneficiary.publicKey,
+      })
+      .signers([beneficiary])
+      .rpc();
+  }
+
+  async function revoke({ schedule, vault }) {
+    await program.methods
+      .revoke()
+      .accounts({
+        schedule,
+        vault,
+        creatorTokenAccount,
+        creator: creator.publicKey,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Vesting>('vesting');
+    payer = harness.payer;
+    creator = harness.payer;
+
+    mint = await harness.createMint(payer.publicKey);
+    creatorTokenAccount = await harness.createTokenAccount(mint, creator.publicKey, 1_000_000);
+  });
+
+  it('create schedule and claim after the cliff!', async () => {
+    const vesting = await createSchedule(1_000, 2, 4, false);
+
+    const scheduleState = await program.account.vestingSchedule.fetch(vesting.schedule);
+    expect(scheduleState.beneficiary).to.eql(vesting.beneficiary.publicKey);
+    expect(scheduleState.totalAmount.toNumber()).to.equal(1_000);
+    expect(scheduleState.cliffTs.sub(scheduleState.startTs).toNumber()).to.equal(2);
+    expect(scheduleState.endTs.sub(scheduleState.startTs).toNumber()).to.equal(4);
+    expect(await harness.tokenBalance(vesting.vault)).to.equal(1_000);
+
+    try {
+      await claim(vesting);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NothingToClaim');
+      expect(err.error.errorCode.number).to.equal(6003);
+      expect(err.program.equals(program.programId)).is.true;
+    }
+
+    await harness.warp(5);
+    await claim(vesting);
+
+    expect(await harness.tokenBalance(vesting.beneficiaryTokenAccount)).to.equal(1_000);
+    expect(await harness.tokenBalance(vesting.vault)).to.equal(0);
+    expect((await program.account.vestingSchedule.fetch(vesting.schedule)).releasedAmount.toNumber()).to.equal(1_000);
+  });
+
+  it('only the beneficiary can claim', async () => {
+    const vesting = await createSchedule(100, 0, 1, false);
+    const thief = anchor.web3.Keypair.generate();
+    const thiefTokenAccount = await harness.createTokenAccount(mint, thief.publicKey);
+    await harness.warp(2);
+
+    try {
+      await claim({ ...vesting, beneficiary: thief, beneficiaryTokenAccount: thiefTokenAccount });
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('ConstraintHasOne');
+    }
+  });
+
+  it('revoke returns the unvested tokens', async () => {
+    const vesting = await createSchedule(1_000, 100, 1_000, true);
+    const creatorBalance = await harness.tokenBalance(creatorTokenAccount);
+
+    // still before the cliff, so nothing has vested yet
+    await revoke(vesting);
+
+    const scheduleState = await program.account.vestingSchedule.fetch(vesting.schedule);
+    expect(scheduleState.revoked).is.true;
+    expect(scheduleState.totalAmount.toNumber()).to.equal(0);
+    expect(await harness.tokenBalance(creatorTokenAccount)).to.equal(creatorBalance + 1_000);
+
+    try {
+      await revoke(vesting);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('AlreadyRevoked');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+  });
+
+  it('irrevocable schedules stay put', async () => {
+    const vesting = await createSchedule(1_000, 0, 1_000, false);
+
+    try {
+      await revoke(vesting);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('NotRevocable');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+  });
+
+  it('cliff after the end is rejected', async () => {
+    try {
+      await createSchedule(1_000, 11, 10, false);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('CliffAfterEnd');
+      expect(err.error.errorCode.number).to.equal(6002);
+    }
+  });
+});
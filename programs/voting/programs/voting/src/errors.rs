@@ -0,0 +1,13 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum VotingError {
+    TitleTooLong,
+    DescriptionTooLong,
+    InvalidVotingPeriod,
+    InvalidProposalState,
+    VotingClosed,
+    VotingStillOpen,
+    ZeroAmount,
+    NotEnoughTokens,
+}
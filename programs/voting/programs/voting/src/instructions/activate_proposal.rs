@@ -0,0 +1,16 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
+    ctx.accounts
+        .proposal
+        .activate(Clock::get()?.unix_timestamp, ctx.accounts.dao.voting_period)
+}
+
+#[derive(Accounts)]
+pub struct ActivateProposal<'info> {
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    pub proposer: Signer<'info>,
+}
@@ -0,0 +1,66 @@
+use crate::errors::VotingError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn cast_vote(ctx: Context<CastVote>, side: Side, amount: u64) -> Result<()> {
+    require_gt!(amount, 0, VotingError::ZeroAmount);
+
+    ctx.accounts
+        .proposal
+        .vote(side, amount, Clock::get()?.unix_timestamp)?;
+
+    ctx.accounts.vote_record.set_inner(VoteRecord {
+        proposal: ctx.accounts.proposal.key(),
+        voter: ctx.accounts.voter.key(),
+        side,
+        weight: amount,
+        bump: ctx.bumps.vote_record,
+    });
+
+    // locking the tokens is what stops them from being moved to another wallet
+    // and counted a second time
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.voter_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+    // one vote per voter and proposal, a second `init` of the same address fails
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [VoteRecord::SEED_PREFIX, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        mut,
+        seeds = [Proposal::VAULT_SEED_PREFIX, proposal.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = dao.governance_mint,
+        token::authority = voter
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,82 @@
+use crate::errors::VotingError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+pub fn create_proposal(
+    ctx: Context<CreateProposal>,
+    title: String,
+    description: String,
+) -> Result<()> {
+    require!(
+        title.len() <= Proposal::MAXIMUM_TITLE_LENGTH,
+        VotingError::TitleTooLong
+    );
+    require!(
+        description.len() <= Proposal::MAXIMUM_DESCRIPTION_LENGTH,
+        VotingError::DescriptionTooLong
+    );
+    require_gt!(
+        ctx.accounts.proposer_token_account.amount,
+        0,
+        VotingError::NotEnoughTokens
+    );
+
+    let dao = &mut ctx.accounts.dao;
+    ctx.accounts.proposal.set_inner(Proposal {
+        dao: dao.key(),
+        proposer: ctx.accounts.proposer.key(),
+        id: dao.proposal_count,
+        title,
+        description,
+        state: ProposalState::Draft,
+        yes_votes: 0,
+        no_votes: 0,
+        voting_starts_at: 0,
+        voting_ends_at: 0,
+        bump: ctx.bumps.proposal,
+    });
+    dao.proposal_count += 1;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        has_one = governance_mint,
+        seeds = [Dao::SEED_PREFIX, governance_mint.key().as_ref()],
+        bump = dao.bump
+    )]
+    pub dao: Account<'info, Dao>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [Proposal::SEED_PREFIX, dao.key().as_ref(), dao.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    // locked votes are held here until the voter relinquishes them
+    #[account(
+        init,
+        payer = proposer,
+        token::mint = governance_mint,
+        token::authority = proposal,
+        seeds = [Proposal::VAULT_SEED_PREFIX, proposal.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    pub governance_mint: Account<'info, Mint>,
+    // only token holders may put proposals up for a vote
+    #[account(
+        token::mint = governance_mint,
+        token::authority = proposer
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,15 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    // a full governance program would store instructions on the proposal
+    // and invoke them here, signed by a dao-owned PDA
+    ctx.accounts.proposal.execute()
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, has_one = proposer)]
+    pub proposal: Account<'info, Proposal>,
+    pub proposer: Signer<'info>,
+}
@@ -0,0 +1,16 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+    ctx.accounts
+        .proposal
+        .finalize(Clock::get()?.unix_timestamp, ctx.accounts.dao.quorum_votes)
+}
+
+// permissionless, anyone can close the vote once the voting period is over
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    pub dao: Account<'info, Dao>,
+    #[account(mut, has_one = dao)]
+    pub proposal: Account<'info, Proposal>,
+}
@@ -0,0 +1,38 @@
+use crate::errors::VotingError;
+use crate::state::dao::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+pub fn initialize_dao(
+    ctx: Context<InitializeDao>,
+    quorum_votes: u64,
+    voting_period: i64,
+) -> Result<()> {
+    require_gt!(voting_period, 0, VotingError::InvalidVotingPeriod);
+
+    ctx.accounts.dao.set_inner(Dao {
+        authority: ctx.accounts.authority.key(),
+        governance_mint: ctx.accounts.governance_mint.key(),
+        quorum_votes,
+        voting_period,
+        proposal_count: 0,
+        bump: ctx.bumps.dao,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeDao<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Dao::INIT_SPACE,
+        seeds = [Dao::SEED_PREFIX, governance_mint.key().as_ref()],
+        bump
+    )]
+    pub dao: Account<'info, Dao>,
+    pub governance_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,15 @@
+pub use activate_proposal::*;
+pub use cast_vote::*;
+pub use create_proposal::*;
+pub use execute_proposal::*;
+pub use finalize_proposal::*;
+pub use initialize_dao::*;
+pub use relinquish_vote::*;
+
+pub mod activate_proposal;
+pub mod cast_vote;
+pub mod create_proposal;
+pub mod execute_proposal;
+pub mod finalize_proposal;
+pub mod initialize_dao;
+pub mod relinquish_vote;
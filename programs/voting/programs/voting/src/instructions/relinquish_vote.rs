@@ -0,0 +1,58 @@
+use crate::errors::VotingError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+pub fn relinquish_vote(ctx: Context<RelinquishVote>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        !proposal.is_voting_open(Clock::get()?.unix_timestamp),
+        VotingError::VotingStillOpen
+    );
+
+    let id_bytes = proposal.id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        Proposal::SEED_PREFIX,
+        proposal.dao.as_ref(),
+        id_bytes.as_ref(),
+        &[proposal.bump],
+    ]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: ctx.accounts.proposal.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.vote_record.weight,
+    )
+}
+
+#[derive(Accounts)]
+pub struct RelinquishVote<'info> {
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        mut,
+        close = voter,
+        has_one = proposal,
+        has_one = voter,
+        seeds = [VoteRecord::SEED_PREFIX, proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        mut,
+        seeds = [Proposal::VAULT_SEED_PREFIX, proposal.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::authority = voter)]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use instructions::*;
+use state::vote_record::Side;
+
+pub mod errors;
+pub mod instructions;
+pub mod state;
+
+// this key needs to be changed to whatever public key is returned by "anchor keys list"
+declare_id!("E1JYijNBqfFZaDpCzWMC7MYpNzS9N6LBLLMExPGr4ihp");
+
+#[program]
+pub mod voting {
+    use super::*;
+
+    pub fn initialize_dao(
+        ctx: Context<InitializeDao>,
+        quorum_votes: u64,
+        voting_period: i64,
+    ) -> Result<()> {
+        instructions::initialize_dao::initialize_dao(ctx, quorum_votes, voting_period)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        title: String,
+        description: String,
+    ) -> Result<()> {
+        instructions::create_proposal::create_proposal(ctx, title, description)
+    }
+
+    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
+        instructions::activate_proposal::activate_proposal(ctx)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, side: Side, amount: u64) -> Result<()> {
+        instructions::cast_vote::cast_vote(ctx, side, amount)
+    }
+
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        instructions::finalize_proposal::finalize_proposal(ctx)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::execute_proposal(ctx)
+    }
+
+    pub fn relinquish_vote(ctx: Context<RelinquishVote>) -> Result<()> {
+        instructions::relinquish_vote::relinquish_vote(ctx)
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Dao {
+    pub authority: Pubkey,
+    pub governance_mint: Pubkey,
+    // minimum number of votes (yes + no) a proposal needs to be valid
+    pub quorum_votes: u64,
+    // in seconds
+    pub voting_period: i64,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl Dao {
+    pub const SEED_PREFIX: &'static [u8] = b"dao";
+}
@@ -0,0 +1,7 @@
+pub use dao::*;
+pub use proposal::*;
+pub use vote_record::*;
+
+pub mod dao;
+pub mod proposal;
+pub mod vote_record;
@@ -0,0 +1,89 @@
+use crate::errors::VotingError;
+use crate::state::vote_record::Side;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub dao: Pubkey,
+    pub proposer: Pubkey,
+    pub id: u64,
+    #[max_len(64)]
+    pub title: String,
+    #[max_len(256)]
+    pub description: String,
+    pub state: ProposalState,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub voting_starts_at: i64,
+    pub voting_ends_at: i64,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const SEED_PREFIX: &'static [u8] = b"proposal";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"vault";
+    pub const MAXIMUM_TITLE_LENGTH: usize = 64;
+    pub const MAXIMUM_DESCRIPTION_LENGTH: usize = 256;
+
+    pub fn activate(&mut self, now: i64, voting_period: i64) -> Result<()> {
+        require!(
+            self.state == ProposalState::Draft,
+            VotingError::InvalidProposalState
+        );
+        self.state = ProposalState::Active;
+        self.voting_starts_at = now;
+        self.voting_ends_at = now + voting_period;
+        Ok(())
+    }
+
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        self.state == ProposalState::Active && now < self.voting_ends_at
+    }
+
+    pub fn vote(&mut self, side: Side, amount: u64, now: i64) -> Result<()> {
+        require!(self.is_voting_open(now), VotingError::VotingClosed);
+
+        let tally = match side {
+            Side::Yes => &mut self.yes_votes,
+            Side::No => &mut self.no_votes,
+        };
+        // the vault can never hold more than the mint's supply, which fits into a u64
+        *tally += amount;
+        Ok(())
+    }
+
+    pub fn finalize(&mut self, now: i64, quorum_votes: u64) -> Result<()> {
+        require!(
+            self.state == ProposalState::Active,
+            VotingError::InvalidProposalState
+        );
+        require!(now >= self.voting_ends_at, VotingError::VotingStillOpen);
+
+        let quorum_reached = self.yes_votes + self.no_votes >= quorum_votes;
+        self.state = if quorum_reached && self.yes_votes > self.no_votes {
+            ProposalState::Succeeded
+        } else {
+            ProposalState::Defeated
+        };
+        Ok(())
+    }
+
+    pub fn execute(&mut self) -> Result<()> {
+        require!(
+            self.state == ProposalState::Succeeded,
+            VotingError::InvalidProposalState
+        );
+        self.state = ProposalState::Executed;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalState {
+    Draft,
+    Active,
+    Succeeded,
+    Defeated,
+    Executed,
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub side: Side,
+    // the number of tokens locked in the proposal's vault
+    pub weight: u64,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const SEED_PREFIX: &'static [u8] = b"vote";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Yes,
+    No,
+}
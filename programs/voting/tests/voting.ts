@@ -0,0 +1,225 @@
+import * as anchor from '@coral-xyz/anchor';
+import { AnchorError, Program } from '@coral-xyz/anchor';
+import { Harness, findAddress } from 'test-harness';
+import { Voting } from '../../../target/types/voting';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { expect } from 'chai';
+chai.use(chaiAsPromised);
+
+const { BN } = anchor;
+
+describe('voting', () => {
+  let harness: Harness;
+  let program: Program<Voting>;
+  let payer: anchor.web3.Keypair;
+
+  async function newVoter(governanceMint: anchor.web3.PublicKey, tokens: number) {
+    const voter = await harness.fundedKeypair(anchor.web3.LAMPORTS_PER_SOL);
+    const tokenAccount = await harness.createTokenAccount(governanceMint, voter.publicKey, tokens);
+    return { voter, tokenAccount };
+  }
+
+  async function setupDao(quorumVotes: number, votingPeriod: number) {
+    const governanceMint = await harness.createMint(payer.publicKey);
+    const dao = findAddress([Buffer.from('dao'), governanceMint.toBuffer()], program.programId);
+    await program.methods
+      .initializeDao(new BN(quorumVotes), new BN(votingPeriod))
+      .accounts({
+        dao,
+        governanceMint,
+        authority: payer.publicKey,
+      })
+      .rpc();
+    return { dao, governanceMint };
+  }
+
+  async function createProposal(
+    dao: anchor.web3.PublicKey,
+    governanceMint: anchor.web3.PublicKey,
+    proposer: anchor.web3.Keypair,
+    proposerTokenAccount: anchor.web3.PublicKey,
+    title = 'Fund the book',
+    description = 'Pay the authors of the next chapter.'
+  ) {
+    const { proposalCount } = await program.account.dao.fetch(dao);
+    const proposal = findAddress(
+      [Buffer.from('proposal'), dao.toBuffer(), proposalCount.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const vault = findAddress([Buffer.from('vault'), proposal.toBuffer()], program.programId);
+
+    await program.methods
+      .createProposal(title, description)
+      .accounts({
+        dao,
+        proposal,
+        vault,
+        governanceMint,
+        proposerTokenAccount,
+        proposer: proposer.publicKey,
+      })
+      .signers([proposer])
+      .rpc();
+
+    await program.methods
+      .activateProposal()
+      .accounts({ dao, proposal, proposer: proposer.publicKey })
+      .signers([proposer])
+      .rpc();
+
+    return { proposal, vault };
+  }
+
+  async function castVote(
+    dao: anchor.web3.PublicKey,
+    proposal: anchor.web3.PublicKey,
+    vault: anchor.web3.PublicKey,
+    { voter, tokenAccount },
+    side: object,
+    amount: number
+  ) {
+    await program.methods
+      .castVote(side as any, new BN(amount))
+      .accounts({
+        dao,
+        proposal,
+        voteRecord: findAddress(
+          [Buffer.from('vote'), proposal.toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        ),
+        vault,
+        voterTokenAccount: tokenAccount,
+        voter: voter.publicKey,
+      })
+      .signers([voter])
+      .rpc();
+  }
+
+  before(async () => {
+    harness = await Harness.start();
+    program = harness.program<Voting>('voting');
+    payer = harness.payer;
+  });
+
+  it('proposal succeeds and is executed!', async () => {
+    const { dao, governanceMint } = await setupDao(100, 2);
+    const alice = await newVoter(governanceMint, 100);
+    const bob = await newVoter(governanceMint, 50);
+
+    const { proposal, vault } = await createProposal(dao, governanceMint, alice.voter, alice.tokenAccount);
+    let proposalState = await program.account.proposal.fetch(proposal);
+    expect(proposalState.state).to.eql({ active: {} });
+    expect(proposalState.title).to.equal('Fund the book');
+    expect(proposalState.votingEndsAt.sub(proposalState.votingStartsAt).toNumber()).to.equal(2);
+
+    await castVote(dao, proposal, vault, alice, { yes: {} }, 100);
+    await castVote(dao, proposal, vault, bob, { no: {} }, 50);
+
+    proposalState = await program.account.proposal.fetch(proposal);
+    expect(proposalState.yesVotes.toNumber()).to.equal(100);
+    expect(proposalState.noVotes.toNumber()).to.equal(50);
+    expect(await harness.tokenBalance(vault)).to.equal(150);
+
+    try {
+      await program.methods.finalizeProposal().accounts({ dao, proposal }).rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('VotingStillOpen');
+      expect(err.error.errorCode.number).to.equal(6005);
+    }
+
+    await harness.warp(3);
+    await program.methods.finalizeProposal().accounts({ dao, proposal }).rpc();
+    expect((await program.account.proposal.fetch(proposal)).state).to.eql({ succeeded: {} });
+
+    await program.methods
+      .executeProposal()
+      .accounts({ proposal, proposer: alice.voter.publicKey })
+      .signers([alice.voter])
+      .rpc();
+    expect((await program.account.proposal.fetch(proposal)).state).to.eql({ executed: {} });
+
+    // votes are unlocked once voting is over
+    const voteRecord = findAddress(
+      [Buffer.from('vote'), proposal.toBuffer(), bob.voter.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .relinquishVote()
+      .accounts({
+        proposal,
+        voteRecord,
+        vault,
+        voterTokenAccount: bob.tokenAccount,
+        voter: bob.voter.publicKey,
+      })
+      .signers([bob.voter])
+      .rpc();
+    expect(await harness.tokenBalance(bob.tokenAccount)).to.equal(50);
+    expect(await harness.account(voteRecord)).to.be.null;
+  });
+
+  it('proposal without quorum is defeated', async () => {
+    const { dao, governanceMint } = await setupDao(1_000, 1);
+    const alice = await newVoter(governanceMint, 100);
+    const { proposal, vault } = await createProposal(dao, governanceMint, alice.voter, alice.tokenAccount);
+
+    await castVote(dao, proposal, vault, alice, { yes: {} }, 100);
+    await harness.warp(2);
+    await program.methods.finalizeProposal().accounts({ dao, proposal }).rpc();
+    expect((await program.account.proposal.fetch(proposal)).state).to.eql({ defeated: {} });
+
+    try {
+      await program.methods
+        .executeProposal()
+        .accounts({ proposal, proposer: alice.voter.publicKey })
+        .signers([alice.voter])
+        .rpc();
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('InvalidProposalState');
+    }
+  });
+
+  it('cannot vote twice or after the deadline', async () => {
+    const { dao, governanceMint } = await setupDao(1, 2);
+    const alice = await newVoter(governanceMint, 100);
+    const { proposal, vault } = await createProposal(dao, governanceMint, alice.voter, alice.tokenAccount);
+
+    await castVote(dao, proposal, vault, alice, { yes: {} }, 10);
+    // the vote record already exists, so its `init` fails
+    await expect(castVote(dao, proposal, vault, alice, { yes: {} }, 10)).to.be.rejected;
+
+    const bob = await newVoter(governanceMint, 100);
+    await harness.warp(3);
+    try {
+      await castVote(dao, proposal, vault, bob, { no: {} }, 100);
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('VotingClosed');
+      expect(err.error.errorCode.number).to.equal(6004);
+    }
+  });
+
+  it('title too long', async () => {
+    const { dao, governanceMint } = await setupDao(1, 10);
+    const alice = await newVoter(governanceMint, 1);
+
+    try {
+      await createProposal(dao, governanceMint, alice.voter, alice.tokenAccount, 'a'.repeat(65));
+      chai.assert(false, "should've failed but didn't ");
+    } catch (_err) {
+      expect(_err).to.be.instanceOf(AnchorError);
+      const err: AnchorError = _err;
+      expect(err.error.errorCode.code).to.equal('TitleTooLong');
+      expect(err.error.errorCode.number).to.equal(6000);
+    }
+  });
+});